@@ -52,6 +52,8 @@ class CellControllerBuilder {
           cellDataLoader: cellDataLoader,
           cellDataPersistence: DateCellDataPersistence(cellId: _cellId),
         );
+      case FieldType.Duration:
+      case FieldType.Rating:
       case FieldType.Number:
         final cellDataLoader = CellDataLoader(
           cellId: _cellId,
@@ -64,6 +66,12 @@ class CellControllerBuilder {
           cellDataLoader: cellDataLoader,
           cellDataPersistence: TextCellDataPersistence(cellId: _cellId),
         );
+      case FieldType.Concat:
+      case FieldType.Order:
+      case FieldType.IpAddress:
+      case FieldType.Json:
+      case FieldType.Email:
+      case FieldType.Phone:
       case FieldType.RichText:
         final cellDataLoader = CellDataLoader(
           cellId: _cellId,
@@ -102,6 +110,17 @@ class CellControllerBuilder {
           cellDataLoader: cellDataLoader,
           cellDataPersistence: TextCellDataPersistence(cellId: _cellId),
         );
+      case FieldType.Relation:
+        final cellDataLoader = CellDataLoader(
+          cellId: _cellId,
+          parser: RelationCellDataParser(),
+        );
+        return TextCellController(
+          cellId: _cellId,
+          cellCache: _cellCache,
+          cellDataLoader: cellDataLoader,
+          cellDataPersistence: TextCellDataPersistence(cellId: _cellId),
+        );
     }
     throw UnimplementedError;
   }
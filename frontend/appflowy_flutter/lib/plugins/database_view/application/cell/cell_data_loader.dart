@@ -93,3 +93,15 @@ class URLCellDataParser implements CellDataParser<URLCellDataPB> {
     return URLCellDataPB.fromBuffer(data);
   }
 }
+
+/// The relation cell is shown as the ids of the linked rows, separated by
+/// commas.
+class RelationCellDataParser implements CellDataParser<String> {
+  @override
+  String? parserData(List<int> data) {
+    if (data.isEmpty) {
+      return null;
+    }
+    return RelationCellDataPB.fromBuffer(data).rowIds.join(", ");
+  }
+}
@@ -59,6 +59,8 @@ extension NumberFormatExtension on NumberFormat {
         return "Chilean peso";
       case NumberFormat.ColombianPeso:
         return "Colombian peso";
+      case NumberFormat.Currency:
+        return "Currency";
       case NumberFormat.DanishKrone:
         return "Danish krone";
       case NumberFormat.Dirham:
@@ -67,6 +69,8 @@ extension NumberFormatExtension on NumberFormat {
         return "Euro";
       case NumberFormat.Forint:
         return "Forint";
+      case NumberFormat.FractionalPercent:
+        return "Fractional percent";
       case NumberFormat.Franc:
         return "Franc";
       case NumberFormat.HongKongDollar:
@@ -89,6 +93,8 @@ extension NumberFormatExtension on NumberFormat {
         return "Norwegian krone";
       case NumberFormat.Num:
         return "Number";
+      case NumberFormat.Ordinal:
+        return "Ordinal";
       case NumberFormat.Percent:
         return "Percent";
       case NumberFormat.PhilippinePeso:
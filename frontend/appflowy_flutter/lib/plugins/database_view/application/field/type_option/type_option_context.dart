@@ -2,8 +2,17 @@ import 'package:appflowy_backend/dispatch/dispatch.dart';
 import 'package:appflowy_backend/protobuf/flowy-error/errors.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/checkbox_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/checklist_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/concat_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/date_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/duration_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/email_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/field_entities.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/ip_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/json_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/order_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/phone_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/rating_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/relation_type_option.pb.dart';
 import 'package:dartz/dartz.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/multi_select_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/number_type_option.pb.dart';
@@ -106,6 +115,104 @@ class ChecklistTypeOptionWidgetDataParser
   }
 }
 
+// Concatenate
+typedef ConcatTypeOptionContext = TypeOptionContext<ConcatTypeOptionPB>;
+
+class ConcatTypeOptionWidgetDataParser
+    extends TypeOptionParser<ConcatTypeOptionPB> {
+  @override
+  ConcatTypeOptionPB fromBuffer(List<int> buffer) {
+    return ConcatTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
+// Order
+typedef OrderTypeOptionContext = TypeOptionContext<OrderTypeOptionPB>;
+
+class OrderTypeOptionWidgetDataParser
+    extends TypeOptionParser<OrderTypeOptionPB> {
+  @override
+  OrderTypeOptionPB fromBuffer(List<int> buffer) {
+    return OrderTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
+// Relation
+typedef RelationTypeOptionContext = TypeOptionContext<RelationTypeOptionPB>;
+
+class RelationTypeOptionWidgetDataParser
+    extends TypeOptionParser<RelationTypeOptionPB> {
+  @override
+  RelationTypeOptionPB fromBuffer(List<int> buffer) {
+    return RelationTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
+// Duration
+typedef DurationTypeOptionContext = TypeOptionContext<DurationTypeOptionPB>;
+
+class DurationTypeOptionWidgetDataParser
+    extends TypeOptionParser<DurationTypeOptionPB> {
+  @override
+  DurationTypeOptionPB fromBuffer(List<int> buffer) {
+    return DurationTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
+// Rating
+typedef RatingTypeOptionContext = TypeOptionContext<RatingTypeOptionPB>;
+
+class RatingTypeOptionWidgetDataParser
+    extends TypeOptionParser<RatingTypeOptionPB> {
+  @override
+  RatingTypeOptionPB fromBuffer(List<int> buffer) {
+    return RatingTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
+// IP address
+typedef IpTypeOptionContext = TypeOptionContext<IpTypeOptionPB>;
+
+class IpTypeOptionWidgetDataParser extends TypeOptionParser<IpTypeOptionPB> {
+  @override
+  IpTypeOptionPB fromBuffer(List<int> buffer) {
+    return IpTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
+// JSON
+typedef JsonTypeOptionContext = TypeOptionContext<JsonTypeOptionPB>;
+
+class JsonTypeOptionWidgetDataParser
+    extends TypeOptionParser<JsonTypeOptionPB> {
+  @override
+  JsonTypeOptionPB fromBuffer(List<int> buffer) {
+    return JsonTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
+// Email
+typedef EmailTypeOptionContext = TypeOptionContext<EmailTypeOptionPB>;
+
+class EmailTypeOptionWidgetDataParser
+    extends TypeOptionParser<EmailTypeOptionPB> {
+  @override
+  EmailTypeOptionPB fromBuffer(List<int> buffer) {
+    return EmailTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
+// Phone
+typedef PhoneTypeOptionContext = TypeOptionContext<PhoneTypeOptionPB>;
+
+class PhoneTypeOptionWidgetDataParser
+    extends TypeOptionParser<PhoneTypeOptionPB> {
+  @override
+  PhoneTypeOptionPB fromBuffer(List<int> buffer) {
+    return PhoneTypeOptionPB.fromBuffer(buffer);
+  }
+}
+
 class TypeOptionContext<T extends GeneratedMessage> {
   T? _typeOptionObject;
   final TypeOptionParser<T> dataParser;
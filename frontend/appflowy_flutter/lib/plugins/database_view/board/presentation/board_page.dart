@@ -375,6 +375,24 @@ Widget? _buildHeaderIcon(GroupData customData) {
       break;
     case FieldType.Checklist:
       break;
+    case FieldType.Concat:
+      break;
+    case FieldType.Order:
+      break;
+    case FieldType.Relation:
+      break;
+    case FieldType.Duration:
+      break;
+    case FieldType.Rating:
+      break;
+    case FieldType.IpAddress:
+      break;
+    case FieldType.Json:
+      break;
+    case FieldType.Email:
+      break;
+    case FieldType.Phone:
+      break;
   }
 
   if (widget != null) {
@@ -166,6 +166,8 @@ extension ChecklistFilterConditionPBExtension on ChecklistFilterConditionPB {
         return LocaleKeys.grid_checklistFilter_isComplete.tr();
       case ChecklistFilterConditionPB.IsIncomplete:
         return LocaleKeys.grid_checklistFilter_isIncomplted.tr();
+      case ChecklistFilterConditionPB.PercentAtLeast:
+        return LocaleKeys.grid_checklistFilter_percentAtLeast.tr();
       default:
         return "";
     }
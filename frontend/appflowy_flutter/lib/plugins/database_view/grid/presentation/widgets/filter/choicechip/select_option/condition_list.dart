@@ -96,6 +96,14 @@ extension SelectOptionConditionPBExtension on SelectOptionConditionPB {
         return LocaleKeys.grid_singleSelectOptionFilter_isNot.tr();
       case SelectOptionConditionPB.OptionIsNotEmpty:
         return LocaleKeys.grid_singleSelectOptionFilter_isNotEmpty.tr();
+      case SelectOptionConditionPB.OptionInRange:
+        return LocaleKeys.grid_singleSelectOptionFilter_isInRange.tr();
+      case SelectOptionConditionPB.OptionContainsAll:
+        return LocaleKeys.grid_singleSelectOptionFilter_isAllOf.tr();
+      case SelectOptionConditionPB.OptionContainsAny:
+        return LocaleKeys.grid_singleSelectOptionFilter_isAnyOf.tr();
+      case SelectOptionConditionPB.OptionIsExactly:
+        return LocaleKeys.grid_singleSelectOptionFilter_isExactly.tr();
       default:
         return "";
     }
@@ -111,6 +119,14 @@ extension SelectOptionConditionPBExtension on SelectOptionConditionPB {
         return LocaleKeys.grid_multiSelectOptionFilter_doesNotContain.tr();
       case SelectOptionConditionPB.OptionIsNotEmpty:
         return LocaleKeys.grid_multiSelectOptionFilter_isNotEmpty.tr();
+      case SelectOptionConditionPB.OptionInRange:
+        return LocaleKeys.grid_multiSelectOptionFilter_isInRange.tr();
+      case SelectOptionConditionPB.OptionContainsAll:
+        return LocaleKeys.grid_multiSelectOptionFilter_containsAll.tr();
+      case SelectOptionConditionPB.OptionContainsAny:
+        return LocaleKeys.grid_multiSelectOptionFilter_containsAny.tr();
+      case SelectOptionConditionPB.OptionIsExactly:
+        return LocaleKeys.grid_multiSelectOptionFilter_isExactly.tr();
       default:
         return "";
     }
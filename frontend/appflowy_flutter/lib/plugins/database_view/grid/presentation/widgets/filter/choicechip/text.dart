@@ -242,6 +242,10 @@ extension TextFilterConditionPBExtension on TextFilterConditionPB {
         return LocaleKeys.grid_textFilter_isEmpty.tr();
       case TextFilterConditionPB.TextIsNotEmpty:
         return LocaleKeys.grid_textFilter_isNotEmpty.tr();
+      case TextFilterConditionPB.Matches:
+        return LocaleKeys.grid_textFilter_matches.tr();
+      case TextFilterConditionPB.DoesNotMatch:
+        return LocaleKeys.grid_textFilter_doesNotMatch.tr();
       default:
         return "";
     }
@@ -261,6 +265,10 @@ extension TextFilterConditionPBExtension on TextFilterConditionPB {
         return LocaleKeys.grid_textFilter_choicechipPrefix_isEmpty.tr();
       case TextFilterConditionPB.TextIsNotEmpty:
         return LocaleKeys.grid_textFilter_choicechipPrefix_isNotEmpty.tr();
+      case TextFilterConditionPB.Matches:
+        return LocaleKeys.grid_textFilter_choicechipPrefix_matches.tr();
+      case TextFilterConditionPB.DoesNotMatch:
+        return LocaleKeys.grid_textFilter_choicechipPrefix_doesNotMatch.tr();
       default:
         return "";
     }
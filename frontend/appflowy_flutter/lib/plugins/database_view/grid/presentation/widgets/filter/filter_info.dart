@@ -6,6 +6,7 @@ import 'package:appflowy_backend/protobuf/flowy-database/field_entities.pb.dart'
 import 'package:appflowy_backend/protobuf/flowy-database/select_option_filter.pbserver.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/text_filter.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/util.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/value_in_set_filter.pb.dart';
 
 class FilterInfo {
   final String viewId;
@@ -23,27 +24,30 @@ class FilterInfo {
   }
 
   DateFilterPB? dateFilter() {
-    if (filter.fieldType != FieldType.DateTime) {
+    if (filter.isValueInSet || filter.fieldType != FieldType.DateTime) {
       return null;
     }
     return DateFilterPB.fromBuffer(filter.data);
   }
 
   TextFilterPB? textFilter() {
-    if (filter.fieldType != FieldType.RichText) {
+    if (filter.isValueInSet || filter.fieldType != FieldType.RichText) {
       return null;
     }
     return TextFilterPB.fromBuffer(filter.data);
   }
 
   CheckboxFilterPB? checkboxFilter() {
-    if (filter.fieldType != FieldType.Checkbox) {
+    if (filter.isValueInSet || filter.fieldType != FieldType.Checkbox) {
       return null;
     }
     return CheckboxFilterPB.fromBuffer(filter.data);
   }
 
   SelectOptionFilterPB? selectOptionFilter() {
+    if (filter.isValueInSet) {
+      return null;
+    }
     if (filter.fieldType == FieldType.SingleSelect ||
         filter.fieldType == FieldType.MultiSelect) {
       return SelectOptionFilterPB.fromBuffer(filter.data);
@@ -53,10 +57,20 @@ class FilterInfo {
   }
 
   ChecklistFilterPB? checklistFilter() {
+    if (filter.isValueInSet) {
+      return null;
+    }
     if (filter.fieldType == FieldType.Checklist) {
       return ChecklistFilterPB.fromBuffer(filter.data);
     } else {
       return null;
     }
   }
+
+  ValueInSetFilterPB? valueInSetFilter() {
+    if (!filter.isValueInSet) {
+      return null;
+    }
+    return ValueInSetFilterPB.fromBuffer(filter.data);
+  }
 }
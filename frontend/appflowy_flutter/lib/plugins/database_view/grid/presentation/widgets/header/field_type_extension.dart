@@ -21,6 +21,24 @@ extension FieldTypeListExtension on FieldType {
         return "grid/field/url";
       case FieldType.Checklist:
         return "grid/field/checklist";
+      case FieldType.Concat:
+        return "grid/field/text";
+      case FieldType.Order:
+        return "grid/field/numbers";
+      case FieldType.Relation:
+        return "grid/field/url";
+      case FieldType.Duration:
+        return "grid/field/number";
+      case FieldType.Rating:
+        return "grid/field/number";
+      case FieldType.IpAddress:
+        return "grid/field/url";
+      case FieldType.Json:
+        return "grid/field/text";
+      case FieldType.Email:
+        return "grid/field/url";
+      case FieldType.Phone:
+        return "grid/field/number";
     }
     throw UnimplementedError;
   }
@@ -43,6 +61,24 @@ extension FieldTypeListExtension on FieldType {
         return LocaleKeys.grid_field_urlFieldName.tr();
       case FieldType.Checklist:
         return LocaleKeys.grid_field_checklistFieldName.tr();
+      case FieldType.Concat:
+        return LocaleKeys.grid_field_concatFieldName.tr();
+      case FieldType.Order:
+        return LocaleKeys.grid_field_orderFieldName.tr();
+      case FieldType.Relation:
+        return LocaleKeys.grid_field_relationFieldName.tr();
+      case FieldType.Duration:
+        return LocaleKeys.grid_field_durationFieldName.tr();
+      case FieldType.Rating:
+        return LocaleKeys.grid_field_ratingFieldName.tr();
+      case FieldType.IpAddress:
+        return LocaleKeys.grid_field_ipAddressFieldName.tr();
+      case FieldType.Json:
+        return LocaleKeys.grid_field_jsonFieldName.tr();
+      case FieldType.Email:
+        return LocaleKeys.grid_field_emailFieldName.tr();
+      case FieldType.Phone:
+        return LocaleKeys.grid_field_phoneFieldName.tr();
     }
     throw UnimplementedError;
   }
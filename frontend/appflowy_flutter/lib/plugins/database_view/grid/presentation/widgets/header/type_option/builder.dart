@@ -6,9 +6,18 @@ import 'package:appflowy/plugins/database_view/application/field/type_option/typ
 import 'package:appflowy_popover/appflowy_popover.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/checkbox_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/checklist_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/concat_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/date_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/duration_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/email_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/ip_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/json_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/multi_select_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/number_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/order_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/phone_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/rating_type_option.pb.dart';
+import 'package:appflowy_backend/protobuf/flowy-database/relation_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/single_select_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/text_type_option.pb.dart';
 import 'package:appflowy_backend/protobuf/flowy-database/url_type_option.pb.dart';
@@ -17,9 +26,18 @@ import 'package:appflowy_backend/protobuf/flowy-database/field_entities.pb.dart'
 import 'package:flutter/material.dart';
 import 'checkbox.dart';
 import 'checklist.dart';
+import 'concat.dart';
 import 'date.dart';
+import 'duration.dart';
+import 'email.dart';
+import 'ip.dart';
+import 'json.dart';
 import 'multi_select.dart';
 import 'number.dart';
+import 'order.dart';
+import 'phone.dart';
+import 'rating.dart';
+import 'relation.dart';
 import 'rich_text.dart';
 import 'single_select.dart';
 import 'url.dart';
@@ -136,6 +154,87 @@ TypeOptionWidgetBuilder makeTypeOptionWidgetBuilder({
           dataController: dataController,
         ),
       );
+
+    case FieldType.Concat:
+      return ConcatTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<ConcatTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
+
+    case FieldType.Order:
+      return OrderTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<OrderTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
+
+    case FieldType.Relation:
+      return RelationTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<RelationTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
+
+    case FieldType.Duration:
+      return DurationTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<DurationTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
+
+    case FieldType.Rating:
+      return RatingTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<RatingTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
+
+    case FieldType.IpAddress:
+      return IpTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<IpTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
+
+    case FieldType.Json:
+      return JsonTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<JsonTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
+
+    case FieldType.Email:
+      return EmailTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<EmailTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
+
+    case FieldType.Phone:
+      return PhoneTypeOptionWidgetBuilder(
+        makeTypeOptionContextWithDataController<PhoneTypeOptionPB>(
+          viewId: viewId,
+          fieldType: fieldType,
+          dataController: dataController,
+        ),
+      );
   }
   throw UnimplementedError;
 }
@@ -239,6 +338,60 @@ TypeOptionContext<T>
         dataController: dataController,
         dataParser: URLTypeOptionWidgetDataParser(),
       ) as TypeOptionContext<T>;
+
+    case FieldType.Concat:
+      return ConcatTypeOptionContext(
+        dataController: dataController,
+        dataParser: ConcatTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
+
+    case FieldType.Order:
+      return OrderTypeOptionContext(
+        dataController: dataController,
+        dataParser: OrderTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
+
+    case FieldType.Relation:
+      return RelationTypeOptionContext(
+        dataController: dataController,
+        dataParser: RelationTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
+
+    case FieldType.Duration:
+      return DurationTypeOptionContext(
+        dataController: dataController,
+        dataParser: DurationTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
+
+    case FieldType.Rating:
+      return RatingTypeOptionContext(
+        dataController: dataController,
+        dataParser: RatingTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
+
+    case FieldType.IpAddress:
+      return IpTypeOptionContext(
+        dataController: dataController,
+        dataParser: IpTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
+
+    case FieldType.Json:
+      return JsonTypeOptionContext(
+        dataController: dataController,
+        dataParser: JsonTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
+
+    case FieldType.Email:
+      return EmailTypeOptionContext(
+        dataController: dataController,
+        dataParser: EmailTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
+
+    case FieldType.Phone:
+      return PhoneTypeOptionContext(
+        dataController: dataController,
+        dataParser: PhoneTypeOptionWidgetDataParser(),
+      ) as TypeOptionContext<T>;
   }
 
   throw UnimplementedError;
@@ -0,0 +1,10 @@
+import 'package:appflowy/plugins/database_view/application/field/type_option/type_option_context.dart';
+import 'package:flutter/material.dart';
+import 'builder.dart';
+
+class OrderTypeOptionWidgetBuilder extends TypeOptionWidgetBuilder {
+  OrderTypeOptionWidgetBuilder(OrderTypeOptionContext typeOptionContext);
+
+  @override
+  Widget? build(BuildContext context) => null;
+}
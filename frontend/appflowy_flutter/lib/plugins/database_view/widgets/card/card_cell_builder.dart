@@ -83,6 +83,76 @@ class CardCellBuilder<CustomCardData> {
           cellControllerBuilder: cellControllerBuilder,
           key: key,
         );
+      case FieldType.Concat:
+        return TextCardCell<CustomCardData>(
+          renderHook: renderHook?.renderHook[FieldType.Concat],
+          cellControllerBuilder: cellControllerBuilder,
+          cardData: cardData,
+          style: isStyleOrNull<TextCardCellStyle>(style),
+          key: key,
+        );
+      case FieldType.Order:
+        return TextCardCell<CustomCardData>(
+          renderHook: renderHook?.renderHook[FieldType.Order],
+          cellControllerBuilder: cellControllerBuilder,
+          cardData: cardData,
+          style: isStyleOrNull<TextCardCellStyle>(style),
+          key: key,
+        );
+      case FieldType.Relation:
+        return TextCardCell<CustomCardData>(
+          renderHook: renderHook?.renderHook[FieldType.Relation],
+          cellControllerBuilder: cellControllerBuilder,
+          cardData: cardData,
+          style: isStyleOrNull<TextCardCellStyle>(style),
+          key: key,
+        );
+      case FieldType.Duration:
+        return NumberCardCell(
+          cellControllerBuilder: cellControllerBuilder,
+          key: key,
+        );
+      case FieldType.Rating:
+        return NumberCardCell(
+          cellControllerBuilder: cellControllerBuilder,
+          key: key,
+        );
+      case FieldType.IpAddress:
+        return TextCardCell<CustomCardData>(
+          renderHook: renderHook?.renderHook[FieldType.IpAddress],
+          cellControllerBuilder: cellControllerBuilder,
+          editableNotifier: cellNotifier,
+          cardData: cardData,
+          style: isStyleOrNull<TextCardCellStyle>(style),
+          key: key,
+        );
+      case FieldType.Json:
+        return TextCardCell<CustomCardData>(
+          renderHook: renderHook?.renderHook[FieldType.Json],
+          cellControllerBuilder: cellControllerBuilder,
+          editableNotifier: cellNotifier,
+          cardData: cardData,
+          style: isStyleOrNull<TextCardCellStyle>(style),
+          key: key,
+        );
+      case FieldType.Email:
+        return TextCardCell<CustomCardData>(
+          renderHook: renderHook?.renderHook[FieldType.Email],
+          cellControllerBuilder: cellControllerBuilder,
+          editableNotifier: cellNotifier,
+          cardData: cardData,
+          style: isStyleOrNull<TextCardCellStyle>(style),
+          key: key,
+        );
+      case FieldType.Phone:
+        return TextCardCell<CustomCardData>(
+          renderHook: renderHook?.renderHook[FieldType.Phone],
+          cellControllerBuilder: cellControllerBuilder,
+          editableNotifier: cellNotifier,
+          cardData: cardData,
+          style: isStyleOrNull<TextCardCellStyle>(style),
+          key: key,
+        );
     }
     throw UnimplementedError;
   }
@@ -73,6 +73,58 @@ class GridCellBuilder {
           style: style,
           key: key,
         );
+      case FieldType.Concat:
+        return GridTextCell(
+          cellControllerBuilder: cellControllerBuilder,
+          style: style ?? GridTextCellStyle(readOnly: true),
+          key: key,
+        );
+      case FieldType.Order:
+        return GridTextCell(
+          cellControllerBuilder: cellControllerBuilder,
+          style: style ?? GridTextCellStyle(readOnly: true),
+          key: key,
+        );
+      case FieldType.Relation:
+        return GridTextCell(
+          cellControllerBuilder: cellControllerBuilder,
+          style: style ?? GridTextCellStyle(readOnly: true),
+          key: key,
+        );
+      case FieldType.Duration:
+        return GridNumberCell(
+          cellControllerBuilder: cellControllerBuilder,
+          key: key,
+        );
+      case FieldType.Rating:
+        return GridNumberCell(
+          cellControllerBuilder: cellControllerBuilder,
+          key: key,
+        );
+      case FieldType.IpAddress:
+        return GridTextCell(
+          cellControllerBuilder: cellControllerBuilder,
+          style: style,
+          key: key,
+        );
+      case FieldType.Json:
+        return GridTextCell(
+          cellControllerBuilder: cellControllerBuilder,
+          style: style,
+          key: key,
+        );
+      case FieldType.Email:
+        return GridTextCell(
+          cellControllerBuilder: cellControllerBuilder,
+          style: style,
+          key: key,
+        );
+      case FieldType.Phone:
+        return GridTextCell(
+          cellControllerBuilder: cellControllerBuilder,
+          style: style,
+          key: key,
+        );
     }
     throw UnimplementedError;
   }
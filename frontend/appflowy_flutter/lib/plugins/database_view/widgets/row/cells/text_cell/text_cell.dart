@@ -9,8 +9,13 @@ import '../../cell_builder.dart';
 class GridTextCellStyle extends GridCellStyle {
   String? placeholder;
 
+  /// The content of the read-only cell is computed by the backend, for
+  /// example, the concat cell, so it can't be edited.
+  bool readOnly;
+
   GridTextCellStyle({
     this.placeholder,
+    this.readOnly = false,
   });
 }
 
@@ -37,6 +42,8 @@ class _GridTextCellState extends GridFocusNodeCellState<GridTextCell> {
   late TextCellBloc _cellBloc;
   late TextEditingController _controller;
 
+  bool get _readOnly => widget.cellStyle?.readOnly ?? false;
+
   @override
   void initState() {
     final cellController =
@@ -65,6 +72,7 @@ class _GridTextCellState extends GridFocusNodeCellState<GridTextCell> {
           child: TextField(
             controller: _controller,
             focusNode: focusNode,
+            readOnly: _readOnly,
             maxLines: null,
             style: Theme.of(context).textTheme.bodyMedium,
             decoration: InputDecoration(
@@ -93,14 +101,18 @@ class _GridTextCellState extends GridFocusNodeCellState<GridTextCell> {
 
   @override
   void onInsert(String value) {
-    _cellBloc.add(TextCellEvent.updateText(value));
+    if (!_readOnly) {
+      _cellBloc.add(TextCellEvent.updateText(value));
+    }
   }
 
   @override
   Future<void> focusChanged() {
-    _cellBloc.add(
-      TextCellEvent.updateText(_controller.text),
-    );
+    if (!_readOnly) {
+      _cellBloc.add(
+        TextCellEvent.updateText(_controller.text),
+      );
+    }
     return super.focusChanged();
   }
 }
@@ -337,6 +337,32 @@ GridCellStyle? _customCellStyle(FieldType fieldType) {
           GridURLCellAccessoryType.copyURL,
         ],
       );
+    case FieldType.Concat:
+      return GridTextCellStyle(readOnly: true);
+    case FieldType.Order:
+      return GridTextCellStyle(readOnly: true);
+    case FieldType.Relation:
+      return GridTextCellStyle(readOnly: true);
+    case FieldType.Duration:
+      return null;
+    case FieldType.Rating:
+      return null;
+    case FieldType.IpAddress:
+      return GridTextCellStyle(
+        placeholder: LocaleKeys.grid_row_textPlaceholder.tr(),
+      );
+    case FieldType.Json:
+      return GridTextCellStyle(
+        placeholder: LocaleKeys.grid_row_textPlaceholder.tr(),
+      );
+    case FieldType.Email:
+      return GridTextCellStyle(
+        placeholder: LocaleKeys.grid_row_textPlaceholder.tr(),
+      );
+    case FieldType.Phone:
+      return GridTextCellStyle(
+        placeholder: LocaleKeys.grid_row_textPlaceholder.tr(),
+      );
   }
   throw UnimplementedError;
 }
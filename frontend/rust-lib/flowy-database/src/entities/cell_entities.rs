@@ -1,5 +1,8 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::FieldType;
+use crate::services::field::{
+  DateCellDataPB, RelationCellDataPB, SelectOptionCellDataPB, URLCellDataPB,
+};
 use database_model::{CellRevision, RowChangeset};
 use flowy_derive::ProtoBuf;
 use flowy_error::ErrorCode;
@@ -113,6 +116,36 @@ impl CellPB {
   }
 }
 
+/// Represents as the value of the cell. Only the field that matches the field type of the cell
+/// is set, so one message can represent the value of any field type.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct CellValuePB {
+  #[pb(index = 1, one_of)]
+  pub text: Option<String>,
+
+  /// The number string that is formatted by the number format of the field.
+  #[pb(index = 2, one_of)]
+  pub number: Option<String>,
+
+  #[pb(index = 3, one_of)]
+  pub date: Option<DateCellDataPB>,
+
+  #[pb(index = 4, one_of)]
+  pub select_option: Option<SelectOptionCellDataPB>,
+
+  #[pb(index = 5, one_of)]
+  pub checkbox: Option<bool>,
+
+  #[pb(index = 6, one_of)]
+  pub url: Option<URLCellDataPB>,
+
+  #[pb(index = 7, one_of)]
+  pub checklist: Option<SelectOptionCellDataPB>,
+
+  #[pb(index = 8, one_of)]
+  pub relation: Option<RelationCellDataPB>,
+}
+
 #[derive(Debug, Default, ProtoBuf)]
 pub struct RepeatedCellPB {
   #[pb(index = 1)]
@@ -33,6 +33,9 @@ pub struct FieldPB {
 
   #[pb(index = 8)]
   pub is_primary: bool,
+
+  #[pb(index = 9)]
+  pub is_required: bool,
 }
 
 impl std::convert::From<FieldRevision> for FieldPB {
@@ -46,6 +49,7 @@ impl std::convert::From<FieldRevision> for FieldPB {
       visibility: field_rev.visibility,
       width: field_rev.width,
       is_primary: field_rev.is_primary,
+      is_required: field_rev.is_required,
     }
   }
 }
@@ -413,6 +417,8 @@ pub struct FieldChangesetPB {
   pub width: Option<i32>,
   // #[pb(index = 9, one_of)]
   // pub type_option_data: Option<Vec<u8>>,
+  #[pb(index = 10, one_of)]
+  pub is_required: Option<bool>,
 }
 
 impl TryInto<FieldChangesetParams> for FieldChangesetPB {
@@ -437,6 +443,7 @@ impl TryInto<FieldChangesetParams> for FieldChangesetPB {
       frozen: self.frozen,
       visibility: self.visibility,
       width: self.width,
+      is_required: self.is_required,
       // type_option_data: self.type_option_data,
     })
   }
@@ -460,6 +467,7 @@ pub struct FieldChangesetParams {
 
   pub width: Option<i32>,
   // pub type_option_data: Option<Vec<u8>>,
+  pub is_required: Option<bool>,
 }
 /// Certain field types have user-defined options such as color, date format, number format,
 /// or a list of values for a multi-select list. These options are defined within a specialization
@@ -494,6 +502,15 @@ pub enum FieldType {
   Checkbox = 5,
   URL = 6,
   Checklist = 7,
+  Concat = 8,
+  Order = 9,
+  Relation = 10,
+  Duration = 11,
+  Rating = 12,
+  IpAddress = 13,
+  Json = 14,
+  Email = 15,
+  Phone = 16,
 }
 
 pub const RICH_TEXT_FIELD: FieldType = FieldType::RichText;
@@ -504,6 +521,15 @@ pub const MULTI_SELECT_FIELD: FieldType = FieldType::MultiSelect;
 pub const CHECKBOX_FIELD: FieldType = FieldType::Checkbox;
 pub const URL_FIELD: FieldType = FieldType::URL;
 pub const CHECKLIST_FIELD: FieldType = FieldType::Checklist;
+pub const CONCAT_FIELD: FieldType = FieldType::Concat;
+pub const ORDER_FIELD: FieldType = FieldType::Order;
+pub const RELATION_FIELD: FieldType = FieldType::Relation;
+pub const DURATION_FIELD: FieldType = FieldType::Duration;
+pub const RATING_FIELD: FieldType = FieldType::Rating;
+pub const IP_ADDRESS_FIELD: FieldType = FieldType::IpAddress;
+pub const JSON_FIELD: FieldType = FieldType::Json;
+pub const EMAIL_FIELD: FieldType = FieldType::Email;
+pub const PHONE_FIELD: FieldType = FieldType::Phone;
 
 impl std::default::Default for FieldType {
   fn default() -> Self {
@@ -571,6 +597,42 @@ impl FieldType {
     self == &CHECKLIST_FIELD
   }
 
+  pub fn is_concat(&self) -> bool {
+    self == &CONCAT_FIELD
+  }
+
+  pub fn is_order(&self) -> bool {
+    self == &ORDER_FIELD
+  }
+
+  pub fn is_relation(&self) -> bool {
+    self == &RELATION_FIELD
+  }
+
+  pub fn is_duration(&self) -> bool {
+    self == &DURATION_FIELD
+  }
+
+  pub fn is_rating(&self) -> bool {
+    self == &RATING_FIELD
+  }
+
+  pub fn is_ip_address(&self) -> bool {
+    self == &IP_ADDRESS_FIELD
+  }
+
+  pub fn is_json(&self) -> bool {
+    self == &JSON_FIELD
+  }
+
+  pub fn is_email(&self) -> bool {
+    self == &EMAIL_FIELD
+  }
+
+  pub fn is_phone(&self) -> bool {
+    self == &PHONE_FIELD
+  }
+
   pub fn can_be_group(&self) -> bool {
     self.is_select_option() || self.is_checkbox() || self.is_url()
   }
@@ -605,6 +667,15 @@ impl std::convert::From<FieldTypeRevision> for FieldType {
       5 => FieldType::Checkbox,
       6 => FieldType::URL,
       7 => FieldType::Checklist,
+      8 => FieldType::Concat,
+      9 => FieldType::Order,
+      10 => FieldType::Relation,
+      11 => FieldType::Duration,
+      12 => FieldType::Rating,
+      13 => FieldType::IpAddress,
+      14 => FieldType::Json,
+      15 => FieldType::Email,
+      16 => FieldType::Phone,
       _ => {
         tracing::error!("Can't convert FieldTypeRevision: {} to FieldType", ty);
         FieldType::RichText
@@ -14,6 +14,7 @@ pub struct CheckboxFilterPB {
 pub enum CheckboxFilterConditionPB {
   IsChecked = 0,
   IsUnChecked = 1,
+  IsUnset = 2,
 }
 
 impl std::convert::From<CheckboxFilterConditionPB> for u32 {
@@ -35,6 +36,7 @@ impl std::convert::TryFrom<u8> for CheckboxFilterConditionPB {
     match value {
       0 => Ok(CheckboxFilterConditionPB::IsChecked),
       1 => Ok(CheckboxFilterConditionPB::IsUnChecked),
+      2 => Ok(CheckboxFilterConditionPB::IsUnset),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -7,6 +7,9 @@ use flowy_error::ErrorCode;
 pub struct ChecklistFilterPB {
   #[pb(index = 1)]
   pub condition: ChecklistFilterConditionPB,
+
+  #[pb(index = 2)]
+  pub content: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
@@ -14,6 +17,9 @@ pub struct ChecklistFilterPB {
 pub enum ChecklistFilterConditionPB {
   IsComplete = 0,
   IsIncomplete = 1,
+  /// The content of the filter is the percentage between 0 and 100, for example, `40` keeps the
+  /// checklists that are at least 40% complete.
+  PercentAtLeast = 2,
 }
 
 impl std::convert::From<ChecklistFilterConditionPB> for u32 {
@@ -35,6 +41,7 @@ impl std::convert::TryFrom<u8> for ChecklistFilterConditionPB {
     match value {
       0 => Ok(ChecklistFilterConditionPB::IsComplete),
       1 => Ok(ChecklistFilterConditionPB::IsIncomplete),
+      2 => Ok(ChecklistFilterConditionPB::PercentAtLeast),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -48,6 +55,7 @@ impl FromFilterString for ChecklistFilterPB {
     ChecklistFilterPB {
       condition: ChecklistFilterConditionPB::try_from(filter_rev.condition)
         .unwrap_or(ChecklistFilterConditionPB::IsIncomplete),
+      content: filter_rev.content.clone(),
     }
   }
 }
@@ -57,6 +65,7 @@ impl std::convert::From<&FilterRevision> for ChecklistFilterPB {
     ChecklistFilterPB {
       condition: ChecklistFilterConditionPB::try_from(rev.condition)
         .unwrap_or(ChecklistFilterConditionPB::IsIncomplete),
+      content: rev.content.clone(),
     }
   }
 }
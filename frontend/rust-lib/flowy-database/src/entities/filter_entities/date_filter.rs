@@ -18,6 +18,14 @@ pub struct DateFilterPB {
 
   #[pb(index = 4, one_of)]
   pub timestamp: Option<i64>,
+
+  #[pb(index = 5, one_of)]
+  pub business_days: Option<i64>,
+
+  /// The number of days of the [DateFilterConditionPB::DateWithinNextDays] and the
+  /// [DateFilterConditionPB::DateWithinLastDays] conditions.
+  #[pb(index = 6, one_of)]
+  pub days: Option<i64>,
 }
 
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
@@ -25,6 +33,10 @@ pub struct DateFilterContentPB {
   pub start: Option<i64>,
   pub end: Option<i64>,
   pub timestamp: Option<i64>,
+  #[serde(default)]
+  pub business_days: Option<i64>,
+  #[serde(default)]
+  pub days: Option<i64>,
 }
 
 impl ToString for DateFilterContentPB {
@@ -52,6 +64,17 @@ pub enum DateFilterConditionPB {
   DateWithIn = 5,
   DateIsEmpty = 6,
   DateIsNotEmpty = 7,
+  DateOlderThanBusinessDays = 8,
+  // The relative conditions are evaluated against the current date, see `RelativeDateContext`.
+  DateIsToday = 9,
+  DateIsTomorrow = 10,
+  DateIsYesterday = 11,
+  DateIsThisWeek = 12,
+  DateIsLastWeek = 13,
+  DateIsNextWeek = 14,
+  DateIsThisMonth = 15,
+  DateWithinNextDays = 16,
+  DateWithinLastDays = 17,
 }
 
 impl std::convert::From<DateFilterConditionPB> for u32 {
@@ -77,6 +100,17 @@ impl std::convert::TryFrom<u8> for DateFilterConditionPB {
       4 => Ok(DateFilterConditionPB::DateOnOrAfter),
       5 => Ok(DateFilterConditionPB::DateWithIn),
       6 => Ok(DateFilterConditionPB::DateIsEmpty),
+      7 => Ok(DateFilterConditionPB::DateIsNotEmpty),
+      8 => Ok(DateFilterConditionPB::DateOlderThanBusinessDays),
+      9 => Ok(DateFilterConditionPB::DateIsToday),
+      10 => Ok(DateFilterConditionPB::DateIsTomorrow),
+      11 => Ok(DateFilterConditionPB::DateIsYesterday),
+      12 => Ok(DateFilterConditionPB::DateIsThisWeek),
+      13 => Ok(DateFilterConditionPB::DateIsLastWeek),
+      14 => Ok(DateFilterConditionPB::DateIsNextWeek),
+      15 => Ok(DateFilterConditionPB::DateIsThisMonth),
+      16 => Ok(DateFilterConditionPB::DateWithinNextDays),
+      17 => Ok(DateFilterConditionPB::DateWithinLastDays),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -97,6 +131,8 @@ impl FromFilterString for DateFilterPB {
       filter.start = content.start;
       filter.end = content.end;
       filter.timestamp = content.timestamp;
+      filter.business_days = content.business_days;
+      filter.days = content.days;
     };
 
     filter
@@ -115,6 +151,8 @@ impl std::convert::From<&FilterRevision> for DateFilterPB {
       filter.start = content.start;
       filter.end = content.end;
       filter.timestamp = content.timestamp;
+      filter.business_days = content.business_days;
+      filter.days = content.days;
     };
 
     filter
@@ -0,0 +1,67 @@
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct EmailFilterPB {
+  #[pb(index = 1)]
+  pub condition: EmailFilterConditionPB,
+
+  /// The text that the `DomainContains` condition looks for in the domain of the address, for
+  /// example, `appflowy` matches `hello@appflowy.io`.
+  #[pb(index = 2)]
+  pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum EmailFilterConditionPB {
+  DomainContains = 0,
+  EmailIsEmpty = 1,
+  EmailIsNotEmpty = 2,
+}
+
+impl std::default::Default for EmailFilterConditionPB {
+  fn default() -> Self {
+    EmailFilterConditionPB::DomainContains
+  }
+}
+
+impl std::convert::From<EmailFilterConditionPB> for u32 {
+  fn from(value: EmailFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::convert::TryFrom<u8> for EmailFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(n: u8) -> Result<Self, Self::Error> {
+    match n {
+      0 => Ok(EmailFilterConditionPB::DomainContains),
+      1 => Ok(EmailFilterConditionPB::EmailIsEmpty),
+      2 => Ok(EmailFilterConditionPB::EmailIsNotEmpty),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for EmailFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    EmailFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for EmailFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    EmailFilterPB {
+      condition: EmailFilterConditionPB::try_from(rev.condition)
+        .unwrap_or(EmailFilterConditionPB::DomainContains),
+      content: rev.content.clone(),
+    }
+  }
+}
@@ -0,0 +1,69 @@
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct IpFilterPB {
+  #[pb(index = 1)]
+  pub condition: IpFilterConditionPB,
+
+  /// The CIDR range that the `CidrRange` conditions check, for example, `10.0.0.0/8`. The single
+  /// address without the prefix length is the range of one address.
+  #[pb(index = 2)]
+  pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum IpFilterConditionPB {
+  InCidrRange = 0,
+  NotInCidrRange = 1,
+  IpIsEmpty = 2,
+  IpIsNotEmpty = 3,
+}
+
+impl std::default::Default for IpFilterConditionPB {
+  fn default() -> Self {
+    IpFilterConditionPB::InCidrRange
+  }
+}
+
+impl std::convert::From<IpFilterConditionPB> for u32 {
+  fn from(value: IpFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::convert::TryFrom<u8> for IpFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(n: u8) -> Result<Self, Self::Error> {
+    match n {
+      0 => Ok(IpFilterConditionPB::InCidrRange),
+      1 => Ok(IpFilterConditionPB::NotInCidrRange),
+      2 => Ok(IpFilterConditionPB::IpIsEmpty),
+      3 => Ok(IpFilterConditionPB::IpIsNotEmpty),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for IpFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    IpFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for IpFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    IpFilterPB {
+      condition: IpFilterConditionPB::try_from(rev.condition)
+        .unwrap_or(IpFilterConditionPB::InCidrRange),
+      content: rev.content.clone(),
+    }
+  }
+}
@@ -0,0 +1,104 @@
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct JsonFilterPB {
+  #[pb(index = 1)]
+  pub condition: JsonFilterConditionPB,
+
+  /// The JSONPath of the value, for example, `$.owner.name` or `$.tags[0]`.
+  #[pb(index = 2)]
+  pub path: String,
+
+  /// The JSON value that the [JsonFilterConditionPB::PathEquals] condition compares with. The
+  /// value that isn't a valid JSON is compared as a string, so `open` is the same as `"open"`.
+  #[pb(index = 3)]
+  pub value: String,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct JsonFilterContentPB {
+  pub path: String,
+  #[serde(default)]
+  pub value: String,
+}
+
+impl ToString for JsonFilterContentPB {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap()
+  }
+}
+
+impl FromStr for JsonFilterContentPB {
+  type Err = serde_json::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    serde_json::from_str(s)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum JsonFilterConditionPB {
+  PathExists = 0,
+  PathEquals = 1,
+  JsonIsEmpty = 2,
+  JsonIsNotEmpty = 3,
+}
+
+impl std::default::Default for JsonFilterConditionPB {
+  fn default() -> Self {
+    JsonFilterConditionPB::PathExists
+  }
+}
+
+impl std::convert::From<JsonFilterConditionPB> for u32 {
+  fn from(value: JsonFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::convert::TryFrom<u8> for JsonFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(n: u8) -> Result<Self, Self::Error> {
+    match n {
+      0 => Ok(JsonFilterConditionPB::PathExists),
+      1 => Ok(JsonFilterConditionPB::PathEquals),
+      2 => Ok(JsonFilterConditionPB::JsonIsEmpty),
+      3 => Ok(JsonFilterConditionPB::JsonIsNotEmpty),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for JsonFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    JsonFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for JsonFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    let condition =
+      JsonFilterConditionPB::try_from(rev.condition).unwrap_or(JsonFilterConditionPB::PathExists);
+    let mut filter = JsonFilterPB {
+      condition,
+      ..Default::default()
+    };
+
+    if let Ok(content) = JsonFilterContentPB::from_str(&rev.content) {
+      filter.path = content.path;
+      filter.value = content.value;
+    }
+
+    filter
+  }
+}
@@ -1,17 +1,29 @@
 mod checkbox_filter;
 mod checklist_filter;
 mod date_filter;
+mod email_filter;
 mod filter_changeset;
+mod ip_filter;
+mod json_filter;
 mod number_filter;
+mod phone_filter;
+mod relation_filter;
 mod select_option_filter;
 mod text_filter;
 mod util;
+mod value_in_set_filter;
 
 pub use checkbox_filter::*;
 pub use checklist_filter::*;
 pub use date_filter::*;
+pub use email_filter::*;
 pub use filter_changeset::*;
+pub use ip_filter::*;
+pub use json_filter::*;
 pub use number_filter::*;
+pub use phone_filter::*;
+pub use relation_filter::*;
 pub use select_option_filter::*;
 pub use text_filter::*;
 pub use util::*;
+pub use value_in_set_filter::*;
@@ -23,6 +23,10 @@ pub enum NumberFilterConditionPB {
   LessThanOrEqualTo = 5,
   NumberIsEmpty = 6,
   NumberIsNotEmpty = 7,
+  /// The content of the filter is the divisor.
+  DivisibleBy = 8,
+  IsEven = 9,
+  IsOdd = 10,
 }
 
 impl std::default::Default for NumberFilterConditionPB {
@@ -49,6 +53,9 @@ impl std::convert::TryFrom<u8> for NumberFilterConditionPB {
       5 => Ok(NumberFilterConditionPB::LessThanOrEqualTo),
       6 => Ok(NumberFilterConditionPB::NumberIsEmpty),
       7 => Ok(NumberFilterConditionPB::NumberIsNotEmpty),
+      8 => Ok(NumberFilterConditionPB::DivisibleBy),
+      9 => Ok(NumberFilterConditionPB::IsEven),
+      10 => Ok(NumberFilterConditionPB::IsOdd),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -0,0 +1,69 @@
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct PhoneFilterPB {
+  #[pb(index = 1)]
+  pub condition: PhoneFilterConditionPB,
+
+  /// The digits that the `Contains` and `StartsWith` conditions look for. It's normalized the
+  /// same way as the phone cells, so `(555) 123` is the same as `555123`.
+  #[pb(index = 2)]
+  pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum PhoneFilterConditionPB {
+  PhoneContains = 0,
+  PhoneStartsWith = 1,
+  PhoneIsEmpty = 2,
+  PhoneIsNotEmpty = 3,
+}
+
+impl std::default::Default for PhoneFilterConditionPB {
+  fn default() -> Self {
+    PhoneFilterConditionPB::PhoneContains
+  }
+}
+
+impl std::convert::From<PhoneFilterConditionPB> for u32 {
+  fn from(value: PhoneFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::convert::TryFrom<u8> for PhoneFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(n: u8) -> Result<Self, Self::Error> {
+    match n {
+      0 => Ok(PhoneFilterConditionPB::PhoneContains),
+      1 => Ok(PhoneFilterConditionPB::PhoneStartsWith),
+      2 => Ok(PhoneFilterConditionPB::PhoneIsEmpty),
+      3 => Ok(PhoneFilterConditionPB::PhoneIsNotEmpty),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for PhoneFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    PhoneFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for PhoneFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    PhoneFilterPB {
+      condition: PhoneFilterConditionPB::try_from(rev.condition)
+        .unwrap_or(PhoneFilterConditionPB::PhoneContains),
+      content: rev.content.clone(),
+    }
+  }
+}
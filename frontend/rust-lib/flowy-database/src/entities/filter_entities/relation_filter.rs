@@ -0,0 +1,76 @@
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RelationFilterPB {
+  #[pb(index = 1)]
+  pub condition: RelationFilterConditionPB,
+
+  /// The number of the linked rows that the `LinkCount` conditions compare with.
+  #[pb(index = 2)]
+  pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum RelationFilterConditionPB {
+  LinkCountEqual = 0,
+  LinkCountNotEqual = 1,
+  LinkCountGreaterThan = 2,
+  LinkCountLessThan = 3,
+  LinkCountGreaterThanOrEqualTo = 4,
+  LinkCountLessThanOrEqualTo = 5,
+  RelationIsEmpty = 6,
+  RelationIsNotEmpty = 7,
+}
+
+impl std::default::Default for RelationFilterConditionPB {
+  fn default() -> Self {
+    RelationFilterConditionPB::RelationIsNotEmpty
+  }
+}
+
+impl std::convert::From<RelationFilterConditionPB> for u32 {
+  fn from(value: RelationFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::convert::TryFrom<u8> for RelationFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(n: u8) -> Result<Self, Self::Error> {
+    match n {
+      0 => Ok(RelationFilterConditionPB::LinkCountEqual),
+      1 => Ok(RelationFilterConditionPB::LinkCountNotEqual),
+      2 => Ok(RelationFilterConditionPB::LinkCountGreaterThan),
+      3 => Ok(RelationFilterConditionPB::LinkCountLessThan),
+      4 => Ok(RelationFilterConditionPB::LinkCountGreaterThanOrEqualTo),
+      5 => Ok(RelationFilterConditionPB::LinkCountLessThanOrEqualTo),
+      6 => Ok(RelationFilterConditionPB::RelationIsEmpty),
+      7 => Ok(RelationFilterConditionPB::RelationIsNotEmpty),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for RelationFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    RelationFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for RelationFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    RelationFilterPB {
+      condition: RelationFilterConditionPB::try_from(rev.condition)
+        .unwrap_or(RelationFilterConditionPB::RelationIsNotEmpty),
+      content: rev.content.clone(),
+    }
+  }
+}
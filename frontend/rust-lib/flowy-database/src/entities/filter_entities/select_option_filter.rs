@@ -13,6 +13,17 @@ pub struct SelectOptionFilterPB {
   pub option_ids: Vec<String>,
 }
 
+impl SelectOptionFilterPB {
+  /// Creates the [SelectOptionConditionPB::OptionInRange] filter from the id of the option that
+  /// starts the range to the id of the option that ends it.
+  pub fn in_range(from: &str, to: &str) -> Self {
+    Self {
+      condition: SelectOptionConditionPB::OptionInRange,
+      option_ids: vec![from.to_owned(), to.to_owned()],
+    }
+  }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
 #[repr(u8)]
 pub enum SelectOptionConditionPB {
@@ -20,6 +31,15 @@ pub enum SelectOptionConditionPB {
   OptionIsNot = 1,
   OptionIsEmpty = 2,
   OptionIsNotEmpty = 3,
+  /// Matches the cell whose option falls between two options, inclusively, by the order of the
+  /// options in the type option. The `option_ids` of the filter are the bounds of the range.
+  OptionInRange = 4,
+  /// Matches the cell that has every option of the filter.
+  OptionContainsAll = 5,
+  /// Matches the cell that has any option of the filter.
+  OptionContainsAny = 6,
+  /// Matches the cell that has exactly the options of the filter, in any order.
+  OptionIsExactly = 7,
 }
 
 impl std::convert::From<SelectOptionConditionPB> for u32 {
@@ -43,6 +63,10 @@ impl std::convert::TryFrom<u8> for SelectOptionConditionPB {
       1 => Ok(SelectOptionConditionPB::OptionIsNot),
       2 => Ok(SelectOptionConditionPB::OptionIsEmpty),
       3 => Ok(SelectOptionConditionPB::OptionIsNotEmpty),
+      4 => Ok(SelectOptionConditionPB::OptionInRange),
+      5 => Ok(SelectOptionConditionPB::OptionContainsAll),
+      6 => Ok(SelectOptionConditionPB::OptionContainsAny),
+      7 => Ok(SelectOptionConditionPB::OptionIsExactly),
       _ => Err(ErrorCode::InvalidData),
     }
   }
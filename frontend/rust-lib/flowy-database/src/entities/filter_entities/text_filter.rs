@@ -23,6 +23,9 @@ pub enum TextFilterConditionPB {
   EndsWith = 5,
   TextIsEmpty = 6,
   TextIsNotEmpty = 7,
+  /// The content is a regular expression that is matched against the text.
+  Matches = 8,
+  DoesNotMatch = 9,
 }
 
 impl std::convert::From<TextFilterConditionPB> for u32 {
@@ -50,6 +53,8 @@ impl std::convert::TryFrom<u8> for TextFilterConditionPB {
       5 => Ok(TextFilterConditionPB::EndsWith),
       6 => Ok(TextFilterConditionPB::TextIsEmpty),
       7 => Ok(TextFilterConditionPB::TextIsNotEmpty),
+      8 => Ok(TextFilterConditionPB::Matches),
+      9 => Ok(TextFilterConditionPB::DoesNotMatch),
       _ => Err(ErrorCode::InvalidData),
     }
   }
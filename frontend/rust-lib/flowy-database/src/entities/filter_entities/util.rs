@@ -1,10 +1,11 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
-  CheckboxFilterPB, ChecklistFilterPB, DateFilterContentPB, DateFilterPB, FieldType,
-  NumberFilterPB, SelectOptionFilterPB, TextFilterPB,
+  CheckboxFilterPB, ChecklistFilterPB, DateFilterContentPB, DateFilterPB, EmailFilterPB, FieldType,
+  IpFilterPB, JsonFilterContentPB, JsonFilterPB, NumberFilterPB, PhoneFilterPB, RelationFilterPB,
+  SelectOptionFilterPB, TextFilterPB, ValueInSetFilterPB,
 };
 use crate::services::field::SelectOptionIds;
-use crate::services::filter::FilterType;
+use crate::services::filter::{FilterType, VALUE_IN_SET_FILTER_CONDITION};
 use bytes::Bytes;
 use database_model::{FieldRevision, FieldTypeRevision, FilterRevision};
 use flowy_derive::ProtoBuf;
@@ -25,26 +26,44 @@ pub struct FilterPB {
 
   #[pb(index = 4)]
   pub data: Vec<u8>,
+
+  /// The `data` is the [ValueInSetFilterPB] instead of the filter of the field type if it's true.
+  #[pb(index = 5)]
+  pub is_value_in_set: bool,
 }
 
 impl std::convert::From<&FilterRevision> for FilterPB {
   fn from(rev: &FilterRevision) -> Self {
     let field_type: FieldType = rev.field_type.into();
-    let bytes: Bytes = match field_type {
-      FieldType::RichText => TextFilterPB::from(rev).try_into().unwrap(),
-      FieldType::Number => NumberFilterPB::from(rev).try_into().unwrap(),
-      FieldType::DateTime => DateFilterPB::from(rev).try_into().unwrap(),
-      FieldType::SingleSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
-      FieldType::MultiSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
-      FieldType::Checklist => ChecklistFilterPB::from(rev).try_into().unwrap(),
-      FieldType::Checkbox => CheckboxFilterPB::from(rev).try_into().unwrap(),
-      FieldType::URL => TextFilterPB::from(rev).try_into().unwrap(),
+    let is_value_in_set = rev.condition == VALUE_IN_SET_FILTER_CONDITION;
+    let bytes: Bytes = if is_value_in_set {
+      ValueInSetFilterPB::from(rev).try_into().unwrap()
+    } else {
+      match field_type {
+        FieldType::RichText => TextFilterPB::from(rev).try_into().unwrap(),
+        FieldType::Number | FieldType::Duration | FieldType::Rating => {
+          NumberFilterPB::from(rev).try_into().unwrap()
+        },
+        FieldType::DateTime => DateFilterPB::from(rev).try_into().unwrap(),
+        FieldType::SingleSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
+        FieldType::MultiSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
+        FieldType::Checklist => ChecklistFilterPB::from(rev).try_into().unwrap(),
+        FieldType::Checkbox => CheckboxFilterPB::from(rev).try_into().unwrap(),
+        FieldType::URL => TextFilterPB::from(rev).try_into().unwrap(),
+        FieldType::Concat | FieldType::Order => TextFilterPB::from(rev).try_into().unwrap(),
+        FieldType::Relation => RelationFilterPB::from(rev).try_into().unwrap(),
+        FieldType::IpAddress => IpFilterPB::from(rev).try_into().unwrap(),
+        FieldType::Json => JsonFilterPB::from(rev).try_into().unwrap(),
+        FieldType::Email => EmailFilterPB::from(rev).try_into().unwrap(),
+        FieldType::Phone => PhoneFilterPB::from(rev).try_into().unwrap(),
+      }
     };
     Self {
       id: rev.id.clone(),
       field_id: rev.field_id.clone(),
       field_type: rev.field_type.into(),
       data: bytes.to_vec(),
+      is_value_in_set,
     }
   }
 }
@@ -180,7 +199,7 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
     let bytes: &[u8] = self.data.as_ref();
 
     match self.field_type {
-      FieldType::RichText | FieldType::URL => {
+      FieldType::RichText | FieldType::URL | FieldType::Concat | FieldType::Order => {
         let filter = TextFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
         condition = filter.condition as u8;
         content = filter.content;
@@ -189,7 +208,7 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
         let filter = CheckboxFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
         condition = filter.condition as u8;
       },
-      FieldType::Number => {
+      FieldType::Number | FieldType::Duration | FieldType::Rating => {
         let filter = NumberFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
         condition = filter.condition as u8;
         content = filter.content;
@@ -201,14 +220,50 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
           start: filter.start,
           end: filter.end,
           timestamp: filter.timestamp,
+          business_days: filter.business_days,
+          days: filter.days,
         }
         .to_string();
       },
-      FieldType::SingleSelect | FieldType::MultiSelect | FieldType::Checklist => {
+      FieldType::SingleSelect | FieldType::MultiSelect => {
         let filter = SelectOptionFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
         condition = filter.condition as u8;
         content = SelectOptionIds::from(filter.option_ids).to_string();
       },
+      FieldType::Checklist => {
+        let filter = ChecklistFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = filter.content;
+      },
+      FieldType::Relation => {
+        let filter = RelationFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = filter.content;
+      },
+      FieldType::IpAddress => {
+        let filter = IpFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = filter.content;
+      },
+      FieldType::Json => {
+        let filter = JsonFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = JsonFilterContentPB {
+          path: filter.path,
+          value: filter.value,
+        }
+        .to_string();
+      },
+      FieldType::Email => {
+        let filter = EmailFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = filter.content;
+      },
+      FieldType::Phone => {
+        let filter = PhoneFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = filter.content;
+      },
     }
 
     Ok(AlterFilterParams {
@@ -0,0 +1,22 @@
+use crate::services::filter::{FromFilterString, ValueInSet};
+use database_model::FilterRevision;
+use flowy_derive::ProtoBuf;
+
+/// The [ValueInSet] filter, which works on any field type. It's sent in place of the filter of the
+/// field type, see [FilterPB::is_value_in_set](crate::entities::FilterPB).
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct ValueInSetFilterPB {
+  #[pb(index = 1)]
+  pub values: Vec<String>,
+}
+
+impl std::convert::From<&FilterRevision> for ValueInSetFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    let mut values = ValueInSet::from_filter_rev(rev)
+      .0
+      .into_iter()
+      .collect::<Vec<String>>();
+    values.sort();
+    ValueInSetFilterPB { values }
+  }
+}
@@ -3,7 +3,7 @@ use crate::entities::FieldType;
 use crate::services::sort::SortType;
 use std::sync::Arc;
 
-use database_model::{FieldTypeRevision, SortCondition, SortRevision};
+use database_model::{FieldTypeRevision, NullsOrder, SortCondition, SortRevision};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 
@@ -20,6 +20,9 @@ pub struct SortPB {
 
   #[pb(index = 4)]
   pub condition: SortConditionPB,
+
+  #[pb(index = 5, one_of)]
+  pub nulls_order: Option<NullsOrderPB>,
 }
 
 impl std::convert::From<&SortRevision> for SortPB {
@@ -29,6 +32,7 @@ impl std::convert::From<&SortRevision> for SortPB {
       field_id: sort_rev.field_id.clone(),
       field_type: sort_rev.field_type.into(),
       condition: sort_rev.condition.clone().into(),
+      nulls_order: sort_rev.nulls_order.map(|nulls_order| nulls_order.into()),
     }
   }
 }
@@ -74,6 +78,28 @@ impl std::convert::From<SortCondition> for SortConditionPB {
   }
 }
 
+/// Groups the empty cells at the top or the bottom, whatever the sort condition is.
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum NullsOrderPB {
+  First = 0,
+  Last = 1,
+}
+impl std::default::Default for NullsOrderPB {
+  fn default() -> Self {
+    Self::Last
+  }
+}
+
+impl std::convert::From<NullsOrder> for NullsOrderPB {
+  fn from(nulls_order: NullsOrder) -> Self {
+    match nulls_order {
+      NullsOrder::First => NullsOrderPB::First,
+      NullsOrder::Last => NullsOrderPB::Last,
+    }
+  }
+}
+
 #[derive(ProtoBuf, Debug, Default, Clone)]
 pub struct AlterSortPayloadPB {
   #[pb(index = 1)]
@@ -91,6 +117,10 @@ pub struct AlterSortPayloadPB {
 
   #[pb(index = 5)]
   pub condition: SortConditionPB,
+
+  /// Compares the empty cells as the default value of the field type if it's None.
+  #[pb(index = 6, one_of)]
+  pub nulls_order: Option<NullsOrderPB>,
 }
 
 impl TryInto<AlterSortParams> for AlterSortPayloadPB {
@@ -120,6 +150,7 @@ impl TryInto<AlterSortParams> for AlterSortPayloadPB {
       sort_id,
       field_type: self.field_type.into(),
       condition: self.condition as u8,
+      nulls_order: self.nulls_order.map(|nulls_order| nulls_order as u8),
     })
   }
 }
@@ -132,6 +163,7 @@ pub struct AlterSortParams {
   pub sort_id: Option<String>,
   pub field_type: FieldTypeRevision,
   pub condition: u8,
+  pub nulls_order: Option<u8>,
 }
 
 #[derive(ProtoBuf, Debug, Default, Clone)]
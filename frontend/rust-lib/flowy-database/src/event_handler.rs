@@ -472,10 +472,7 @@ pub(crate) async fn get_select_option_handler(
         .await?;
       let type_option = select_type_option_from_field_rev(&field_rev)?;
       let type_cell_data: TypeCellData = match cell_rev {
-        None => TypeCellData {
-          cell_str: "".to_string(),
-          field_type: field_rev.ty.into(),
-        },
+        None => TypeCellData::from_field_type(&field_rev.ty.into()),
         Some(cell_rev) => cell_rev.try_into()?,
       };
       let ids = SelectOptionIds::from_cell_str(&type_cell_data.cell_str)?;
@@ -521,6 +518,8 @@ pub(crate) async fn update_date_cell_handler(
     time: data.time,
     include_time: data.include_time,
     is_utc: data.is_utc,
+    precision: None,
+    end_timestamp: data.end_timestamp,
   };
 
   let editor = manager.get_database_editor(&cell_path.view_id).await?;
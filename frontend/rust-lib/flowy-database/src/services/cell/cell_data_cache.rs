@@ -1,74 +1,205 @@
 use parking_lot::RwLock;
 use std::any::{type_name, Any};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::services::filter::FilterType;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 pub type AtomicCellDataCache = Arc<RwLock<AnyTypeCache<u64>>>;
 pub type AtomicCellFilterCache = Arc<RwLock<AnyTypeCache<FilterType>>>;
 
-#[derive(Default, Debug)]
-pub struct AnyTypeCache<TypeValueKey>(HashMap<TypeValueKey, TypeValue>);
+/// The default number of entries kept by the [AnyTypeCache].
+pub const DEFAULT_CACHE_CAPACITY: usize = 50_000;
+
+/// The hit and miss counters and the number of entries of an [AnyTypeCache].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+  pub hits: u64,
+  pub misses: u64,
+  pub len: usize,
+}
+
+/// A cache of values of any type, which evicts the least recently used entries once it holds
+/// more than `capacity` entries.
+///
+/// The `get` only requires a read guard, so the recency of each entry is tracked with an atomic
+/// tick instead of reordering the entries. Finding the least recently used entries requires a
+/// scan, so the eviction removes a tenth of the entries at once to keep the inserts cheap.
+#[derive(Debug)]
+pub struct AnyTypeCache<TypeValueKey> {
+  values: HashMap<TypeValueKey, TypeValue>,
+  /// The keys inserted by [AnyTypeCache::insert_for_field], grouped by the field id.
+  field_keys: HashMap<String, HashSet<TypeValueKey>>,
+  capacity: usize,
+  clock: AtomicU64,
+  hits: AtomicU64,
+  misses: AtomicU64,
+}
+
+impl<TypeValueKey> Default for AnyTypeCache<TypeValueKey> {
+  fn default() -> Self {
+    Self {
+      values: HashMap::default(),
+      field_keys: HashMap::default(),
+      capacity: DEFAULT_CACHE_CAPACITY,
+      clock: AtomicU64::new(0),
+      hits: AtomicU64::new(0),
+      misses: AtomicU64::new(0),
+    }
+  }
+}
 
 impl<TypeValueKey> AnyTypeCache<TypeValueKey>
 where
   TypeValueKey: Clone + Hash + Eq,
 {
   pub fn new() -> Arc<RwLock<AnyTypeCache<TypeValueKey>>> {
-    Arc::new(RwLock::new(AnyTypeCache(HashMap::default())))
+    Self::with_capacity(DEFAULT_CACHE_CAPACITY)
+  }
+
+  /// Creates the cache that keeps at most `capacity` entries. The capacity is at least one.
+  pub fn with_capacity(capacity: usize) -> Arc<RwLock<AnyTypeCache<TypeValueKey>>> {
+    Arc::new(RwLock::new(AnyTypeCache {
+      capacity: capacity.max(1),
+      ..Default::default()
+    }))
   }
 
   pub fn insert<T>(&mut self, key: &TypeValueKey, val: T) -> Option<T>
+  where
+    T: 'static + Send + Sync,
+  {
+    let tick = self.tick();
+    let old_value = self
+      .values
+      .insert(key.clone(), TypeValue::new(val, tick))
+      .and_then(downcast_owned);
+    if self.values.len() > self.capacity {
+      self.evict();
+    }
+    old_value
+  }
+
+  /// Same as [AnyTypeCache::insert] but remembers that the key belongs to the field, so it's
+  /// removed by [AnyTypeCache::invalidate_field].
+  pub fn insert_for_field<T>(&mut self, field_id: &str, key: &TypeValueKey, val: T) -> Option<T>
   where
     T: 'static + Send + Sync,
   {
     self
-      .0
-      .insert(key.clone(), TypeValue::new(val))
-      .and_then(downcast_owned)
+      .field_keys
+      .entry(field_id.to_owned())
+      .or_default()
+      .insert(key.clone());
+    self.insert(key, val)
   }
 
+  /// Removes the entry, and forgets the field that the key belongs to, so a later
+  /// [AnyTypeCache::invalidate_field] doesn't remove the value that is inserted again.
   pub fn remove(&mut self, key: &TypeValueKey) {
-    self.0.remove(key);
+    self.values.remove(key);
+    self.field_keys.retain(|_, keys| {
+      keys.remove(key);
+      !keys.is_empty()
+    });
+  }
+
+  /// Removes all the entries of the field. It's called after the field is changed, so the values
+  /// decoded with the old type option are never returned even if the new key collides with an
+  /// old one.
+  pub fn invalidate_field(&mut self, field_id: &str) {
+    if let Some(keys) = self.field_keys.remove(field_id) {
+      for key in keys {
+        self.values.remove(&key);
+      }
+    }
   }
 
   // pub fn remove<T, K: AsRef<TypeValueKey>>(&mut self, key: K) -> Option<T>
   //     where
   //         T: 'static + Send + Sync,
   // {
-  //     self.0.remove(key.as_ref()).and_then(downcast_owned)
+  //     self.values.remove(key.as_ref()).and_then(downcast_owned)
   // }
 
   pub fn get<T>(&self, key: &TypeValueKey) -> Option<&T>
   where
     T: 'static + Send + Sync,
   {
-    self
-      .0
-      .get(key)
-      .and_then(|type_value| type_value.boxed.downcast_ref())
+    let type_value = self.values.get(key)?;
+    type_value.touch(self.tick());
+    type_value.boxed.downcast_ref()
   }
 
   pub fn get_mut<T>(&mut self, key: &TypeValueKey) -> Option<&mut T>
   where
     T: 'static + Send + Sync,
   {
-    self
-      .0
-      .get_mut(key)
-      .and_then(|type_value| type_value.boxed.downcast_mut())
+    let tick = self.tick();
+    let type_value = self.values.get_mut(key)?;
+    type_value.touch(tick);
+    type_value.boxed.downcast_mut()
   }
 
+  /// Returns whether the key is cached without marking it as recently used.
   pub fn contains(&self, key: &TypeValueKey) -> bool {
-    self.0.contains_key(key)
+    self.values.contains_key(key)
+  }
+
+  pub fn len(&self) -> usize {
+    self.values.len()
   }
 
   pub fn is_empty(&self) -> bool {
-    self.0.is_empty()
+    self.values.is_empty()
+  }
+
+  /// Counts a lookup that found the value. The counters are only updated by the callers that
+  /// want to measure the cache, so the `get` doesn't count by itself.
+  pub fn record_hit(&self) {
+    self.hits.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Counts a lookup that didn't find the value, see [AnyTypeCache::record_hit].
+  pub fn record_miss(&self) {
+    self.misses.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn stats(&self) -> CacheStats {
+    CacheStats {
+      hits: self.hits.load(Ordering::Relaxed),
+      misses: self.misses.load(Ordering::Relaxed),
+      len: self.values.len(),
+    }
+  }
+
+  fn tick(&self) -> u64 {
+    self.clock.fetch_add(1, Ordering::Relaxed)
+  }
+
+  /// Removes the least recently used entries until the cache is a tenth below its capacity.
+  fn evict(&mut self) {
+    let retained = self.capacity - self.capacity / 10;
+    let mut last_accesses = self
+      .values
+      .values()
+      .map(|type_value| type_value.last_access())
+      .collect::<Vec<u64>>();
+    last_accesses.sort_unstable();
+    // The ticks are unique, so exactly `retained` entries are accessed at or after the threshold.
+    let threshold = last_accesses[last_accesses.len() - retained];
+    self
+      .values
+      .retain(|_, type_value| type_value.last_access() >= threshold);
+    let values = &self.values;
+    self.field_keys.retain(|_, keys| {
+      keys.retain(|key| values.contains_key(key));
+      !keys.is_empty()
+    });
   }
 }
 
@@ -81,18 +212,28 @@ struct TypeValue {
   boxed: Box<dyn Any + Send + Sync + 'static>,
   #[allow(dead_code)]
   ty: &'static str,
+  last_access: AtomicU64,
 }
 
 impl TypeValue {
-  pub fn new<T>(value: T) -> Self
+  pub fn new<T>(value: T, tick: u64) -> Self
   where
     T: Send + Sync + 'static,
   {
     Self {
       boxed: Box::new(value),
       ty: type_name::<T>(),
+      last_access: AtomicU64::new(tick),
     }
   }
+
+  fn touch(&self, tick: u64) {
+    self.last_access.store(tick, Ordering::Relaxed);
+  }
+
+  fn last_access(&self) -> u64 {
+    self.last_access.load(Ordering::Relaxed)
+  }
 }
 
 impl std::ops::Deref for TypeValue {
@@ -109,20 +250,79 @@ impl std::ops::DerefMut for TypeValue {
   }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use crate::services::cell::CellDataCache;
-//
-//     #[test]
-//     fn test() {
-//         let mut ext = CellDataCache::new();
-//         ext.insert("1", "a".to_string());
-//         ext.insert("2", 2);
-//
-//         let a: &String = ext.get("1").unwrap();
-//         assert_eq!(a, "a");
-//
-//         let a: Option<&usize> = ext.get("1");
-//         assert!(a.is_none());
-//     }
-// }
+#[cfg(test)]
+mod tests {
+  use crate::services::cell::AnyTypeCache;
+
+  #[test]
+  fn any_type_cache_downcast_test() {
+    let cache = AnyTypeCache::<u64>::new();
+    let mut write_guard = cache.write();
+    write_guard.insert(&1, "a".to_string());
+    write_guard.insert(&2, 2_usize);
+
+    let a: &String = write_guard.get(&1).unwrap();
+    assert_eq!(a, "a");
+
+    let a: Option<&usize> = write_guard.get(&1);
+    assert!(a.is_none());
+  }
+
+  #[test]
+  fn any_type_cache_invalidate_field_test() {
+    let cache = AnyTypeCache::<u64>::new();
+    let mut write_guard = cache.write();
+    write_guard.insert_for_field("a", &1, 1_u64);
+    write_guard.insert_for_field("a", &2, 2_u64);
+    write_guard.insert_for_field("b", &3, 3_u64);
+    write_guard.insert(&4, 4_u64);
+
+    write_guard.invalidate_field("a");
+    assert!(!write_guard.contains(&1));
+    assert!(!write_guard.contains(&2));
+    assert!(write_guard.contains(&3));
+    assert!(write_guard.contains(&4));
+
+    // Invalidating the unknown field does nothing.
+    write_guard.invalidate_field("c");
+    assert_eq!(write_guard.len(), 2);
+  }
+
+  #[test]
+  fn any_type_cache_remove_test() {
+    let cache = AnyTypeCache::<u64>::new();
+    let mut write_guard = cache.write();
+    write_guard.insert_for_field("a", &1, 1_u64);
+    write_guard.remove(&1);
+    assert!(!write_guard.contains(&1));
+
+    // The key no longer belongs to the field after it's removed.
+    write_guard.insert(&1, 1_u64);
+    write_guard.invalidate_field("a");
+    assert!(write_guard.contains(&1));
+  }
+
+  #[test]
+  fn any_type_cache_lru_eviction_test() {
+    let cache = AnyTypeCache::<u64>::with_capacity(10);
+    for key in 0..10 {
+      cache.write().insert(&key, key);
+    }
+    assert_eq!(cache.read().len(), 10);
+
+    // Reading the oldest key makes it the most recently used one.
+    assert_eq!(cache.read().get::<u64>(&0), Some(&0));
+
+    // Exceeding the capacity evicts the least recently used tenth of the entries, which are the
+    // keys 1 and 2 here.
+    cache.write().insert(&10, 10_u64);
+    let read_guard = cache.read();
+    assert_eq!(read_guard.len(), 9);
+    assert!(read_guard.contains(&0));
+    assert!(!read_guard.contains(&1));
+    assert!(!read_guard.contains(&2));
+    for key in 3..=10 {
+      assert!(read_guard.contains(&key));
+    }
+  }
+}
@@ -33,6 +33,56 @@ pub trait CellDataDecoder: TypeOption {
   /// For example, The string of the Multi-Select cell will be a list of the option's name
   /// separated by a comma.
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String;
+
+  /// Same as `decode_cell_data_to_str` but displays the number with `precision` decimal places,
+  /// which is the per-cell precision stored in the [TypeCellData]. Defaults to ignore the
+  /// precision, since only the number cells have decimal places.
+  fn decode_cell_data_to_str_with_precision(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+    _precision: u32,
+  ) -> String {
+    self.decode_cell_data_to_str(cell_data)
+  }
+
+  /// Decode the cell data to the text that is read by the screen readers. For example, the
+  /// checkbox cell is read as `Checked` instead of its readable string, `Yes`.
+  fn accessibility_label(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+    _field_rev: &FieldRevision,
+  ) -> String {
+    self.decode_cell_data_to_str(cell_data)
+  }
+
+  /// Decode the cell data to the native value of the spreadsheet. Defaults to the readable string
+  /// of the cell data.
+  fn decode_cell_data_to_spreadsheet_value(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> SpreadsheetValue {
+    SpreadsheetValue::Text(self.decode_cell_data_to_str(cell_data))
+  }
+
+  /// Decode the cell data to the keys of the pivot table headers. Defaults to the readable string
+  /// of the cell data.
+  fn decode_cell_data_to_pivot_keys(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> Vec<String> {
+    let s = self.decode_cell_data_to_str(cell_data);
+    if s.is_empty() {
+      vec![]
+    } else {
+      vec![s]
+    }
+  }
+
+  /// Decode the cell data to the markdown that is used when exporting to the documents. Defaults
+  /// to the readable string of the cell data.
+  fn decode_cell_data_to_markdown(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    self.decode_cell_data_to_str(cell_data)
+  }
 }
 
 pub trait CellDataChangeset: TypeOption {
@@ -45,6 +95,66 @@ pub trait CellDataChangeset: TypeOption {
     changeset: <Self as TypeOption>::CellChangeset,
     type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)>;
+
+  /// Converts the JSON value, for example, the value posted by the webhook, into the changeset.
+  /// Defaults to accept the string, and the number or the boolean as their string. The null
+  /// value clears the cell.
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    let s = match value {
+      serde_json::Value::Null => "".to_owned(),
+      serde_json::Value::String(s) => s.clone(),
+      serde_json::Value::Number(n) => n.to_string(),
+      serde_json::Value::Bool(b) => b.to_string(),
+      _ => return Err(invalid_json_value_error(value, "a string")),
+    };
+    <Self as TypeOption>::CellChangeset::from_changeset(s)
+  }
+
+  /// Checks the changeset without applying it, so the invalid input can be reported before the
+  /// cell is written instead of being stored as an empty cell. Defaults to accept the changeset.
+  fn validate_changeset(
+    &self,
+    _changeset: &<Self as TypeOption>::CellChangeset,
+  ) -> FlowyResult<()> {
+    Ok(())
+  }
+
+  /// Returns false if the cell is derived from the other cells, so the user can't edit it.
+  fn is_editable(&self) -> bool {
+    true
+  }
+
+  /// Infers the changeset of the cell that follows the `cells` when filling a series, for example,
+  /// `4` after `1, 2, 3`. The `cells` are not empty. Returns None if there is no pattern.
+  fn next_in_series(
+    &self,
+    _cells: &[<Self as TypeOption>::CellData],
+  ) -> Option<<Self as TypeOption>::CellChangeset> {
+    None
+  }
+}
+
+/// Returns the common difference of the arithmetic sequence, for example, `2` for `1, 3, 5`.
+/// Returns None if there are fewer than two values or the differences are not the same.
+pub fn series_step<T>(values: &[T]) -> Option<T>
+where
+  T: Copy + PartialEq + std::ops::Sub<Output = T>,
+{
+  let step = values.get(1)?.sub(values[0]);
+  values
+    .windows(2)
+    .all(|pair| pair[1] - pair[0] == step)
+    .then_some(step)
+}
+
+/// Returns the error of the JSON value that can't be converted into the cell. The `expected`
+/// describes the accepted values, for example, `a number or a numeric string`.
+pub fn invalid_json_value_error(value: &serde_json::Value, expected: &str) -> FlowyError {
+  let msg = format!("Expected {}, but got: {}", expected, value);
+  FlowyError::new(ErrorCode::InvalidData, &msg)
 }
 
 /// changeset: It will be deserialized into specific data base on the FieldType.
@@ -67,6 +177,10 @@ pub fn apply_cell_data_changeset<C: ToCellChangesetString, T: AsRef<FieldRevisio
     Ok(type_cell_data) => Some(type_cell_data),
     Err(_) => None,
   });
+  // The precision of the cell is kept when its value changes.
+  let precision = type_cell_data
+    .as_ref()
+    .and_then(|type_cell_data| type_cell_data.precision);
 
   let cell_str = match TypeOptionCellExt::new_with_cell_data_cache(field_rev, cell_data_cache)
     .get_type_option_cell_data_handler(&field_type)
@@ -74,7 +188,29 @@ pub fn apply_cell_data_changeset<C: ToCellChangesetString, T: AsRef<FieldRevisio
     None => "".to_string(),
     Some(handler) => handler.handle_cell_changeset(changeset, type_cell_data, field_rev)?,
   };
-  Ok(TypeCellData::new(cell_str, field_type).to_json())
+  let mut type_cell_data = TypeCellData::new(cell_str, field_type);
+  type_cell_data.precision = precision;
+  Ok(type_cell_data.to_json())
+}
+
+/// Sets the number of decimal places that the number cell is displayed with, see
+/// [TypeCellData::precision]. None falls back to the `decimal_places` of the field. The value of
+/// the cell is kept, and so is the precision when the value changes later.
+pub fn apply_cell_precision(
+  cell_rev: Option<CellRevision>,
+  field_rev: &FieldRevision,
+  precision: Option<u32>,
+) -> FlowyResult<String> {
+  let field_type: FieldType = field_rev.ty.into();
+  if !field_type.is_number() {
+    let msg = format!("The precision can't be set on the {:?} cell", field_type);
+    return Err(FlowyError::new(ErrorCode::FieldInvalidOperation, &msg));
+  }
+  let mut type_cell_data = cell_rev
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .unwrap_or_else(|| TypeCellData::from_field_type(&field_type));
+  type_cell_data.precision = precision;
+  Ok(type_cell_data.to_json())
 }
 
 pub fn get_type_cell_protobuf<T: TryInto<TypeCellData, Error = FlowyError> + Debug>(
@@ -88,6 +224,7 @@ pub fn get_type_cell_protobuf<T: TryInto<TypeCellData, Error = FlowyError> + Deb
       let TypeCellData {
         cell_str,
         field_type,
+        ..
       } = type_cell_data;
       match try_decode_cell_str_to_cell_protobuf(
         cell_str,
@@ -127,6 +264,7 @@ where
       let TypeCellData {
         cell_str,
         field_type,
+        ..
       } = type_cell_data;
       try_decode_cell_str_to_cell_data(
         cell_str,
@@ -254,6 +392,8 @@ pub fn insert_date_cell(date_cell_data: DateCellData, field_rev: &FieldRevision)
     time: None,
     include_time: Some(date_cell_data.include_time),
     is_utc: true,
+    precision: date_cell_data.precision,
+    end_timestamp: date_cell_data.end_timestamp.map(|t| t.to_string()),
   })
   .unwrap();
   let data = apply_cell_data_changeset(cell_data, None, field_rev, None).unwrap();
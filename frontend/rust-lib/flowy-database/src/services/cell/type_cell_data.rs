@@ -19,6 +19,16 @@ pub struct TypeCellData {
   #[serde(rename = "data")]
   pub cell_str: String,
   pub field_type: FieldType,
+
+  /// The timestamp, in seconds, when the cell data was computed. Only the derived cells, whose
+  /// data is computed from other sources, have this timestamp.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub last_computed: Option<i64>,
+
+  /// The number of decimal places that the number cell is displayed with, which overrides the
+  /// `decimal_places` of the field. The stored number keeps its full precision.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub precision: Option<u32>,
 }
 
 impl TypeCellData {
@@ -26,6 +36,8 @@ impl TypeCellData {
     Self {
       cell_str: "".to_string(),
       field_type: field_type.clone(),
+      last_computed: None,
+      precision: None,
     }
   }
 
@@ -77,9 +89,21 @@ impl TypeCellData {
     TypeCellData {
       cell_str,
       field_type,
+      last_computed: None,
+      precision: None,
     }
   }
 
+  pub fn with_last_computed(mut self, last_computed: i64) -> Self {
+    self.last_computed = Some(last_computed);
+    self
+  }
+
+  pub fn with_precision(mut self, precision: u32) -> Self {
+    self.precision = Some(precision);
+    self
+  }
+
   pub fn to_json(&self) -> String {
     serde_json::to_string(self).unwrap_or_else(|_| "".to_owned())
   }
@@ -119,6 +143,10 @@ impl TypeCellData {
   pub fn is_select_option(&self) -> bool {
     self.field_type == FieldType::MultiSelect || self.field_type == FieldType::SingleSelect
   }
+
+  pub fn is_relation(&self) -> bool {
+    self.field_type == FieldType::Relation
+  }
 }
 
 /// The data is encoded by protobuf or utf8. You should choose the corresponding decode struct to parse it.
@@ -3,13 +3,14 @@ use crate::entities::*;
 use crate::manager::DatabaseUser;
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::cell::{
-  apply_cell_data_changeset, get_type_cell_protobuf, stringify_cell_data, AnyTypeCache,
-  AtomicCellDataCache, CellProtobufBlob, ToCellChangesetString, TypeCellData,
+  apply_cell_data_changeset, apply_cell_precision, get_type_cell_protobuf, stringify_cell_data,
+  AnyTypeCache, AtomicCellDataCache, CellProtobufBlob, ToCellChangesetString, TypeCellData,
 };
 use crate::services::database::DatabaseBlocks;
 use crate::services::field::{
-  default_type_option_builder_from_type, transform_type_option, type_option_builder_from_bytes,
-  FieldBuilder, RowSingleCellData,
+  concat_cells_of_row, default_type_option_builder_from_type, transform_type_option,
+  type_option_builder_from_bytes, ConcatTypeOptionPB, FieldBuilder, RowSingleCellData,
+  SingleSelectTypeOptionPB,
 };
 
 use crate::services::database::DatabaseViewDataImpl;
@@ -175,6 +176,8 @@ impl DatabaseEditor {
         Ok(changeset)
       })
       .await?;
+    self.cell_data_cache.write().invalidate_field(field_id);
+    self.update_concat_cells_of_source(field_id).await?;
 
     self
       .database_views
@@ -229,6 +232,7 @@ impl DatabaseEditor {
 
   pub async fn update_field(&self, params: FieldChangesetParams) -> FlowyResult<()> {
     let field_id = params.field_id.clone();
+    let is_field_type_changed = params.field_type.is_some();
     self
       .modify(|pad| {
         let changeset = pad.modify_field(&params.field_id, |field| {
@@ -250,11 +254,17 @@ impl DatabaseEditor {
           if let Some(width) = params.width {
             field.width = width;
           }
+          if let Some(is_required) = params.is_required {
+            field.is_required = is_required;
+          }
           Ok(Some(()))
         })?;
         Ok(changeset)
       })
       .await?;
+    if is_field_type_changed {
+      self.cell_data_cache.write().invalidate_field(&field_id);
+    }
     self.notify_did_update_database_field(&field_id).await?;
     Ok(())
   }
@@ -276,6 +286,7 @@ impl DatabaseEditor {
       .await?;
 
     if is_changed {
+      self.cell_data_cache.write().invalidate_field(field_id);
       match self
         .database_views
         .did_update_field_type_option(view_id, field_id, old_field_rev)
@@ -293,6 +304,8 @@ impl DatabaseEditor {
     self
       .modify(|pad| Ok(pad.delete_field_rev(field_id)?))
       .await?;
+    self.cell_data_cache.write().invalidate_field(field_id);
+    self.update_concat_cells_of_source(field_id).await?;
     let field_order = FieldIdPB::from(field_id);
     let notified_changeset = DatabaseFieldChangesetPB::delete(&self.database_id, vec![field_order]);
     self.notify_did_update_database(notified_changeset).await?;
@@ -323,7 +336,17 @@ impl DatabaseEditor {
     field_id: &str,
     new_field_type: &FieldType,
   ) -> FlowyResult<()> {
-    //
+    // The distinct texts of the cells become the options when switching a text field to single
+    // select.
+    let text_cell_strs = match self.get_field_rev(field_id).await {
+      Some(field_rev)
+        if new_field_type.is_single_select() && FieldType::from(field_rev.ty).is_text() =>
+      {
+        self.get_text_cell_strs(field_id).await?
+      },
+      _ => vec![],
+    };
+
     let make_default_type_option = || -> String {
       return default_type_option_builder_from_type(new_field_type)
         .serializer()
@@ -334,12 +357,19 @@ impl DatabaseEditor {
                                  old_type_option: Option<String>,
                                  new_type_option: String| {
       let old_field_type: FieldType = old_field_type.into();
-      transform_type_option(
+      let type_option_data = transform_type_option(
         &new_type_option,
         new_field_type,
         old_type_option,
         old_field_type,
-      )
+      );
+      if text_cell_strs.is_empty() {
+        return type_option_data;
+      }
+
+      let mut single_select = SingleSelectTypeOptionPB::from_json_str(&type_option_data);
+      single_select.transform_text_cells(text_cell_strs.iter().map(|s| s.as_str()));
+      single_select.json_str()
     };
 
     self
@@ -352,12 +382,27 @@ impl DatabaseEditor {
         )?)
       })
       .await?;
+    self.cell_data_cache.write().invalidate_field(field_id);
+    self.update_concat_cells_of_source(field_id).await?;
 
     self.notify_did_update_database_field(field_id).await?;
 
     Ok(())
   }
 
+  async fn get_text_cell_strs(&self, field_id: &str) -> FlowyResult<Vec<String>> {
+    let blocks = self.database_blocks.get_blocks(None).await?;
+    let cell_strs = blocks
+      .iter()
+      .flat_map(|block| block.row_revs.iter())
+      .flat_map(|row_rev| row_rev.cells.get(field_id))
+      .flat_map(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+      .filter(|type_cell_data| type_cell_data.field_type.is_text())
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .collect();
+    Ok(cell_strs)
+  }
+
   pub async fn duplicate_field(&self, field_id: &str) -> FlowyResult<()> {
     let duplicated_field_id = gen_field_id();
     self
@@ -433,6 +478,13 @@ impl DatabaseEditor {
       .database_views
       .will_create_row(&mut row_rev, &params)
       .await;
+    let field_revs = self.get_field_revs(None).await?;
+    let concat_cells = concat_cells_of_row(
+      &field_revs,
+      &row_rev.cells,
+      Some(self.cell_data_cache.clone()),
+    );
+    row_rev.cells.extend(concat_cells);
 
     let row_pb = self
       .create_row_pb(row_rev, params.start_row_id.clone())
@@ -450,9 +502,16 @@ impl DatabaseEditor {
 
   pub async fn insert_rows(&self, row_revs: Vec<RowRevision>) -> FlowyResult<Vec<RowPB>> {
     let block_id = self.block_id().await?;
+    let field_revs = self.get_field_revs(None).await?;
     let mut rows_by_block_id: HashMap<String, Vec<RowRevision>> = HashMap::new();
     let mut row_orders = vec![];
-    for row_rev in row_revs {
+    for mut row_rev in row_revs {
+      let concat_cells = concat_cells_of_row(
+        &field_revs,
+        &row_rev.cells,
+        Some(self.cell_data_cache.clone()),
+      );
+      row_rev.cells.extend(concat_cells);
       row_orders.push(RowPB::from(&row_rev));
       rows_by_block_id
         .entry(block_id.clone())
@@ -666,6 +725,12 @@ impl DatabaseEditor {
           type_cell_data,
         };
         self.database_blocks.update_cell(cell_changeset).await?;
+        let field_revs = self.get_field_revs(None).await?;
+        if is_concat_source(&field_revs, field_id) {
+          if let Some(row_rev) = self.get_row_rev(row_id).await? {
+            self.update_concat_cells(&field_revs, &row_rev).await?;
+          }
+        }
         self
           .database_views
           .did_update_row(old_row_rev, row_id)
@@ -687,6 +752,38 @@ impl DatabaseEditor {
       .await
   }
 
+  /// Overrides the number of decimal places of a number cell, see [apply_cell_precision].
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn update_cell_precision(
+    &self,
+    row_id: &str,
+    field_id: &str,
+    precision: Option<u32>,
+  ) -> FlowyResult<()> {
+    let field_rev = match self.database_pad.read().await.get_field_rev(field_id) {
+      None => {
+        let msg = format!("Field with id:{} not found", &field_id);
+        return Err(FlowyError::internal().context(msg));
+      },
+      Some((_, field_rev)) => field_rev.clone(),
+    };
+    let old_row_rev = self.get_row_rev(row_id).await?.clone();
+    let cell_rev = self.get_cell_rev(row_id, field_id).await?;
+    let type_cell_data = apply_cell_precision(cell_rev, &field_rev, precision)?;
+    let cell_changeset = CellChangesetPB {
+      view_id: self.database_id.clone(),
+      row_id: row_id.to_owned(),
+      field_id: field_id.to_owned(),
+      type_cell_data,
+    };
+    self.database_blocks.update_cell(cell_changeset).await?;
+    self
+      .database_views
+      .did_update_row(old_row_rev, row_id)
+      .await;
+    Ok(())
+  }
+
   pub async fn get_block_meta_revs(&self) -> FlowyResult<Vec<Arc<DatabaseBlockMetaRevision>>> {
     let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
     Ok(block_meta_revs)
@@ -1000,6 +1097,50 @@ impl DatabaseEditor {
     view_editor.v_get_calendar_event(row_id).await
   }
 
+  /// Stores the recomputed concat cells of the row, see [concat_cells_of_row]. Returns true if
+  /// any of the concat cells changed.
+  async fn update_concat_cells(
+    &self,
+    field_revs: &[Arc<FieldRevision>],
+    row_rev: &RowRevision,
+  ) -> FlowyResult<bool> {
+    let concat_cells = concat_cells_of_row(
+      field_revs,
+      &row_rev.cells,
+      Some(self.cell_data_cache.clone()),
+    );
+    let is_changed = !concat_cells.is_empty();
+    for (field_id, cell_rev) in concat_cells {
+      let cell_changeset = CellChangesetPB {
+        view_id: self.database_id.clone(),
+        row_id: row_rev.id.clone(),
+        field_id,
+        type_cell_data: cell_rev.type_cell_data,
+      };
+      self.database_blocks.update_cell(cell_changeset).await?;
+    }
+    Ok(is_changed)
+  }
+
+  /// Recomputes the concat cells of all the rows if the field is a concat field or one of the
+  /// source fields of a concat field, for example, after its type option changed or it's deleted.
+  async fn update_concat_cells_of_source(&self, field_id: &str) -> FlowyResult<()> {
+    let field_revs = self.get_field_revs(None).await?;
+    if !is_concat_source(&field_revs, field_id) {
+      return Ok(());
+    }
+
+    for row_rev in self.database_blocks.get_row_revs().await? {
+      if self.update_concat_cells(&field_revs, &row_rev).await? {
+        self
+          .database_views
+          .did_update_row(Some(row_rev.clone()), &row_rev.id)
+          .await;
+      }
+    }
+    Ok(())
+  }
+
   async fn create_row_rev(
     &self,
     cell_data_by_field_id: Option<HashMap<String, String>>,
@@ -1121,6 +1262,20 @@ impl DatabaseEditor {
   }
 }
 
+/// Returns true if the field is a concat field or one of the source fields of a concat field, so
+/// the rows don't need to be read when there is no concat field depending on it.
+fn is_concat_source(field_revs: &[Arc<FieldRevision>], field_id: &str) -> bool {
+  field_revs.iter().any(|field_rev| {
+    FieldType::from(field_rev.ty).is_concat()
+      && (field_rev.id == field_id
+        || field_rev
+          .get_type_option::<ConcatTypeOptionPB>(field_rev.ty)
+          .map_or(false, |type_option| {
+            type_option.source_field_ids.iter().any(|id| id == field_id)
+          }))
+  })
+}
+
 #[cfg(feature = "flowy_unit_test")]
 impl DatabaseEditor {
   pub fn rev_manager(&self) -> Arc<RevisionManager<Arc<ConnectionPool>>> {
@@ -19,8 +19,8 @@ use crate::services::sort::{
 };
 use database_model::{
   gen_database_filter_id, gen_database_id, gen_database_sort_id, CalendarLayoutSetting,
-  FieldRevision, FieldTypeRevision, FilterRevision, LayoutRevision, RowChangeset, RowRevision,
-  SortRevision,
+  FieldRevision, FieldTypeRevision, FilterRevision, LayoutRevision, NullsOrder, RowChangeset,
+  RowRevision, SortRevision,
 };
 use flowy_client_sync::client_database::{
   make_database_view_operations, DatabaseViewRevisionChangeset, DatabaseViewRevisionPad,
@@ -501,6 +501,7 @@ impl DatabaseViewEditor {
       field_id: params.field_id.clone(),
       field_type: params.field_type,
       condition: params.condition.into(),
+      nulls_order: params.nulls_order.map(NullsOrder::from),
     };
 
     let mut sort_controller = self.sort_controller.write().await;
@@ -40,6 +40,7 @@ impl FieldBuilder {
       width: field.width,
       type_options: IndexMap::default(),
       is_primary: field.is_primary,
+      is_required: field.is_required,
     };
     Self {
       field_rev,
@@ -62,6 +63,11 @@ impl FieldBuilder {
     self
   }
 
+  pub fn required(mut self, is_required: bool) -> Self {
+    self.field_rev.is_required = is_required;
+    self
+  }
+
   pub fn visibility(mut self, visibility: bool) -> Self {
     self.field_rev.visibility = visibility;
     self
@@ -21,6 +21,15 @@ pub fn default_type_option_builder_from_type(field_type: &FieldType) -> Box<dyn
     FieldType::Checkbox => CheckboxTypeOptionPB::default().into(),
     FieldType::URL => URLTypeOptionPB::default().into(),
     FieldType::Checklist => ChecklistTypeOptionPB::default().into(),
+    FieldType::Concat => ConcatTypeOptionPB::default().into(),
+    FieldType::Order => OrderTypeOptionPB::default().into(),
+    FieldType::Relation => RelationTypeOptionPB::default().into(),
+    FieldType::Duration => DurationTypeOptionPB::default().into(),
+    FieldType::Rating => RatingTypeOptionPB::default().into(),
+    FieldType::IpAddress => IpTypeOptionPB::default().into(),
+    FieldType::Json => JsonTypeOptionPB::default().into(),
+    FieldType::Email => EmailTypeOptionPB::default().into(),
+    FieldType::Phone => PhoneTypeOptionPB::default().into(),
   };
 
   type_option_builder_from_json_str(&s, field_type)
@@ -39,6 +48,15 @@ pub fn type_option_builder_from_json_str(
     FieldType::Checkbox => Box::new(CheckboxTypeOptionBuilder::from_json_str(s)),
     FieldType::URL => Box::new(URLTypeOptionBuilder::from_json_str(s)),
     FieldType::Checklist => Box::new(ChecklistTypeOptionBuilder::from_json_str(s)),
+    FieldType::Concat => Box::new(ConcatTypeOptionBuilder::from_json_str(s)),
+    FieldType::Order => Box::new(OrderTypeOptionBuilder::from_json_str(s)),
+    FieldType::Relation => Box::new(RelationTypeOptionBuilder::from_json_str(s)),
+    FieldType::Duration => Box::new(DurationTypeOptionBuilder::from_json_str(s)),
+    FieldType::Rating => Box::new(RatingTypeOptionBuilder::from_json_str(s)),
+    FieldType::IpAddress => Box::new(IpTypeOptionBuilder::from_json_str(s)),
+    FieldType::Json => Box::new(JsonTypeOptionBuilder::from_json_str(s)),
+    FieldType::Email => Box::new(EmailTypeOptionBuilder::from_json_str(s)),
+    FieldType::Phone => Box::new(PhoneTypeOptionBuilder::from_json_str(s)),
   }
 }
 
@@ -56,5 +74,14 @@ pub fn type_option_builder_from_bytes<T: Into<Bytes>>(
     FieldType::Checkbox => Box::new(CheckboxTypeOptionBuilder::from_protobuf_bytes(bytes)),
     FieldType::URL => Box::new(URLTypeOptionBuilder::from_protobuf_bytes(bytes)),
     FieldType::Checklist => Box::new(ChecklistTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::Concat => Box::new(ConcatTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::Order => Box::new(OrderTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::Relation => Box::new(RelationTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::Duration => Box::new(DurationTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::Rating => Box::new(RatingTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::IpAddress => Box::new(IpTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::Json => Box::new(JsonTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::Email => Box::new(EmailTypeOptionBuilder::from_protobuf_bytes(bytes)),
+    FieldType::Phone => Box::new(PhoneTypeOptionBuilder::from_protobuf_bytes(bytes)),
   }
 }
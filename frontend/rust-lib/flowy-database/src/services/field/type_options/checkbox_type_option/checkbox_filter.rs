@@ -2,11 +2,20 @@ use crate::entities::{CheckboxFilterConditionPB, CheckboxFilterPB};
 use crate::services::field::CheckboxCellData;
 
 impl CheckboxFilterPB {
-  pub fn is_visible(&self, cell_data: &CheckboxCellData) -> bool {
+  /// If `tri_state` is false, the unset cell is treated as unchecked and the `IsUnset` condition
+  /// never matches.
+  pub fn is_visible(&self, cell_data: &CheckboxCellData, tri_state: bool) -> bool {
     let is_check = cell_data.is_check();
     match self.condition {
       CheckboxFilterConditionPB::IsChecked => is_check,
-      CheckboxFilterConditionPB::IsUnChecked => !is_check,
+      CheckboxFilterConditionPB::IsUnChecked => {
+        if tri_state {
+          cell_data.is_uncheck()
+        } else {
+          !is_check
+        }
+      },
+      CheckboxFilterConditionPB::IsUnset => tri_state && cell_data.is_unset(),
     }
   }
 }
@@ -29,7 +38,7 @@ mod tests {
       ("no", false),
     ] {
       let data = CheckboxCellData::from_str(value).unwrap();
-      assert_eq!(checkbox_filter.is_visible(&data), visible);
+      assert_eq!(checkbox_filter.is_visible(&data, false), visible);
     }
   }
 
@@ -45,7 +54,36 @@ mod tests {
       ("yes", false),
     ] {
       let data = CheckboxCellData::from_str(value).unwrap();
-      assert_eq!(checkbox_filter.is_visible(&data), visible);
+      assert_eq!(checkbox_filter.is_visible(&data, false), visible);
     }
   }
+
+  #[test]
+  fn checkbox_filter_tri_state_test() {
+    for (condition, expected) in [
+      (CheckboxFilterConditionPB::IsChecked, [true, false, false]),
+      (CheckboxFilterConditionPB::IsUnChecked, [false, true, false]),
+      (CheckboxFilterConditionPB::IsUnset, [false, false, true]),
+    ] {
+      let checkbox_filter = CheckboxFilterPB { condition };
+      for (value, visible) in ["yes", "no", ""].into_iter().zip(expected) {
+        let data = CheckboxCellData::from_str(value).unwrap();
+        assert_eq!(checkbox_filter.is_visible(&data, true), visible);
+      }
+    }
+  }
+
+  #[test]
+  fn checkbox_filter_unset_without_tri_state_test() {
+    let data = CheckboxCellData::from_str("").unwrap();
+    let unchecked_filter = CheckboxFilterPB {
+      condition: CheckboxFilterConditionPB::IsUnChecked,
+    };
+    assert!(unchecked_filter.is_visible(&data, false));
+
+    let unset_filter = CheckboxFilterPB {
+      condition: CheckboxFilterConditionPB::IsUnset,
+    };
+    assert!(!unset_filter.is_visible(&data, false));
+  }
 }
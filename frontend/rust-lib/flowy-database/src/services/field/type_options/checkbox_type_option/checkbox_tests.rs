@@ -1,11 +1,24 @@
 #[cfg(test)]
 mod tests {
-  use crate::entities::FieldType;
-  use crate::services::cell::CellDataDecoder;
+  use crate::entities::{CheckboxFilterConditionPB, CheckboxFilterPB, FieldType};
+  use crate::services::cell::{
+    insert_checkbox_cell, insert_text_cell, AnyTypeCache, CellDataChangeset, CellDataDecoder,
+    TypeCellData,
+  };
   use crate::services::field::type_options::checkbox_type_option::*;
-  use crate::services::field::FieldBuilder;
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, ingest_json_value, is_cell_empty,
+    row_single_cell_data, spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, AggregateKind, CellGlyph, ComparableKind, FieldBuilder,
+    SelectOptionColorPB, SpreadsheetValue, TypeOptionCellData, TypeOptionCellExt,
+  };
+  use crate::services::group::make_no_status_group;
 
-  use database_model::FieldRevision;
+  use database_model::{CellRevision, FieldRevision};
+  use serde_json::json;
+  use std::collections::HashMap;
 
   #[test]
   fn checkout_box_description_test() {
@@ -31,6 +44,116 @@ mod tests {
     assert_checkbox(&type_option, "", "", &field_type, &field_rev);
   }
 
+  #[test]
+  fn checkbox_tri_state_test() {
+    let type_option = CheckboxTypeOptionPB {
+      tri_state: true,
+      ..Default::default()
+    };
+    let field_type = FieldType::Checkbox;
+    let field_rev = FieldBuilder::from_field_type(&field_type).build();
+
+    assert_checkbox(&type_option, "yes", CHECK, &field_type, &field_rev);
+    assert_checkbox(&type_option, "no", UNCHECK, &field_type, &field_rev);
+    assert_checkbox(&type_option, "", "", &field_type, &field_rev);
+
+    // Only the unset cell is empty
+    for (input_str, is_empty) in [("yes", false), ("no", false), ("", true)] {
+      let (_, cell_data) = type_option
+        .apply_changeset(input_str.to_owned(), None)
+        .unwrap();
+      assert_eq!(type_option.is_cell_empty(&cell_data), is_empty);
+    }
+  }
+
+  #[test]
+  fn checkbox_glyph_test() {
+    let checked = CellGlyph {
+      char: '✓',
+      color: Some(SelectOptionColorPB::Green),
+    };
+    let unchecked = CellGlyph {
+      char: '✗',
+      color: None,
+    };
+    for (tri_state, unset) in [(false, Some(unchecked.clone())), (true, None)] {
+      let mut field_rev = FieldBuilder::from_field_type(&FieldType::Checkbox).build();
+      field_rev.insert_type_option(&CheckboxTypeOptionPB {
+        tri_state,
+        ..Default::default()
+      });
+      let handler = cell_data_handler(&field_rev, &FieldType::Checkbox);
+      let glyph = |s: &str| handler.glyph(&insert_text_cell(s.to_owned(), &field_rev), &field_rev);
+
+      assert_eq!(glyph("yes"), Some(checked.clone()));
+      assert_eq!(glyph("no"), Some(unchecked.clone()));
+      assert_eq!(glyph(""), unset, "tri_state: {}", tri_state);
+    }
+  }
+
+  #[test]
+  fn checkbox_without_tri_state_empty_test() {
+    let type_option = CheckboxTypeOptionPB::default();
+    for (input_str, is_empty) in [("yes", false), ("no", true), ("", true)] {
+      let (_, cell_data) = type_option
+        .apply_changeset(input_str.to_owned(), None)
+        .unwrap();
+      assert_eq!(type_option.is_cell_empty(&cell_data), is_empty);
+    }
+  }
+
+  #[test]
+  fn checkbox_custom_labels_test() {
+    let type_option = CheckboxTypeOptionBuilder::default()
+      .set_labels("On", "Off")
+      .0;
+    for (input_str, expected_cell_str, expected_label) in [
+      ("On", CHECK, "On"),
+      ("off", UNCHECK, "Off"),
+      ("yes", CHECK, "On"),
+      ("0", UNCHECK, "Off"),
+      ("maybe", "", ""),
+    ] {
+      let (cell_str, cell_data) = type_option
+        .apply_changeset(input_str.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected_cell_str);
+      assert_eq!(
+        type_option.decode_cell_data_to_str(cell_data),
+        expected_label
+      );
+    }
+
+    // The label round-trips through the changeset.
+    let type_option = CheckboxTypeOptionBuilder::default().set_labels("✓", "✗").0;
+    let (_, cell_data) = type_option.apply_changeset("✓".to_owned(), None).unwrap();
+    let label = type_option.decode_cell_data_to_str(cell_data);
+    assert_eq!(label, "✓");
+    let (cell_str, _) = type_option.apply_changeset(label, None).unwrap();
+    assert_eq!(cell_str, CHECK);
+  }
+
+  #[test]
+  fn checkbox_labels_cache_test() {
+    let cell_data_cache = AnyTypeCache::<u64>::new();
+    let mut field_rev =
+      FieldBuilder::new(CheckboxTypeOptionBuilder::default().set_labels("On", "Off")).build();
+    let stringify = |field_rev: &FieldRevision| {
+      let ext =
+        TypeOptionCellExt::new_with_cell_data_cache(field_rev, Some(cell_data_cache.clone()));
+      let handler = ext
+        .get_type_option_cell_data_handler(&FieldType::Checkbox)
+        .unwrap();
+      handler.stringify_cell_str(CHECK.to_owned(), &FieldType::Checkbox, field_rev)
+    };
+    assert_eq!(stringify(&field_rev), "On");
+
+    // The cached cell data of the old labels isn't reused.
+    let type_option = CheckboxTypeOptionBuilder::default().set_labels("✓", "✗").0;
+    field_rev.insert_type_option(&type_option);
+    assert_eq!(stringify(&field_rev), "✓");
+  }
+
   fn assert_checkbox(
     type_option: &CheckboxTypeOptionPB,
     input_str: &str,
@@ -46,4 +169,194 @@ mod tests {
       expected_str.to_owned()
     );
   }
+
+  #[test]
+  fn checkbox_ingest_json_value_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Checkbox).build();
+    assert_eq!(ingest_json_value(&field_rev, json!(true)).unwrap(), CHECK);
+    assert_eq!(
+      ingest_json_value(&field_rev, json!(false)).unwrap(),
+      UNCHECK
+    );
+    assert_eq!(ingest_json_value(&field_rev, json!("true")).unwrap(), CHECK);
+    assert_eq!(
+      ingest_json_value(&field_rev, json!("FALSE")).unwrap(),
+      UNCHECK
+    );
+    assert_eq!(ingest_json_value(&field_rev, json!(1)).unwrap(), CHECK);
+    assert_eq!(ingest_json_value(&field_rev, json!(null)).unwrap(), "");
+
+    assert!(ingest_json_value(&field_rev, json!("maybe")).is_err());
+    assert!(ingest_json_value(&field_rev, json!(2)).is_err());
+    assert!(ingest_json_value(&field_rev, json!([true])).is_err());
+  }
+
+  #[test]
+  fn checkbox_group_sort_weight_test() {
+    let field_rev = FieldBuilder::new(CheckboxTypeOptionBuilder::default()).build();
+    let no_status_group = make_no_status_group(&field_rev);
+
+    let mut group_ids = vec![
+      UNCHECK.to_string(),
+      no_status_group.id.clone(),
+      CHECK.to_string(),
+    ];
+    let handler = cell_data_handler(&field_rev, &FieldType::Checkbox);
+    group_ids.sort_by_key(|group_id| handler.group_sort_weight(group_id, &field_rev));
+    assert_eq!(
+      group_ids,
+      vec![CHECK.to_string(), UNCHECK.to_string(), no_status_group.id]
+    );
+  }
+
+  #[test]
+  fn checkbox_percent_checked_test() {
+    let field_rev =
+      FieldBuilder::new(CheckboxTypeOptionBuilder::default().set_tri_state(true)).build();
+    let cells = vec![
+      insert_checkbox_cell(true, &field_rev),
+      insert_checkbox_cell(false, &field_rev),
+      insert_text_cell("".to_owned(), &field_rev),
+    ];
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    assert_eq!(ext.percent_checked(&cells, false), Some(50.0));
+    assert_eq!(ext.percent_checked(&cells, true), Some(100.0 / 3.0));
+    assert_eq!(
+      ext.aggregate(&cells, AggregateKind::PercentChecked),
+      Some(50.0)
+    );
+    assert_eq!(ext.percent_checked(&cells[2..], false), None);
+    assert_eq!(ext.percent_checked(&cells[2..], true), Some(0.0));
+
+    // The unchecked cell counts even if the checkbox isn't tri-state.
+    let field_rev = FieldBuilder::new(CheckboxTypeOptionBuilder::default()).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    assert_eq!(ext.percent_checked(&cells, false), Some(50.0));
+    assert_eq!(ext.percent_checked(&cells, true), Some(100.0 / 3.0));
+    let unchecked_cells = vec![
+      insert_checkbox_cell(false, &field_rev),
+      insert_checkbox_cell(false, &field_rev),
+    ];
+    assert_eq!(ext.percent_checked(&unchecked_cells, false), Some(0.0));
+  }
+
+  #[test]
+  fn checkbox_diff_against_baseline_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Checkbox).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let checkbox_cell =
+      |s: &str| CellRevision::new(TypeCellData::new(s.to_owned(), FieldType::Checkbox).to_json());
+
+    assert!(!ext.diff_against_baseline(&checkbox_cell("true"), &checkbox_cell(CHECK)));
+    assert!(ext.diff_against_baseline(&checkbox_cell(UNCHECK), &checkbox_cell(CHECK)));
+
+    let cells = vec![
+      ("row_1".to_owned(), checkbox_cell("1")),
+      ("row_2".to_owned(), checkbox_cell(UNCHECK)),
+      ("row_3".to_owned(), checkbox_cell(CHECK)),
+      ("row_4".to_owned(), checkbox_cell("")),
+    ];
+    let mut baseline = HashMap::new();
+    baseline.insert("row_1".to_owned(), checkbox_cell(CHECK));
+    baseline.insert("row_2".to_owned(), checkbox_cell(CHECK));
+    assert_eq!(
+      ext.rows_changed_since_baseline(&cells, &baseline),
+      vec!["row_2".to_owned(), "row_3".to_owned()]
+    );
+  }
+
+  #[test]
+  fn checkbox_accessibility_label_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Checkbox).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Checkbox);
+    for (is_check, expected) in [(true, "Checked"), (false, "Unchecked")] {
+      let cell_rev = insert_checkbox_cell(is_check, &field_rev);
+      let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+      let label = handler.accessibility_label(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &field_rev,
+      );
+      assert_eq!(label, expected);
+    }
+
+    // The unset cell is only told apart from the unchecked cell if the checkbox is tri-state.
+    let unset_label = |field_rev: &FieldRevision| {
+      cell_data_handler(field_rev, &FieldType::Checkbox).accessibility_label(
+        "".to_owned(),
+        &FieldType::Checkbox,
+        field_rev,
+      )
+    };
+    assert_eq!(unset_label(&field_rev), "Unchecked");
+    let tri_state_field_rev =
+      FieldBuilder::new(CheckboxTypeOptionBuilder::default().set_tri_state(true)).build();
+    assert_eq!(unset_label(&tri_state_field_rev), "Not set");
+    let cell_rev = insert_checkbox_cell(false, &tri_state_field_rev);
+    let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+    assert_eq!(
+      cell_data_handler(&tri_state_field_rev, &FieldType::Checkbox).accessibility_label(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &tri_state_field_rev
+      ),
+      "Unchecked"
+    );
+  }
+
+  #[test]
+  fn checkbox_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Checkbox).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Checkbox);
+    assert_eq!(handler.comparable_kind(), ComparableKind::Bool);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::Checkbox,
+      &FieldType::Checkbox
+    ));
+    assert!(is_transform_lossless(
+      &FieldType::Checkbox,
+      &FieldType::SingleSelect
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = CheckboxFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, CheckboxFilterConditionPB::IsChecked);
+
+    let cell_rev = insert_checkbox_cell(true, &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Bool(true)
+    );
+    assert!(cell_value(&field_rev, &cell_rev).checkbox.unwrap());
+  }
+
+  #[test]
+  fn checkbox_is_cell_empty_test() {
+    // The checkbox filter has no empty condition, but the unchecked cells are empty.
+    let field_rev = FieldBuilder::new(CheckboxTypeOptionBuilder::default()).build();
+    assert!(is_cell_empty(&field_rev, None));
+    assert!(is_cell_empty(
+      &field_rev,
+      Some(&insert_checkbox_cell(false, &field_rev))
+    ));
+    assert!(!is_cell_empty(
+      &field_rev,
+      Some(&insert_checkbox_cell(true, &field_rev))
+    ));
+  }
+
+  #[test]
+  fn checkbox_row_single_cell_data_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Checkbox).build();
+    let cell_rev = insert_checkbox_cell(true, &field_rev);
+
+    let cell_data = row_single_cell_data(&field_rev, &cell_rev)
+      .into_checkbox_field_cell_data()
+      .unwrap();
+    assert!(cell_data.is_check());
+    assert!(row_single_cell_data(&field_rev, &cell_rev)
+      .into_check_list_field_cell_data()
+      .is_none());
+  }
 }
@@ -1,9 +1,13 @@
-use crate::entities::{CheckboxFilterPB, FieldType};
+use crate::entities::{CellValuePB, CheckboxFilterConditionPB, CheckboxFilterPB, FieldType};
 use crate::impl_type_option;
-use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::cell::{
+  invalid_json_value_error, CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData,
+};
 use crate::services::field::{
-  default_order, BoxTypeOptionBuilder, CheckboxCellData, TypeOption, TypeOptionBuilder,
-  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+  default_order, BoxTypeOptionBuilder, CellGlyph, CheckboxCellData, ComparableKind,
+  SelectOptionColorPB, SpreadsheetValue, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, CHECK,
+  NO_STATUS_GROUP_SORT_WEIGHT, UNCHECK,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
@@ -23,6 +27,17 @@ impl CheckboxTypeOptionBuilder {
     self.0.is_selected = is_selected;
     self
   }
+
+  pub fn set_tri_state(mut self, tri_state: bool) -> Self {
+    self.0.tri_state = tri_state;
+    self
+  }
+
+  pub fn set_labels(mut self, true_label: &str, false_label: &str) -> Self {
+    self.0.true_label = true_label.to_owned();
+    self.0.false_label = false_label.to_owned();
+    self
+  }
 }
 
 impl TypeOptionBuilder for CheckboxTypeOptionBuilder {
@@ -35,13 +50,75 @@ impl TypeOptionBuilder for CheckboxTypeOptionBuilder {
   }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+#[derive(Debug, Clone, Serialize, Deserialize, ProtoBuf)]
 pub struct CheckboxTypeOptionPB {
   #[pb(index = 1)]
   pub is_selected: bool,
+
+  /// If true, the cell can be unset which is different from unchecked. Otherwise, the unset
+  /// cell is treated as unchecked.
+  #[pb(index = 2)]
+  #[serde(default)]
+  pub tri_state: bool,
+
+  /// The text that is shown for the checked cell, for example, `On` or `✓`. The cell data is
+  /// always stored as [CHECK], so changing the label doesn't touch the cells.
+  #[pb(index = 3)]
+  #[serde(default = "DEFAULT_TRUE_LABEL")]
+  pub true_label: String,
+
+  /// The text that is shown for the unchecked cell, see [CheckboxTypeOptionPB::true_label].
+  #[pb(index = 4)]
+  #[serde(default = "DEFAULT_FALSE_LABEL")]
+  pub false_label: String,
 }
 impl_type_option!(CheckboxTypeOptionPB, FieldType::Checkbox);
 
+const DEFAULT_TRUE_LABEL: fn() -> String = || CHECK.to_owned();
+const DEFAULT_FALSE_LABEL: fn() -> String = || UNCHECK.to_owned();
+
+impl std::default::Default for CheckboxTypeOptionPB {
+  fn default() -> Self {
+    Self {
+      is_selected: false,
+      tri_state: false,
+      true_label: DEFAULT_TRUE_LABEL(),
+      false_label: DEFAULT_FALSE_LABEL(),
+    }
+  }
+}
+
+impl CheckboxTypeOptionPB {
+  fn true_label(&self) -> &str {
+    if self.true_label.is_empty() {
+      CHECK
+    } else {
+      &self.true_label
+    }
+  }
+
+  fn false_label(&self) -> &str {
+    if self.false_label.is_empty() {
+      UNCHECK
+    } else {
+      &self.false_label
+    }
+  }
+
+  /// Parses the changeset into the cell data. The configured labels are accepted besides the
+  /// built-in values, such as `1`, `true` or `yes`.
+  fn cell_data_from_label(&self, s: &str) -> FlowyResult<CheckboxCellData> {
+    let s = s.trim();
+    if s.to_lowercase() == self.true_label().to_lowercase() {
+      CheckboxCellData::from_str(CHECK)
+    } else if s.to_lowercase() == self.false_label().to_lowercase() {
+      CheckboxCellData::from_str(UNCHECK)
+    } else {
+      CheckboxCellData::from_str(s)
+    }
+  }
+}
+
 impl TypeOption for CheckboxTypeOptionPB {
   type CellData = CheckboxCellData;
   type CellChangeset = CheckboxCellChangeset;
@@ -92,6 +169,39 @@ impl TypeOptionCellData for CheckboxTypeOptionPB {
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     CheckboxCellData::from_cell_str(&cell_str)
   }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    if self.tri_state {
+      cell_data.is_unset()
+    } else {
+      !cell_data.is_check()
+    }
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      checkbox: Some(cell_data.is_check()),
+      ..Default::default()
+    }
+  }
+
+  /// Returns the green `✓` for the checked cell and the `✗` for the unchecked cell. The unset cell
+  /// of the tri-state checkbox has no glyph.
+  fn glyph(&self, cell_data: &<Self as TypeOption>::CellData) -> Option<CellGlyph> {
+    if cell_data.is_check() {
+      Some(CellGlyph {
+        char: '✓',
+        color: Some(SelectOptionColorPB::Green),
+      })
+    } else if self.tri_state && cell_data.is_unset() {
+      None
+    } else {
+      Some(CellGlyph {
+        char: '✗',
+        color: None,
+      })
+    }
+  }
 }
 
 impl CellDataDecoder for CheckboxTypeOptionPB {
@@ -109,7 +219,34 @@ impl CellDataDecoder for CheckboxTypeOptionPB {
   }
 
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
-    cell_data.to_string()
+    if cell_data.is_check() {
+      self.true_label().to_owned()
+    } else if cell_data.is_uncheck() {
+      self.false_label().to_owned()
+    } else {
+      cell_data.to_string()
+    }
+  }
+
+  fn accessibility_label(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+    _field_rev: &FieldRevision,
+  ) -> String {
+    if cell_data.is_check() {
+      "Checked".to_owned()
+    } else if self.tri_state && cell_data.is_unset() {
+      "Not set".to_owned()
+    } else {
+      "Unchecked".to_owned()
+    }
+  }
+
+  fn decode_cell_data_to_spreadsheet_value(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> SpreadsheetValue {
+    SpreadsheetValue::Bool(cell_data.is_check())
   }
 }
 
@@ -121,9 +258,32 @@ impl CellDataChangeset for CheckboxTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     _type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let checkbox_cell_data = CheckboxCellData::from_str(&changeset)?;
+    let checkbox_cell_data = self.cell_data_from_label(&changeset)?;
     Ok((checkbox_cell_data.to_string(), checkbox_cell_data))
   }
+
+  /// Accepts the JSON boolean, the `0` or `1`, or the string that can be parsed as the checkbox,
+  /// for example, `"true"`, `"false"` or the labels of the field.
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    let s = match value {
+      serde_json::Value::Null => return Ok("".to_owned()),
+      serde_json::Value::Bool(true) => return Ok(CHECK.to_owned()),
+      serde_json::Value::Bool(false) => return Ok(UNCHECK.to_owned()),
+      serde_json::Value::Number(n) => n.to_string(),
+      serde_json::Value::String(s) => s.clone(),
+      _ => return Err(invalid_json_value_error(value, "a boolean")),
+    };
+    if s.trim().is_empty() {
+      return Ok("".to_owned());
+    }
+    match self.cell_data_from_label(&s) {
+      Ok(cell_data) if !cell_data.is_unset() => Ok(cell_data.to_string()),
+      _ => Err(invalid_json_value_error(value, "a boolean")),
+    }
+  }
 }
 
 impl TypeOptionCellDataFilter for CheckboxTypeOptionPB {
@@ -136,7 +296,11 @@ impl TypeOptionCellDataFilter for CheckboxTypeOptionPB {
     if !field_type.is_checkbox() {
       return true;
     }
-    filter.is_visible(cell_data)
+    filter.is_visible(cell_data, self.tri_state)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    CheckboxFilterConditionPB::IsChecked as u8
   }
 }
 
@@ -150,7 +314,27 @@ impl TypeOptionCellDataCompare for CheckboxTypeOptionPB {
       (true, true) => Ordering::Equal,
       (true, false) => Ordering::Greater,
       (false, true) => Ordering::Less,
-      (false, false) => default_order(),
+      (false, false) => {
+        if self.tri_state {
+          // The unset cell is placed before the unchecked cell
+          other_cell_data.is_unset().cmp(&cell_data.is_unset())
+        } else {
+          default_order()
+        }
+      },
+    }
+  }
+
+  fn group_sort_weight(&self, group_id: &str, _field_rev: &FieldRevision) -> i64 {
+    // The checked group comes before the unchecked group.
+    match group_id {
+      CHECK => 0,
+      UNCHECK => 1,
+      _ => NO_STATUS_GROUP_SORT_WEIGHT,
     }
   }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::Bool
+  }
 }
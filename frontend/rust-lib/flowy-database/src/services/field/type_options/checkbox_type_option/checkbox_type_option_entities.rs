@@ -22,6 +22,12 @@ impl CheckboxCellData {
   pub fn is_uncheck(&self) -> bool {
     self.0 == UNCHECK
   }
+
+  /// The cell is neither checked nor unchecked. Only distinguishable from unchecked when the
+  /// `tri_state` of the `CheckboxTypeOptionPB` is enabled.
+  pub fn is_unset(&self) -> bool {
+    self.0.is_empty()
+  }
 }
 
 impl AsRef<[u8]> for CheckboxCellData {
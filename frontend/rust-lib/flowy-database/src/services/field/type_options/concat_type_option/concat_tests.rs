@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+  use crate::services::cell::{
+    insert_number_cell, insert_text_cell, CellDataChangeset, TypeCellData,
+  };
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, spreadsheet_value,
+  };
+  use crate::services::field::{
+    concat_cells_of_row, is_transform_lossless, ComparableKind, ConcatTypeOptionBuilder,
+    ConcatTypeOptionPB, FieldBuilder, FieldRefResolver, NumberTypeOptionBuilder,
+    RichTextTypeOptionBuilder, SpreadsheetValue, StrCellData,
+  };
+  use database_model::FieldRevision;
+  use indexmap::IndexMap;
+  use std::sync::Arc;
+
+  #[test]
+  fn concat_text_and_number_test() {
+    let name_field_rev = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+      .name("Name")
+      .build();
+    let price_field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default())
+      .name("Price")
+      .build();
+    let type_option = ConcatTypeOptionPB {
+      source_field_ids: vec![name_field_rev.id.clone(), price_field_rev.id.clone()],
+      separator: " - ".to_owned(),
+    };
+
+    let mut row_cells = IndexMap::new();
+    row_cells.insert(
+      name_field_rev.id.clone(),
+      insert_text_cell("Apple".to_owned(), &name_field_rev),
+    );
+    row_cells.insert(
+      price_field_rev.id.clone(),
+      insert_number_cell(42, &price_field_rev),
+    );
+    let field_revs: Vec<Arc<FieldRevision>> = vec![
+      Arc::new(name_field_rev.clone()),
+      Arc::new(price_field_rev.clone()),
+    ];
+    let resolver = FieldRefResolver::new(&field_revs, None);
+    assert_eq!(
+      type_option.get_cell_data(&row_cells, &resolver).as_str(),
+      "Apple - 42"
+    );
+
+    // The empty source cell is skipped.
+    row_cells.insert(
+      name_field_rev.id.clone(),
+      insert_text_cell("".to_owned(), &name_field_rev),
+    );
+    assert_eq!(
+      type_option.get_cell_data(&row_cells, &resolver).as_str(),
+      "42"
+    );
+  }
+
+  #[test]
+  fn concat_cells_of_row_test() {
+    let name_field_rev = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+      .name("Name")
+      .build();
+    let concat_field_rev = FieldBuilder::new(
+      ConcatTypeOptionBuilder::default()
+        .add_source_field(&name_field_rev.id)
+        .separator(", "),
+    )
+    .name("Summary")
+    .build();
+    let field_revs: Vec<Arc<FieldRevision>> = vec![
+      Arc::new(name_field_rev.clone()),
+      Arc::new(concat_field_rev.clone()),
+    ];
+
+    let mut row_cells = IndexMap::new();
+    row_cells.insert(
+      name_field_rev.id.clone(),
+      insert_text_cell("Apple".to_owned(), &name_field_rev),
+    );
+    let concat_cells = concat_cells_of_row(&field_revs, &row_cells, None);
+    assert_eq!(concat_cells.len(), 1);
+    let (field_id, cell_rev) = concat_cells[0].clone();
+    assert_eq!(field_id, concat_field_rev.id);
+    assert_eq!(TypeCellData::try_from(&cell_rev).unwrap().cell_str, "Apple");
+
+    // The concat cell that is up to date is not returned.
+    row_cells.insert(field_id, cell_rev);
+    assert!(concat_cells_of_row(&field_revs, &row_cells, None).is_empty());
+  }
+
+  #[test]
+  fn concat_changeset_is_ignored_test() {
+    let type_option = ConcatTypeOptionPB::default();
+    let (cell_str, cell_data) = type_option
+      .apply_changeset("AppFlowy".to_owned(), None)
+      .unwrap();
+    assert!(cell_str.is_empty());
+    assert!(cell_data.is_empty());
+  }
+
+  #[test]
+  fn resolve_field_ref_test() {
+    let price_field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default())
+      .name("Price")
+      .build();
+    let name_field_rev = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+      .name("Name")
+      .build();
+    let mut row_cells = IndexMap::new();
+    row_cells.insert(
+      price_field_rev.id.clone(),
+      insert_number_cell(42, &price_field_rev),
+    );
+    row_cells.insert(
+      name_field_rev.id.clone(),
+      insert_text_cell("Apple".to_owned(), &name_field_rev),
+    );
+
+    let field_revs = vec![Arc::new(price_field_rev.clone()), Arc::new(name_field_rev)];
+    let resolver = FieldRefResolver::new(&field_revs, None);
+    let by_name = resolver
+      .resolve_field_ref("Price", &row_cells)
+      .and_then(|cell_data| cell_data.unbox_or_none::<StrCellData>())
+      .unwrap();
+    assert_eq!(by_name.as_str(), "42");
+
+    let by_id = resolver
+      .resolve_field_ref(&price_field_rev.id, &row_cells)
+      .and_then(|cell_data| cell_data.unbox_or_none::<StrCellData>())
+      .unwrap();
+    assert_eq!(by_id.as_str(), "42");
+  }
+
+  #[test]
+  fn stringify_formatted_text_field_ref_test() {
+    let name_field_rev = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+      .name("Name")
+      .build();
+    let mut row_cells = IndexMap::new();
+    row_cells.insert(
+      name_field_rev.id.clone(),
+      insert_text_cell(
+        r#"[{"insert":"Apple","attributes":{"bold":true}}]"#.to_owned(),
+        &name_field_rev,
+      ),
+    );
+
+    let field_revs = vec![Arc::new(name_field_rev)];
+    let resolver = FieldRefResolver::new(&field_revs, None);
+    assert_eq!(
+      resolver.stringify_field_ref("Name", &row_cells).unwrap(),
+      "Apple"
+    );
+  }
+
+  #[test]
+  fn resolve_missing_field_ref_test() {
+    let field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default())
+      .name("Price")
+      .build();
+    let mut row_cells = IndexMap::new();
+    row_cells.insert(field_rev.id.clone(), insert_number_cell(42, &field_rev));
+
+    let field_revs = vec![Arc::new(field_rev)];
+    let resolver = FieldRefResolver::new(&field_revs, None);
+    assert!(resolver.resolve_field_ref("Quantity", &row_cells).is_none());
+  }
+
+  #[test]
+  fn concat_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Concat).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Concat);
+    assert_eq!(handler.comparable_kind(), ComparableKind::String);
+    // The concat cells are computed from the source cells.
+    assert!(!handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::Concat,
+      &FieldType::Concat
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = TextFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, TextFilterConditionPB::Contains);
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_text_cell("".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("".to_owned())
+    );
+    assert!(cell_value(&field_rev, &cell_rev).text.is_some());
+  }
+}
@@ -0,0 +1,233 @@
+use crate::entities::{CellValuePB, FieldType, TextFilterConditionPB};
+use crate::impl_type_option;
+use crate::services::cell::{
+  AtomicCellDataCache, CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData,
+};
+use crate::services::field::{
+  BoxTypeOptionBuilder, ComparableKind, CompiledTextFilter, EmptyFilterCondition, FieldRefResolver,
+  StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{
+  CellRevision, FieldId, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer,
+};
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct ConcatTypeOptionBuilder(ConcatTypeOptionPB);
+impl_into_box_type_option_builder!(ConcatTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(ConcatTypeOptionBuilder, ConcatTypeOptionPB);
+
+impl ConcatTypeOptionBuilder {
+  pub fn add_source_field(mut self, field_id: &str) -> Self {
+    self.0.source_field_ids.push(field_id.to_owned());
+    self
+  }
+
+  pub fn separator(mut self, separator: &str) -> Self {
+    self.0.separator = separator.to_owned();
+    self
+  }
+}
+
+impl TypeOptionBuilder for ConcatTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Concat
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The cell of the concat field is read-only. Its content is computed by joining the display
+/// strings of the source cells in the same row, and it's stored in the cell whenever the row
+/// changes, see [concat_cells_of_row]. So the concat cells are rendered, filtered and sorted like
+/// the other cells.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct ConcatTypeOptionPB {
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub source_field_ids: Vec<String>,
+
+  #[pb(index = 2)]
+  #[serde(default)]
+  pub separator: String,
+}
+impl_type_option!(ConcatTypeOptionPB, FieldType::Concat);
+
+impl ConcatTypeOptionPB {
+  /// Joins the display strings of the source cells with the separator. The source field that
+  /// doesn't exist or whose cell is empty in the row is skipped.
+  pub fn get_cell_data(
+    &self,
+    row_cells: &IndexMap<FieldId, CellRevision>,
+    resolver: &FieldRefResolver,
+  ) -> StrCellData {
+    let s = self
+      .source_field_ids
+      .iter()
+      .flat_map(|field_id| resolver.stringify_field_ref(field_id, row_cells))
+      .filter(|s| !s.is_empty())
+      .collect::<Vec<String>>()
+      .join(&self.separator);
+    StrCellData(s)
+  }
+}
+
+/// Recomputes the cells of the concat fields in the row. Only the cells whose content changed are
+/// returned, keyed by the id of their field.
+pub fn concat_cells_of_row(
+  field_revs: &[Arc<FieldRevision>],
+  row_cells: &IndexMap<FieldId, CellRevision>,
+  cell_data_cache: Option<AtomicCellDataCache>,
+) -> Vec<(FieldId, CellRevision)> {
+  let resolver = FieldRefResolver::new(field_revs, cell_data_cache);
+  field_revs
+    .iter()
+    .filter(|field_rev| FieldType::from(field_rev.ty).is_concat())
+    .flat_map(|field_rev| {
+      let type_option = field_rev.get_type_option::<ConcatTypeOptionPB>(field_rev.ty)?;
+      let cell_str = type_option.get_cell_data(row_cells, &resolver).0;
+      let old_cell_str = row_cells
+        .get(&field_rev.id)
+        .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+        .map(|type_cell_data| type_cell_data.cell_str)
+        .unwrap_or_default();
+      if cell_str == old_cell_str {
+        return None;
+      }
+      let cell_rev = CellRevision::new(TypeCellData::new(cell_str, FieldType::Concat).to_json());
+      Some((field_rev.id.clone(), cell_rev))
+    })
+    .collect()
+}
+
+impl TypeOption for ConcatTypeOptionPB {
+  type CellData = StrCellData;
+  type CellChangeset = String;
+  type CellProtobufType = StrCellData;
+  type CellFilter = CompiledTextFilter;
+}
+
+impl TypeOptionTransform for ConcatTypeOptionPB {}
+
+impl TypeOptionCellData for ConcatTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    cell_data
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    StrCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.is_empty()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      text: Some(cell_data.0),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for ConcatTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_concat() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+}
+
+impl CellDataChangeset for ConcatTypeOptionPB {
+  /// The changeset is ignored because the cell is read-only.
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_str = type_cell_data
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .unwrap_or_default();
+    let cell_data = StrCellData::from_cell_str(&cell_str)?;
+    Ok((cell_str, cell_data))
+  }
+
+  /// Rejects any value because the cell is computed from the other cells of the row.
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    let msg = format!("The concat cell is read-only, but got: {}", value);
+    Err(FlowyError::new(ErrorCode::InvalidData, &msg))
+  }
+
+  fn is_editable(&self) -> bool {
+    false
+  }
+}
+
+impl TypeOptionCellDataFilter for ConcatTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_concat() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    TextFilterConditionPB::Contains as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for ConcatTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data.0.cmp(&other_cell_data.0)
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::String
+  }
+}
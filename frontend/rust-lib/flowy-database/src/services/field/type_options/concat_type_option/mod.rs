@@ -0,0 +1,5 @@
+#![allow(clippy::module_inception)]
+mod concat_tests;
+mod concat_type_option;
+
+pub use concat_type_option::*;
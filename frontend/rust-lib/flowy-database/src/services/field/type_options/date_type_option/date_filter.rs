@@ -1,8 +1,148 @@
 use crate::entities::{DateFilterConditionPB, DateFilterPB};
-use chrono::NaiveDateTime;
+use crate::services::field::{DateTypeOptionPB, EmptyFilterCondition};
+use crate::services::filter::FromFilterString;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Utc, Weekday};
+use database_model::FilterRevision;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// Returns the number of business days after `from` up to and including `to`. The weekends and
+/// the `holidays` are not business days. Returns a negative number if `to` is before `from`.
+pub fn business_days_between(from: NaiveDate, to: NaiveDate, holidays: &HashSet<NaiveDate>) -> i64 {
+  if to < from {
+    return -business_days_between(to, from, holidays);
+  }
+  from
+    .iter_days()
+    .skip(1)
+    .take_while(|date| *date <= to)
+    .filter(|date| !matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+    .filter(|date| !holidays.contains(date))
+    .count() as i64
+}
+
+/// The reference point of the relative date conditions, for example,
+/// [DateFilterConditionPB::DateIsThisWeek].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeDateContext {
+  pub today: NaiveDate,
+  pub first_day_of_week: Weekday,
+  /// The seconds east of UTC of the timezone that the dates are compared in.
+  pub timezone_offset: i32,
+}
+
+impl RelativeDateContext {
+  /// Returns the context of the current date in the timezone that is `timezone_offset` seconds
+  /// east of UTC.
+  pub fn now(timezone_offset: i32, first_day_of_week: Weekday) -> Self {
+    let now = Utc::now().naive_utc() + Duration::seconds(timezone_offset as i64);
+    Self {
+      today: now.date(),
+      first_day_of_week,
+      timezone_offset,
+    }
+  }
+
+  /// Returns the date of the timestamp in the timezone of the context.
+  fn local_date(&self, timestamp: i64) -> Option<NaiveDate> {
+    NaiveDateTime::from_timestamp_opt(timestamp + self.timezone_offset as i64, 0)
+      .map(|time| time.date())
+  }
+
+  fn start_of_week(&self) -> NaiveDate {
+    let days_from_start = (self.today.weekday().num_days_from_monday() + 7
+      - self.first_day_of_week.num_days_from_monday())
+      % 7;
+    self.today - Duration::days(days_from_start as i64)
+  }
+
+  fn start_of_month(&self) -> NaiveDate {
+    self.today.with_day(1).unwrap_or(self.today)
+  }
+
+  fn end_of_month(&self) -> NaiveDate {
+    let start = self.start_of_month();
+    let next_month = match start.month() {
+      12 => NaiveDate::from_ymd_opt(start.year() + 1, 1, 1),
+      month => NaiveDate::from_ymd_opt(start.year(), month + 1, 1),
+    };
+    next_month
+      .and_then(|date| date.pred_opt())
+      .unwrap_or(self.today)
+  }
+
+  /// Returns the dates that the relative condition matches. Returns None if the condition isn't
+  /// relative. The `days` is the number of days of the `DateWithin...Days` conditions.
+  pub fn date_range(
+    &self,
+    condition: &DateFilterConditionPB,
+    days: Option<i64>,
+  ) -> Option<RangeInclusive<NaiveDate>> {
+    let today = self.today;
+    let days = Duration::days(days.unwrap_or(0).max(0));
+    let range = match condition {
+      DateFilterConditionPB::DateIsToday => today..=today,
+      DateFilterConditionPB::DateIsTomorrow => {
+        let tomorrow = today + Duration::days(1);
+        tomorrow..=tomorrow
+      },
+      DateFilterConditionPB::DateIsYesterday => {
+        let yesterday = today - Duration::days(1);
+        yesterday..=yesterday
+      },
+      DateFilterConditionPB::DateIsThisWeek => {
+        let start = self.start_of_week();
+        start..=start + Duration::days(6)
+      },
+      DateFilterConditionPB::DateIsLastWeek => {
+        let start = self.start_of_week() - Duration::days(7);
+        start..=start + Duration::days(6)
+      },
+      DateFilterConditionPB::DateIsNextWeek => {
+        let start = self.start_of_week() + Duration::days(7);
+        start..=start + Duration::days(6)
+      },
+      DateFilterConditionPB::DateIsThisMonth => self.start_of_month()..=self.end_of_month(),
+      DateFilterConditionPB::DateWithinNextDays => today..=today + days,
+      DateFilterConditionPB::DateWithinLastDays => today - days..=today,
+      _ => return None,
+    };
+    Some(range)
+  }
+}
 
 impl DateFilterPB {
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      DateFilterConditionPB::DateIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      DateFilterConditionPB::DateIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
   pub fn is_visible<T: Into<Option<i64>>>(&self, cell_timestamp: T) -> bool {
+    self.is_visible_with_holidays(cell_timestamp, &HashSet::new())
+  }
+
+  pub fn is_visible_with_holidays<T: Into<Option<i64>>>(
+    &self,
+    cell_timestamp: T,
+    holidays: &HashSet<NaiveDate>,
+  ) -> bool {
+    let context = RelativeDateContext::now(0, Weekday::Sun);
+    self.is_visible_in(cell_timestamp, holidays, &context)
+  }
+
+  /// Same as [DateFilterPB::is_visible], but the `holidays` are excluded when counting the
+  /// business days for the [DateFilterConditionPB::DateOlderThanBusinessDays] condition, whose
+  /// reference date is the `timestamp` of the filter. The relative conditions are evaluated
+  /// against the `context`, and the timestamps are compared as the dates in its timezone.
+  pub fn is_visible_in<T: Into<Option<i64>>>(
+    &self,
+    cell_timestamp: T,
+    holidays: &HashSet<NaiveDate>,
+    context: &RelativeDateContext,
+  ) -> bool {
     match cell_timestamp.into() {
       None => DateFilterConditionPB::DateIsEmpty == self.condition,
       Some(timestamp) => {
@@ -16,8 +156,13 @@ impl DateFilterPB {
           _ => {},
         }
 
-        let cell_time = NaiveDateTime::from_timestamp_opt(timestamp, 0);
-        let cell_date = cell_time.map(|time| time.date());
+        let cell_date = context.local_date(timestamp);
+        if let Some(range) = context.date_range(&self.condition, self.days) {
+          return cell_date
+            .map(|cell_date| range.contains(&cell_date))
+            .unwrap_or(false);
+        }
+
         match self.timestamp {
           None => {
             if self.start.is_none() {
@@ -28,17 +173,13 @@ impl DateFilterPB {
               return true;
             }
 
-            let start_time = NaiveDateTime::from_timestamp_opt(*self.start.as_ref().unwrap(), 0);
-            let start_date = start_time.map(|time| time.date());
-
-            let end_time = NaiveDateTime::from_timestamp_opt(*self.end.as_ref().unwrap(), 0);
-            let end_date = end_time.map(|time| time.date());
+            let start_date = context.local_date(*self.start.as_ref().unwrap());
+            let end_date = context.local_date(*self.end.as_ref().unwrap());
 
             cell_date >= start_date && cell_date <= end_date
           },
           Some(timestamp) => {
-            let expected_timestamp = NaiveDateTime::from_timestamp_opt(timestamp, 0);
-            let expected_date = expected_timestamp.map(|time| time.date());
+            let expected_date = context.local_date(timestamp);
 
             // We assume that the cell_timestamp doesn't contain hours, just day.
             match self.condition {
@@ -47,6 +188,14 @@ impl DateFilterPB {
               DateFilterConditionPB::DateAfter => cell_date > expected_date,
               DateFilterConditionPB::DateOnOrBefore => cell_date <= expected_date,
               DateFilterConditionPB::DateOnOrAfter => cell_date >= expected_date,
+              DateFilterConditionPB::DateOlderThanBusinessDays => {
+                match (cell_date, expected_date, self.business_days) {
+                  (Some(cell_date), Some(expected_date), Some(business_days)) => {
+                    business_days_between(cell_date, expected_date, holidays) > business_days
+                  },
+                  _ => true,
+                }
+              },
               _ => true,
             }
           },
@@ -56,10 +205,65 @@ impl DateFilterPB {
   }
 }
 
+/// The cached form of the [DateFilterPB]. The `context` of the relative conditions and the
+/// `holidays` of the business day conditions are refreshed from the type option once per filter
+/// pass, see [CompiledDateFilter::refresh_context], instead of every time a cell is filtered.
+#[derive(Debug, Clone)]
+pub struct CompiledDateFilter {
+  filter: DateFilterPB,
+  context: RelativeDateContext,
+  holidays: HashSet<NaiveDate>,
+}
+
+impl CompiledDateFilter {
+  pub fn new(filter: DateFilterPB, context: RelativeDateContext) -> Self {
+    Self {
+      filter,
+      context,
+      holidays: HashSet::new(),
+    }
+  }
+
+  pub fn is_visible<T: Into<Option<i64>>>(&self, cell_timestamp: T) -> bool {
+    self
+      .filter
+      .is_visible_in(cell_timestamp, &self.holidays, &self.context)
+  }
+
+  /// Updates the current date, the timezone and the holidays from the type option of the field.
+  pub fn refresh_context(&mut self, type_option: &DateTypeOptionPB) {
+    self.context =
+      RelativeDateContext::now(type_option.timezone_offset, type_option.first_weekday());
+    self.holidays = type_option.holiday_dates();
+  }
+
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    self.filter.empty_filter_condition()
+  }
+}
+
+impl std::convert::From<&DateFilterPB> for CompiledDateFilter {
+  fn from(filter: &DateFilterPB) -> Self {
+    Self::new(filter.clone(), RelativeDateContext::now(0, Weekday::Sun))
+  }
+}
+
+impl FromFilterString for CompiledDateFilter {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    CompiledDateFilter::from(&DateFilterPB::from_filter_rev(filter_rev))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::all)]
   use crate::entities::{DateFilterConditionPB, DateFilterPB};
+  use crate::services::field::{business_days_between, RelativeDateContext};
+  use chrono::{NaiveDate, Weekday};
+  use std::collections::HashSet;
 
   #[test]
   fn date_filter_is_test() {
@@ -68,6 +272,8 @@ mod tests {
       timestamp: Some(1668387885),
       end: None,
       start: None,
+      business_days: None,
+      days: None,
     };
 
     for (val, visible) in vec![(1668387885, true), (1647251762, false)] {
@@ -81,6 +287,8 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      business_days: None,
+      days: None,
     };
 
     for (val, visible, msg) in vec![(1668387884, false, "1"), (1647251762, true, "2")] {
@@ -95,6 +303,8 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      business_days: None,
+      days: None,
     };
 
     for (val, visible) in vec![(1668387884, true), (1668387885, true)] {
@@ -108,6 +318,8 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      business_days: None,
+      days: None,
     };
 
     for (val, visible) in vec![(1668387888, false), (1668531885, true), (0, false)] {
@@ -122,6 +334,8 @@ mod tests {
       start: Some(1668272685), // 11/13
       end: Some(1668618285),   // 11/17
       timestamp: None,
+      business_days: None,
+      days: None,
     };
 
     for (val, visible, _msg) in vec![
@@ -140,10 +354,329 @@ mod tests {
       start: None,
       end: None,
       timestamp: None,
+      business_days: None,
+      days: None,
     };
 
     for (val, visible) in vec![(None, true), (Some(123), false)] {
       assert_eq!(filter.is_visible(val), visible);
     }
   }
+
+  #[test]
+  fn business_days_between_weekend_test() {
+    let friday = NaiveDate::from_ymd_opt(2022, 11, 11).unwrap();
+    let monday = NaiveDate::from_ymd_opt(2022, 11, 14).unwrap();
+    let wednesday = NaiveDate::from_ymd_opt(2022, 11, 16).unwrap();
+    let holidays = HashSet::new();
+    assert_eq!(business_days_between(friday, monday, &holidays), 1);
+    assert_eq!(business_days_between(friday, wednesday, &holidays), 3);
+    assert_eq!(business_days_between(wednesday, friday, &holidays), -3);
+    assert_eq!(business_days_between(friday, friday, &holidays), 0);
+  }
+
+  #[test]
+  fn business_days_between_holiday_test() {
+    let friday = NaiveDate::from_ymd_opt(2022, 11, 11).unwrap();
+    let wednesday = NaiveDate::from_ymd_opt(2022, 11, 16).unwrap();
+    let holidays = HashSet::from([
+      NaiveDate::from_ymd_opt(2022, 11, 14).unwrap(),
+      // The holiday on the weekend is not counted twice.
+      NaiveDate::from_ymd_opt(2022, 11, 12).unwrap(),
+    ]);
+    assert_eq!(business_days_between(friday, wednesday, &holidays), 2);
+  }
+
+  #[test]
+  fn date_filter_older_than_business_days_test() {
+    let filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateOlderThanBusinessDays,
+      timestamp: Some(1668618285), // Wed 11/16
+      start: None,
+      end: None,
+      business_days: Some(3),
+      days: None,
+    };
+    let holidays = HashSet::from([NaiveDate::from_ymd_opt(2022, 11, 14).unwrap()]);
+
+    for (val, visible, visible_with_holidays, msg) in vec![
+      (1668359085, false, false, "Sun 11/13"),
+      (1668099885, true, false, "Thu 11/10"),
+      (1668013485, true, true, "Wed 11/9"),
+      (1668704685, false, false, "Thu 11/17"),
+    ] {
+      assert_eq!(filter.is_visible(val as i64), visible, "{}", msg);
+      assert_eq!(
+        filter.is_visible_with_holidays(val as i64, &holidays),
+        visible_with_holidays,
+        "{}",
+        msg
+      );
+    }
+  }
+
+  fn relative_filter(condition: DateFilterConditionPB, days: Option<i64>) -> DateFilterPB {
+    DateFilterPB {
+      condition,
+      start: None,
+      end: None,
+      timestamp: None,
+      business_days: None,
+      days,
+    }
+  }
+
+  fn date_timestamp(year: i32, month: u32, day: u32) -> i64 {
+    NaiveDate::from_ymd_opt(year, month, day)
+      .unwrap()
+      .and_hms_opt(9, 30, 0)
+      .unwrap()
+      .timestamp()
+  }
+
+  #[test]
+  fn date_filter_relative_day_test() {
+    // Wed 11/16
+    let context = RelativeDateContext {
+      today: NaiveDate::from_ymd_opt(2022, 11, 16).unwrap(),
+      first_day_of_week: Weekday::Sun,
+      timezone_offset: 0,
+    };
+    let holidays = HashSet::new();
+    for (condition, days, date, visible) in vec![
+      (
+        DateFilterConditionPB::DateIsToday,
+        None,
+        (2022, 11, 16),
+        true,
+      ),
+      (
+        DateFilterConditionPB::DateIsToday,
+        None,
+        (2022, 11, 17),
+        false,
+      ),
+      (
+        DateFilterConditionPB::DateIsTomorrow,
+        None,
+        (2022, 11, 17),
+        true,
+      ),
+      (
+        DateFilterConditionPB::DateIsTomorrow,
+        None,
+        (2022, 11, 16),
+        false,
+      ),
+      (
+        DateFilterConditionPB::DateIsYesterday,
+        None,
+        (2022, 11, 15),
+        true,
+      ),
+      (
+        DateFilterConditionPB::DateIsYesterday,
+        None,
+        (2022, 11, 14),
+        false,
+      ),
+      (
+        DateFilterConditionPB::DateWithinNextDays,
+        Some(3),
+        (2022, 11, 19),
+        true,
+      ),
+      (
+        DateFilterConditionPB::DateWithinNextDays,
+        Some(3),
+        (2022, 11, 20),
+        false,
+      ),
+      (
+        DateFilterConditionPB::DateWithinNextDays,
+        Some(3),
+        (2022, 11, 15),
+        false,
+      ),
+      (
+        DateFilterConditionPB::DateWithinLastDays,
+        Some(3),
+        (2022, 11, 13),
+        true,
+      ),
+      (
+        DateFilterConditionPB::DateWithinLastDays,
+        Some(3),
+        (2022, 11, 12),
+        false,
+      ),
+      (
+        DateFilterConditionPB::DateWithinLastDays,
+        Some(3),
+        (2022, 11, 17),
+        false,
+      ),
+      (
+        DateFilterConditionPB::DateIsThisMonth,
+        None,
+        (2022, 11, 1),
+        true,
+      ),
+      (
+        DateFilterConditionPB::DateIsThisMonth,
+        None,
+        (2022, 11, 30),
+        true,
+      ),
+      (
+        DateFilterConditionPB::DateIsThisMonth,
+        None,
+        (2022, 12, 1),
+        false,
+      ),
+    ] {
+      let filter = relative_filter(condition.clone(), days);
+      let (year, month, day) = date;
+      assert_eq!(
+        filter.is_visible_in(date_timestamp(year, month, day), &holidays, &context),
+        visible,
+        "{:?} {:?}",
+        condition,
+        date
+      );
+    }
+
+    // The relative filters don't match the empty cell.
+    let filter = relative_filter(DateFilterConditionPB::DateIsToday, None);
+    assert_eq!(filter.is_visible_in(None, &holidays, &context), false);
+  }
+
+  #[test]
+  fn date_filter_relative_week_test() {
+    let today = NaiveDate::from_ymd_opt(2022, 11, 16).unwrap();
+    let holidays = HashSet::new();
+    for (first_day_of_week, condition, date, visible) in vec![
+      // The week is Sun 11/13 - Sat 11/19.
+      (
+        Weekday::Sun,
+        DateFilterConditionPB::DateIsThisWeek,
+        (2022, 11, 13),
+        true,
+      ),
+      (
+        Weekday::Sun,
+        DateFilterConditionPB::DateIsThisWeek,
+        (2022, 11, 19),
+        true,
+      ),
+      (
+        Weekday::Sun,
+        DateFilterConditionPB::DateIsThisWeek,
+        (2022, 11, 20),
+        false,
+      ),
+      (
+        Weekday::Sun,
+        DateFilterConditionPB::DateIsLastWeek,
+        (2022, 11, 6),
+        true,
+      ),
+      (
+        Weekday::Sun,
+        DateFilterConditionPB::DateIsLastWeek,
+        (2022, 11, 13),
+        false,
+      ),
+      (
+        Weekday::Sun,
+        DateFilterConditionPB::DateIsNextWeek,
+        (2022, 11, 20),
+        true,
+      ),
+      (
+        Weekday::Sun,
+        DateFilterConditionPB::DateIsNextWeek,
+        (2022, 11, 27),
+        false,
+      ),
+      // The week is Mon 11/14 - Sun 11/20.
+      (
+        Weekday::Mon,
+        DateFilterConditionPB::DateIsThisWeek,
+        (2022, 11, 13),
+        false,
+      ),
+      (
+        Weekday::Mon,
+        DateFilterConditionPB::DateIsThisWeek,
+        (2022, 11, 20),
+        true,
+      ),
+      (
+        Weekday::Mon,
+        DateFilterConditionPB::DateIsLastWeek,
+        (2022, 11, 13),
+        true,
+      ),
+      (
+        Weekday::Mon,
+        DateFilterConditionPB::DateIsNextWeek,
+        (2022, 11, 27),
+        true,
+      ),
+    ] {
+      let context = RelativeDateContext {
+        today,
+        first_day_of_week,
+        timezone_offset: 0,
+      };
+      let filter = relative_filter(condition.clone(), None);
+      let (year, month, day) = date;
+      assert_eq!(
+        filter.is_visible_in(date_timestamp(year, month, day), &holidays, &context),
+        visible,
+        "{:?} {:?} {:?}",
+        first_day_of_week,
+        condition,
+        date
+      );
+    }
+  }
+
+  #[test]
+  fn date_filter_this_month_at_year_end_test() {
+    let context = RelativeDateContext {
+      today: NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+      first_day_of_week: Weekday::Sun,
+      timezone_offset: 0,
+    };
+    let filter = relative_filter(DateFilterConditionPB::DateIsThisMonth, None);
+    let holidays = HashSet::new();
+    assert!(filter.is_visible_in(date_timestamp(2022, 12, 31), &holidays, &context));
+    assert!(!filter.is_visible_in(date_timestamp(2023, 1, 1), &holidays, &context));
+  }
+
+  #[test]
+  fn date_filter_relative_day_in_timezone_test() {
+    // 2022-11-16 22:00 UTC is already 11/17 in UTC+8.
+    let timestamp = NaiveDate::from_ymd_opt(2022, 11, 16)
+      .unwrap()
+      .and_hms_opt(22, 0, 0)
+      .unwrap()
+      .timestamp();
+    let filter = relative_filter(DateFilterConditionPB::DateIsToday, None);
+    let holidays = HashSet::new();
+    let context = RelativeDateContext {
+      today: NaiveDate::from_ymd_opt(2022, 11, 17).unwrap(),
+      first_day_of_week: Weekday::Sun,
+      timezone_offset: 8 * 3600,
+    };
+    assert!(filter.is_visible_in(timestamp, &holidays, &context));
+
+    let context = RelativeDateContext {
+      timezone_offset: 0,
+      ..context
+    };
+    assert!(!filter.is_visible_in(timestamp, &holidays, &context));
+  }
 }
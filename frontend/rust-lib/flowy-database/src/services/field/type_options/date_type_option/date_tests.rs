@@ -1,14 +1,25 @@
 #[cfg(test)]
 mod tests {
-  use crate::entities::FieldType;
-  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::entities::{DateFilterConditionPB, DateFilterPB, FieldType};
+  use crate::services::cell::{
+    apply_cell_data_changeset, insert_date_cell, CellDataChangeset, CellDataDecoder,
+    FromCellString, ToCellChangesetString, TypeCellData,
+  };
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, filter_is_empty, ingest_json_value,
+    spreadsheet_value,
+  };
 
   use crate::services::field::{
-    DateCellChangeset, DateFormat, DateTypeOptionPB, FieldBuilder, TimeFormat, TypeOptionCellData,
+    is_transform_lossless, AggregateKind, ComparableKind, CompiledDateFilter, DateCellChangeset,
+    DateCellData, DateFormat, DatePrecision, DateTypeOptionBuilder, DateTypeOptionPB, FieldBuilder,
+    RichTextTypeOptionPB, SpreadsheetValue, TimeFormat, TypeOptionCellData, TypeOptionCellExt,
+    TypeOptionTransform,
   };
   use chrono::format::strftime::StrftimeItems;
   use chrono::{FixedOffset, NaiveDateTime};
-  use database_model::FieldRevision;
+  use database_model::{CellRevision, FieldRevision};
+  use serde_json::json;
   use strum::IntoEnumIterator;
 
   #[test]
@@ -145,6 +156,107 @@ mod tests {
     assert_date(&type_option, "abc", None, "", false, &field_rev);
   }
 
+  #[test]
+  fn date_type_option_excel_serial_date_test() {
+    let mut type_option = DateTypeOptionPB {
+      date_format: DateFormat::ISO,
+      excel_serial_date: true,
+      ..Default::default()
+    };
+    let changeset = DateCellChangeset {
+      date: Some("44197".to_owned()),
+      time: None,
+      include_time: Some(false),
+      is_utc: false,
+      precision: None,
+      end_timestamp: None,
+    };
+    let (_, cell_data) = type_option
+      .apply_changeset(changeset.clone(), None)
+      .unwrap();
+    assert_eq!(cell_data.timestamp, Some(1609459200));
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "2021-01-01");
+
+    // The large integer is still treated as the timestamp.
+    let timestamp_changeset = DateCellChangeset {
+      date: Some("1647251762".to_owned()),
+      ..changeset.clone()
+    };
+    let (_, cell_data) = type_option
+      .apply_changeset(timestamp_changeset, None)
+      .unwrap();
+    assert_eq!(cell_data.timestamp, Some(1647251762));
+
+    // The serial date is treated as the timestamp if it's not enabled.
+    type_option.excel_serial_date = false;
+    let (_, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_data.timestamp, Some(44197));
+  }
+
+  #[test]
+  fn date_type_option_precision_test() {
+    let type_option = DateTypeOptionPB {
+      date_format: DateFormat::ISO,
+      ..Default::default()
+    };
+    // Mar 14,2022 09:56
+    let expected = [
+      (DatePrecision::Year, "2022", ""),
+      (DatePrecision::Month, "2022-03", ""),
+      (DatePrecision::Day, "2022-03-14", ""),
+      (DatePrecision::Time, "2022-03-14", "09:56"),
+    ];
+    for (precision, expected_date, expected_time) in expected {
+      let changeset = DateCellChangeset {
+        date: Some("1647251762".to_owned()),
+        time: None,
+        include_time: Some(false),
+        is_utc: true,
+        precision: Some(precision),
+        end_timestamp: None,
+      };
+      let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+      assert_eq!(cell_data.precision, Some(precision));
+
+      let cell_data = DateCellData::from_cell_str(&cell_str).unwrap();
+      let desc = type_option.convert_to_protobuf(cell_data);
+      assert_eq!(desc.date, expected_date);
+      assert_eq!(desc.time, expected_time);
+    }
+  }
+
+  #[test]
+  fn date_type_option_out_of_range_timestamp_test() {
+    let type_option = DateTypeOptionPB::default();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    for timestamp in [i64::MAX, i64::MIN] {
+      let changeset = DateCellChangeset {
+        date: Some(timestamp.to_string()),
+        time: None,
+        include_time: Some(false),
+        is_utc: false,
+        precision: None,
+        end_timestamp: None,
+      };
+      assert!(type_option.apply_changeset(changeset, None).is_err());
+
+      let cell_data = DateCellData {
+        timestamp: Some(timestamp),
+        include_time: true,
+        precision: None,
+        end_timestamp: None,
+      };
+      assert!(type_option
+        .decode_cell_str(cell_data.to_string(), &FieldType::DateTime, &field_rev)
+        .is_err());
+      assert_eq!(type_option.decode_cell_data_to_str(cell_data.clone()), "");
+
+      let cell_data_pb = type_option.convert_to_protobuf(cell_data);
+      assert!(cell_data_pb.date.is_empty());
+      assert!(cell_data_pb.time.is_empty());
+    }
+  }
+
   #[test]
   #[should_panic]
   fn date_type_option_invalid_include_time_str_test() {
@@ -265,6 +377,8 @@ mod tests {
       time: include_time_str,
       is_utc: false,
       include_time: Some(include_time),
+      precision: None,
+      end_timestamp: None,
     };
     let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
 
@@ -292,4 +406,408 @@ mod tests {
       decoded_data.date
     }
   }
+
+  #[test]
+  fn date_ingest_json_value_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let ingest = |value: serde_json::Value| {
+      let cell_str = ingest_json_value(&field_rev, value).unwrap();
+      let cell_data = DateCellData::from_cell_str(&cell_str).unwrap();
+      (cell_data.timestamp, cell_data.include_time)
+    };
+
+    // 2022-03-14 09:56:02 UTC
+    assert_eq!(ingest(json!(1647251762)), (Some(1647251762), false));
+    assert_eq!(ingest(json!("1647251762")), (Some(1647251762), false));
+    assert_eq!(ingest(json!(1647251762000_i64)), (Some(1647251762), false));
+    assert_eq!(ingest(json!("2022-03-14")), (Some(1647216000), false));
+    assert_eq!(
+      ingest(json!("2022-03-14T09:56:02Z")),
+      (Some(1647251762), true)
+    );
+    assert_eq!(
+      ingest(json!("2022-03-14T17:56:02+08:00")),
+      (Some(1647251762), true)
+    );
+    assert_eq!(
+      ingest(json!("2022-03-14 09:56:02")),
+      (Some(1647251762), true)
+    );
+    assert_eq!(ingest(json!(null)), (None, false));
+
+    assert!(ingest_json_value(&field_rev, json!("next tuesday")).is_err());
+    assert!(ingest_json_value(&field_rev, json!(true)).is_err());
+    assert!(ingest_json_value(&field_rev, json!(["2022-03-14"])).is_err());
+
+    // The extreme epochs are out of range even in milliseconds, and must not overflow.
+    assert!(ingest_json_value(&field_rev, json!(i64::MIN)).is_err());
+    assert!(ingest_json_value(&field_rev, json!(i64::MAX)).is_err());
+    assert!(ingest_json_value(&field_rev, json!(i64::MIN.to_string())).is_err());
+  }
+
+  #[test]
+  fn date_range_to_days_test() {
+    let type_option = DateTypeOptionPB::default();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let date_cell = |timestamp: Option<i64>, end_timestamp: Option<i64>| {
+      let cell_data = DateCellData {
+        timestamp,
+        include_time: false,
+        precision: None,
+        end_timestamp,
+      };
+      CellRevision::new(TypeCellData::new(cell_data.to_string(), FieldType::DateTime).to_json())
+    };
+
+    // 2022-03-14 09:56:02 to 2022-03-16 09:56:02
+    let range_cell = date_cell(Some(1647251762), Some(1647251762 + 2 * 86400));
+    assert_eq!(
+      type_option.range_to_days(&range_cell, &field_rev).unwrap(),
+      vec![1647216000, 1647302400, 1647388800]
+    );
+
+    // The single date expands to its own day.
+    let single_cell = date_cell(Some(1647251762), None);
+    assert_eq!(
+      type_option.range_to_days(&single_cell, &field_rev).unwrap(),
+      vec![1647216000]
+    );
+
+    let empty_cell = date_cell(None, None);
+    assert!(type_option
+      .range_to_days(&empty_cell, &field_rev)
+      .unwrap()
+      .is_empty());
+
+    let huge_cell = date_cell(Some(1647251762), Some(1647251762 + 400 * 86400));
+    assert!(type_option.range_to_days(&huge_cell, &field_rev).is_err());
+  }
+
+  #[test]
+  fn date_range_changeset_test() {
+    let type_option = DateTypeOptionPB::default();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let changeset = |date: Option<&str>, end_timestamp: Option<&str>| DateCellChangeset {
+      date: date.map(|date| date.to_owned()),
+      time: None,
+      include_time: None,
+      is_utc: true,
+      precision: None,
+      end_timestamp: end_timestamp.map(|end| end.to_owned()),
+    };
+    let apply = |changeset: DateCellChangeset, cell_rev: Option<CellRevision>| {
+      apply_cell_data_changeset(changeset, cell_rev, &field_rev, None).map(CellRevision::new)
+    };
+
+    // 2022-03-14 09:56:02 to 2022-03-16 09:56:02
+    let cell_rev = apply(changeset(Some("1647251762"), Some("1647424562")), None).unwrap();
+    assert_eq!(
+      type_option.range_to_days(&cell_rev, &field_rev).unwrap(),
+      vec![1647216000, 1647302400, 1647388800]
+    );
+
+    // The end is kept if the changeset doesn't set it.
+    let cell_rev = apply(changeset(Some("1647338162"), None), Some(cell_rev)).unwrap();
+    assert_eq!(
+      type_option.range_to_days(&cell_rev, &field_rev).unwrap(),
+      vec![1647302400, 1647388800]
+    );
+
+    // The empty end turns the range back into a single date.
+    let cell_rev = apply(changeset(None, Some("")), Some(cell_rev)).unwrap();
+    assert_eq!(
+      type_option.range_to_days(&cell_rev, &field_rev).unwrap(),
+      vec![1647302400]
+    );
+
+    // The end before the start and the invalid end are rejected.
+    assert!(type_option
+      .validate_changeset(&changeset(Some("1647251762"), Some("1647165362")))
+      .is_err());
+    assert!(type_option
+      .validate_changeset(&changeset(None, Some("tomorrow")))
+      .is_err());
+    assert!(apply(changeset(Some("1647251762"), Some("1647165362")), None).is_err());
+  }
+
+  #[test]
+  fn date_transform_from_text_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let transform = |type_option: &DateTypeOptionPB, text: &str| {
+      type_option
+        .transform_type_option_cell_str(text, &FieldType::RichText, &field_rev)
+        .unwrap()
+    };
+
+    let type_option = DateTypeOptionPB::default();
+    // ISO 8601
+    assert_eq!(
+      transform(&type_option, "2022-03-14").timestamp,
+      Some(1647216000)
+    );
+    let cell_data = transform(&type_option, "2022-03-14T09:56:02Z");
+    assert_eq!(cell_data.timestamp, Some(1647251762));
+    assert!(cell_data.include_time);
+    // MM/DD/YYYY
+    assert_eq!(
+      transform(&type_option, " 03/14/2022 ").timestamp,
+      Some(1647216000)
+    );
+    // DD.MM.YYYY
+    assert_eq!(
+      transform(&type_option, "14.03.2022").timestamp,
+      Some(1647216000)
+    );
+    // The display format, which is `Friendly` by default.
+    assert_eq!(
+      transform(&type_option, "Mar 14,2022").timestamp,
+      Some(1647216000)
+    );
+
+    // The unparseable values become empty dates.
+    for text in ["", "tomorrow", "14/03/2022", "2022-13-01"] {
+      assert_eq!(transform(&type_option, text).timestamp, None);
+    }
+
+    // The ambiguous value is parsed with the display format first, then the input formats in
+    // order.
+    assert_eq!(
+      transform(&type_option, "03/04/2022").timestamp,
+      Some(1646352000)
+    );
+    let day_month_year = DateTypeOptionPB {
+      date_format: DateFormat::DayMonthYear,
+      ..Default::default()
+    };
+    assert_eq!(
+      transform(&day_month_year, "03/04/2022").timestamp,
+      Some(1648944000)
+    );
+
+    // The input formats are configurable.
+    let no_input_formats = DateTypeOptionPB {
+      input_formats: vec![],
+      ..Default::default()
+    };
+    assert_eq!(transform(&no_input_formats, "14.03.2022").timestamp, None);
+
+    // The other field types are not transformed.
+    assert!(type_option
+      .transform_type_option_cell_str("1647251762", &FieldType::Number, &field_rev)
+      .is_none());
+  }
+
+  #[test]
+  fn date_to_text_writes_display_string_test() {
+    let date_field_rev =
+      FieldBuilder::new(DateTypeOptionBuilder::default().date_format(DateFormat::DayMonthYear))
+        .build();
+    let type_option = RichTextTypeOptionPB::default();
+    let cell_data = type_option
+      .transform_type_option_cell_str(
+        &DateCellData {
+          timestamp: Some(1647251762),
+          ..Default::default()
+        }
+        .to_string(),
+        &FieldType::DateTime,
+        &date_field_rev,
+      )
+      .unwrap();
+    assert_eq!(cell_data.as_str(), "14/03/2022");
+  }
+
+  #[test]
+  fn normalize_date_column_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    // The legacy date cell stores the timestamp directly.
+    let legacy_cell =
+      CellRevision::new(TypeCellData::new("1647251762".to_owned(), FieldType::DateTime).to_json());
+    let date_cell_data = DateCellData {
+      timestamp: Some(1647251762),
+      include_time: false,
+      precision: None,
+      end_timestamp: None,
+    };
+    let cells = vec![legacy_cell, insert_date_cell(date_cell_data, &field_rev)];
+
+    let normalized_cells =
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None).normalize_column(&cells);
+    assert_eq!(normalized_cells[0].as_ref(), Some(&cells[1]));
+    assert!(normalized_cells[1].is_none());
+  }
+
+  #[test]
+  fn date_aggregate_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let date_cell = |timestamp: Option<i64>| {
+      insert_date_cell(
+        DateCellData {
+          timestamp,
+          include_time: false,
+          precision: None,
+          end_timestamp: None,
+        },
+        &field_rev,
+      )
+    };
+    // Mar 14,2022, Mar 04,2022 and Mar 24,2022
+    let cells = vec![
+      date_cell(Some(1647251762)),
+      date_cell(None),
+      date_cell(Some(1647251762 - 10 * 86400)),
+      date_cell(Some(1647251762 + 10 * 86400)),
+    ];
+    assert_eq!(
+      ext.aggregate(&cells, AggregateKind::EarliestDate),
+      Some((1647251762 - 10 * 86400) as f64)
+    );
+    assert_eq!(
+      ext.aggregate(&cells, AggregateKind::LatestDate),
+      Some((1647251762 + 10 * 86400) as f64)
+    );
+    assert_eq!(
+      ext.aggregate(&cells, AggregateKind::DateRangeDays),
+      Some(20.0)
+    );
+
+    let empty_cells = vec![date_cell(None), date_cell(None)];
+    for kind in [
+      AggregateKind::EarliestDate,
+      AggregateKind::LatestDate,
+      AggregateKind::DateRangeDays,
+    ] {
+      assert_eq!(ext.aggregate(&empty_cells, kind), None);
+    }
+  }
+
+  #[test]
+  fn date_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::DateTime);
+    assert_eq!(handler.comparable_kind(), ComparableKind::DateTime);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::DateTime,
+      &FieldType::DateTime
+    ));
+    assert!(is_transform_lossless(
+      &FieldType::DateTime,
+      &FieldType::RichText
+    ));
+    assert!(!is_transform_lossless(
+      &FieldType::DateTime,
+      &FieldType::Number
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = DateFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, DateFilterConditionPB::DateIs);
+    assert_eq!(filter.timestamp, None);
+
+    let date_cell = |timestamp: i64| {
+      let date_cell_data = DateCellData {
+        timestamp: Some(timestamp),
+        include_time: false,
+        precision: None,
+        end_timestamp: None,
+      };
+      insert_date_cell(date_cell_data, &field_rev)
+    };
+    // 2021-01-01 is exported as the serial date of the spreadsheet.
+    assert_eq!(
+      spreadsheet_value(&field_rev, &date_cell(1609459200)),
+      SpreadsheetValue::Date(44197.0)
+    );
+    assert_eq!(
+      cell_value(&field_rev, &date_cell(1647251762))
+        .date
+        .unwrap()
+        .timestamp,
+      1647251762
+    );
+  }
+
+  #[test]
+  fn date_is_empty_filter_test() {
+    let date_filter = |condition: DateFilterConditionPB| {
+      CompiledDateFilter::from(&DateFilterPB {
+        condition,
+        ..Default::default()
+      })
+    };
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let date_cell = |timestamp: Option<i64>| {
+      let date_cell_data = DateCellData {
+        timestamp,
+        include_time: false,
+        precision: None,
+        end_timestamp: None,
+      };
+      insert_date_cell(date_cell_data, &field_rev)
+    };
+    for (cell_rev, expected) in [
+      (None, true),
+      (Some(date_cell(None)), true),
+      (Some(date_cell(Some(1647251762))), false),
+    ] {
+      let is_empty = filter_is_empty(
+        &field_rev,
+        date_filter,
+        DateFilterConditionPB::DateIsEmpty,
+        DateFilterConditionPB::DateIsNotEmpty,
+        cell_rev.as_ref(),
+      );
+      assert_eq!(is_empty, expected);
+    }
+  }
+
+  #[test]
+  fn date_validate_cell_changeset_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::DateTime);
+    let validate = |date: &str, time: Option<&str>| {
+      let changeset = DateCellChangeset {
+        date: Some(date.to_owned()),
+        time: time.map(|time| time.to_owned()),
+        include_time: Some(time.is_some()),
+        is_utc: true,
+        precision: None,
+        end_timestamp: None,
+      }
+      .to_cell_changeset_str();
+      handler.validate_cell_changeset(&changeset, &field_rev)
+    };
+    assert!(validate("1647251762", None).is_ok());
+    assert!(validate("1647251762", Some("09:56")).is_ok());
+    assert!(validate("tomorrow", None).is_err());
+    assert!(validate("1647251762", Some("noon")).is_err());
+  }
+
+  #[test]
+  fn date_next_in_series_test() {
+    // The dates are a week apart.
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::DateTime);
+    let cell_revs = [1647251762, 1647856562]
+      .into_iter()
+      .map(|timestamp| {
+        let date_cell_data = DateCellData {
+          timestamp: Some(timestamp),
+          include_time: true,
+          ..Default::default()
+        };
+        insert_date_cell(date_cell_data, &field_rev)
+      })
+      .collect::<Vec<CellRevision>>();
+    let cell_rev = handler.next_in_series(&cell_revs, &field_rev).unwrap();
+    let cell_str = TypeCellData::try_from(&cell_rev).unwrap().cell_str;
+    let date_cell_data: DateCellData = serde_json::from_str(&cell_str).unwrap();
+    assert_eq!(date_cell_data.timestamp, Some(1648461362));
+    assert!(date_cell_data.include_time);
+    assert!(handler
+      .next_in_series(&cell_revs[..1], &field_rev)
+      .is_none());
+  }
 }
@@ -1,22 +1,29 @@
-use crate::entities::{DateFilterPB, FieldType};
+use crate::entities::{CellValuePB, DateFilterConditionPB, FieldType};
 use crate::impl_type_option;
-use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::cell::{
+  invalid_json_value_error, series_step, CellDataChangeset, CellDataDecoder, FromCellString,
+  TypeCellData,
+};
 use crate::services::field::{
-  default_order, BoxTypeOptionBuilder, DateCellChangeset, DateCellData, DateCellDataPB, DateFormat,
-  TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
-  TypeOptionCellDataFilter, TypeOptionTransform,
+  default_order, plain_text, BoxTypeOptionBuilder, ComparableKind, CompiledDateFilter,
+  DateCellChangeset, DateCellData, DateCellDataPB, DateFormat, DatePrecision, EmptyFilterCondition,
+  SpreadsheetValue, TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use bytes::Bytes;
 use chrono::format::strftime::StrftimeItems;
-use chrono::NaiveDateTime;
-use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use database_model::{
+  CellRevision, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer,
+};
 use flowy_derive::ProtoBuf;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::HashSet;
 
 // Date
-#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+#[derive(Clone, Debug, Serialize, Deserialize, ProtoBuf)]
 pub struct DateTypeOptionPB {
   #[pb(index = 1)]
   pub date_format: DateFormat,
@@ -26,14 +33,78 @@ pub struct DateTypeOptionPB {
 
   #[pb(index = 3)]
   pub include_time: bool,
+
+  /// Interprets the integer date as the Excel serial date, the number of days since 1899-12-30,
+  /// if the integer is in the range of the serial dates. It's used when importing the dates
+  /// from the spreadsheet.
+  #[pb(index = 4)]
+  #[serde(default)]
+  pub excel_serial_date: bool,
+
+  /// The offset of the timezone in seconds east of UTC. It decides the current date when
+  /// evaluating the relative date filters, for example, `Today`.
+  #[pb(index = 5)]
+  #[serde(default)]
+  pub timezone_offset: i32,
+
+  /// The first day of the week used by the week filters, 0 is Sunday and 6 is Saturday.
+  #[pb(index = 6)]
+  #[serde(default)]
+  pub first_day_of_week: i32,
+
+  /// The `strftime` formats that are tried, in order, to parse the text when converting the text
+  /// cells into dates. The `date_format` is always tried first.
+  #[pb(index = 7)]
+  #[serde(default = "DEFAULT_INPUT_FORMATS")]
+  pub input_formats: Vec<String>,
+
+  /// The dates, in the `%Y-%m-%d` format, that are not business days. They're skipped by the
+  /// `DateOlderThanBusinessDays` filter along with the weekends.
+  #[pb(index = 8)]
+  #[serde(default)]
+  pub holidays: Vec<String>,
 }
 impl_type_option!(DateTypeOptionPB, FieldType::DateTime);
 
+/// ISO 8601, `MM/DD/YYYY` and `DD.MM.YYYY`.
+const DEFAULT_INPUT_FORMATS: fn() -> Vec<String> = || {
+  ["%Y-%m-%d", "%m/%d/%Y", "%d.%m.%Y"]
+    .iter()
+    .map(|format| format.to_string())
+    .collect()
+};
+
+impl std::default::Default for DateTypeOptionPB {
+  fn default() -> Self {
+    Self {
+      date_format: DateFormat::default(),
+      time_format: TimeFormat::default(),
+      include_time: false,
+      excel_serial_date: false,
+      timezone_offset: 0,
+      first_day_of_week: 0,
+      input_formats: DEFAULT_INPUT_FORMATS(),
+      holidays: vec![],
+    }
+  }
+}
+
+/// The number of days from 1899-12-30, the epoch of Excel serial date, to 1970-01-01.
+const EXCEL_SERIAL_DATE_UNIX_EPOCH: i64 = 25569;
+const SECONDS_PER_DAY: i64 = 86400;
+/// The Excel serial date of 9999-12-31, the max date that the Excel supports.
+const MAX_EXCEL_SERIAL_DATE: i64 = 2958465;
+/// The epoch that is greater than this value is considered as milliseconds, which is the
+/// seconds of the year 5138.
+const MAX_EPOCH_SECONDS: i64 = 99_999_999_999;
+/// The max number of days that a date range cell can be expanded into.
+pub const MAX_DATE_RANGE_DAYS: i64 = 366;
+
 impl TypeOption for DateTypeOptionPB {
   type CellData = DateCellData;
   type CellChangeset = DateCellChangeset;
   type CellProtobufType = DateCellDataPB;
-  type CellFilter = DateFilterPB;
+  type CellFilter = CompiledDateFilter;
 }
 
 impl TypeOptionCellData for DateTypeOptionPB {
@@ -48,7 +119,23 @@ impl TypeOptionCellData for DateTypeOptionPB {
     &self,
     cell_str: String,
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
-    DateCellData::from_cell_str(&cell_str)
+    let cell_data = DateCellData::from_cell_str(&cell_str)?;
+    if let Some(timestamp) = cell_data.timestamp {
+      // The timestamp might be out of range if it was imported from other sources.
+      naive_date_time_from_timestamp(timestamp)?;
+    }
+    Ok(cell_data)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.timestamp.is_none()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      date: Some(self.convert_to_protobuf(cell_data)),
+      ..Default::default()
+    }
   }
 }
 
@@ -62,17 +149,24 @@ impl DateTypeOptionPB {
     let timestamp = cell_data.timestamp.unwrap_or_default();
     let include_time = cell_data.include_time;
 
-    let naive = chrono::NaiveDateTime::from_timestamp_opt(timestamp, 0);
-    if naive.is_none() {
-      return DateCellDataPB::default();
-    }
-    let naive = naive.unwrap();
+    let naive = match naive_date_time_from_timestamp(timestamp) {
+      Ok(naive) => naive,
+      Err(_) => return DateCellDataPB::default(),
+    };
     if timestamp == 0 {
       return DateCellDataPB::default();
     }
-    let fmt = self.date_format.format_str();
+    let fmt = match cell_data.precision {
+      Some(DatePrecision::Year) => "%Y",
+      Some(DatePrecision::Month) => "%Y-%m",
+      _ => self.date_format.format_str(),
+    };
     let date = format!("{}", naive.format_with_items(StrftimeItems::new(fmt)));
 
+    let include_time = match cell_data.precision {
+      None => include_time,
+      Some(precision) => precision == DatePrecision::Time,
+    };
     let time = if include_time {
       let fmt = self.time_format.format_str();
       format!("{}", naive.format_with_items(StrftimeItems::new(fmt)))
@@ -113,7 +207,184 @@ impl DateTypeOptionPB {
   }
 }
 
-impl TypeOptionTransform for DateTypeOptionPB {}
+impl DateTypeOptionPB {
+  pub(crate) fn first_weekday(&self) -> Weekday {
+    match self.first_day_of_week.rem_euclid(7) {
+      1 => Weekday::Mon,
+      2 => Weekday::Tue,
+      3 => Weekday::Wed,
+      4 => Weekday::Thu,
+      5 => Weekday::Fri,
+      6 => Weekday::Sat,
+      _ => Weekday::Sun,
+    }
+  }
+
+  /// Returns the `holidays` that can be parsed. The others are ignored.
+  pub(crate) fn holiday_dates(&self) -> HashSet<NaiveDate> {
+    self
+      .holidays
+      .iter()
+      .filter_map(|date| NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok())
+      .collect()
+  }
+
+  /// Returns the timestamp of the start of each day in the span of the cell, inclusively. The
+  /// single date cell returns its own day, and the empty cell returns nothing. Returns error if
+  /// the span is longer than [MAX_DATE_RANGE_DAYS].
+  pub fn range_to_days(
+    &self,
+    cell_rev: &CellRevision,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<Vec<i64>> {
+    let type_cell_data = TypeCellData::try_from(cell_rev)?;
+    let cell_data = self.decode_cell_str(
+      type_cell_data.cell_str,
+      &type_cell_data.field_type,
+      field_rev,
+    )?;
+    let start = match cell_data.timestamp {
+      None => return Ok(vec![]),
+      Some(timestamp) => naive_date_time_from_timestamp(timestamp)?.date(),
+    };
+    let end = match cell_data.end_timestamp {
+      None => start,
+      Some(timestamp) => naive_date_time_from_timestamp(timestamp)?.date(),
+    };
+    let (start, end) = (start.min(end), start.max(end));
+
+    let num_of_days = (end - start).num_days() + 1;
+    if num_of_days > MAX_DATE_RANGE_DAYS {
+      let msg = format!(
+        "The date range spans {} days, more than the max {} days",
+        num_of_days, MAX_DATE_RANGE_DAYS
+      );
+      return Err(FlowyError::new(ErrorCode::InvalidData, &msg));
+    }
+
+    Ok(
+      start
+        .iter_days()
+        .take(num_of_days as usize)
+        .flat_map(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date_time| date_time.timestamp())
+        .collect(),
+    )
+  }
+
+  /// Parses the text with the `date_format` and then the `input_formats`. The ISO 8601 date time,
+  /// for example, `2022-03-14T09:56:02Z`, is also accepted, which includes the time.
+  pub(crate) fn date_cell_data_from_text(&self, text: &str) -> Option<DateCellData> {
+    let text = text.trim();
+    if text.is_empty() {
+      return None;
+    }
+    let formats = std::iter::once(self.date_format.format_str())
+      .chain(self.input_formats.iter().map(|format| format.as_str()));
+    let (timestamp, include_time) = formats
+      .filter_map(|format| NaiveDate::parse_from_str(text, format).ok())
+      .find_map(|date| date.and_hms_opt(0, 0, 0))
+      .map(|naive| (naive.timestamp(), false))
+      .or_else(|| timestamp_from_iso_str(text))?;
+    Some(DateCellData {
+      timestamp: Some(timestamp),
+      include_time,
+      ..Default::default()
+    })
+  }
+
+  /// Converts the Excel serial date to the timestamp if the `excel_serial_date` is enabled.
+  /// Otherwise, returns the passed-in timestamp.
+  fn timestamp_from_excel_serial_date(&self, timestamp: i64) -> i64 {
+    if self.excel_serial_date && (1..=MAX_EXCEL_SERIAL_DATE).contains(&timestamp) {
+      (timestamp - EXCEL_SERIAL_DATE_UNIX_EPOCH) * SECONDS_PER_DAY
+    } else {
+      timestamp
+    }
+  }
+}
+
+/// Returns the timestamp in seconds and whether the time is included. The value is either the
+/// epoch in seconds or milliseconds, or the ISO 8601 date, for example, `2022-03-14` or
+/// `2022-03-14T09:56:02Z`. The date time without the offset is considered as UTC.
+fn timestamp_from_json_value(value: &serde_json::Value) -> Option<(i64, bool)> {
+  let epoch = match value {
+    serde_json::Value::Number(n) => n.as_i64().or_else(|| n.as_f64().map(|f| f as i64)),
+    serde_json::Value::String(s) => {
+      let s = s.trim();
+      match s.parse::<i64>() {
+        Ok(epoch) => Some(epoch),
+        Err(_) => return timestamp_from_iso_str(s),
+      }
+    },
+    _ => None,
+  }?;
+  if epoch.unsigned_abs() > MAX_EPOCH_SECONDS as u64 {
+    Some((epoch / 1000, false))
+  } else {
+    Some((epoch, false))
+  }
+}
+
+fn timestamp_from_iso_str(s: &str) -> Option<(i64, bool)> {
+  if let Ok(date_time) = DateTime::parse_from_rfc3339(s) {
+    return Some((date_time.timestamp(), true));
+  }
+  for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M"] {
+    if let Ok(naive) = NaiveDateTime::parse_from_str(s, format) {
+      return Some((naive.timestamp(), true));
+    }
+  }
+  let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?;
+  Some((date.and_hms_opt(0, 0, 0)?.timestamp(), false))
+}
+
+/// Parses the end of the date range. Returns error if it's not a timestamp in the supported range
+/// or it's before the `start` of the range.
+fn parse_end_timestamp(end: &str, start: Option<i64>) -> FlowyResult<i64> {
+  let end_timestamp = end.parse::<i64>().map_err(|_| {
+    let msg = format!("{} is not a valid date", end);
+    FlowyError::new(ErrorCode::InvalidDateTimeFormat, &msg)
+  })?;
+  naive_date_time_from_timestamp(end_timestamp)?;
+  if start.map_or(false, |start| end_timestamp < start) {
+    let msg = format!("The end of the date range: {} is before its start", end);
+    return Err(FlowyError::new(ErrorCode::InvalidDateTimeFormat, &msg));
+  }
+  Ok(end_timestamp)
+}
+
+/// Returns error if the timestamp is out of the range that chrono supports.
+fn naive_date_time_from_timestamp(timestamp: i64) -> FlowyResult<NaiveDateTime> {
+  NaiveDateTime::from_timestamp_opt(timestamp, 0).ok_or_else(|| {
+    let msg = format!("The timestamp: {} is out of range", timestamp);
+    FlowyError::new(ErrorCode::InvalidDateTimeFormat, &msg)
+  })
+}
+
+impl TypeOptionTransform for DateTypeOptionPB {
+  fn transformable(&self) -> bool {
+    true
+  }
+
+  /// Parses the dates out of the text cells, see `date_cell_data_from_text`. The cell is empty if
+  /// the text can't be parsed.
+  fn transform_type_option_cell_str(
+    &self,
+    cell_str: &str,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> Option<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_text() {
+      return None;
+    }
+    Some(
+      self
+        .date_cell_data_from_text(&plain_text(cell_str))
+        .unwrap_or_default(),
+    )
+  }
+}
 
 impl CellDataDecoder for DateTypeOptionPB {
   fn decode_cell_str(
@@ -136,6 +407,37 @@ impl CellDataDecoder for DateTypeOptionPB {
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
     self.today_desc_from_timestamp(cell_data).date
   }
+
+  /// Spells out the month regardless of the date format, for example, `March 14, 2022`.
+  fn accessibility_label(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+    _field_rev: &FieldRevision,
+  ) -> String {
+    let naive = match cell_data.timestamp.map(naive_date_time_from_timestamp) {
+      Some(Ok(naive)) => naive,
+      _ => return "".to_owned(),
+    };
+    let date = naive.format("%B %-d, %Y").to_string();
+    if cell_data.include_time {
+      let time = naive.format_with_items(StrftimeItems::new(self.time_format.format_str()));
+      format!("{} at {}", date, time)
+    } else {
+      date
+    }
+  }
+
+  fn decode_cell_data_to_spreadsheet_value(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> SpreadsheetValue {
+    match cell_data.timestamp {
+      None => SpreadsheetValue::Text("".to_owned()),
+      Some(timestamp) => SpreadsheetValue::Date(
+        timestamp as f64 / SECONDS_PER_DAY as f64 + EXCEL_SERIAL_DATE_UNIX_EPOCH as f64,
+      ),
+    }
+  }
 }
 
 impl CellDataChangeset for DateTypeOptionPB {
@@ -144,40 +446,141 @@ impl CellDataChangeset for DateTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let (timestamp, include_time) = match type_cell_data {
-      None => (None, false),
+    let (timestamp, include_time, precision, end_timestamp) = match type_cell_data {
+      None => (None, false, None, None),
       Some(type_cell_data) => {
         let cell_data = DateCellData::from_cell_str(&type_cell_data.cell_str).unwrap_or_default();
-        (cell_data.timestamp, cell_data.include_time)
+        (
+          cell_data.timestamp,
+          cell_data.include_time,
+          cell_data.precision,
+          cell_data.end_timestamp,
+        )
       },
     };
+    let precision = changeset.precision.or(precision);
 
     let include_time = match changeset.include_time {
       None => include_time,
       Some(include_time) => include_time,
     };
-    let timestamp = match changeset.date_timestamp() {
+    let date_timestamp = changeset
+      .date_timestamp()
+      .map(|date_timestamp| self.timestamp_from_excel_serial_date(date_timestamp));
+    let timestamp = match date_timestamp {
       None => timestamp,
-      Some(date_timestamp) => match (include_time, changeset.time) {
-        (true, Some(time)) => {
-          let time = Some(time.trim().to_uppercase());
-          let naive = NaiveDateTime::from_timestamp_opt(date_timestamp, 0);
-          if let Some(naive) = naive {
+      Some(date_timestamp) => {
+        let naive = naive_date_time_from_timestamp(date_timestamp)?;
+        match (include_time, changeset.time) {
+          (true, Some(time)) => {
+            let time = Some(time.trim().to_uppercase());
             Some(self.timestamp_from_utc_with_time(&naive, &time)?)
-          } else {
-            Some(date_timestamp)
-          }
-        },
-        _ => Some(date_timestamp),
+          },
+          _ => Some(date_timestamp),
+        }
       },
     };
 
+    let end_timestamp = match changeset.end_timestamp.as_deref().map(str::trim) {
+      None => end_timestamp,
+      Some("") => None,
+      Some(end) => Some(parse_end_timestamp(end, timestamp)?),
+    };
+
     let date_cell_data = DateCellData {
       timestamp,
       include_time,
+      precision,
+      end_timestamp,
     };
     Ok((date_cell_data.to_string(), date_cell_data))
   }
+
+  /// Rejects the date that is not a timestamp in the supported range, which keeps the old date
+  /// otherwise, and the time that doesn't match the `time_format`.
+  fn validate_changeset(&self, changeset: &<Self as TypeOption>::CellChangeset) -> FlowyResult<()> {
+    if let Some(date) = changeset.date.as_ref().filter(|date| !date.is_empty()) {
+      let timestamp = changeset.date_timestamp().ok_or_else(|| {
+        let msg = format!("{} is not a valid date", date);
+        FlowyError::new(ErrorCode::InvalidDateTimeFormat, &msg)
+      })?;
+      naive_date_time_from_timestamp(self.timestamp_from_excel_serial_date(timestamp))?;
+    }
+
+    if let Some(end) = changeset.end_timestamp.as_deref().map(str::trim) {
+      if !end.is_empty() {
+        let start = changeset
+          .date_timestamp()
+          .map(|timestamp| self.timestamp_from_excel_serial_date(timestamp));
+        parse_end_timestamp(end, start)?;
+      }
+    }
+
+    if let Some(time) = changeset.time.as_ref() {
+      let time = time.trim().to_uppercase();
+      if !time.is_empty()
+        && NaiveTime::parse_from_str(&time, self.time_format.format_str()).is_err()
+      {
+        let msg = format!("{} is not a valid time", time);
+        return Err(FlowyError::new(ErrorCode::InvalidDateTimeFormat, &msg));
+      }
+    }
+    Ok(())
+  }
+
+  /// Accepts the epoch in seconds or milliseconds, as the JSON number or the numeric string, or
+  /// the ISO 8601 date string. The time is included if the ISO string contains the time.
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    let mut changeset = DateCellChangeset {
+      date: None,
+      time: None,
+      include_time: None,
+      is_utc: true,
+      precision: None,
+      end_timestamp: None,
+    };
+    let is_blank = match value {
+      serde_json::Value::Null => true,
+      serde_json::Value::String(s) => s.trim().is_empty(),
+      _ => false,
+    };
+    if is_blank {
+      return Ok(changeset);
+    }
+
+    let (timestamp, include_time) = timestamp_from_json_value(value).ok_or_else(|| {
+      invalid_json_value_error(value, "an epoch timestamp or an ISO 8601 date string")
+    })?;
+    naive_date_time_from_timestamp(timestamp)?;
+    changeset.date = Some(timestamp.to_string());
+    changeset.include_time = Some(include_time);
+    Ok(changeset)
+  }
+
+  /// Steps the date by the interval between the dates, for example, a week after the dates that
+  /// are a week apart. The next date keeps the `include_time` and the precision of the last date.
+  fn next_in_series(
+    &self,
+    cells: &[<Self as TypeOption>::CellData],
+  ) -> Option<<Self as TypeOption>::CellChangeset> {
+    let timestamps = cells
+      .iter()
+      .map(|cell_data| cell_data.timestamp)
+      .collect::<Option<Vec<i64>>>()?;
+    let next = timestamps.last()?.checked_add(series_step(&timestamps)?)?;
+    let last = cells.last()?;
+    Some(DateCellChangeset {
+      date: Some(next.to_string()),
+      time: None,
+      include_time: Some(last.include_time),
+      is_utc: true,
+      precision: last.precision,
+      end_timestamp: None,
+    })
+  }
 }
 
 impl TypeOptionCellDataFilter for DateTypeOptionPB {
@@ -193,6 +596,17 @@ impl TypeOptionCellDataFilter for DateTypeOptionPB {
 
     filter.is_visible(cell_data.timestamp)
   }
+
+  fn default_filter_condition(&self) -> u8 {
+    DateFilterConditionPB::DateIs as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
 }
 
 impl TypeOptionCellDataCompare for DateTypeOptionPB {
@@ -208,6 +622,10 @@ impl TypeOptionCellDataCompare for DateTypeOptionPB {
       (None, None) => default_order(),
     }
   }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::DateTime
+  }
 }
 
 #[derive(Default)]
@@ -225,6 +643,31 @@ impl DateTypeOptionBuilder {
     self.0.time_format = time_format;
     self
   }
+
+  pub fn timezone_offset(mut self, timezone_offset: i32) -> Self {
+    self.0.timezone_offset = timezone_offset;
+    self
+  }
+
+  pub fn first_day_of_week(mut self, first_day_of_week: i32) -> Self {
+    self.0.first_day_of_week = first_day_of_week;
+    self
+  }
+
+  pub fn excel_serial_date(mut self, excel_serial_date: bool) -> Self {
+    self.0.excel_serial_date = excel_serial_date;
+    self
+  }
+
+  pub fn input_formats(mut self, input_formats: Vec<String>) -> Self {
+    self.0.input_formats = input_formats;
+    self
+  }
+
+  pub fn holidays(mut self, holidays: Vec<String>) -> Self {
+    self.0.holidays = holidays;
+    self
+  }
 }
 impl TypeOptionBuilder for DateTypeOptionBuilder {
   fn field_type(&self) -> FieldType {
@@ -43,6 +43,10 @@ pub struct DateChangesetPB {
 
   #[pb(index = 5)]
   pub is_utc: bool,
+
+  /// The end of the date range, see [DateCellChangeset::end_timestamp].
+  #[pb(index = 6, one_of)]
+  pub end_timestamp: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -51,6 +55,12 @@ pub struct DateCellChangeset {
   pub time: Option<String>,
   pub include_time: Option<bool>,
   pub is_utc: bool,
+  #[serde(default)]
+  pub precision: Option<DatePrecision>,
+  /// The timestamp of the end of the date range. None keeps the end of the cell, and the empty
+  /// string removes it, which turns the range back into a single date.
+  #[serde(default)]
+  pub end_timestamp: Option<String>,
 }
 
 impl DateCellChangeset {
@@ -85,6 +95,15 @@ impl ToCellChangesetString for DateCellChangeset {
 pub struct DateCellData {
   pub timestamp: Option<i64>,
   pub include_time: bool,
+
+  /// The part of the date that is known. None means the date is known to the day, or to the
+  /// minute if the `include_time` is true.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub precision: Option<DatePrecision>,
+
+  /// The end of the date range. None means the cell holds a single date.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub end_timestamp: Option<i64>,
 }
 
 impl<'de> serde::Deserialize<'de> for DateCellData {
@@ -110,6 +129,8 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
         Ok(DateCellData {
           timestamp: Some(value),
           include_time: false,
+          precision: None,
+          end_timestamp: None,
         })
       }
 
@@ -126,6 +147,8 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
       {
         let mut timestamp: Option<i64> = None;
         let mut include_time: Option<bool> = None;
+        let mut precision: Option<DatePrecision> = None;
+        let mut end_timestamp: Option<i64> = None;
 
         while let Some(key) = map.next_key()? {
           match key {
@@ -135,6 +158,12 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
             "include_time" => {
               include_time = map.next_value()?;
             },
+            "precision" => {
+              precision = map.next_value()?;
+            },
+            "end_timestamp" => {
+              end_timestamp = map.next_value()?;
+            },
             _ => {},
           }
         }
@@ -144,6 +173,8 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
         Ok(DateCellData {
           timestamp,
           include_time,
+          precision,
+          end_timestamp,
         })
       }
     }
@@ -167,6 +198,16 @@ impl ToString for DateCellData {
   }
 }
 
+/// The precision of the date. The historical data sometimes only knows the year or the month,
+/// so the date is displayed only to the known part, for example, `2021` or `2021-03`.
+#[derive(Clone, Copy, PartialEq, Eq, EnumIter, Debug, Serialize, Deserialize)]
+pub enum DatePrecision {
+  Year = 0,
+  Month = 1,
+  Day = 2,
+  Time = 3,
+}
+
 #[derive(Clone, Debug, Copy, EnumIter, Serialize, Deserialize, ProtoBuf_Enum)]
 pub enum DateFormat {
   Local = 0,
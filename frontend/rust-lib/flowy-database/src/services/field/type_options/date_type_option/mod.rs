@@ -4,5 +4,6 @@ mod date_tests;
 mod date_type_option;
 mod date_type_option_entities;
 
+pub use date_filter::*;
 pub use date_type_option::*;
 pub use date_type_option_entities::*;
@@ -0,0 +1,339 @@
+use crate::services::cell::FromCellString;
+use flowy_derive::ProtoBuf_Enum;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use strum_macros::EnumIter;
+
+const SECONDS_PER_MINUTE: i64 = 60;
+const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+
+/// The number of seconds of the duration cell. It's None if the cell is empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DurationCellData(pub Option<i64>);
+
+impl DurationCellData {
+  pub fn seconds(&self) -> Option<i64> {
+    self.0
+  }
+}
+
+impl FromCellString for DurationCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Ok(Self(s.trim().parse::<i64>().ok()))
+  }
+}
+
+impl ToString for DurationCellData {
+  fn to_string(&self) -> String {
+    self
+      .0
+      .map(|seconds| seconds.to_string())
+      .unwrap_or_default()
+  }
+}
+
+/// The smallest unit that the duration is displayed in. The duration is rounded to the nearest
+/// unit, for example, 3661 seconds is displayed as `1h 1m 1s`, `1h 1m` or `1h`.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, EnumIter, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum DurationGranularity {
+  Seconds = 0,
+  Minutes = 1,
+  Hours = 2,
+}
+
+impl std::default::Default for DurationGranularity {
+  fn default() -> Self {
+    DurationGranularity::Seconds
+  }
+}
+
+impl DurationGranularity {
+  pub fn seconds(&self) -> i64 {
+    match self {
+      DurationGranularity::Seconds => 1,
+      DurationGranularity::Minutes => SECONDS_PER_MINUTE,
+      DurationGranularity::Hours => SECONDS_PER_HOUR,
+    }
+  }
+
+  fn suffix(&self) -> &'static str {
+    match self {
+      DurationGranularity::Seconds => "s",
+      DurationGranularity::Minutes => "m",
+      DurationGranularity::Hours => "h",
+    }
+  }
+}
+
+/// How the duration is displayed.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, EnumIter, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum DurationDisplayFormat {
+  /// The hours, minutes and seconds, for example, `1h 30m`.
+  Human = 0,
+  /// The ISO 8601 duration, for example, `PT1H30M`.
+  Iso8601 = 1,
+  /// The zero-padded hours, minutes and seconds, for example, `01:30:00`.
+  HoursMinutesSeconds = 2,
+  /// The hours and minutes, for example, `1:30`.
+  HoursMinutes = 3,
+  /// The hours with up to two decimals, for example, `1.5h`.
+  DecimalHours = 4,
+}
+
+impl std::default::Default for DurationDisplayFormat {
+  fn default() -> Self {
+    DurationDisplayFormat::Human
+  }
+}
+
+/// Rounds the seconds to the nearest unit of the `granularity`, dropping the sign.
+fn round_to_granularity(seconds: i64, granularity: DurationGranularity) -> u64 {
+  let unit = granularity.seconds() as u64;
+  (seconds.unsigned_abs() + unit / 2) / unit * unit
+}
+
+/// Formats the seconds as the hours, minutes and seconds, for example, `1h 1m 1s`. The seconds
+/// are rounded to the nearest unit of the `granularity`, and the zero parts are omitted.
+pub fn format_duration(seconds: i64, granularity: DurationGranularity) -> String {
+  let rounded = round_to_granularity(seconds, granularity);
+  let parts = [
+    (rounded / SECONDS_PER_HOUR as u64, "h"),
+    (
+      rounded % SECONDS_PER_HOUR as u64 / SECONDS_PER_MINUTE as u64,
+      "m",
+    ),
+    (rounded % SECONDS_PER_MINUTE as u64, "s"),
+  ]
+  .iter()
+  .filter(|(value, _)| *value > 0)
+  .map(|(value, suffix)| format!("{}{}", value, suffix))
+  .collect::<Vec<String>>();
+
+  if parts.is_empty() {
+    return format!("0{}", granularity.suffix());
+  }
+  let sign = if seconds < 0 { "-" } else { "" };
+  format!("{}{}", sign, parts.join(" "))
+}
+
+/// Formats the seconds as the ISO 8601 duration with the days, hours, minutes and seconds, for
+/// example, `P1DT2H`. The seconds are rounded like [format_duration]. The negative duration is
+/// prefixed with `-`, and the zero duration is `PT0S`.
+pub fn format_iso8601_duration(seconds: i64, granularity: DurationGranularity) -> String {
+  let rounded = round_to_granularity(seconds, granularity);
+  if rounded == 0 {
+    return "PT0S".to_owned();
+  }
+
+  let days = rounded / SECONDS_PER_DAY as u64;
+  let time = [
+    (
+      rounded % SECONDS_PER_DAY as u64 / SECONDS_PER_HOUR as u64,
+      "H",
+    ),
+    (
+      rounded % SECONDS_PER_HOUR as u64 / SECONDS_PER_MINUTE as u64,
+      "M",
+    ),
+    (rounded % SECONDS_PER_MINUTE as u64, "S"),
+  ]
+  .iter()
+  .filter(|(value, _)| *value > 0)
+  .map(|(value, designator)| format!("{}{}", value, designator))
+  .collect::<String>();
+
+  let mut s = String::new();
+  if seconds < 0 {
+    s.push('-');
+  }
+  s.push('P');
+  if days > 0 {
+    s.push_str(&format!("{}D", days));
+  }
+  if !time.is_empty() {
+    s.push('T');
+    s.push_str(&time);
+  }
+  s
+}
+
+/// Formats the seconds as `HH:MM:SS`, for example, `01:30:00`. The hours are not limited to 24.
+pub fn format_hours_minutes_seconds(seconds: i64) -> String {
+  let abs = seconds.unsigned_abs();
+  let sign = if seconds < 0 { "-" } else { "" };
+  format!(
+    "{}{:02}:{:02}:{:02}",
+    sign,
+    abs / SECONDS_PER_HOUR as u64,
+    abs % SECONDS_PER_HOUR as u64 / SECONDS_PER_MINUTE as u64,
+    abs % SECONDS_PER_MINUTE as u64
+  )
+}
+
+/// Formats the seconds as `H:MM` rounded to the nearest minute, for example, `1:30`.
+pub fn format_hours_minutes(seconds: i64) -> String {
+  let rounded = round_to_granularity(seconds, DurationGranularity::Minutes);
+  let sign = if seconds < 0 && rounded > 0 { "-" } else { "" };
+  format!(
+    "{}{}:{:02}",
+    sign,
+    rounded / SECONDS_PER_HOUR as u64,
+    rounded % SECONDS_PER_HOUR as u64 / SECONDS_PER_MINUTE as u64
+  )
+}
+
+/// Formats the seconds as the hours rounded to two decimals without the trailing zeros, for
+/// example, `1.5h`.
+pub fn format_decimal_hours(seconds: i64) -> String {
+  let hours = (Decimal::from(seconds) / Decimal::from(SECONDS_PER_HOUR))
+    .round_dp(2)
+    .normalize();
+  format!("{}h", hours)
+}
+
+fn invalid_duration_error(s: &str) -> FlowyError {
+  let msg = format!("Invalid duration: {}", s);
+  FlowyError::new(ErrorCode::InvalidData, &msg)
+}
+
+/// Parses the duration into seconds. The duration is the number of seconds, for example, `3661`,
+/// the hours, minutes and seconds, for example, `1h 1m 1s`, `90m` or `1.5h`, the clock time, for
+/// example, `1:30:00` or `1:30`, or the ISO 8601 duration, for example, `PT1H30M`. The fractional
+/// seconds are rounded. Returns None if the string is empty.
+pub fn parse_duration(s: &str) -> FlowyResult<Option<i64>> {
+  let s = s.trim();
+  if s.is_empty() {
+    return Ok(None);
+  }
+  if let Ok(seconds) = s.parse::<i64>() {
+    return Ok(Some(seconds));
+  }
+
+  let invalid_duration = || invalid_duration_error(s);
+  let (sign, rest) = match s.strip_prefix('-') {
+    Some(rest) => (-1, rest),
+    None => (1, s),
+  };
+  if let Some(iso8601) = rest.strip_prefix('P') {
+    return Ok(Some(
+      sign * parse_iso8601_duration(iso8601).ok_or_else(invalid_duration)?,
+    ));
+  }
+  if rest.contains(':') {
+    return Ok(Some(
+      sign * parse_clock_duration(rest).ok_or_else(invalid_duration)?,
+    ));
+  }
+  let mut seconds: i64 = 0;
+  let mut value = String::new();
+  for c in rest.chars() {
+    match c {
+      '0'..='9' | '.' => value.push(c),
+      'h' | 'm' | 's' => {
+        let unit = match c {
+          'h' => SECONDS_PER_HOUR,
+          'm' => SECONDS_PER_MINUTE,
+          _ => 1,
+        };
+        let part =
+          Decimal::from_str(&std::mem::take(&mut value)).map_err(|_| invalid_duration())?;
+        seconds = part
+          .checked_mul(Decimal::from(unit))
+          .and_then(|part| part.round().to_i64())
+          .and_then(|part| seconds.checked_add(part))
+          .ok_or_else(invalid_duration)?;
+      },
+      c if c.is_whitespace() && value.is_empty() => {},
+      _ => return Err(invalid_duration()),
+    }
+  }
+  if !value.is_empty() {
+    return Err(invalid_duration());
+  }
+  Ok(Some(sign * seconds))
+}
+
+/// Parses the `H:MM:SS` or `H:MM` clock time into seconds, the minutes and the seconds must have
+/// at most two digits and be less than 60. Returns None if the clock time is invalid.
+fn parse_clock_duration(s: &str) -> Option<i64> {
+  let parts = s.split(':').collect::<Vec<&str>>();
+  if !(2..=3).contains(&parts.len())
+    || parts
+      .iter()
+      .any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()))
+  {
+    return None;
+  }
+
+  let mut seconds = parts[0]
+    .parse::<i64>()
+    .ok()?
+    .checked_mul(SECONDS_PER_HOUR)?;
+  for (part, unit) in parts[1..].iter().zip([SECONDS_PER_MINUTE, 1]) {
+    let value = part.parse::<i64>().ok()?;
+    if part.len() > 2 || value >= 60 {
+      return None;
+    }
+    seconds = seconds.checked_add(value * unit)?;
+  }
+  Some(seconds)
+}
+
+/// Parses the ISO 8601 duration after the leading `P` into seconds, for example, `1DT2H` of
+/// `P1DT2H`. Only the weeks and the days are accepted before the `T`, because the length of the
+/// years and the months varies. Returns None if the duration is invalid.
+fn parse_iso8601_duration(s: &str) -> Option<i64> {
+  let (date, time) = match s.split_once('T') {
+    Some((date, time)) if !time.is_empty() => (date, Some(time)),
+    Some(_) => return None,
+    None => (s, None),
+  };
+  if date.is_empty() && time.is_none() {
+    return None;
+  }
+
+  let date_seconds = parse_iso8601_parts(date, |designator| match designator {
+    'W' => Some(SECONDS_PER_WEEK),
+    'D' => Some(SECONDS_PER_DAY),
+    _ => None,
+  })?;
+  let time_seconds = match time {
+    None => 0,
+    Some(time) => parse_iso8601_parts(time, |designator| match designator {
+      'H' => Some(SECONDS_PER_HOUR),
+      'M' => Some(SECONDS_PER_MINUTE),
+      'S' => Some(1),
+      _ => None,
+    })?,
+  };
+  date_seconds.checked_add(time_seconds)
+}
+
+/// Sums the `<number><designator>` parts, where `unit` returns the seconds of each designator.
+fn parse_iso8601_parts(s: &str, unit: impl Fn(char) -> Option<i64>) -> Option<i64> {
+  let mut seconds: i64 = 0;
+  let mut value = String::new();
+  for c in s.chars() {
+    if c.is_ascii_digit() {
+      value.push(c);
+    } else {
+      let part = std::mem::take(&mut value).parse::<i64>().ok()?;
+      seconds = part
+        .checked_mul(unit(c)?)
+        .and_then(|part| seconds.checked_add(part))?;
+    }
+  }
+  if value.is_empty() {
+    Some(seconds)
+  } else {
+    None
+  }
+}
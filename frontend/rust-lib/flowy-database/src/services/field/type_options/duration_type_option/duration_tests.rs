@@ -0,0 +1,247 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{insert_text_cell, CellDataChangeset, CellDataDecoder};
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, parse_duration, ComparableKind, DurationCellData, DurationDisplayFormat,
+    DurationGranularity, DurationTypeOptionBuilder, DurationTypeOptionPB, FieldBuilder,
+    SpreadsheetValue, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+  use std::cmp::Ordering;
+
+  #[test]
+  fn duration_display_granularity_test() {
+    let field_rev = FieldBuilder::new(DurationTypeOptionBuilder::default()).build();
+    let stringify = |display_granularity: DurationGranularity, cell_str: &str| {
+      let type_option = DurationTypeOptionPB {
+        display_granularity,
+        ..Default::default()
+      };
+      let cell_data = type_option
+        .decode_cell_str(cell_str.to_owned(), &FieldType::Duration, &field_rev)
+        .unwrap();
+      type_option.decode_cell_data_to_str(cell_data)
+    };
+
+    assert_eq!(stringify(DurationGranularity::Seconds, "3661"), "1h 1m 1s");
+    assert_eq!(stringify(DurationGranularity::Minutes, "3661"), "1h 1m");
+    assert_eq!(stringify(DurationGranularity::Hours, "3661"), "1h");
+
+    // The duration is rounded to the nearest unit.
+    assert_eq!(stringify(DurationGranularity::Minutes, "89"), "1m");
+    assert_eq!(stringify(DurationGranularity::Minutes, "90"), "2m");
+    assert_eq!(stringify(DurationGranularity::Hours, "5400"), "2h");
+    assert_eq!(stringify(DurationGranularity::Hours, "1000"), "0h");
+    assert_eq!(stringify(DurationGranularity::Seconds, "-61"), "-1m 1s");
+    assert_eq!(stringify(DurationGranularity::Seconds, ""), "");
+  }
+
+  #[test]
+  fn duration_changeset_keeps_exact_seconds_test() {
+    let type_option = DurationTypeOptionPB {
+      display_granularity: DurationGranularity::Hours,
+      ..Default::default()
+    };
+    for (changeset, expected) in [
+      ("3661", "3661"),
+      ("1h 1m 1s", "3661"),
+      ("1h1m1s", "3661"),
+      ("90m", "5400"),
+      ("-5m", "-300"),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected, "{}", changeset);
+    }
+
+    assert!(parse_duration("1x").is_err());
+    assert!(parse_duration("h").is_err());
+    assert!(parse_duration("1h 30").is_err());
+  }
+
+  #[test]
+  fn duration_cmp_and_filter_use_exact_seconds_test() {
+    let type_option = DurationTypeOptionPB {
+      display_granularity: DurationGranularity::Hours,
+      ..Default::default()
+    };
+    // Both are displayed as `1h`, but they are compared by the exact seconds.
+    let left = DurationCellData(Some(3661));
+    let right = DurationCellData(Some(3600));
+    assert_eq!(type_option.apply_cmp(&left, &right), Ordering::Greater);
+    assert_eq!(type_option.apply_cmp(&left, &left), Ordering::Equal);
+    assert_eq!(
+      type_option.apply_cmp(&DurationCellData(None), &right),
+      Ordering::Less
+    );
+
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "3600".to_owned(),
+    };
+    assert!(type_option.apply_filter(&filter, &FieldType::Duration, &left));
+    assert!(!type_option.apply_filter(&filter, &FieldType::Duration, &right));
+  }
+
+  #[test]
+  fn duration_iso8601_test() {
+    for (changeset, expected) in [
+      ("PT90M", Some(5400)),
+      ("PT1H30M", Some(5400)),
+      ("P1DT2H", Some(93600)),
+      ("P1W", Some(604800)),
+      ("PT0S", Some(0)),
+      ("-PT1M", Some(-60)),
+    ] {
+      assert_eq!(
+        parse_duration(changeset).unwrap(),
+        expected,
+        "{}",
+        changeset
+      );
+    }
+    for changeset in ["P", "PT", "P1Y", "P1M", "PT1D", "PT1H30", "P1DT"] {
+      assert!(parse_duration(changeset).is_err(), "{}", changeset);
+    }
+
+    let field_rev = FieldBuilder::new(DurationTypeOptionBuilder::default()).build();
+    let type_option = DurationTypeOptionPB {
+      display_format: DurationDisplayFormat::Iso8601,
+      ..Default::default()
+    };
+    for (cell_str, expected) in [
+      ("5400", "PT1H30M"),
+      ("93600", "P1DT2H"),
+      ("86400", "P1D"),
+      ("61", "PT1M1S"),
+      ("0", "PT0S"),
+      ("-60", "-PT1M"),
+    ] {
+      let cell_data = type_option
+        .decode_cell_str(cell_str.to_owned(), &FieldType::Duration, &field_rev)
+        .unwrap();
+      let s = type_option.decode_cell_data_to_str(cell_data);
+      assert_eq!(s, expected);
+
+      // The displayed duration is parsed back into the same seconds.
+      let (round_trip, _) = type_option.apply_changeset(s, None).unwrap();
+      assert_eq!(round_trip, cell_str);
+    }
+  }
+
+  #[test]
+  fn duration_clock_and_decimal_hours_test() {
+    for (changeset, expected) in [
+      ("1:30:00", Some(5400)),
+      ("01:30:00", Some(5400)),
+      ("1:30", Some(5400)),
+      ("25:00:01", Some(90001)),
+      ("-0:01:30", Some(-90)),
+      ("1.5h", Some(5400)),
+      ("0.25h 30m", Some(2700)),
+      ("1.5m", Some(90)),
+      ("90m", Some(5400)),
+    ] {
+      assert_eq!(
+        parse_duration(changeset).unwrap(),
+        expected,
+        "{}",
+        changeset
+      );
+    }
+    for changeset in [
+      "1:", ":30", "1:60", "1:30:60", "1:030", "1:2:3:4", "1.5", "1..5h",
+    ] {
+      assert!(parse_duration(changeset).is_err(), "{}", changeset);
+    }
+
+    let field_rev = FieldBuilder::new(DurationTypeOptionBuilder::default()).build();
+    for (display_format, cell_str, expected) in [
+      (
+        DurationDisplayFormat::HoursMinutesSeconds,
+        "5400",
+        "01:30:00",
+      ),
+      (
+        DurationDisplayFormat::HoursMinutesSeconds,
+        "90001",
+        "25:00:01",
+      ),
+      (
+        DurationDisplayFormat::HoursMinutesSeconds,
+        "-90",
+        "-00:01:30",
+      ),
+      (DurationDisplayFormat::HoursMinutes, "5400", "1:30"),
+      (DurationDisplayFormat::HoursMinutes, "0", "0:00"),
+      (DurationDisplayFormat::HoursMinutes, "-5400", "-1:30"),
+      (DurationDisplayFormat::DecimalHours, "5400", "1.5h"),
+      (DurationDisplayFormat::DecimalHours, "9000", "2.5h"),
+      (DurationDisplayFormat::DecimalHours, "0", "0h"),
+      (DurationDisplayFormat::DecimalHours, "-900", "-0.25h"),
+    ] {
+      let type_option = DurationTypeOptionPB {
+        display_format,
+        ..Default::default()
+      };
+      let cell_data = type_option
+        .decode_cell_str(cell_str.to_owned(), &FieldType::Duration, &field_rev)
+        .unwrap();
+      let s = type_option.decode_cell_data_to_str(cell_data);
+      assert_eq!(s, expected);
+
+      // The displayed duration is parsed back into the same seconds.
+      let (round_trip, _) = type_option.apply_changeset(s, None).unwrap();
+      assert_eq!(round_trip, cell_str);
+    }
+
+    // The formats round the seconds they can't display.
+    let type_option = DurationTypeOptionPB {
+      display_format: DurationDisplayFormat::HoursMinutes,
+      ..Default::default()
+    };
+    assert_eq!(
+      type_option.decode_cell_data_to_str(DurationCellData(Some(5430))),
+      "1:31"
+    );
+    let type_option = DurationTypeOptionPB {
+      display_format: DurationDisplayFormat::DecimalHours,
+      ..Default::default()
+    };
+    assert_eq!(
+      type_option.decode_cell_data_to_str(DurationCellData(Some(3661))),
+      "1.02h"
+    );
+  }
+
+  #[test]
+  fn duration_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Duration).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Duration);
+    assert_eq!(handler.comparable_kind(), ComparableKind::Number);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::Duration,
+      &FieldType::Duration
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = NumberFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, NumberFilterConditionPB::GreaterThan);
+    assert!(filter.content.is_empty());
+
+    // The duration is exported as the number of seconds.
+    let cell_rev = insert_text_cell("1h 1m 1s".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Number(3661.0)
+    );
+    let cell_rev = insert_text_cell("90m".to_owned(), &field_rev);
+    assert_eq!(cell_value(&field_rev, &cell_rev).number.unwrap(), "5400");
+  }
+}
@@ -0,0 +1,194 @@
+use crate::entities::{CellValuePB, FieldType, NumberFilterConditionPB, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  format_decimal_hours, format_duration, format_hours_minutes, format_hours_minutes_seconds,
+  format_iso8601_duration, parse_duration, BoxTypeOptionBuilder, ComparableKind, DurationCellData,
+  DurationDisplayFormat, DurationGranularity, EmptyFilterCondition, NumberCellData,
+  SpreadsheetValue, StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct DurationTypeOptionBuilder(DurationTypeOptionPB);
+impl_into_box_type_option_builder!(DurationTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(DurationTypeOptionBuilder, DurationTypeOptionPB);
+
+impl DurationTypeOptionBuilder {
+  pub fn display_granularity(mut self, display_granularity: DurationGranularity) -> Self {
+    self.0.display_granularity = display_granularity;
+    self
+  }
+
+  pub fn display_format(mut self, display_format: DurationDisplayFormat) -> Self {
+    self.0.display_format = display_format;
+    self
+  }
+}
+
+impl TypeOptionBuilder for DurationTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Duration
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The duration field stores the exact number of seconds. The `display_granularity` only affects
+/// how the duration is displayed, the comparison and the filter always use the exact seconds.
+/// The clock and the decimal hours formats have their own precision, so they ignore the
+/// `display_granularity`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct DurationTypeOptionPB {
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub display_granularity: DurationGranularity,
+
+  #[pb(index = 2)]
+  #[serde(default)]
+  pub display_format: DurationDisplayFormat,
+}
+impl_type_option!(DurationTypeOptionPB, FieldType::Duration);
+
+impl TypeOption for DurationTypeOptionPB {
+  type CellData = DurationCellData;
+  type CellChangeset = String;
+  type CellProtobufType = StrCellData;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for DurationTypeOptionPB {}
+
+impl TypeOptionCellData for DurationTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    StrCellData(self.decode_cell_data_to_str(cell_data))
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    DurationCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.0.is_none()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      number: Some(cell_data.to_string()),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for DurationTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_duration() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match cell_data.0 {
+      None => "".to_owned(),
+      Some(seconds) => match self.display_format {
+        DurationDisplayFormat::Human => format_duration(seconds, self.display_granularity),
+        DurationDisplayFormat::Iso8601 => {
+          format_iso8601_duration(seconds, self.display_granularity)
+        },
+        DurationDisplayFormat::HoursMinutesSeconds => format_hours_minutes_seconds(seconds),
+        DurationDisplayFormat::HoursMinutes => format_hours_minutes(seconds),
+        DurationDisplayFormat::DecimalHours => format_decimal_hours(seconds),
+      },
+    }
+  }
+
+  fn decode_cell_data_to_spreadsheet_value(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> SpreadsheetValue {
+    match cell_data.0 {
+      None => SpreadsheetValue::Text("".to_owned()),
+      Some(seconds) => SpreadsheetValue::Number(seconds as f64),
+    }
+  }
+}
+
+impl CellDataChangeset for DurationTypeOptionPB {
+  /// The changeset is any duration that [parse_duration] accepts, for example, `5400`, `90m`,
+  /// `1.5h`, `1:30:00` or `PT1H30M`. The empty changeset clears the cell.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = DurationCellData(parse_duration(&changeset)?);
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for DurationTypeOptionPB {
+  /// The content of the filter is the number of seconds.
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_duration() {
+      return true;
+    }
+
+    let number_cell_data = match cell_data.0 {
+      None => NumberCellData::new(),
+      Some(seconds) => NumberCellData::from_decimal(Decimal::from(seconds)),
+    };
+    filter.is_visible(&number_cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    NumberFilterConditionPB::GreaterThan as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for DurationTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data.0.cmp(&other_cell_data.0)
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::Number
+  }
+}
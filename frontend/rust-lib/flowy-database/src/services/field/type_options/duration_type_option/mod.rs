@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod duration_entities;
+mod duration_tests;
+mod duration_type_option;
+
+pub use duration_entities::*;
+pub use duration_type_option::*;
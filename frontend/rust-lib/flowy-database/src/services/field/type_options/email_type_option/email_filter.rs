@@ -0,0 +1,28 @@
+use crate::entities::{EmailFilterConditionPB, EmailFilterPB};
+use crate::services::field::{EmailCellData, EmptyFilterCondition};
+
+impl EmailFilterPB {
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      EmailFilterConditionPB::EmailIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      EmailFilterConditionPB::EmailIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
+  /// The `DomainContains` condition ignores the case of the content, since the domains are
+  /// case-insensitive. The empty cell doesn't contain any domain.
+  pub fn is_visible(&self, cell_data: &EmailCellData) -> bool {
+    match self.condition {
+      EmailFilterConditionPB::EmailIsEmpty => cell_data.0.is_none(),
+      EmailFilterConditionPB::EmailIsNotEmpty => cell_data.0.is_some(),
+      EmailFilterConditionPB::DomainContains => {
+        let content = self.content.trim().to_lowercase();
+        cell_data
+          .domain()
+          .map(|domain| domain.contains(&content))
+          .unwrap_or(false)
+      },
+    }
+  }
+}
@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{EmailFilterConditionPB, EmailFilterPB, FieldType};
+  use crate::services::cell::{insert_text_cell, CellDataChangeset, CellDataDecoder};
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, is_valid_email, ComparableKind, EmailCellData, EmailTypeOptionPB,
+    FieldBuilder, SpreadsheetValue, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+
+  fn email_cell_data(s: &str) -> EmailCellData {
+    EmailCellData(Some(s.to_owned()))
+  }
+
+  #[test]
+  fn email_valid_address_test() {
+    for address in [
+      "hello@appflowy.io",
+      "first.last@example.com",
+      "user+tag@sub.example.co.uk",
+      "o'neil@example-mail.org",
+      "a@b.cd",
+    ] {
+      assert!(is_valid_email(address), "{}", address);
+    }
+
+    for address in [
+      "",
+      "hello",
+      "hello@",
+      "@appflowy.io",
+      "hello@appflowy",
+      "hello@@appflowy.io",
+      "hello world@appflowy.io",
+      ".hello@appflowy.io",
+      "hello.@appflowy.io",
+      "hel..lo@appflowy.io",
+      "hello@-appflowy.io",
+      "hello@appflowy..io",
+      "hello@appflowy.i",
+      "hello@appflowy.123",
+      "hello@192.168.0.1",
+    ] {
+      assert!(!is_valid_email(address), "{}", address);
+    }
+  }
+
+  #[test]
+  fn email_changeset_test() {
+    let type_option = EmailTypeOptionPB::default();
+    for (changeset, expected) in [
+      ("hello@appflowy.io", "hello@appflowy.io"),
+      (" Hello@AppFlowy.IO ", "Hello@appflowy.io"),
+      ("mailto:hello@appflowy.io", "hello@appflowy.io"),
+      ("MAILTO:hello@appflowy.io", "hello@appflowy.io"),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected, "{}", changeset);
+      assert!(type_option
+        .validate_changeset(&changeset.to_owned())
+        .is_ok());
+    }
+
+    for changeset in [
+      "hello",
+      "hello@appflowy",
+      "mailto:hello",
+      "hello@app flowy.io",
+    ] {
+      let changeset = changeset.to_owned();
+      assert!(
+        type_option.validate_changeset(&changeset).is_err(),
+        "{}",
+        changeset
+      );
+      assert!(
+        type_option
+          .apply_changeset(changeset.clone(), None)
+          .is_err(),
+        "{}",
+        changeset
+      );
+    }
+  }
+
+  #[test]
+  fn email_mailto_stringify_test() {
+    let cell_data = email_cell_data("hello@appflowy.io");
+    let type_option = EmailTypeOptionPB::default();
+    assert_eq!(
+      type_option.decode_cell_data_to_str(cell_data.clone()),
+      "hello@appflowy.io"
+    );
+
+    let type_option = EmailTypeOptionPB { mailto: true };
+    assert_eq!(
+      type_option.decode_cell_data_to_str(cell_data),
+      "mailto:hello@appflowy.io"
+    );
+    assert_eq!(type_option.decode_cell_data_to_str(EmailCellData(None)), "");
+  }
+
+  #[test]
+  fn email_cmp_test() {
+    let type_option = EmailTypeOptionPB::default();
+    let mut cells = vec![
+      email_cell_data("bob@example.com"),
+      email_cell_data("alice@zeta.io"),
+      EmailCellData(None),
+      email_cell_data("alice@alpha.io"),
+    ];
+    cells.sort_by(|left, right| type_option.apply_cmp(left, right));
+    assert_eq!(
+      cells,
+      vec![
+        EmailCellData(None),
+        email_cell_data("alice@alpha.io"),
+        email_cell_data("alice@zeta.io"),
+        email_cell_data("bob@example.com"),
+      ]
+    );
+  }
+
+  #[test]
+  fn email_domain_filter_test() {
+    let type_option = EmailTypeOptionPB::default();
+    let filter = |condition: EmailFilterConditionPB, content: &str| EmailFilterPB {
+      condition,
+      content: content.to_owned(),
+    };
+
+    let domain_contains = filter(EmailFilterConditionPB::DomainContains, "AppFlowy");
+    for (cell_data, expected) in [
+      (email_cell_data("hello@appflowy.io"), true),
+      (email_cell_data("team@mail.appflowy.io"), true),
+      (email_cell_data("appflowy@example.com"), false),
+      (EmailCellData(None), false),
+    ] {
+      assert_eq!(
+        type_option.apply_filter(&domain_contains, &FieldType::Email, &cell_data),
+        expected,
+        "{:?}",
+        cell_data
+      );
+    }
+
+    let is_empty = filter(EmailFilterConditionPB::EmailIsEmpty, "");
+    let is_not_empty = filter(EmailFilterConditionPB::EmailIsNotEmpty, "");
+    let cell_data = email_cell_data("hello@appflowy.io");
+    assert!(!type_option.apply_filter(&is_empty, &FieldType::Email, &cell_data));
+    assert!(type_option.apply_filter(&is_not_empty, &FieldType::Email, &cell_data));
+    assert!(type_option.apply_filter(&is_empty, &FieldType::Email, &EmailCellData(None)));
+  }
+
+  #[test]
+  fn email_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Email).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Email);
+    assert_eq!(handler.comparable_kind(), ComparableKind::String);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(&FieldType::Email, &FieldType::Email));
+
+    let filter = default_filter(&field_rev);
+    let filter = EmailFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, EmailFilterConditionPB::DomainContains);
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_text_cell("hello@appflowy.io".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("hello@appflowy.io".to_owned())
+    );
+    assert_eq!(
+      cell_value(&field_rev, &cell_rev).text.unwrap(),
+      "hello@appflowy.io"
+    );
+  }
+}
@@ -0,0 +1,273 @@
+use crate::entities::{CellValuePB, EmailFilterConditionPB, EmailFilterPB, FieldType};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  plain_text, BoxTypeOptionBuilder, ComparableKind, EmptyFilterCondition, StrCellData, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct EmailTypeOptionBuilder(EmailTypeOptionPB);
+impl_into_box_type_option_builder!(EmailTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(EmailTypeOptionBuilder, EmailTypeOptionPB);
+
+impl EmailTypeOptionBuilder {
+  pub fn mailto(mut self, mailto: bool) -> Self {
+    self.0.mailto = mailto;
+    self
+  }
+}
+
+impl TypeOptionBuilder for EmailTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Email
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The email field stores a single email address, for example, `hello@appflowy.io`. The domain of
+/// the address is stored in lowercase, the local part is kept as it is.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct EmailTypeOptionPB {
+  /// Prefixes the `mailto:` to the address when the cell is stringified, so the exported cells
+  /// can be opened by the mail clients.
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub mailto: bool,
+}
+impl_type_option!(EmailTypeOptionPB, FieldType::Email);
+
+const MAILTO_PREFIX: &str = "mailto:";
+
+/// The address of the email cell. It's None if the cell is empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EmailCellData(pub Option<String>);
+
+impl EmailCellData {
+  /// Parses the email address, the `mailto:` prefix is ignored. Returns None if the string is
+  /// empty, and error if it isn't an address, see [is_valid_email].
+  pub fn parse(s: &str) -> FlowyResult<Self> {
+    let s = s.trim();
+    let address = match s.get(..MAILTO_PREFIX.len()) {
+      Some(prefix) if prefix.eq_ignore_ascii_case(MAILTO_PREFIX) => &s[MAILTO_PREFIX.len()..],
+      _ => s,
+    };
+    if address.is_empty() {
+      return Ok(Self(None));
+    }
+    match address.rsplit_once('@') {
+      Some((local, domain)) if is_valid_email(address) => {
+        Ok(Self(Some(format!("{}@{}", local, domain.to_lowercase()))))
+      },
+      _ => {
+        let msg = format!("{} is not a valid email address", s);
+        Err(FlowyError::new(ErrorCode::InvalidData, &msg))
+      },
+    }
+  }
+
+  /// Returns the part of the address after the `@`.
+  pub fn domain(&self) -> Option<&str> {
+    self
+      .0
+      .as_ref()
+      .and_then(|address| address.rsplit_once('@'))
+      .map(|(_, domain)| domain)
+  }
+}
+
+impl FromCellString for EmailCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Ok(Self::parse(s).unwrap_or_default())
+  }
+}
+
+impl ToString for EmailCellData {
+  fn to_string(&self) -> String {
+    self.0.clone().unwrap_or_default()
+  }
+}
+
+/// Returns true if the `s` looks like an email address. It's a practical subset of the RFC 5322:
+/// the local part is made of the letters, digits and `!#$%&'*+/=?^_`{|}~-` separated by single
+/// dots, and the domain has at least two labels of the letters, digits and hyphens, the last of
+/// which is a top-level domain of two or more letters. The quoted local parts and the IP address
+/// domains aren't supported.
+pub fn is_valid_email(s: &str) -> bool {
+  let (local, domain) = match s.rsplit_once('@') {
+    None => return false,
+    Some(parts) => parts,
+  };
+
+  let is_valid_local = !local.is_empty()
+    && local.len() <= 64
+    && !local.starts_with('.')
+    && !local.ends_with('.')
+    && !local.contains("..")
+    && local
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || c == '.' || "!#$%&'*+/=?^_`{|}~-".contains(c));
+  if !is_valid_local || domain.len() > 253 {
+    return false;
+  }
+
+  let labels = domain.split('.').collect::<Vec<_>>();
+  let is_valid_label = |label: &&str| {
+    !label.is_empty()
+      && label.len() <= 63
+      && !label.starts_with('-')
+      && !label.ends_with('-')
+      && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+  };
+  let is_valid_tld = labels
+    .last()
+    .map(|tld| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()))
+    .unwrap_or(false);
+  labels.len() >= 2 && labels.iter().all(is_valid_label) && is_valid_tld
+}
+
+impl TypeOption for EmailTypeOptionPB {
+  type CellData = EmailCellData;
+  type CellChangeset = String;
+  type CellProtobufType = StrCellData;
+  type CellFilter = EmailFilterPB;
+}
+
+impl TypeOptionTransform for EmailTypeOptionPB {
+  fn transformable(&self) -> bool {
+    true
+  }
+
+  /// Keeps the text cells that are email addresses, the other text cells become empty.
+  fn transform_type_option_cell_str(
+    &self,
+    cell_str: &str,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> Option<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_text() {
+      return None;
+    }
+    Some(EmailCellData::parse(&plain_text(cell_str)).unwrap_or_default())
+  }
+}
+
+impl TypeOptionCellData for EmailTypeOptionPB {
+  /// The editor always gets the bare address, the `mailto:` is only added when stringifying.
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    StrCellData(cell_data.to_string())
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    EmailCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.0.is_none()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      text: Some(cell_data.to_string()),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for EmailTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_email() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match &cell_data.0 {
+      Some(address) if self.mailto => format!("{}{}", MAILTO_PREFIX, address),
+      _ => cell_data.to_string(),
+    }
+  }
+}
+
+impl CellDataChangeset for EmailTypeOptionPB {
+  /// The changeset is the email address, with or without the `mailto:` prefix. The empty
+  /// changeset clears the cell.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = EmailCellData::parse(&changeset)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+
+  fn validate_changeset(&self, changeset: &<Self as TypeOption>::CellChangeset) -> FlowyResult<()> {
+    EmailCellData::parse(changeset).map(|_| ())
+  }
+}
+
+impl TypeOptionCellDataFilter for EmailTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_email() {
+      return true;
+    }
+    filter.is_visible(cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    EmailFilterConditionPB::DomainContains as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for EmailTypeOptionPB {
+  /// Sorts by the full address, the empty cells come first.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data.0.cmp(&other_cell_data.0)
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::String
+  }
+}
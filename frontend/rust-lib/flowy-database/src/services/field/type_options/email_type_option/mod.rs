@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod email_filter;
+mod email_tests;
+mod email_type_option;
+
+pub use email_type_option::*;
@@ -0,0 +1,70 @@
+use crate::entities::{IpFilterConditionPB, IpFilterPB};
+use crate::services::field::{EmptyFilterCondition, IpCellData};
+use std::net::IpAddr;
+
+impl IpFilterPB {
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      IpFilterConditionPB::IpIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      IpFilterConditionPB::IpIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
+  /// The `CidrRange` conditions check whether the address is in the range of the content. The
+  /// IPv4 address is never in the IPv6 range, and vice versa. The cell is visible if the content
+  /// isn't a CIDR range.
+  pub fn is_visible(&self, cell_data: &IpCellData) -> bool {
+    let in_range = match self.condition {
+      IpFilterConditionPB::IpIsEmpty => return cell_data.0.is_none(),
+      IpFilterConditionPB::IpIsNotEmpty => return cell_data.0.is_some(),
+      IpFilterConditionPB::InCidrRange | IpFilterConditionPB::NotInCidrRange => {
+        let (network, prefix_len) = match parse_cidr(&self.content) {
+          None => return true,
+          Some(cidr) => cidr,
+        };
+        cell_data
+          .0
+          .map(|ip_addr| cidr_contains(network, prefix_len, ip_addr))
+          .unwrap_or(false)
+      },
+    };
+    match self.condition {
+      IpFilterConditionPB::NotInCidrRange => !in_range,
+      _ => in_range,
+    }
+  }
+}
+
+/// Parses the CIDR range, for example, `192.168.0.0/16` or `2001:db8::/32`, into the network
+/// address and the prefix length. The address without the prefix length is the range of that
+/// single address.
+pub fn parse_cidr(s: &str) -> Option<(IpAddr, u8)> {
+  let s = s.trim();
+  let (address, prefix_len) = match s.split_once('/') {
+    None => (s, None),
+    Some((address, prefix_len)) => (address, Some(prefix_len.trim().parse::<u8>().ok()?)),
+  };
+  let network = address.trim().parse::<IpAddr>().ok()?;
+  let max_prefix_len = match network {
+    IpAddr::V4(_) => 32,
+    IpAddr::V6(_) => 128,
+  };
+  let prefix_len = prefix_len.unwrap_or(max_prefix_len);
+  (prefix_len <= max_prefix_len).then_some((network, prefix_len))
+}
+
+/// Returns true if the first `prefix_len` bits of the `ip_addr` are the same as the `network`'s.
+pub fn cidr_contains(network: IpAddr, prefix_len: u8, ip_addr: IpAddr) -> bool {
+  match (network, ip_addr) {
+    (IpAddr::V4(network), IpAddr::V4(ip_addr)) => {
+      let mask = u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0);
+      u32::from(network) & mask == u32::from(ip_addr) & mask
+    },
+    (IpAddr::V6(network), IpAddr::V6(ip_addr)) => {
+      let mask = u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or(0);
+      u128::from(network) & mask == u128::from(ip_addr) & mask
+    },
+    _ => false,
+  }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, IpFilterConditionPB, IpFilterPB};
+  use crate::services::cell::{insert_text_cell, CellDataChangeset};
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, ComparableKind, FieldBuilder, IpCellData, IpTypeOptionPB,
+    SpreadsheetValue, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+
+  fn ip_cell_data(s: &str) -> IpCellData {
+    IpCellData(Some(s.parse().unwrap()))
+  }
+
+  #[test]
+  fn ip_changeset_normalize_test() {
+    let type_option = IpTypeOptionPB::default();
+    for (changeset, expected) in [
+      ("192.168.0.1", "192.168.0.1"),
+      (" 10.0.0.255 ", "10.0.0.255"),
+      ("2001:0DB8:0000:0000:0000:0000:0000:0001", "2001:db8::1"),
+      ("[FE80::1]", "fe80::1"),
+      ("::ffff:192.0.2.128", "::ffff:192.0.2.128"),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected, "{}", changeset);
+    }
+
+    for changeset in ["256.0.0.1", "192.168.1", "2001:db8:::1", "localhost"] {
+      assert!(
+        type_option
+          .apply_changeset(changeset.to_owned(), None)
+          .is_err(),
+        "{}",
+        changeset
+      );
+    }
+  }
+
+  #[test]
+  fn ip_cmp_test() {
+    let type_option = IpTypeOptionPB::default();
+    let mut cells = vec![
+      ip_cell_data("::1"),
+      ip_cell_data("10.0.0.2"),
+      IpCellData(None),
+      ip_cell_data("9.255.255.255"),
+      ip_cell_data("10.0.0.10"),
+    ];
+    cells.sort_by(|left, right| type_option.apply_cmp(left, right));
+    assert_eq!(
+      cells,
+      vec![
+        IpCellData(None),
+        ip_cell_data("9.255.255.255"),
+        ip_cell_data("10.0.0.2"),
+        ip_cell_data("10.0.0.10"),
+        ip_cell_data("::1"),
+      ]
+    );
+  }
+
+  #[test]
+  fn ip_cidr_filter_test() {
+    let type_option = IpTypeOptionPB::default();
+    let filter = |condition: IpFilterConditionPB, content: &str| IpFilterPB {
+      condition,
+      content: content.to_owned(),
+    };
+
+    let in_range = filter(IpFilterConditionPB::InCidrRange, "10.1.0.0/16");
+    let not_in_range = filter(IpFilterConditionPB::NotInCidrRange, "10.1.0.0/16");
+    for (cell_data, expected) in [
+      (ip_cell_data("10.1.0.0"), true),
+      (ip_cell_data("10.1.255.7"), true),
+      (ip_cell_data("10.2.0.1"), false),
+      (ip_cell_data("::ffff:10.1.0.1"), false),
+      (IpCellData(None), false),
+    ] {
+      assert_eq!(
+        type_option.apply_filter(&in_range, &FieldType::IpAddress, &cell_data),
+        expected,
+        "{:?}",
+        cell_data
+      );
+      assert_eq!(
+        type_option.apply_filter(&not_in_range, &FieldType::IpAddress, &cell_data),
+        !expected,
+        "{:?}",
+        cell_data
+      );
+    }
+
+    let v6_range = filter(IpFilterConditionPB::InCidrRange, "2001:db8::/32");
+    for (cell_data, expected) in [
+      (ip_cell_data("2001:db8:ffff::1"), true),
+      (ip_cell_data("2001:db9::1"), false),
+      (ip_cell_data("10.1.0.1"), false),
+    ] {
+      assert_eq!(
+        type_option.apply_filter(&v6_range, &FieldType::IpAddress, &cell_data),
+        expected,
+        "{:?}",
+        cell_data
+      );
+    }
+
+    // The address without the prefix length matches itself only, and `/0` matches every address
+    // of the same version.
+    let single = filter(IpFilterConditionPB::InCidrRange, "10.1.0.1");
+    assert!(type_option.apply_filter(&single, &FieldType::IpAddress, &ip_cell_data("10.1.0.1")));
+    assert!(!type_option.apply_filter(&single, &FieldType::IpAddress, &ip_cell_data("10.1.0.2")));
+    let any_v4 = filter(IpFilterConditionPB::InCidrRange, "0.0.0.0/0");
+    assert!(type_option.apply_filter(&any_v4, &FieldType::IpAddress, &ip_cell_data("8.8.8.8")));
+
+    // The invalid range doesn't filter out any cell.
+    for content in ["10.1.0.0/33", "10.1.0.0/abc", "not a range"] {
+      let invalid = filter(IpFilterConditionPB::InCidrRange, content);
+      assert!(
+        type_option.apply_filter(&invalid, &FieldType::IpAddress, &ip_cell_data("8.8.8.8")),
+        "{}",
+        content
+      );
+    }
+  }
+
+  #[test]
+  fn ip_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::IpAddress).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::IpAddress);
+    assert_eq!(handler.comparable_kind(), ComparableKind::Number);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::IpAddress,
+      &FieldType::IpAddress
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = IpFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, IpFilterConditionPB::InCidrRange);
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_text_cell("10.0.0.1".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("10.0.0.1".to_owned())
+    );
+    let cell_rev = insert_text_cell("::1".to_owned(), &field_rev);
+    assert_eq!(cell_value(&field_rev, &cell_rev).text.unwrap(), "::1");
+  }
+}
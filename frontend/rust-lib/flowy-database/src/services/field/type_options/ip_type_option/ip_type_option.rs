@@ -0,0 +1,195 @@
+use crate::entities::{CellValuePB, FieldType, IpFilterConditionPB, IpFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  BoxTypeOptionBuilder, ComparableKind, EmptyFilterCondition, StrCellData, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::net::IpAddr;
+
+#[derive(Default)]
+pub struct IpTypeOptionBuilder(IpTypeOptionPB);
+impl_into_box_type_option_builder!(IpTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(IpTypeOptionBuilder, IpTypeOptionPB);
+
+impl TypeOptionBuilder for IpTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::IpAddress
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The IP address field stores an IPv4 or IPv6 address in its canonical form, for example,
+/// `2001:db8::1` for `2001:0DB8:0000:0000:0000:0000:0000:0001`. The cells are sorted by the
+/// numeric address, the IPv4 addresses come before the IPv6 addresses.
+///
+/// The `data` property is not used yet.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct IpTypeOptionPB {
+  #[pb(index = 1)]
+  #[serde(default)]
+  data: String,
+}
+impl_type_option!(IpTypeOptionPB, FieldType::IpAddress);
+
+/// The address of the IP address cell. It's None if the cell is empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IpCellData(pub Option<IpAddr>);
+
+impl IpCellData {
+  /// Parses the IPv4 or IPv6 address. The IPv6 address can be wrapped in brackets, for example,
+  /// `[::1]`. Returns None if the string is empty, and error if it isn't an address.
+  pub fn parse(s: &str) -> FlowyResult<Self> {
+    let s = s.trim();
+    if s.is_empty() {
+      return Ok(Self(None));
+    }
+    let address = s
+      .strip_prefix('[')
+      .and_then(|s| s.strip_suffix(']'))
+      .unwrap_or(s);
+    match address.parse::<IpAddr>() {
+      Ok(ip_addr) => Ok(Self(Some(ip_addr))),
+      Err(_) => {
+        let msg = format!("Invalid IP address: {}", s);
+        Err(FlowyError::new(ErrorCode::InvalidData, &msg))
+      },
+    }
+  }
+}
+
+impl FromCellString for IpCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Ok(Self::parse(s).unwrap_or_default())
+  }
+}
+
+impl ToString for IpCellData {
+  fn to_string(&self) -> String {
+    self
+      .0
+      .map(|ip_addr| ip_addr.to_string())
+      .unwrap_or_default()
+  }
+}
+
+impl TypeOption for IpTypeOptionPB {
+  type CellData = IpCellData;
+  type CellChangeset = String;
+  type CellProtobufType = StrCellData;
+  type CellFilter = IpFilterPB;
+}
+
+impl TypeOptionTransform for IpTypeOptionPB {}
+
+impl TypeOptionCellData for IpTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    StrCellData(self.decode_cell_data_to_str(cell_data))
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    IpCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.0.is_none()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      text: Some(cell_data.to_string()),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for IpTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_ip_address() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+}
+
+impl CellDataChangeset for IpTypeOptionPB {
+  /// The changeset is the IPv4 or IPv6 address, which is stored in its canonical form. The empty
+  /// changeset clears the cell.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = IpCellData::parse(&changeset)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for IpTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_ip_address() {
+      return true;
+    }
+    filter.is_visible(cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    IpFilterConditionPB::InCidrRange as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for IpTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    // The `IpAddr::V4` is ordered before the `IpAddr::V6`.
+    cell_data.0.cmp(&other_cell_data.0)
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::Number
+  }
+}
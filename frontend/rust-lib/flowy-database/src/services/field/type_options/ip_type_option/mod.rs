@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod ip_filter;
+mod ip_tests;
+mod ip_type_option;
+
+pub use ip_filter::*;
+pub use ip_type_option::*;
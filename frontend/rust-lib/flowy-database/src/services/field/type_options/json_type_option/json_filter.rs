@@ -0,0 +1,46 @@
+use crate::entities::{JsonFilterConditionPB, JsonFilterPB};
+use crate::services::field::{
+  parse_json_path, select_json_path, EmptyFilterCondition, JsonCellData,
+};
+use serde_json::Value;
+
+impl JsonFilterPB {
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      JsonFilterConditionPB::JsonIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      JsonFilterConditionPB::JsonIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
+  /// The `Path` conditions look up the value at the path of the filter. The numbers are equal if
+  /// they have the same value, so `1` equals `1.0`. The cell is visible if the path is invalid.
+  pub fn is_visible(&self, cell_data: &JsonCellData) -> bool {
+    let segments = match self.condition {
+      JsonFilterConditionPB::JsonIsEmpty => return cell_data.0.is_none(),
+      JsonFilterConditionPB::JsonIsNotEmpty => return cell_data.0.is_some(),
+      _ => match parse_json_path(&self.path) {
+        None => return true,
+        Some(segments) => segments,
+      },
+    };
+
+    let selected = cell_data
+      .0
+      .as_ref()
+      .and_then(|value| select_json_path(value, &segments));
+    match self.condition {
+      JsonFilterConditionPB::PathEquals => {
+        let expected = serde_json::from_str::<Value>(&self.value)
+          .unwrap_or_else(|_| Value::String(self.value.clone()));
+        selected
+          .map(|selected| match (selected, &expected) {
+            (Value::Number(left), Value::Number(right)) => left.as_f64() == right.as_f64(),
+            (selected, expected) => selected == expected,
+          })
+          .unwrap_or(false)
+      },
+      _ => selected.is_some(),
+    }
+  }
+}
@@ -0,0 +1,61 @@
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonPathSegment {
+  Key(String),
+  Index(usize),
+}
+
+/// Parses the minimal subset of the JSONPath: the root `$`, the child `.key` or `['key']`, and
+/// the array index `[0]`, for example, `$.tags[0]` or `$['first name']`. The leading `$` can be
+/// omitted, so `owner.name` is the same as `$.owner.name`. Returns None if the path is invalid.
+pub fn parse_json_path(path: &str) -> Option<Vec<JsonPathSegment>> {
+  let path = path.trim();
+  let normalized;
+  let mut rest = match path.strip_prefix('$') {
+    Some(rest) => rest,
+    None if path.starts_with('[') => path,
+    None => {
+      normalized = format!(".{}", path);
+      normalized.as_str()
+    },
+  };
+
+  let mut segments = vec![];
+  while !rest.is_empty() {
+    if let Some(child) = rest.strip_prefix('.') {
+      let end = child.find(|c| c == '.' || c == '[').unwrap_or(child.len());
+      let key = &child[..end];
+      if key.is_empty() {
+        return None;
+      }
+      segments.push(JsonPathSegment::Key(key.to_owned()));
+      rest = &child[end..];
+    } else if let Some(child) = rest.strip_prefix('[') {
+      let end = child.find(']')?;
+      let inner = child[..end].trim();
+      rest = &child[end + 1..];
+      let quoted = inner.len() >= 2
+        && ((inner.starts_with('\'') && inner.ends_with('\''))
+          || (inner.starts_with('"') && inner.ends_with('"')));
+      if quoted {
+        segments.push(JsonPathSegment::Key(inner[1..inner.len() - 1].to_owned()));
+      } else {
+        segments.push(JsonPathSegment::Index(inner.parse::<usize>().ok()?));
+      }
+    } else {
+      return None;
+    }
+  }
+  Some(segments)
+}
+
+/// Returns the value at the path, or None if the path doesn't exist in the value.
+pub fn select_json_path<'a>(value: &'a Value, segments: &[JsonPathSegment]) -> Option<&'a Value> {
+  segments
+    .iter()
+    .try_fold(value, |value, segment| match segment {
+      JsonPathSegment::Key(key) => value.get(key.as_str()),
+      JsonPathSegment::Index(index) => value.get(*index),
+    })
+}
@@ -0,0 +1,264 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, JsonFilterConditionPB, JsonFilterPB};
+  use crate::services::cell::{insert_text_cell, CellDataChangeset, CellDataDecoder};
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, parse_json_path, ComparableKind, FieldBuilder, JsonCellData,
+    JsonPathSegment, JsonTypeOptionPB, SpreadsheetValue, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter,
+  };
+  use database_model::FieldRevision;
+
+  fn json_cell_data(s: &str) -> JsonCellData {
+    JsonCellData::parse(s).unwrap()
+  }
+
+  fn field_rev() -> FieldRevision {
+    FieldBuilder::from_field_type(&FieldType::Json).build()
+  }
+
+  #[test]
+  fn json_changeset_canonical_test() {
+    let type_option = JsonTypeOptionPB::default();
+    for (changeset, expected) in [
+      (
+        r#"{ "b": 1, "a": [true, null] }"#,
+        r#"{"a":[true,null],"b":1}"#,
+      ),
+      (" 42 ", "42"),
+      (r#""text""#, r#""text""#),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected, "{}", changeset);
+    }
+  }
+
+  #[test]
+  fn json_invalid_changeset_test() {
+    let type_option = JsonTypeOptionPB::default();
+    for changeset in [r#"{"a": 1"#, "{a: 1}", "text", "[1, 2,]"] {
+      assert!(
+        type_option
+          .apply_changeset(changeset.to_owned(), None)
+          .is_err(),
+        "{}",
+        changeset
+      );
+    }
+  }
+
+  #[test]
+  fn json_depth_guard_test() {
+    let type_option = JsonTypeOptionPB::default();
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(type_option.apply_changeset(nested(64), None).is_ok());
+    assert!(type_option.apply_changeset(nested(65), None).is_err());
+    // Unbalanced brackets hit the guard before the parser sees the invalid JSON.
+    let err = type_option
+      .decode_cell_str("[".repeat(100_000), &FieldType::Json, &field_rev())
+      .unwrap_err();
+    assert!(
+      err.msg.contains("nested deeper than 64 levels"),
+      "{}",
+      err.msg
+    );
+
+    let shallow = JsonTypeOptionPB {
+      max_depth: 2,
+      ..Default::default()
+    };
+    assert!(shallow
+      .apply_changeset(r#"{"a": [1, 2]}"#.to_owned(), None)
+      .is_ok());
+    assert!(shallow
+      .apply_changeset(r#"{"a": {"b": [1]}}"#.to_owned(), None)
+      .is_err());
+    // The brackets inside the strings are not nested values.
+    assert!(shallow
+      .apply_changeset(r#"{"a": "[[[{\"{{"}"#.to_owned(), None)
+      .is_ok());
+  }
+
+  #[test]
+  fn json_length_guard_test() {
+    let type_option = JsonTypeOptionPB {
+      max_length: 16,
+      ..Default::default()
+    };
+    assert!(type_option
+      .apply_changeset(r#"{"a": "short"}"#.to_owned(), None)
+      .is_ok());
+    let long = format!(r#"{{"a": "{}"}}"#, "x".repeat(16));
+    assert!(type_option.apply_changeset(long.clone(), None).is_err());
+    let err = type_option
+      .decode_cell_str(long, &FieldType::Json, &field_rev())
+      .unwrap_err();
+    assert!(err.msg.contains("longer than 16 bytes"), "{}", err.msg);
+
+    let default = JsonTypeOptionPB::default();
+    let huge = format!(r#""{}""#, "x".repeat(100_000));
+    assert!(default.apply_changeset(huge, None).is_err());
+  }
+
+  #[test]
+  fn json_stringify_test() {
+    let cell_data = json_cell_data(r#"{"a": {"b": 1}}"#);
+    let compact = JsonTypeOptionPB {
+      pretty: false,
+      ..Default::default()
+    };
+    assert_eq!(
+      compact.decode_cell_data_to_str(cell_data.clone()),
+      r#"{"a":{"b":1}}"#
+    );
+    let pretty = JsonTypeOptionPB {
+      pretty: true,
+      ..Default::default()
+    };
+    assert_eq!(
+      pretty.decode_cell_data_to_str(cell_data),
+      "{\n  \"a\": {\n    \"b\": 1\n  }\n}"
+    );
+  }
+
+  #[test]
+  fn json_path_parse_test() {
+    let key = |key: &str| JsonPathSegment::Key(key.to_owned());
+    assert_eq!(parse_json_path("$"), Some(vec![]));
+    assert_eq!(
+      parse_json_path("$.owner.name"),
+      Some(vec![key("owner"), key("name")])
+    );
+    assert_eq!(
+      parse_json_path("owner.name"),
+      Some(vec![key("owner"), key("name")])
+    );
+    assert_eq!(
+      parse_json_path("$.tags[1]"),
+      Some(vec![key("tags"), JsonPathSegment::Index(1)])
+    );
+    assert_eq!(
+      parse_json_path("$['first name']"),
+      Some(vec![key("first name")])
+    );
+    for path in ["$..a", "$.a[x]", "$.a[0", "$a"] {
+      assert_eq!(parse_json_path(path), None, "{}", path);
+    }
+  }
+
+  #[test]
+  fn json_path_filter_test() {
+    let type_option = JsonTypeOptionPB::default();
+    let filter = |condition: JsonFilterConditionPB, path: &str, value: &str| JsonFilterPB {
+      condition,
+      path: path.to_owned(),
+      value: value.to_owned(),
+    };
+    let cells = [
+      json_cell_data(r#"{"status": "open", "owner": {"name": "Lucas"}, "tags": ["a", "b"]}"#),
+      json_cell_data(r#"{"status": "closed", "priority": 1}"#),
+      json_cell_data(r#"{"priority": 1.0}"#),
+      JsonCellData(None),
+    ];
+
+    for (filter, expected) in [
+      (
+        filter(JsonFilterConditionPB::PathEquals, "$.status", r#""open""#),
+        [true, false, false, false],
+      ),
+      // The value that isn't a valid JSON is compared as a string.
+      (
+        filter(JsonFilterConditionPB::PathEquals, "$.status", "closed"),
+        [false, true, false, false],
+      ),
+      (
+        filter(
+          JsonFilterConditionPB::PathEquals,
+          "$.owner.name",
+          r#""Lucas""#,
+        ),
+        [true, false, false, false],
+      ),
+      (
+        filter(JsonFilterConditionPB::PathEquals, "$.tags[1]", r#""b""#),
+        [true, false, false, false],
+      ),
+      (
+        filter(JsonFilterConditionPB::PathEquals, "$.priority", "1"),
+        [false, true, true, false],
+      ),
+      (
+        filter(JsonFilterConditionPB::PathExists, "$.priority", ""),
+        [false, true, true, false],
+      ),
+      (
+        filter(JsonFilterConditionPB::PathExists, "$.tags[2]", ""),
+        [false, false, false, false],
+      ),
+      // The invalid path doesn't filter out any cell.
+      (
+        filter(JsonFilterConditionPB::PathExists, "$..status", ""),
+        [true, true, true, true],
+      ),
+    ] {
+      for (cell_data, expected) in cells.iter().zip(expected) {
+        assert_eq!(
+          type_option.apply_filter(&filter, &FieldType::Json, cell_data),
+          expected,
+          "{:?} {:?}",
+          filter,
+          cell_data
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn json_sort_key_test() {
+    let type_option = JsonTypeOptionPB::default();
+    assert_eq!(type_option.sort_key(&json_cell_data("")), None);
+
+    // The cells are compared by their serialized JSON, so the string `"1"` comes before the
+    // number `1`.
+    let mut cells = vec![r#"{"a":1}"#, "[1]", "1", r#""1""#]
+      .into_iter()
+      .map(|s| {
+        let sort_key = type_option.sort_key(&json_cell_data(s)).unwrap();
+        (s, sort_key)
+      })
+      .collect::<Vec<_>>();
+    cells.sort_by(|(_, left), (_, right)| type_option.apply_cmp(left, right));
+    assert_eq!(
+      cells.into_iter().map(|(s, _)| s).collect::<Vec<_>>(),
+      vec![r#""1""#, "1", "[1]", r#"{"a":1}"#]
+    );
+  }
+
+  #[test]
+  fn json_cell_data_handler_test() {
+    let field_rev = field_rev();
+    let handler = cell_data_handler(&field_rev, &FieldType::Json);
+    assert_eq!(handler.comparable_kind(), ComparableKind::String);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(&FieldType::Json, &FieldType::Json));
+
+    let filter = default_filter(&field_rev);
+    let filter = JsonFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, JsonFilterConditionPB::PathExists);
+    assert!(filter.path.is_empty());
+
+    let cell_rev = insert_text_cell(r#"{"a":1}"#.to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text(r#"{"a":1}"#.to_owned())
+    );
+    let cell_rev = insert_text_cell("[1,2]".to_owned(), &field_rev);
+    assert_eq!(cell_value(&field_rev, &cell_rev).text.unwrap(), "[1,2]");
+  }
+}
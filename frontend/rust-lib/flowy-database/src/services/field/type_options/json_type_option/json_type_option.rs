@@ -0,0 +1,301 @@
+use crate::entities::{CellValuePB, FieldType, JsonFilterConditionPB, JsonFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  BoxTypeOptionBuilder, ComparableKind, EmptyFilterCondition, StrCellData, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct JsonTypeOptionBuilder(JsonTypeOptionPB);
+impl_into_box_type_option_builder!(JsonTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(JsonTypeOptionBuilder, JsonTypeOptionPB);
+
+impl JsonTypeOptionBuilder {
+  pub fn pretty(mut self, pretty: bool) -> Self {
+    self.0.pretty = pretty;
+    self
+  }
+
+  pub fn max_depth(mut self, max_depth: i32) -> Self {
+    self.0.max_depth = max_depth;
+    self
+  }
+
+  pub fn max_length(mut self, max_length: i32) -> Self {
+    self.0.max_length = max_length;
+    self
+  }
+}
+
+impl TypeOptionBuilder for JsonTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Json
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The JSON field stores the structured metadata. The cell is stored in its canonical form, the
+/// compact JSON with the sorted object keys, so the cells are compared by the canonical form.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct JsonTypeOptionPB {
+  /// Pretty-prints the JSON when the cell is stringified, otherwise it's compact.
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub pretty: bool,
+
+  /// The max nesting depth of the arrays and objects of the cell. 0 uses the default, 64.
+  #[pb(index = 2)]
+  #[serde(default)]
+  pub max_depth: i32,
+
+  /// The max length of the cell in bytes. 0 uses the default, 100,000 bytes.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub max_length: i32,
+}
+impl_type_option!(JsonTypeOptionPB, FieldType::Json);
+
+/// Lower than the recursion limit of the serde_json, so the guard is hit before the parser's.
+const DEFAULT_MAX_JSON_DEPTH: usize = 64;
+const DEFAULT_MAX_JSON_LENGTH: usize = 100_000;
+
+impl JsonTypeOptionPB {
+  /// Returns the error if the JSON is longer or nested deeper than the limits of the field. The
+  /// depth is counted by scanning the brackets, so the pathological cell is rejected before the
+  /// parser recurses through it or allocates its values.
+  pub fn check_limits(&self, s: &str) -> FlowyResult<()> {
+    let max_length = positive_or(self.max_length, DEFAULT_MAX_JSON_LENGTH);
+    if s.len() > max_length {
+      let msg = format!("The JSON is longer than {} bytes", max_length);
+      return Err(FlowyError::new(ErrorCode::InvalidData, &msg));
+    }
+
+    let max_depth = positive_or(self.max_depth, DEFAULT_MAX_JSON_DEPTH);
+    if exceeds_json_depth(s, max_depth) {
+      let msg = format!("The JSON is nested deeper than {} levels", max_depth);
+      return Err(FlowyError::new(ErrorCode::InvalidData, &msg));
+    }
+    Ok(())
+  }
+}
+
+fn positive_or(value: i32, default: usize) -> usize {
+  usize::try_from(value)
+    .ok()
+    .filter(|value| *value > 0)
+    .unwrap_or(default)
+}
+
+/// Returns true if the arrays and objects are nested deeper than `max_depth`. The brackets inside
+/// the strings are ignored.
+fn exceeds_json_depth(s: &str, max_depth: usize) -> bool {
+  let mut depth = 0;
+  let mut in_string = false;
+  let mut escaped = false;
+  for b in s.bytes() {
+    if in_string {
+      match b {
+        _ if escaped => escaped = false,
+        b'\\' => escaped = true,
+        b'"' => in_string = false,
+        _ => {},
+      }
+      continue;
+    }
+    match b {
+      b'"' => in_string = true,
+      b'[' | b'{' => {
+        depth += 1;
+        if depth > max_depth {
+          return true;
+        }
+      },
+      b']' | b'}' => depth = depth.saturating_sub(1),
+      _ => {},
+    }
+  }
+  false
+}
+
+/// The JSON value of the cell. It's None if the cell is empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsonCellData(pub Option<Value>);
+
+impl JsonCellData {
+  /// Parses the JSON. Returns None if the string is empty, and error if it isn't a valid JSON.
+  pub fn parse(s: &str) -> FlowyResult<Self> {
+    let s = s.trim();
+    if s.is_empty() {
+      return Ok(Self(None));
+    }
+    match serde_json::from_str::<Value>(s) {
+      Ok(value) => Ok(Self(Some(value))),
+      Err(err) => {
+        let msg = format!("Invalid JSON: {}", err);
+        Err(FlowyError::new(ErrorCode::InvalidData, &msg))
+      },
+    }
+  }
+}
+
+impl FromCellString for JsonCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Ok(Self::parse(s).unwrap_or_default())
+  }
+}
+
+impl ToString for JsonCellData {
+  fn to_string(&self) -> String {
+    self
+      .0
+      .as_ref()
+      .map(|value| value.to_string())
+      .unwrap_or_default()
+  }
+}
+
+impl TypeOption for JsonTypeOptionPB {
+  type CellData = JsonCellData;
+  type CellChangeset = String;
+  type CellProtobufType = StrCellData;
+  type CellFilter = JsonFilterPB;
+}
+
+impl TypeOptionTransform for JsonTypeOptionPB {}
+
+impl TypeOptionCellData for JsonTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    StrCellData(self.decode_cell_data_to_str(cell_data))
+  }
+
+  /// The cell that exceeds the limits fails to decode, see [JsonTypeOptionPB::check_limits].
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    self.check_limits(&cell_str)?;
+    JsonCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.0.is_none()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      text: Some(cell_data.to_string()),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for JsonTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_json() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match cell_data.0 {
+      None => "".to_owned(),
+      Some(value) if self.pretty => serde_json::to_string_pretty(&value).unwrap_or_default(),
+      Some(value) => value.to_string(),
+    }
+  }
+}
+
+impl CellDataChangeset for JsonTypeOptionPB {
+  /// The changeset is the JSON, which is stored in its canonical form. The empty changeset clears
+  /// the cell.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    self.check_limits(&changeset)?;
+    let cell_data = JsonCellData::parse(&changeset)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for JsonTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_json() {
+      return true;
+    }
+    filter.is_visible(cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    JsonFilterConditionPB::PathExists as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for JsonTypeOptionPB {
+  /// Compares the serialized JSON of the cells. The cells are passed in as their sort keys, which
+  /// hold the serialized JSON as a string value, see [TypeOptionCellDataCompare::sort_key].
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    serialized_json(cell_data).cmp(&serialized_json(other_cell_data))
+  }
+
+  fn sort_key(&self, cell_data: &<Self as TypeOption>::CellData) -> Option<JsonCellData> {
+    let value = cell_data.0.as_ref()?;
+    Some(JsonCellData(Some(Value::String(value.to_string()))))
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::String
+  }
+}
+
+/// Returns the serialized JSON that is held by the sort key of the cell.
+fn serialized_json(cell_data: &JsonCellData) -> Cow<'_, str> {
+  match &cell_data.0 {
+    None => Cow::Borrowed(""),
+    Some(Value::String(s)) => Cow::Borrowed(s),
+    Some(value) => Cow::Owned(value.to_string()),
+  }
+}
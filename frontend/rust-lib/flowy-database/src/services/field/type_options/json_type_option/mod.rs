@@ -0,0 +1,8 @@
+#![allow(clippy::module_inception)]
+mod json_filter;
+mod json_path;
+mod json_tests;
+mod json_type_option;
+
+pub use json_path::*;
+pub use json_type_option::*;
@@ -1,15 +1,35 @@
 pub mod checkbox_type_option;
+pub mod concat_type_option;
 pub mod date_type_option;
+pub mod duration_type_option;
+pub mod email_type_option;
+pub mod ip_type_option;
+pub mod json_type_option;
 pub mod number_type_option;
+pub mod order_type_option;
+pub mod phone_type_option;
+pub mod rating_type_option;
+pub mod relation_type_option;
 pub mod selection_type_option;
+#[cfg(test)]
+pub(crate) mod test_util;
 pub mod text_type_option;
 mod type_option;
 mod type_option_cell;
 pub mod url_type_option;
 
 pub use checkbox_type_option::*;
+pub use concat_type_option::*;
 pub use date_type_option::*;
+pub use duration_type_option::*;
+pub use email_type_option::*;
+pub use ip_type_option::*;
+pub use json_type_option::*;
 pub use number_type_option::*;
+pub use order_type_option::*;
+pub use phone_type_option::*;
+pub use rating_type_option::*;
+pub use relation_type_option::*;
 pub use selection_type_option::*;
 pub use text_type_option::*;
 pub use type_option::*;
@@ -1,8 +1,10 @@
 use flowy_derive::ProtoBuf_Enum;
 use lazy_static::lazy_static;
 
+use rust_decimal::{Decimal, RoundingStrategy};
 use rusty_money::define_currency_set;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
@@ -51,6 +53,12 @@ pub enum NumberFormat {
   ArgentinePeso = 34,
   UruguayanPeso = 35,
   Percent = 36,
+  Ordinal = 37,
+  /// The currency of the field is configured by the ISO 4217 code, see [CurrencyStyle].
+  Currency = 38,
+  /// The percent that is stored as the fraction, for example, `42%` is stored as `0.42`. Unlike
+  /// the [NumberFormat::Percent], which stores the number as it's displayed.
+  FractionalPercent = 39,
 }
 
 impl std::default::Default for NumberFormat {
@@ -445,10 +453,176 @@ impl NumberFormat {
       NumberFormat::ArgentinePeso => number_currency::ARS,
       NumberFormat::UruguayanPeso => number_currency::UYU,
       NumberFormat::Percent => number_currency::PERCENT,
+      // The ordinal number is parsed as the plain number.
+      NumberFormat::Ordinal => number_currency::NUMBER,
+      // The currency is rendered by the [CurrencyStyle] of the field's currency code.
+      NumberFormat::Currency => number_currency::NUMBER,
+      // The fraction is rendered as the percent by the number type option.
+      NumberFormat::FractionalPercent => number_currency::NUMBER,
     }
   }
 
   pub fn symbol(&self) -> String {
     self.currency().symbol.to_string()
   }
+
+  /// Returns the ISO 4217 code of the currency. Returns None if the format isn't a currency.
+  pub fn currency_code(&self) -> Option<&'static str> {
+    let code = match self {
+      NumberFormat::Num
+      | NumberFormat::Percent
+      | NumberFormat::Ordinal
+      | NumberFormat::Currency
+      | NumberFormat::FractionalPercent => return None,
+      NumberFormat::USD => "USD",
+      NumberFormat::CanadianDollar => "CAD",
+      NumberFormat::EUR => "EUR",
+      NumberFormat::Pound => "GBP",
+      NumberFormat::Yen => "JPY",
+      NumberFormat::Ruble => "RUB",
+      NumberFormat::Rupee => "INR",
+      NumberFormat::Won => "KRW",
+      NumberFormat::Yuan => "CNY",
+      NumberFormat::Real => "BRL",
+      NumberFormat::Lira => "TRY",
+      NumberFormat::Rupiah => "IDR",
+      NumberFormat::Franc => "CHF",
+      NumberFormat::HongKongDollar => "HKD",
+      NumberFormat::NewZealandDollar => "NZD",
+      NumberFormat::Krona => "SEK",
+      NumberFormat::NorwegianKrone => "NOK",
+      NumberFormat::MexicanPeso => "MXN",
+      NumberFormat::Rand => "ZAR",
+      NumberFormat::NewTaiwanDollar => "TWD",
+      NumberFormat::DanishKrone => "DKK",
+      NumberFormat::Baht => "THB",
+      NumberFormat::Forint => "HUF",
+      NumberFormat::Koruna => "CZK",
+      NumberFormat::Shekel => "ILS",
+      NumberFormat::ChileanPeso => "CLP",
+      NumberFormat::PhilippinePeso => "PHP",
+      NumberFormat::Dirham => "AED",
+      NumberFormat::ColombianPeso => "COP",
+      NumberFormat::Riyal => "SAR",
+      NumberFormat::Ringgit => "MYR",
+      NumberFormat::Leu => "RON",
+      NumberFormat::ArgentinePeso => "ARS",
+      NumberFormat::UruguayanPeso => "UYU",
+    };
+    Some(code)
+  }
+
+  /// Returns the currency format of the ISO 4217 code, case-insensitive.
+  pub fn from_currency_code(code: &str) -> Option<NumberFormat> {
+    NumberFormat::iter().find(|format| {
+      format
+        .currency_code()
+        .map(|format_code| format_code.eq_ignore_ascii_case(code))
+        .unwrap_or(false)
+    })
+  }
+
+  pub fn is_currency(&self) -> bool {
+    self.currency_code().is_some()
+  }
+}
+
+/// Describes how the amount of the [NumberFormat::Currency] is rendered, for example, `$1,234.56`
+/// for USD and `1 234,56 €` for EUR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyStyle {
+  pub symbol: String,
+  pub symbol_first: bool,
+  pub group_separator: char,
+  pub decimal_separator: char,
+  /// The number of the fraction digits.
+  pub exponent: u32,
+}
+
+impl CurrencyStyle {
+  fn new(
+    symbol: &str,
+    symbol_first: bool,
+    group_separator: char,
+    decimal_separator: char,
+    exponent: u32,
+  ) -> Self {
+    Self {
+      symbol: symbol.to_owned(),
+      symbol_first,
+      group_separator,
+      decimal_separator,
+      exponent,
+    }
+  }
+
+  /// Returns the style of the ISO 4217 code, case-insensitive. The unknown code is used as the
+  /// symbol, and the empty code renders the grouped amount without any symbol.
+  pub fn from_currency_code(code: &str) -> Self {
+    let code = code.trim().to_uppercase();
+    match code.as_str() {
+      "USD" => Self::new("$", true, ',', '.', 2),
+      "CAD" => Self::new("CA$", true, ',', '.', 2),
+      "GBP" => Self::new("£", true, ',', '.', 2),
+      "EUR" => Self::new("€", false, ' ', ',', 2),
+      "CHF" => Self::new("CHF", true, '\'', '.', 2),
+      "JPY" => Self::new("¥", true, ',', '.', 0),
+      "KRW" => Self::new("₩", true, ',', '.', 0),
+      "CNY" => Self::new("CN¥", true, ',', '.', 2),
+      "INR" => Self::new("₹", true, ',', '.', 2),
+      _ => Self::new(&code, true, ',', '.', 2),
+    }
+  }
+
+  /// Parses the amount that may contain the symbol and the group separators, for example,
+  /// `1 234,56 €` for EUR. Returns None if the string doesn't contain a number.
+  pub fn parse(&self, s: &str) -> Option<Decimal> {
+    let s = if self.symbol.is_empty() {
+      s.to_owned()
+    } else {
+      s.replace(&self.symbol, "")
+    };
+    let num_str = s
+      .chars()
+      .filter(|c| !c.is_whitespace() && *c != self.group_separator)
+      .map(|c| if c == self.decimal_separator { '.' } else { c })
+      .collect::<String>();
+    Decimal::from_str(&num_str).ok()
+  }
+
+  /// Renders the amount rounded to the currency's exponent with the group separators.
+  pub fn format(&self, decimal: Decimal) -> String {
+    let rounded =
+      decimal.round_dp_with_strategy(self.exponent, RoundingStrategy::MidpointAwayFromZero);
+    let digits = format!("{:.*}", self.exponent as usize, rounded.abs());
+    let (integer, fraction) = match digits.split_once('.') {
+      Some((integer, fraction)) => (integer, Some(fraction)),
+      None => (digits.as_str(), None),
+    };
+
+    let mut amount = String::new();
+    for (index, c) in integer.chars().enumerate() {
+      if index > 0 && (integer.len() - index) % 3 == 0 {
+        amount.push(self.group_separator);
+      }
+      amount.push(c);
+    }
+    if let Some(fraction) = fraction {
+      amount.push(self.decimal_separator);
+      amount.push_str(fraction);
+    }
+
+    let sign = if rounded.is_sign_negative() && !rounded.is_zero() {
+      "-"
+    } else {
+      ""
+    };
+    if self.symbol.is_empty() {
+      format!("{}{}", sign, amount)
+    } else if self.symbol_first {
+      format!("{}{}{}", sign, self.symbol, amount)
+    } else {
+      format!("{}{} {}", sign, amount, self.symbol)
+    }
+  }
 }
@@ -1,6 +1,6 @@
 use crate::entities::{NumberFilterConditionPB, NumberFilterPB};
 
-use crate::services::field::NumberCellData;
+use crate::services::field::{EmptyFilterCondition, NumberCellData};
 
 use rust_decimal::prelude::Zero;
 use rust_decimal::Decimal;
@@ -21,6 +21,24 @@ impl NumberFilterPB {
     }
     match num_cell_data.decimal().as_ref() {
       None => false,
+      Some(cell_decimal) if self.is_divisibility_condition() => {
+        let divisor = match self.condition {
+          NumberFilterConditionPB::IsEven | NumberFilterConditionPB::IsOdd => Decimal::TWO,
+          _ => match Decimal::from_str(&self.content) {
+            Ok(divisor) => divisor,
+            Err(_) => return false,
+          },
+        };
+        // Only the integers are divisible, and nothing is divisible by zero.
+        if !cell_decimal.fract().is_zero() || !divisor.fract().is_zero() || divisor.is_zero() {
+          return false;
+        }
+        let is_divisible = (cell_decimal % divisor).is_zero();
+        match self.condition {
+          NumberFilterConditionPB::IsOdd => !is_divisible,
+          _ => is_divisible,
+        }
+      },
       Some(cell_decimal) => {
         let decimal = Decimal::from_str(&self.content).unwrap_or_else(|_| Decimal::zero());
         match self.condition {
@@ -35,6 +53,23 @@ impl NumberFilterPB {
       },
     }
   }
+
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      NumberFilterConditionPB::NumberIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      NumberFilterConditionPB::NumberIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
+  fn is_divisibility_condition(&self) -> bool {
+    matches!(
+      self.condition,
+      NumberFilterConditionPB::DivisibleBy
+        | NumberFilterConditionPB::IsEven
+        | NumberFilterConditionPB::IsOdd
+    )
+  }
 }
 
 #[cfg(test)]
@@ -82,4 +117,56 @@ mod tests {
       assert_eq!(number_filter.is_visible(&data), visible);
     }
   }
+
+  #[test]
+  fn number_filter_even_odd_test() {
+    let even_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::IsEven,
+      content: "".to_owned(),
+    };
+    let odd_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::IsOdd,
+      content: "".to_owned(),
+    };
+    for (num_str, is_even, is_odd) in [
+      ("2", true, false),
+      ("7", false, true),
+      ("0", true, false),
+      ("-3", false, true),
+      ("4.5", false, false),
+      ("", false, false),
+    ] {
+      let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
+      assert_eq!(even_filter.is_visible(&data), is_even, "{}", num_str);
+      assert_eq!(odd_filter.is_visible(&data), is_odd, "{}", num_str);
+    }
+  }
+
+  #[test]
+  fn number_filter_divisible_by_test() {
+    let number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::DivisibleBy,
+      content: "3".to_owned(),
+    };
+    for (num_str, visible) in [
+      ("9", true),
+      ("-12", true),
+      ("10", false),
+      ("3.0", true),
+      ("4.5", false),
+      ("", false),
+    ] {
+      let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
+      assert_eq!(number_filter.is_visible(&data), visible, "{}", num_str);
+    }
+
+    for content in ["0", "1.5", "abc"] {
+      let number_filter = NumberFilterPB {
+        condition: NumberFilterConditionPB::DivisibleBy,
+        content: content.to_owned(),
+      };
+      let data = NumberCellData::from_format_str("6", true, &NumberFormat::Num).unwrap();
+      assert!(!number_filter.is_visible(&data), "{}", content);
+    }
+  }
 }
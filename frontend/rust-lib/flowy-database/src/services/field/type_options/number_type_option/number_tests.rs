@@ -1,11 +1,26 @@
 #[cfg(test)]
 mod tests {
-  use crate::entities::FieldType;
-  use crate::services::cell::CellDataDecoder;
-  use crate::services::field::FieldBuilder;
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{
+    apply_cell_data_changeset, apply_cell_precision, insert_number_cell, insert_text_cell,
+    AnyTypeCache, CellDataChangeset, CellDataDecoder, TypeCellData,
+  };
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, filter_is_empty, ingest_json_value,
+    spreadsheet_value,
+  };
+  use crate::services::field::{FieldBuilder, TypeOptionCellExt};
 
-  use crate::services::field::{strip_currency_symbol, NumberFormat, NumberTypeOptionPB};
-  use database_model::FieldRevision;
+  use crate::services::field::{
+    extract_number, is_transform_lossless, public_cache_key, strip_currency_symbol, AggregateKind,
+    ComparableKind, ExchangeRatePB, NumberFormat, NumberStats, NumberTypeOptionBuilder,
+    NumberTypeOptionPB, PercentInputMode, SpreadsheetValue, StrCellData, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter, TypeOptionTransform,
+  };
+  use database_model::{CellRevision, FieldRevision, NullsOrder};
+  use rust_decimal::Decimal;
+  use serde_json::json;
+  use std::cmp::Ordering;
   use strum::IntoEnumIterator;
 
   /// Testing when the input is not a number.
@@ -151,6 +166,15 @@ mod tests {
         NumberFormat::Percent => {
           assert_number(&type_option, "18443", "18,443%", &field_type, &field_rev)
         },
+        NumberFormat::Ordinal => {
+          assert_number(&type_option, "18443", "18443", &field_type, &field_rev)
+        },
+        NumberFormat::Currency => {
+          assert_number(&type_option, "18443", "18,443.00", &field_type, &field_rev)
+        },
+        NumberFormat::FractionalPercent => {
+          assert_number(&type_option, "18443", "1844300%", &field_type, &field_rev)
+        },
       }
     }
   }
@@ -519,6 +543,21 @@ mod tests {
           assert_number(&type_option, "10.1", "10.1%", &field_type, &field_rev);
           assert_number(&type_option, "100", "100%", &field_type, &field_rev);
         },
+        NumberFormat::Ordinal => {
+          assert_number(&type_option, "18443", "18443", &field_type, &field_rev);
+          assert_number(&type_option, "0.2", "0.2", &field_type, &field_rev);
+        },
+        NumberFormat::Currency => {
+          assert_number(&type_option, "1,844.5", "1,844.50", &field_type, &field_rev);
+          assert_number(&type_option, "0.2", "0.20", &field_type, &field_rev);
+          assert_number(&type_option, "abc", "", &field_type, &field_rev);
+        },
+        NumberFormat::FractionalPercent => {
+          assert_number(&type_option, "0.42", "42%", &field_type, &field_rev);
+          assert_number(&type_option, "42%", "42%", &field_type, &field_rev);
+          assert_number(&type_option, "", "", &field_type, &field_rev);
+          assert_number(&type_option, "abc", "", &field_type, &field_rev);
+        },
       }
     }
   }
@@ -646,10 +685,281 @@ mod tests {
         NumberFormat::Percent => {
           assert_number(&type_option, "18443", "-18,443%", &field_type, &field_rev)
         },
+        NumberFormat::Ordinal => {
+          assert_number(&type_option, "18443", "18443", &field_type, &field_rev)
+        },
+        NumberFormat::Currency => {
+          assert_number(&type_option, "18443", "-18,443.00", &field_type, &field_rev)
+        },
+        NumberFormat::FractionalPercent => {
+          assert_number(&type_option, "18443", "-1844300%", &field_type, &field_rev)
+        },
       }
     }
   }
 
+  #[test]
+  fn number_type_option_ordinal_test() {
+    let type_option = NumberTypeOptionPB {
+      format: NumberFormat::Ordinal,
+      ..Default::default()
+    };
+    let field_type = FieldType::Number;
+    let field_rev = FieldBuilder::from_field_type(&field_type).build();
+
+    for (input_str, expected_str) in [
+      ("1", "1st"),
+      ("2", "2nd"),
+      ("3", "3rd"),
+      ("4", "4th"),
+      ("11", "11th"),
+      ("12", "12th"),
+      ("13", "13th"),
+      ("21", "21st"),
+      ("22", "22nd"),
+      ("23", "23rd"),
+      ("111", "111th"),
+    ] {
+      let cell_data = type_option
+        .decode_cell_str(input_str.to_owned(), &field_type, &field_rev)
+        .unwrap();
+      assert_eq!(cell_data.as_str(), input_str);
+      assert_eq!(type_option.decode_cell_data_to_str(cell_data), expected_str);
+    }
+    assert_eq!(
+      type_option.decode_cell_data_to_str("1.5".to_owned().into()),
+      "1.5"
+    );
+  }
+
+  #[test]
+  fn number_cell_currency_override_test() {
+    let mut type_option = NumberTypeOptionPB::default();
+    type_option.set_format(NumberFormat::USD);
+    let field_type = FieldType::Number;
+    let field_rev = FieldBuilder::from_field_type(&field_type).build();
+
+    for (input_str, expected_str) in [
+      ("12", "$12"),
+      ("$12", "$12"),
+      ("EUR 12.5", "€12,5"),
+      ("eur 1", "€1"),
+      ("GBP 3", "£3"),
+      ("CAD 4", "CA$4"),
+      ("XYZ 4", ""),
+    ] {
+      assert_number(
+        &type_option,
+        input_str,
+        expected_str,
+        &field_type,
+        &field_rev,
+      );
+      let cell_data = type_option
+        .decode_cell_str(input_str.to_owned(), &field_type, &field_rev)
+        .unwrap();
+      assert_eq!(type_option.decode_cell_data_to_str(cell_data), expected_str);
+    }
+
+    // The currency code is ignored if the field isn't a currency.
+    type_option.set_format(NumberFormat::Num);
+    assert_number(&type_option, "EUR 12.5", "12.5", &field_type, &field_rev);
+  }
+
+  #[test]
+  fn number_cell_currency_override_compare_test() {
+    let mut type_option = NumberTypeOptionPB::default();
+    type_option.set_format(NumberFormat::USD);
+    type_option.exchange_rates.push(ExchangeRatePB {
+      currency_code: "EUR".to_owned(),
+      rate: "2".to_owned(),
+    });
+    let field_type = FieldType::Number;
+    let field_rev = FieldBuilder::from_field_type(&field_type).build();
+    let decode = |s: &str| {
+      type_option
+        .decode_cell_str(s.to_owned(), &field_type, &field_rev)
+        .unwrap()
+    };
+
+    // Compare the raw amounts by default: $10 > €6.
+    let usd = decode("10");
+    let eur = decode("EUR 6");
+    let gbp = decode("GBP 11");
+    assert_eq!(type_option.apply_cmp(&usd, &eur), Ordering::Greater);
+
+    // €6 is worth $12 after the conversion. There is no rate for GBP, so its raw amount is used.
+    type_option.compare_in_base_currency = true;
+    assert_eq!(type_option.apply_cmp(&usd, &eur), Ordering::Less);
+    assert_eq!(type_option.apply_cmp(&eur, &gbp), Ordering::Greater);
+    assert_eq!(type_option.apply_cmp(&usd, &gbp), Ordering::Less);
+    assert_eq!(
+      type_option.apply_cmp(&usd, &"".to_owned().into()),
+      Ordering::Greater
+    );
+  }
+
+  #[test]
+  fn number_currency_code_test() {
+    let field_type = FieldType::Number;
+    let field_rev = FieldBuilder::from_field_type(&field_type).build();
+    let currency = |code: &str| NumberTypeOptionPB {
+      format: NumberFormat::Currency,
+      currency_code: code.to_owned(),
+      ..Default::default()
+    };
+
+    let usd = currency("USD");
+    assert_number(&usd, "1234.56", "$1,234.56", &field_type, &field_rev);
+    assert_number(&usd, "$1,234.56", "$1,234.56", &field_type, &field_rev);
+    assert_number(&usd, "1234567", "$1,234,567.00", &field_type, &field_rev);
+
+    let eur = currency("eur");
+    assert_number(&eur, "1234.56", "1 234,56 €", &field_type, &field_rev);
+    assert_number(&eur, "1 234,56 €", "1 234,56 €", &field_type, &field_rev);
+    assert_number(&eur, "0,5", "0,50 €", &field_type, &field_rev);
+
+    let jpy = currency("JPY");
+    assert_number(&jpy, "1234.56", "¥1,235", &field_type, &field_rev);
+    assert_number(&jpy, "¥1,234", "¥1,234", &field_type, &field_rev);
+
+    // The symbol and the group separators are stripped, only the raw amount is stored.
+    for (type_option, input_str, expected_str) in [
+      (&usd, "$1,234.56", "1234.56"),
+      (&eur, "1 234,56 €", "1234.56"),
+      (&jpy, "¥1,234", "1234"),
+      (&usd, "abc", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(input_str.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected_str);
+    }
+
+    // The raw amount is rendered with the current currency code.
+    let cell_data = usd
+      .decode_cell_str("1234.56".to_owned(), &field_type, &field_rev)
+      .unwrap();
+    assert_eq!(usd.decode_cell_data_to_str(cell_data), "$1,234.56");
+    assert_eq!(
+      usd.apply_cmp(&"$9".to_owned().into(), &"$10".to_owned().into()),
+      Ordering::Less
+    );
+  }
+
+  #[test]
+  fn number_fractional_percent_test() {
+    let field_type = FieldType::Number;
+    let field_rev = FieldBuilder::from_field_type(&field_type).build();
+    let mut type_option = NumberTypeOptionPB {
+      format: NumberFormat::FractionalPercent,
+      ..Default::default()
+    };
+
+    // The input with `%` is divided by 100, and the bare number is already the fraction.
+    for (input_str, expected_cell_str, expected_str) in [
+      ("50%", "0.5", "50%"),
+      ("0.5", "0.5", "50%"),
+      ("100%", "1", "100%"),
+      ("", "", ""),
+    ] {
+      let (cell_str, cell_data) = type_option
+        .apply_changeset(input_str.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected_cell_str);
+      assert_eq!(type_option.decode_cell_data_to_str(cell_data), expected_str);
+      assert_number(
+        &type_option,
+        &cell_str,
+        expected_str,
+        &field_type,
+        &field_rev,
+      );
+    }
+
+    // The bare number is the percent in the percent input mode.
+    type_option.percent_input_mode = PercentInputMode::Percent;
+    for (input_str, expected_cell_str) in [("50", "0.5"), ("0.5", "0.005"), ("100%", "1")] {
+      let (cell_str, _) = type_option
+        .apply_changeset(input_str.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected_cell_str);
+    }
+
+    // The filter and the sort operate on the fraction.
+    let decode = |s: &str| {
+      type_option
+        .decode_cell_str(s.to_owned(), &field_type, &field_rev)
+        .unwrap()
+    };
+    let half = decode("0.5");
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "0.4".to_owned(),
+    };
+    assert!(type_option.apply_filter(&filter, &field_type, &half));
+    assert!(!type_option.apply_filter(&filter, &field_type, &decode("0.3")));
+    assert_eq!(type_option.apply_cmp(&half, &decode("1")), Ordering::Less);
+    assert_eq!(
+      type_option.apply_cmp(&decode("0.9"), &decode("1")),
+      Ordering::Less
+    );
+  }
+
+  #[test]
+  fn number_cell_precision_override_test() {
+    let field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default().decimal_places(2)).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Number);
+    let stringify =
+      |type_cell_data: TypeCellData| handler.stringify_type_cell_data(type_cell_data, &field_rev);
+
+    // The field's decimal places.
+    let cell = TypeCellData::new("3.14159".to_owned(), FieldType::Number);
+    assert_eq!(stringify(cell), "3.14");
+    let cell = TypeCellData::new("2.5".to_owned(), FieldType::Number);
+    assert_eq!(stringify(cell), "2.50");
+
+    // The cell's precision overrides the field's decimal places.
+    let cell = TypeCellData::new("3.14159".to_owned(), FieldType::Number).with_precision(4);
+    assert_eq!(stringify(cell), "3.1416");
+    let cell = TypeCellData::new("3.14159".to_owned(), FieldType::Number).with_precision(0);
+    assert_eq!(stringify(cell), "3");
+
+    // The precision is set on the cell without changing its value.
+    let data = apply_cell_data_changeset("3.14159".to_owned(), None, &field_rev, None).unwrap();
+    let data = apply_cell_precision(Some(CellRevision::new(data)), &field_rev, Some(4)).unwrap();
+    let type_cell_data = TypeCellData::from_json_str(&data).unwrap();
+    assert_eq!(type_cell_data.cell_str, "3.14159");
+    assert_eq!(stringify(type_cell_data), "3.1416");
+
+    // The precision is kept when the cell changes, and the full value is stored.
+    let cell_rev = CellRevision::new(data);
+    let data =
+      apply_cell_data_changeset("2.718281".to_owned(), Some(cell_rev), &field_rev, None).unwrap();
+    let type_cell_data = TypeCellData::from_json_str(&data).unwrap();
+    assert_eq!(type_cell_data.cell_str, "2.718281");
+    assert_eq!(type_cell_data.precision, Some(4));
+    assert_eq!(stringify(type_cell_data), "2.7183");
+
+    // Clearing the precision falls back to the field's decimal places.
+    let data = apply_cell_precision(Some(CellRevision::new(data)), &field_rev, None).unwrap();
+    assert_eq!(
+      stringify(TypeCellData::from_json_str(&data).unwrap()),
+      "2.72"
+    );
+
+    // Only the number cells have the precision.
+    let text_field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    assert!(apply_cell_precision(None, &text_field_rev, Some(2)).is_err());
+
+    // The comparison uses the full stored value, even if both are displayed as `3.14`.
+    let type_option = NumberTypeOptionPB::from(&field_rev);
+    assert_eq!(
+      type_option.apply_cmp(&"3.14159".to_owned().into(), &"3.1401".to_owned().into()),
+      Ordering::Greater
+    );
+  }
+
   fn assert_number(
     type_option: &NumberTypeOptionPB,
     input_str: &str,
@@ -665,4 +975,492 @@ mod tests {
       expected_str.to_owned()
     );
   }
+
+  #[test]
+  fn number_ingest_json_value_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    assert_eq!(ingest_json_value(&field_rev, json!(42)).unwrap(), "42");
+    assert_eq!(ingest_json_value(&field_rev, json!(3.5)).unwrap(), "3.5");
+    assert_eq!(ingest_json_value(&field_rev, json!("42")).unwrap(), "42");
+    assert_eq!(
+      ingest_json_value(&field_rev, json!(" 1,200 ")).unwrap(),
+      "1200"
+    );
+    assert_eq!(ingest_json_value(&field_rev, json!(null)).unwrap(), "");
+
+    assert!(ingest_json_value(&field_rev, json!("abc")).is_err());
+    assert!(ingest_json_value(&field_rev, json!(true)).is_err());
+    assert!(ingest_json_value(&field_rev, json!([1, 2])).is_err());
+    assert!(ingest_json_value(&field_rev, json!({ "value": 1 })).is_err());
+  }
+
+  #[test]
+  fn number_transform_from_text_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let transform = |type_option: &NumberTypeOptionPB, text: &str| {
+      let cell_data = type_option
+        .transform_type_option_cell_str(text, &FieldType::RichText, &field_rev)
+        .unwrap();
+      type_option.decode_cell_data_to_str(cell_data)
+    };
+
+    let num = NumberTypeOptionPB::default();
+    // Currency strings
+    assert_eq!(transform(&num, "$1,299.00 USD"), "1299.00");
+    assert_eq!(transform(&num, "Total: 42.5 EUR"), "42.5");
+    // Percentages
+    assert_eq!(transform(&num, "12.5%"), "12.5");
+    assert_eq!(transform(&num, "up 3 % today"), "3");
+    // Plain garbage text
+    assert_eq!(transform(&num, "abc"), "");
+    assert_eq!(transform(&num, "N/A - ."), "");
+    assert_eq!(transform(&num, ""), "");
+    // The marks of the formatted text are not the numbers of the text.
+    assert_eq!(
+      transform(&num, r#"[{"attributes":{"header":2},"insert":"42.5 EUR"}]"#),
+      "42.5"
+    );
+
+    // The decimal separator of the target number format.
+    let currency = |code: &str| NumberTypeOptionPB {
+      format: NumberFormat::Currency,
+      currency_code: code.to_owned(),
+      ..Default::default()
+    };
+    let eur = currency("EUR");
+    assert_eq!(eur.decimal_separator(), ',');
+    assert_eq!(transform(&eur, "Price 1.299,50 € incl. tax"), "1 299,50 €");
+    let usd = currency("USD");
+    assert_eq!(transform(&usd, "$1,299.00 USD"), "$1,299.00");
+    assert_eq!(transform(&usd, "no price"), "");
+
+    // The fractional percent keeps the meaning of the `%`.
+    let fractional_percent = NumberTypeOptionPB {
+      format: NumberFormat::FractionalPercent,
+      ..Default::default()
+    };
+    assert_eq!(transform(&fractional_percent, "about 42 % done"), "42%");
+    assert_eq!(transform(&fractional_percent, "0.42"), "42%");
+
+    // The other field types are not transformed.
+    assert!(num
+      .transform_type_option_cell_str("12", &FieldType::Checkbox, &field_rev)
+      .is_none());
+    assert_eq!(extract_number("1'000.5", '.'), Some(Decimal::new(10005, 1)));
+  }
+
+  #[test]
+  fn number_percentile_ranks_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let cells = vec![
+      insert_number_cell(3, &field_rev),
+      insert_number_cell(1, &field_rev),
+      insert_text_cell("".to_owned(), &field_rev),
+      insert_number_cell(2, &field_rev),
+      insert_number_cell(2, &field_rev),
+    ];
+    let ranks =
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None).percentile_ranks(&cells);
+    assert_eq!(
+      ranks,
+      vec![Some(1.0), Some(0.0), None, Some(1.0 / 3.0), Some(1.0 / 3.0)]
+    );
+  }
+
+  #[test]
+  fn number_percentile_ranks_single_value_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let cells = vec![
+      insert_text_cell("".to_owned(), &field_rev),
+      insert_number_cell(42, &field_rev),
+    ];
+    let ranks =
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None).percentile_ranks(&cells);
+    assert_eq!(ranks, vec![None, Some(1.0)]);
+  }
+
+  #[test]
+  fn normalize_number_column_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let legacy_cell =
+      CellRevision::new(TypeCellData::new(" 42 ".to_owned(), FieldType::Number).to_json());
+    let cells = vec![legacy_cell, insert_number_cell(42, &field_rev)];
+
+    let normalized_cells =
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None).normalize_column(&cells);
+    let normalized_cell = normalized_cells[0].as_ref().unwrap();
+    assert_eq!(normalized_cell, &insert_number_cell(42, &field_rev));
+    assert!(normalized_cells[1].is_none());
+  }
+
+  #[test]
+  fn number_mode_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let cells = vec![
+      insert_number_cell(3, &field_rev),
+      insert_text_cell("".to_owned(), &field_rev),
+      insert_number_cell(2, &field_rev),
+      insert_text_cell("".to_owned(), &field_rev),
+      insert_number_cell(2, &field_rev),
+    ];
+    let mode = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None)
+      .mode(&cells)
+      .and_then(|cell_data| cell_data.unbox_or_none::<StrCellData>())
+      .unwrap();
+    assert_eq!(mode.as_str(), "2");
+  }
+
+  #[test]
+  fn number_describe_changeset_test() {
+    let field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default())
+      .name("Price")
+      .build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let old_cell = insert_number_cell(1, &field_rev);
+    let new_cell = insert_number_cell(2, &field_rev);
+    assert_eq!(
+      ext.describe_changeset(Some(&old_cell), &new_cell),
+      "changed Price from 1 to 2"
+    );
+    assert_eq!(ext.describe_changeset(None, &new_cell), "set Price to 2");
+  }
+
+  #[test]
+  fn number_aggregate_empty_as_zero_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cells = vec![
+      insert_number_cell(2, &field_rev),
+      insert_text_cell("".to_owned(), &field_rev),
+      insert_number_cell(4, &field_rev),
+    ];
+    assert_eq!(ext.aggregate(&cells, AggregateKind::Average), Some(3.0));
+    assert_eq!(
+      ext.aggregate_number(&cells, AggregateKind::Average, true),
+      Some(2.0)
+    );
+    assert_eq!(
+      ext.aggregate_number(&cells, AggregateKind::Sum, false),
+      Some(6.0)
+    );
+    assert_eq!(
+      ext.aggregate_number(&cells, AggregateKind::Sum, true),
+      Some(6.0)
+    );
+  }
+
+  #[test]
+  fn number_aggregate_kinds_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cells = vec![
+      insert_number_cell(3, &field_rev),
+      insert_text_cell("".to_owned(), &field_rev),
+      insert_number_cell(-1, &field_rev),
+      insert_text_cell("abc".to_owned(), &field_rev),
+      insert_number_cell(10, &field_rev),
+    ];
+    for (kind, expected) in [
+      (AggregateKind::Sum, Some(12.0)),
+      (AggregateKind::Average, Some(4.0)),
+      (AggregateKind::Min, Some(-1.0)),
+      (AggregateKind::Max, Some(10.0)),
+      (AggregateKind::CountNonEmpty, Some(3.0)),
+      (AggregateKind::CountEmpty, Some(2.0)),
+    ] {
+      assert_eq!(ext.aggregate(&cells, kind), expected, "{:?}", kind);
+    }
+
+    // The min, max and average of the empty input are undefined.
+    for (kind, expected) in [
+      (AggregateKind::Sum, Some(0.0)),
+      (AggregateKind::Average, None),
+      (AggregateKind::Min, None),
+      (AggregateKind::Max, None),
+      (AggregateKind::CountNonEmpty, Some(0.0)),
+      (AggregateKind::CountEmpty, Some(0.0)),
+    ] {
+      assert_eq!(ext.aggregate(&[], kind), expected, "{:?}", kind);
+    }
+
+    // The number kinds don't apply to the other field types.
+    let text_field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let text_cells = vec![insert_text_cell("1".to_owned(), &text_field_rev)];
+    assert_eq!(
+      TypeOptionCellExt::new_with_cell_data_cache(&text_field_rev, None)
+        .aggregate(&text_cells, AggregateKind::Max),
+      None
+    );
+  }
+
+  #[test]
+  fn number_percent_change_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cell = |num: i64| insert_number_cell(num, &field_rev);
+    let empty = insert_text_cell("".to_owned(), &field_rev);
+
+    assert_eq!(ext.percent_change(&cell(200), &cell(300)), Some(0.5));
+    assert_eq!(ext.percent_change(&cell(200), &cell(50)), Some(-0.75));
+    assert_eq!(ext.percent_change(&cell(200), &cell(200)), Some(0.0));
+    assert_eq!(ext.percent_change(&cell(0), &cell(300)), None);
+    assert_eq!(ext.percent_change(&empty, &cell(300)), None);
+    assert_eq!(ext.percent_change(&cell(200), &empty), None);
+
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let from = insert_text_cell("200".to_owned(), &field_rev);
+    let to = insert_text_cell("300".to_owned(), &field_rev);
+    assert_eq!(ext.percent_change(&from, &to), None);
+  }
+
+  #[test]
+  fn number_stats_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let mut cells = [2, 4, 4, 4, 5, 5, 7, 9]
+      .into_iter()
+      .map(|num| insert_number_cell(num, &field_rev))
+      .collect::<Vec<CellRevision>>();
+    cells.push(insert_text_cell("".to_owned(), &field_rev));
+
+    let stats = ext.number_stats(&cells);
+    assert_eq!(stats.count, 8);
+    assert_eq!(stats.empty, 1);
+    assert_eq!(stats.sum, 40.0);
+    assert_eq!(stats.min, Some(2.0));
+    assert_eq!(stats.max, Some(9.0));
+    assert_eq!(stats.mean, Some(5.0));
+    assert!((stats.stddev.unwrap() - 2.0).abs() < 1e-9);
+  }
+
+  #[test]
+  fn number_stats_all_empty_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cells = vec![
+      insert_text_cell("".to_owned(), &field_rev),
+      insert_text_cell("".to_owned(), &field_rev),
+    ];
+    assert_eq!(
+      ext.number_stats(&cells),
+      NumberStats {
+        empty: 2,
+        ..Default::default()
+      }
+    );
+    assert_eq!(ext.number_stats(&[]), NumberStats::default());
+  }
+
+  #[test]
+  fn number_diff_against_baseline_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let number_cell =
+      |s: &str| CellRevision::new(TypeCellData::new(s.to_owned(), FieldType::Number).to_json());
+    let baseline = number_cell("1,000");
+
+    // Reformatting the number doesn't change its value.
+    assert!(!ext.diff_against_baseline(&number_cell("1000"), &baseline));
+    assert!(ext.diff_against_baseline(&number_cell("1001"), &baseline));
+    assert!(ext.diff_against_baseline(&number_cell(""), &baseline));
+    assert!(!ext.diff_against_baseline(&number_cell(""), &number_cell("abc")));
+
+    let field_rev =
+      FieldBuilder::new(NumberTypeOptionBuilder::default().set_format(NumberFormat::USD)).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let baseline = number_cell("$1,000");
+    assert!(!ext.diff_against_baseline(&number_cell("1000.00"), &baseline));
+    assert!(ext.diff_against_baseline(&number_cell("$1,200"), &baseline));
+  }
+
+  #[test]
+  fn number_nulls_order_compare_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Number);
+    let sort = |nulls_order: Option<NullsOrder>| {
+      let mut cells = vec!["3", "", "1", "abc", "2"];
+      cells
+        .sort_by(|left, right| handler.handle_cell_compare(left, right, &field_rev, nulls_order));
+      cells
+    };
+
+    assert_eq!(
+      sort(Some(NullsOrder::First)),
+      vec!["", "abc", "1", "2", "3"]
+    );
+    assert_eq!(sort(Some(NullsOrder::Last)), vec!["1", "2", "3", "", "abc"]);
+    assert_eq!(
+      handler.handle_cell_compare("", "abc", &field_rev, Some(NullsOrder::Last)),
+      Ordering::Equal
+    );
+  }
+
+  #[test]
+  fn number_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Number);
+    assert_eq!(handler.comparable_kind(), ComparableKind::Number);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::Number,
+      &FieldType::Number
+    ));
+    assert!(is_transform_lossless(
+      &FieldType::Number,
+      &FieldType::RichText
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = NumberFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, NumberFilterConditionPB::GreaterThan);
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_number_cell(42, &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Number(42.0)
+    );
+    assert_eq!(cell_value(&field_rev, &cell_rev).number.unwrap(), "42");
+    // The number cells don't have a glyph.
+    assert_eq!(handler.glyph(&cell_rev, &field_rev), None);
+  }
+
+  #[test]
+  fn number_value_hash_test() {
+    let num_field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default()).build();
+    let usd_field_rev =
+      FieldBuilder::new(NumberTypeOptionBuilder::default().set_format(NumberFormat::USD)).build();
+    let value_hash = |field_rev: &FieldRevision, cell_str: &str| {
+      let cell_rev = insert_text_cell(cell_str.to_owned(), field_rev);
+      let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+      cell_data_handler(field_rev, &FieldType::Number).value_hash(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        field_rev,
+      )
+    };
+
+    let hash = value_hash(&num_field_rev, "1000");
+    assert_eq!(value_hash(&num_field_rev, "1,000"), hash);
+    assert_eq!(value_hash(&num_field_rev, "1000.00"), hash);
+    assert_eq!(value_hash(&usd_field_rev, "$1,000.00"), hash);
+    assert_eq!(value_hash(&usd_field_rev, "1000"), hash);
+    assert_ne!(value_hash(&num_field_rev, "1001"), hash);
+    assert_ne!(value_hash(&usd_field_rev, "$1,000.01"), hash);
+
+    // The hash doesn't depend on the format, unlike the cache key.
+    let num_key = public_cache_key(&num_field_rev, &FieldType::Number, "1000");
+    let usd_key = public_cache_key(&usd_field_rev, &FieldType::Number, "1000");
+    assert_ne!(num_key, usd_key);
+
+    assert_eq!(
+      value_hash(&num_field_rev, ""),
+      value_hash(&usd_field_rev, "")
+    );
+  }
+
+  #[test]
+  fn number_is_empty_filter_test() {
+    let number_filter = |condition: NumberFilterConditionPB| NumberFilterPB {
+      condition,
+      content: "".to_owned(),
+    };
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    for (cell_rev, expected) in [
+      (None, true),
+      (Some(insert_text_cell("".to_owned(), &field_rev)), true),
+      (Some(insert_number_cell(0, &field_rev)), false),
+    ] {
+      let is_empty = filter_is_empty(
+        &field_rev,
+        number_filter,
+        NumberFilterConditionPB::NumberIsEmpty,
+        NumberFilterConditionPB::NumberIsNotEmpty,
+        cell_rev.as_ref(),
+      );
+      assert_eq!(is_empty, expected);
+    }
+  }
+
+  #[test]
+  fn number_validate_cell_changeset_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Number);
+    assert!(handler.validate_cell_changeset("12.5", &field_rev).is_ok());
+    assert!(handler.validate_cell_changeset(" ", &field_rev).is_ok());
+    assert!(handler.validate_cell_changeset("abc", &field_rev).is_err());
+
+    // Validating doesn't write anything.
+    let cell_data_cache = AnyTypeCache::<u64>::new();
+    TypeOptionCellExt::new_with_cell_data_cache(&field_rev, Some(cell_data_cache.clone()))
+      .get_type_option_cell_data_handler(&FieldType::Number)
+      .unwrap()
+      .validate_cell_changeset("12", &field_rev)
+      .unwrap();
+    assert!(cell_data_cache.read().is_empty());
+  }
+
+  #[test]
+  fn derived_cell_is_stale_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let ttl = 60 * 60;
+    let last_computed = 1647251762;
+    let derived_cell = CellRevision::new(
+      TypeCellData::new("1.25".to_owned(), FieldType::Number)
+        .with_last_computed(last_computed)
+        .to_json(),
+    );
+    assert!(!ext.is_stale(&derived_cell, last_computed + 60, ttl));
+    assert!(!ext.is_stale(&derived_cell, last_computed + ttl, ttl));
+    assert!(ext.is_stale(&derived_cell, last_computed + ttl + 1, ttl));
+
+    // The cell that isn't derived never expires.
+    let cell = insert_number_cell(1, &field_rev);
+    assert!(!ext.is_stale(&cell, last_computed + ttl + 1, ttl));
+  }
+
+  #[test]
+  fn number_required_field_satisfied_test() {
+    let field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default())
+      .required(true)
+      .build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Number);
+    let number_cell = insert_number_cell(1, &field_rev);
+    let empty_number_cell = insert_text_cell("".to_owned(), &field_rev);
+    assert!(handler.satisfies_required(Some(&number_cell), &field_rev));
+    assert!(!handler.satisfies_required(Some(&empty_number_cell), &field_rev));
+    assert!(!handler.satisfies_required(None, &field_rev));
+  }
+
+  #[test]
+  fn optional_field_satisfied_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Number);
+    let empty_cell = insert_text_cell("".to_owned(), &field_rev);
+    assert!(handler.satisfies_required(Some(&empty_cell), &field_rev));
+    assert!(handler.satisfies_required(None, &field_rev));
+  }
+
+  #[test]
+  fn number_next_in_series_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Number);
+    for (nums, expected) in [
+      (vec![1, 2, 3], Some("4")),
+      (vec![10, 7], Some("4")),
+      (vec![1, 2, 4], None),
+      (vec![1], None),
+    ] {
+      let cell_revs = nums
+        .iter()
+        .map(|num| insert_number_cell(*num, &field_rev))
+        .collect::<Vec<CellRevision>>();
+      let next = handler
+        .next_in_series(&cell_revs, &field_rev)
+        .map(|cell_rev| TypeCellData::try_from(&cell_rev).unwrap().cell_str);
+      assert_eq!(next.as_deref(), expected, "{:?}", nums);
+    }
+  }
 }
@@ -1,22 +1,28 @@
-use crate::entities::{FieldType, NumberFilterPB};
+use crate::entities::{CellValuePB, FieldType, NumberFilterConditionPB, NumberFilterPB};
 use crate::impl_type_option;
-use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+use crate::services::cell::{
+  invalid_json_value_error, series_step, CellDataChangeset, CellDataDecoder, TypeCellData,
+};
 use crate::services::field::type_options::number_type_option::format::*;
 use crate::services::field::{
-  BoxTypeOptionBuilder, NumberCellData, StrCellData, TypeOption, TypeOptionBuilder,
-  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+  plain_text, BoxTypeOptionBuilder, ComparableKind, EmptyFilterCondition, NumberCellData,
+  SpreadsheetValue, StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use fancy_regex::Regex;
-use flowy_derive::ProtoBuf;
-use flowy_error::FlowyResult;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use lazy_static::lazy_static;
-use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use rusty_money::Locale;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::default::Default;
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 
 #[derive(Default)]
 pub struct NumberTypeOptionBuilder(NumberTypeOptionPB);
@@ -43,6 +49,34 @@ impl NumberTypeOptionBuilder {
     self.0.sign_positive = positive;
     self
   }
+
+  pub fn compare_in_base_currency(mut self, compare_in_base_currency: bool) -> Self {
+    self.0.compare_in_base_currency = compare_in_base_currency;
+    self
+  }
+
+  pub fn currency_code(mut self, currency_code: &str) -> Self {
+    self.0.currency_code = currency_code.to_owned();
+    self
+  }
+
+  pub fn decimal_places(mut self, decimal_places: u32) -> Self {
+    self.0.decimal_places = Some(decimal_places as i32);
+    self
+  }
+
+  pub fn percent_input_mode(mut self, percent_input_mode: PercentInputMode) -> Self {
+    self.0.percent_input_mode = percent_input_mode;
+    self
+  }
+
+  pub fn exchange_rate(mut self, currency_code: &str, rate: &str) -> Self {
+    self.0.exchange_rates.push(ExchangeRatePB {
+      currency_code: currency_code.to_owned(),
+      rate: rate.to_owned(),
+    });
+    self
+  }
 }
 
 impl TypeOptionBuilder for NumberTypeOptionBuilder {
@@ -72,9 +106,60 @@ pub struct NumberTypeOptionPB {
 
   #[pb(index = 5)]
   pub name: String,
+
+  /// If true, the cells that carry their own currency are compared after being converted into
+  /// the field's currency with the `exchange_rates`. Otherwise, the raw amounts are compared.
+  #[pb(index = 6)]
+  #[serde(default)]
+  pub compare_in_base_currency: bool,
+
+  #[pb(index = 7)]
+  #[serde(default)]
+  pub exchange_rates: Vec<ExchangeRatePB>,
+
+  /// The ISO 4217 code of the [NumberFormat::Currency] format, for example, `USD`.
+  #[pb(index = 8)]
+  #[serde(default)]
+  pub currency_code: String,
+
+  #[pb(index = 9)]
+  #[serde(default)]
+  pub percent_input_mode: PercentInputMode,
+
+  /// The number of decimal places that the numbers are displayed with. The numbers are displayed
+  /// as they are stored if it's None. A cell can override it with its own `precision`.
+  #[pb(index = 10, one_of)]
+  #[serde(default)]
+  pub decimal_places: Option<i32>,
 }
 impl_type_option!(NumberTypeOptionPB, FieldType::Number);
 
+/// Decides how the [NumberFormat::FractionalPercent] field reads the input that doesn't end with
+/// `%`. The input that ends with `%` is always divided by 100.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum PercentInputMode {
+  /// `0.42` is already the fraction, so it's displayed as `42%`.
+  Fraction = 0,
+  /// `42` is the percent, so it's stored as `0.42`.
+  Percent = 1,
+}
+
+impl std::default::Default for PercentInputMode {
+  fn default() -> Self {
+    PercentInputMode::Fraction
+  }
+}
+
+/// The `rate` is the amount of the field's currency that one unit of the `currency_code` is worth.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct ExchangeRatePB {
+  #[pb(index = 1)]
+  pub currency_code: String,
+
+  #[pb(index = 2)]
+  pub rate: String,
+}
+
 impl TypeOption for NumberTypeOptionPB {
   type CellData = StrCellData;
   type CellChangeset = NumberCellChangeset;
@@ -96,6 +181,36 @@ impl TypeOptionCellData for NumberTypeOptionPB {
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     Ok(cell_str.into())
   }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    match self.format_cell_data(cell_data) {
+      Ok(cell_data) => cell_data.is_empty(),
+      Err(_) => true,
+    }
+  }
+
+  fn normalize_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> FlowyResult<String> {
+    let (cell_str, _) = self.apply_changeset(cell_data.0, None)?;
+    Ok(cell_str)
+  }
+
+  /// The key is the decimal without the trailing zeros, for example, `$1,000.00` and `1000` have
+  /// the same key.
+  fn value_key(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    self
+      .format_cell_data(&cell_data)
+      .ok()
+      .and_then(|number_cell_data| *number_cell_data.decimal())
+      .map(|decimal| decimal.normalize().to_string())
+      .unwrap_or_default()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      number: Some(self.convert_to_protobuf(cell_data).0),
+      ..Default::default()
+    }
+  }
 }
 
 impl NumberTypeOptionPB {
@@ -105,7 +220,7 @@ impl NumberTypeOptionPB {
 
   pub(crate) fn format_cell_data(&self, s: &str) -> FlowyResult<NumberCellData> {
     match self.format {
-      NumberFormat::Num => {
+      NumberFormat::Num | NumberFormat::Ordinal => {
         if SCIENTIFIC_NOTATION_REGEX.is_match(s).unwrap() {
           match Decimal::from_scientific(&s.to_lowercase()) {
             Ok(value, ..) => Ok(NumberCellData::from_decimal(value)),
@@ -129,7 +244,120 @@ impl NumberTypeOptionPB {
           }
         }
       },
-      _ => NumberCellData::from_format_str(s, self.sign_positive, &self.format),
+      NumberFormat::Currency => {
+        let style = CurrencyStyle::from_currency_code(&self.currency_code);
+        match style.parse(s) {
+          Some(mut decimal) => {
+            decimal.set_sign_positive(self.sign_positive);
+            Ok(NumberCellData::from_display_str(
+              decimal,
+              style.format(decimal),
+            ))
+          },
+          None => Ok(NumberCellData::new()),
+        }
+      },
+      NumberFormat::FractionalPercent => {
+        let s = s.trim();
+        let decimal = match s.strip_suffix('%') {
+          Some(percent) => Decimal::from_str(percent.trim())
+            .ok()
+            .map(|percent| percent / Decimal::ONE_HUNDRED),
+          None => Decimal::from_str(s).ok(),
+        };
+        match decimal {
+          Some(mut decimal) => {
+            decimal.set_sign_positive(self.sign_positive);
+            let percent = (decimal * Decimal::ONE_HUNDRED).normalize();
+            Ok(NumberCellData::from_display_str(
+              decimal,
+              format!("{}%", percent),
+            ))
+          },
+          None => Ok(NumberCellData::new()),
+        }
+      },
+      _ => {
+        let (format, s) = self.cell_currency(s);
+        NumberCellData::from_format_str(s, self.sign_positive, &format)
+      },
+    }
+  }
+
+  /// A cell of the currency field can override the field's currency. The cell string carries the
+  /// ISO code as the prefix, for example, `EUR 12.5`, and the decoded string carries the currency
+  /// symbol, for example, `€12,5`. Returns the currency of the cell and the rest of the string.
+  fn cell_currency<'a>(&self, s: &'a str) -> (NumberFormat, &'a str) {
+    if !self.format.is_currency() {
+      return (self.format, s);
+    }
+
+    if let Some((code, amount)) = s.split_once(char::is_whitespace) {
+      if let Some(format) = NumberFormat::from_currency_code(code) {
+        return (format, amount.trim_start());
+      }
+    }
+
+    // Prefer the longest symbol, so `CA$` wins over `$`. The field's own currency comes first, so
+    // it wins on a tie.
+    let format = std::iter::once(self.format)
+      .chain(NumberFormat::iter())
+      .filter(|format| format.is_currency() && format.currency().symbol_first)
+      .filter(|format| s.starts_with(format.currency().symbol))
+      .reduce(|longest, format| {
+        if format.currency().symbol.len() > longest.currency().symbol.len() {
+          format
+        } else {
+          longest
+        }
+      })
+      .unwrap_or(self.format);
+    (format, s)
+  }
+
+  /// Returns the amount of the cell used to compare the cells. The amount is converted into the
+  /// field's currency if the cell carries its own currency and `compare_in_base_currency` is on.
+  /// The raw amount is used if there is no exchange rate for the cell's currency.
+  fn cell_amount(&self, s: &str) -> Option<Decimal> {
+    let amount = (*self.format_cell_data(s).ok()?.decimal())?;
+    let (format, _) = self.cell_currency(s);
+    if !self.compare_in_base_currency || format == self.format {
+      return Some(amount);
+    }
+
+    match self.exchange_rate(&format) {
+      Some(rate) => Some(amount * rate),
+      None => Some(amount),
+    }
+  }
+
+  fn exchange_rate(&self, format: &NumberFormat) -> Option<Decimal> {
+    let code = format.currency_code()?;
+    self
+      .exchange_rates
+      .iter()
+      .find(|exchange_rate| exchange_rate.currency_code.eq_ignore_ascii_case(code))
+      .and_then(|exchange_rate| Decimal::from_str(exchange_rate.rate.trim()).ok())
+  }
+
+  /// Returns the display string of the number. The number is rounded to the `decimal_places` and
+  /// padded with zeros if it's not None.
+  fn stringify_number(&self, s: &str, decimal_places: Option<u32>) -> String {
+    let number_cell_data = match self.format_cell_data(s) {
+      Ok(number_cell_data) => number_cell_data,
+      Err(_) => return "".to_string(),
+    };
+    match (decimal_places, number_cell_data.decimal()) {
+      (Some(decimal_places), Some(decimal)) => {
+        let mut rounded =
+          decimal.round_dp_with_strategy(decimal_places, RoundingStrategy::MidpointAwayFromZero);
+        rounded.rescale(decimal_places);
+        self.stringify_number(&rounded.to_string(), None)
+      },
+      _ => match self.format {
+        NumberFormat::Ordinal => number_cell_data.to_ordinal_string(),
+        _ => number_cell_data.to_string(),
+      },
     }
   }
 
@@ -137,6 +365,66 @@ impl NumberTypeOptionPB {
     self.format = format;
     self.symbol = format.symbol();
   }
+
+  /// Returns the decimal separator of the number format, for example, `,` for the EUR.
+  pub fn decimal_separator(&self) -> char {
+    match self.format {
+      NumberFormat::Num
+      | NumberFormat::Ordinal
+      | NumberFormat::Percent
+      | NumberFormat::FractionalPercent => '.',
+      NumberFormat::Currency => {
+        CurrencyStyle::from_currency_code(&self.currency_code).decimal_separator
+      },
+      format => match format.currency().locale {
+        Locale::EnEu | Locale::EnBy => ',',
+        _ => '.',
+      },
+    }
+  }
+}
+
+/// Extracts the first number embedded in the text, for example, `1299.00` of `$1,299.00 USD`.
+/// The `decimal_separator` comes from the number format. The other one of `.` and `,`, the spaces
+/// and the apostrophes between the digits are treated as the group separators. The `-` right
+/// before the digits makes the number negative. Returns None if the text doesn't contain a digit.
+pub fn extract_number(s: &str, decimal_separator: char) -> Option<Decimal> {
+  extract_number_with_rest(s, decimal_separator).map(|(decimal, _)| decimal)
+}
+
+/// Same as [extract_number] but also returns the text after the number.
+fn extract_number_with_rest(s: &str, decimal_separator: char) -> Option<(Decimal, String)> {
+  let chars = s.chars().collect::<Vec<char>>();
+  let start = chars.iter().position(|c| c.is_ascii_digit())?;
+  let mut num_str = String::new();
+  if start > 0 && chars[start - 1] == '-' {
+    num_str.push('-');
+  }
+
+  let mut has_decimal_separator = false;
+  let mut end = chars.len();
+  for (i, c) in chars.iter().enumerate().skip(start) {
+    let next_is_digit = chars
+      .get(i + 1)
+      .map(|next| next.is_ascii_digit())
+      .unwrap_or(false);
+    if c.is_ascii_digit() {
+      num_str.push(*c);
+    } else if *c == decimal_separator && !has_decimal_separator && next_is_digit {
+      has_decimal_separator = true;
+      num_str.push('.');
+    } else if matches!(c, '.' | ',' | ' ' | '\'' | '\u{a0}')
+      && !has_decimal_separator
+      && next_is_digit
+    {
+      continue;
+    } else {
+      end = i;
+      break;
+    }
+  }
+  let decimal = Decimal::from_str(&num_str).ok()?;
+  Some((decimal, chars[end..].iter().collect()))
 }
 
 pub(crate) fn strip_currency_symbol<T: ToString>(s: T) -> String {
@@ -150,7 +438,40 @@ pub(crate) fn strip_currency_symbol<T: ToString>(s: T) -> String {
   s
 }
 
-impl TypeOptionTransform for NumberTypeOptionPB {}
+impl TypeOptionTransform for NumberTypeOptionPB {
+  fn transformable(&self) -> bool {
+    true
+  }
+
+  /// Salvages the number embedded in the text cell, for example, `$1,299.00 USD` becomes
+  /// `1299.00`. The cell is empty if the text doesn't contain a number. The `%` that follows the
+  /// number is kept for the [NumberFormat::FractionalPercent], which divides it by 100.
+  fn transform_type_option_cell_str(
+    &self,
+    cell_str: &str,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> Option<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_text() {
+      return None;
+    }
+
+    let text = plain_text(cell_str);
+    let (decimal, rest) = match extract_number_with_rest(&text, self.decimal_separator()) {
+      None => return Some(StrCellData::default()),
+      Some(number) => number,
+    };
+    let number_str =
+      if self.format == NumberFormat::FractionalPercent && rest.trim_start().starts_with('%') {
+        format!("{}%", decimal)
+      } else {
+        decimal.to_string()
+      };
+    self
+      .decode_cell_str(number_str, &FieldType::Number, field_rev)
+      .ok()
+  }
+}
 
 impl CellDataDecoder for NumberTypeOptionPB {
   fn decode_cell_str(
@@ -169,32 +490,141 @@ impl CellDataDecoder for NumberTypeOptionPB {
   }
 
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
-    match self.format_cell_data(&cell_data) {
-      Ok(cell_data) => cell_data.to_string(),
-      Err(_) => "".to_string(),
+    let decimal_places = self
+      .decimal_places
+      .and_then(|decimal_places| u32::try_from(decimal_places).ok());
+    self.stringify_number(&cell_data, decimal_places)
+  }
+
+  fn decode_cell_data_to_str_with_precision(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+    precision: u32,
+  ) -> String {
+    self.stringify_number(&cell_data, Some(precision))
+  }
+
+  fn decode_cell_data_to_spreadsheet_value(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> SpreadsheetValue {
+    let number = self
+      .format_cell_data(&cell_data)
+      .ok()
+      .and_then(|number_cell_data| number_cell_data.decimal().and_then(|d| d.to_f64()));
+    match number {
+      None => SpreadsheetValue::Text("".to_owned()),
+      Some(number) => SpreadsheetValue::Number(number),
     }
   }
 }
 
 pub type NumberCellChangeset = String;
 
+impl NumberTypeOptionPB {
+  /// Appends the `%` to the number typed in the percent input mode of the
+  /// [NumberFormat::FractionalPercent].
+  fn normalize_changeset(&self, changeset: &str) -> String {
+    let data = changeset.trim().to_string();
+    match self.format {
+      NumberFormat::FractionalPercent
+        if self.percent_input_mode == PercentInputMode::Percent
+          && !data.is_empty()
+          && !data.ends_with('%') =>
+      {
+        format!("{}%", data)
+      },
+      _ => data,
+    }
+  }
+}
+
 impl CellDataChangeset for NumberTypeOptionPB {
   fn apply_changeset(
     &self,
     changeset: <Self as TypeOption>::CellChangeset,
     _type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let data = changeset.trim().to_string();
+    let data = self.normalize_changeset(&changeset);
     let number_cell_data = self.format_cell_data(&data)?;
 
     match self.format {
-      NumberFormat::Num => Ok((
+      NumberFormat::Num | NumberFormat::Ordinal => Ok((
         number_cell_data.to_string(),
         number_cell_data.to_string().into(),
       )),
+      // Stores the raw amount, so the cell keeps its value if the currency code is changed.
+      NumberFormat::Currency => Ok((
+        number_cell_data
+          .decimal()
+          .map(|decimal| decimal.to_string())
+          .unwrap_or_default(),
+        number_cell_data.to_string().into(),
+      )),
+      // Stores the fraction, for example, `42%` is stored as `0.42`.
+      NumberFormat::FractionalPercent => Ok((
+        number_cell_data
+          .decimal()
+          .map(|decimal| decimal.normalize().to_string())
+          .unwrap_or_default(),
+        number_cell_data.to_string().into(),
+      )),
       _ => Ok((data, number_cell_data.to_string().into())),
     }
   }
+
+  /// Rejects the text that doesn't contain any number, which is stored as an empty cell otherwise.
+  fn validate_changeset(&self, changeset: &<Self as TypeOption>::CellChangeset) -> FlowyResult<()> {
+    let data = self.normalize_changeset(changeset);
+    if data.is_empty() || !self.format_cell_data(&data)?.is_empty() {
+      return Ok(());
+    }
+    let msg = format!("{} is not a number", changeset.trim());
+    Err(FlowyError::new(ErrorCode::InvalidData, &msg))
+  }
+
+  /// Accepts the JSON number or the numeric string, for example, `"$1,200.50"` for the USD
+  /// field. The string that doesn't contain any number is rejected.
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    match value {
+      serde_json::Value::Null => Ok("".to_owned()),
+      serde_json::Value::Number(n) => Ok(n.to_string()),
+      serde_json::Value::String(s) if s.trim().is_empty() => Ok("".to_owned()),
+      serde_json::Value::String(s) => match self.format_cell_data(s.trim()) {
+        Ok(cell_data) if !cell_data.is_empty() => Ok(s.trim().to_owned()),
+        _ => Err(invalid_json_value_error(
+          value,
+          "a number or a numeric string",
+        )),
+      },
+      _ => Err(invalid_json_value_error(
+        value,
+        "a number or a numeric string",
+      )),
+    }
+  }
+
+  /// Extends the arithmetic sequence of the numbers, for example, `1.5` after `0.5, 1`.
+  fn next_in_series(
+    &self,
+    cells: &[<Self as TypeOption>::CellData],
+  ) -> Option<<Self as TypeOption>::CellChangeset> {
+    let decimals = cells
+      .iter()
+      .map(|cell_data| *self.format_cell_data(cell_data).ok()?.decimal())
+      .collect::<Option<Vec<Decimal>>>()?;
+    let next = decimals.last()?.checked_add(series_step(&decimals)?)?;
+    match self.format {
+      // The changeset is read as the percent if it ends with `%`, whatever the input mode is.
+      NumberFormat::FractionalPercent => {
+        Some(format!("{}%", (next * Decimal::ONE_HUNDRED).normalize()))
+      },
+      _ => Some(next.to_string()),
+    }
+  }
 }
 
 impl TypeOptionCellDataFilter for NumberTypeOptionPB {
@@ -212,6 +642,17 @@ impl TypeOptionCellDataFilter for NumberTypeOptionPB {
       Err(_) => true,
     }
   }
+
+  fn default_filter_condition(&self) -> u8 {
+    NumberFilterConditionPB::GreaterThan as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
 }
 
 impl TypeOptionCellDataCompare for NumberTypeOptionPB {
@@ -220,8 +661,20 @@ impl TypeOptionCellDataCompare for NumberTypeOptionPB {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering {
+    if self.format.is_currency()
+      || self.format == NumberFormat::Currency
+      || self.format == NumberFormat::FractionalPercent
+    {
+      return self
+        .cell_amount(cell_data)
+        .cmp(&self.cell_amount(other_cell_data));
+    }
     cell_data.0.cmp(&other_cell_data.0)
   }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::Number
+  }
 }
 impl std::default::Default for NumberTypeOptionPB {
   fn default() -> Self {
@@ -233,6 +686,11 @@ impl std::default::Default for NumberTypeOptionPB {
       symbol,
       sign_positive: true,
       name: "Number".to_string(),
+      compare_in_base_currency: false,
+      exchange_rates: vec![],
+      currency_code: "".to_string(),
+      percent_input_mode: PercentInputMode::default(),
+      decimal_places: None,
     }
   }
 }
@@ -3,6 +3,7 @@ use crate::services::field::number_currency::Currency;
 use crate::services::field::{strip_currency_symbol, NumberFormat, STRIP_SYMBOL};
 use bytes::Bytes;
 use flowy_error::FlowyResult;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rusty_money::Money;
 use std::str::FromStr;
@@ -62,6 +63,15 @@ impl NumberCellData {
     }
   }
 
+  /// Creates the cell data that is displayed as the given string, for example, the amount
+  /// rendered by the `CurrencyStyle`.
+  pub fn from_display_str(decimal: Decimal, display: String) -> Self {
+    Self {
+      decimal: Some(decimal),
+      money: Some(display),
+    }
+  }
+
   pub fn decimal(&self) -> &Option<Decimal> {
     &self.decimal
   }
@@ -69,6 +79,29 @@ impl NumberCellData {
   pub fn is_empty(&self) -> bool {
     self.decimal.is_none()
   }
+
+  /// Returns the ordinal string of the number, for example, 1st, 2nd, 3rd, 11th. If the number
+  /// is not an integer, returns the plain number string.
+  pub fn to_ordinal_string(&self) -> String {
+    match self.decimal {
+      Some(decimal) if decimal.fract().is_zero() => match decimal.to_i64() {
+        Some(num) => format!("{}{}", num, ordinal_suffix(num)),
+        None => decimal.to_string(),
+      },
+      _ => self.to_string(),
+    }
+  }
+}
+
+fn ordinal_suffix(num: i64) -> &'static str {
+  let num = num.unsigned_abs();
+  match (num % 10, num % 100) {
+    (_, 11..=13) => "th",
+    (1, _) => "st",
+    (2, _) => "nd",
+    (3, _) => "rd",
+    _ => "th",
+  }
 }
 
 // impl FromStr for NumberCellData {
@@ -0,0 +1,5 @@
+#![allow(clippy::module_inception)]
+mod order_tests;
+mod order_type_option;
+
+pub use order_type_option::*;
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+  use crate::services::cell::{insert_text_cell, CellDataChangeset, CellDataDecoder};
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, spreadsheet_value,
+  };
+  use crate::services::field::{
+    index_between, is_transform_lossless, ComparableKind, FieldBuilder, OrderTypeOptionPB,
+    SpreadsheetValue, TypeOptionCellDataCompare,
+  };
+  use std::cmp::Ordering;
+
+  #[test]
+  fn order_index_between_test() {
+    assert_eq!(index_between(None, None).unwrap(), "V");
+    assert_eq!(index_between(Some("a"), Some("b")).unwrap(), "aV");
+    assert_eq!(index_between(Some("a"), Some("aV")).unwrap(), "aG");
+    assert_eq!(index_between(Some("aV"), Some("b")).unwrap(), "al");
+    assert_eq!(index_between(None, Some("1")).unwrap(), "0V");
+    assert_eq!(index_between(Some("z"), None).unwrap(), "zV");
+
+    assert!(index_between(Some("b"), Some("a")).is_err());
+    assert!(index_between(Some("a"), Some("a")).is_err());
+    assert!(index_between(Some("a0"), None).is_err());
+    assert!(index_between(Some("a-"), None).is_err());
+  }
+
+  /// Keep inserting the row right after the first row. Each new key fits between the two
+  /// adjacent keys, so the keys of the other rows never change.
+  #[test]
+  fn order_insert_between_adjacent_keys_test() {
+    let first = "a0V".to_owned();
+    let mut next = "a0W".to_owned();
+    for _ in 0..100 {
+      let key = index_between(Some(&first), Some(&next)).unwrap();
+      assert!(first < key && key < next, "{} {} {}", first, key, next);
+      next = key;
+    }
+
+    let mut prev = first;
+    for _ in 0..100 {
+      let key = index_between(Some(&prev), None).unwrap();
+      assert!(prev < key);
+      prev = key;
+    }
+  }
+
+  #[test]
+  fn order_cell_cmp_test() {
+    let type_option = OrderTypeOptionPB::default();
+    let field_type = FieldType::Order;
+    let field_rev = FieldBuilder::from_field_type(&field_type).build();
+    let decode = |key: &str| {
+      let (cell_str, _) = type_option.apply_changeset(key.to_owned(), None).unwrap();
+      type_option
+        .decode_cell_str(cell_str, &field_type, &field_rev)
+        .unwrap()
+    };
+
+    let key = index_between(Some("a"), Some("b")).unwrap();
+    assert_eq!(
+      type_option.apply_cmp(&decode("a"), &decode(&key)),
+      Ordering::Less
+    );
+    assert_eq!(
+      type_option.apply_cmp(&decode(&key), &decode("b")),
+      Ordering::Less
+    );
+    assert!(type_option.apply_changeset("a0".to_owned(), None).is_err());
+  }
+
+  #[test]
+  fn order_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Order).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Order);
+    assert_eq!(handler.comparable_kind(), ComparableKind::String);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(&FieldType::Order, &FieldType::Order));
+
+    let filter = default_filter(&field_rev);
+    let filter = TextFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, TextFilterConditionPB::Contains);
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_text_cell("".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("".to_owned())
+    );
+    assert!(cell_value(&field_rev, &cell_rev).text.is_some());
+  }
+}
@@ -0,0 +1,229 @@
+use crate::entities::{CellValuePB, FieldType, TextFilterConditionPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  BoxTypeOptionBuilder, ComparableKind, CompiledTextFilter, EmptyFilterCondition, StrCellData,
+  TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// The digits of the order key in ascending order, so the keys can be compared as strings.
+const ORDER_KEY_DIGITS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+#[derive(Default)]
+pub struct OrderTypeOptionBuilder(OrderTypeOptionPB);
+impl_into_box_type_option_builder!(OrderTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(OrderTypeOptionBuilder, OrderTypeOptionPB);
+
+impl TypeOptionBuilder for OrderTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Order
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The order field keeps the manual order of the rows, for example, after dragging a row within
+/// a group. Each cell stores a fractional index, a base62 string that is compared
+/// lexicographically. Use [index_between] to get the key of the row that is inserted between
+/// two rows, which never requires changing the keys of the other rows.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct OrderTypeOptionPB {
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub name: String,
+}
+impl_type_option!(OrderTypeOptionPB, FieldType::Order);
+
+impl TypeOption for OrderTypeOptionPB {
+  type CellData = StrCellData;
+  type CellChangeset = String;
+  type CellProtobufType = StrCellData;
+  type CellFilter = CompiledTextFilter;
+}
+
+impl TypeOptionTransform for OrderTypeOptionPB {}
+
+impl TypeOptionCellData for OrderTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    cell_data
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    StrCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.is_empty()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      text: Some(cell_data.0),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for OrderTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_order() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+}
+
+impl CellDataChangeset for OrderTypeOptionPB {
+  /// The changeset is the order key of the cell. The empty changeset clears the key.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let key = changeset.trim().to_owned();
+    if !key.is_empty() {
+      validate_order_key(&key)?;
+    }
+    Ok((key.clone(), StrCellData(key)))
+  }
+}
+
+impl TypeOptionCellDataFilter for OrderTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_order() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    TextFilterConditionPB::Contains as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for OrderTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data.0.cmp(&other_cell_data.0)
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::String
+  }
+}
+
+/// Returns the order key that sorts between `prev` and `next`. Pass None as `prev` to insert at
+/// the beginning, and None as `next` to insert at the end. Returns an error if the keys are
+/// invalid or `prev` doesn't sort before `next`.
+pub fn index_between(prev: Option<&str>, next: Option<&str>) -> FlowyResult<String> {
+  let prev = prev.unwrap_or("");
+  if !prev.is_empty() {
+    validate_order_key(prev)?;
+  }
+  if let Some(next) = next {
+    validate_order_key(next)?;
+    if prev >= next {
+      let msg = format!("The order key {} should be less than {}", prev, next);
+      return Err(FlowyError::new(ErrorCode::InvalidData, &msg));
+    }
+  }
+  Ok(midpoint(prev, next))
+}
+
+/// The key is a fraction whose digits come after the radix point, so the trailing zero is not
+/// allowed, otherwise there would be two keys for the same position.
+fn validate_order_key(key: &str) -> FlowyResult<()> {
+  if key.is_empty() || key.ends_with('0') || !key.chars().all(|c| ORDER_KEY_DIGITS.contains(c)) {
+    let msg = format!("Invalid order key: {}", key);
+    return Err(FlowyError::new(ErrorCode::InvalidData, &msg));
+  }
+  Ok(())
+}
+
+/// Returns the shortest key between `a` and `b`. `a` is less than `b` and the empty `a` means
+/// the lower bound.
+fn midpoint(a: &str, b: Option<&str>) -> String {
+  let digits = ORDER_KEY_DIGITS.as_bytes();
+  if let Some(b) = b {
+    // Keep the common prefix, the missing digit of `a` is zero.
+    let n = b
+      .bytes()
+      .zip(a.bytes().chain(std::iter::repeat(b'0')))
+      .take_while(|(b_digit, a_digit)| b_digit == a_digit)
+      .count();
+    if n > 0 {
+      return format!(
+        "{}{}",
+        &b[..n],
+        midpoint(&a[a.len().min(n)..], Some(&b[n..]))
+      );
+    }
+  }
+
+  let digit_a = a.bytes().next().map(digit_index).unwrap_or(0);
+  let digit_b = b
+    .and_then(|b| b.bytes().next())
+    .map(digit_index)
+    .unwrap_or(digits.len());
+  if digit_b - digit_a > 1 {
+    let mid = (digit_a + digit_b + 1) / 2;
+    return (digits[mid] as char).to_string();
+  }
+
+  match b {
+    Some(b) if b.len() > 1 => b[..1].to_owned(),
+    _ => format!(
+      "{}{}",
+      digits[digit_a] as char,
+      midpoint(a.get(1..).unwrap_or(""), None)
+    ),
+  }
+}
+
+fn digit_index(digit: u8) -> usize {
+  ORDER_KEY_DIGITS
+    .bytes()
+    .position(|c| c == digit)
+    .unwrap_or(0)
+}
@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod phone_filter;
+mod phone_tests;
+mod phone_type_option;
+
+pub use phone_type_option::*;
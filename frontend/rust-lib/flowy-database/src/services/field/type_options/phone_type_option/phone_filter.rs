@@ -0,0 +1,34 @@
+use crate::entities::{PhoneFilterConditionPB, PhoneFilterPB};
+use crate::services::field::{EmptyFilterCondition, PhoneCellData};
+
+impl PhoneFilterPB {
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      PhoneFilterConditionPB::PhoneIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      PhoneFilterConditionPB::PhoneIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
+  /// The `Contains` and `StartsWith` conditions only look at the digits, both of the content and
+  /// of the number, so the `+` and the separators don't matter. The empty cell doesn't match.
+  pub fn is_visible(&self, cell_data: &PhoneCellData) -> bool {
+    let digits = match self.condition {
+      PhoneFilterConditionPB::PhoneIsEmpty => return cell_data.0.is_none(),
+      PhoneFilterConditionPB::PhoneIsNotEmpty => return cell_data.0.is_some(),
+      _ => match cell_data.digits() {
+        None => return false,
+        Some(digits) => digits,
+      },
+    };
+    let content = self
+      .content
+      .chars()
+      .filter(|c| c.is_ascii_digit())
+      .collect::<String>();
+    match self.condition {
+      PhoneFilterConditionPB::PhoneStartsWith => digits.starts_with(&content),
+      _ => digits.contains(&content),
+    }
+  }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, PhoneFilterConditionPB, PhoneFilterPB};
+  use crate::services::cell::{insert_text_cell, CellDataChangeset, CellDataDecoder};
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, ComparableKind, FieldBuilder, PhoneCellData, PhoneTypeOptionPB,
+    SpreadsheetValue, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+  use std::cmp::Ordering;
+
+  fn phone_cell_data(s: &str) -> PhoneCellData {
+    PhoneCellData(Some(s.to_owned()))
+  }
+
+  #[test]
+  fn phone_changeset_normalize_test() {
+    let type_option = PhoneTypeOptionPB::default();
+    for (changeset, expected) in [
+      ("555-123-4567", "5551234567"),
+      ("(555) 123 4567", "5551234567"),
+      ("+1 (555) 123-4567", "+15551234567"),
+      (" +44 20 7946 0958 ", "+442079460958"),
+      ("", ""),
+      ("  ", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected, "{}", changeset);
+    }
+
+    for changeset in ["555.123.4567", "call me", "1+555", "+", "555-CALL"] {
+      let changeset = changeset.to_owned();
+      assert!(
+        type_option.validate_changeset(&changeset).is_err(),
+        "{}",
+        changeset
+      );
+      assert!(
+        type_option
+          .apply_changeset(changeset.clone(), None)
+          .is_err(),
+        "{}",
+        changeset
+      );
+    }
+  }
+
+  #[test]
+  fn phone_differently_formatted_numbers_are_equal_test() {
+    let type_option = PhoneTypeOptionPB::default();
+    let (_, left) = type_option
+      .apply_changeset("(555) 123-4567".to_owned(), None)
+      .unwrap();
+    let (_, right) = type_option
+      .apply_changeset("555 123 4567".to_owned(), None)
+      .unwrap();
+    assert_eq!(left, right);
+    assert_eq!(type_option.apply_cmp(&left, &right), Ordering::Equal);
+
+    let mut cells = vec![
+      phone_cell_data("5559876543"),
+      PhoneCellData(None),
+      phone_cell_data("5551234567"),
+    ];
+    cells.sort_by(|left, right| type_option.apply_cmp(left, right));
+    assert_eq!(
+      cells,
+      vec![
+        PhoneCellData(None),
+        phone_cell_data("5551234567"),
+        phone_cell_data("5559876543"),
+      ]
+    );
+  }
+
+  #[test]
+  fn phone_display_format_test() {
+    let type_option = PhoneTypeOptionPB {
+      display_format: "(###) ###-####".to_owned(),
+    };
+    assert_eq!(
+      type_option.decode_cell_data_to_str(phone_cell_data("5551234567")),
+      "(555) 123-4567"
+    );
+    // The number that doesn't fit the format is displayed as it's stored.
+    assert_eq!(
+      type_option.decode_cell_data_to_str(phone_cell_data("+15551234567")),
+      "+15551234567"
+    );
+    assert_eq!(type_option.decode_cell_data_to_str(PhoneCellData(None)), "");
+
+    let type_option = PhoneTypeOptionPB {
+      display_format: "+# (###) ###-####".to_owned(),
+    };
+    assert_eq!(
+      type_option.decode_cell_data_to_str(phone_cell_data("+15551234567")),
+      "+1 (555) 123-4567"
+    );
+
+    let type_option = PhoneTypeOptionPB::default();
+    assert_eq!(
+      type_option.decode_cell_data_to_str(phone_cell_data("+15551234567")),
+      "+15551234567"
+    );
+  }
+
+  #[test]
+  fn phone_filter_test() {
+    let type_option = PhoneTypeOptionPB::default();
+    let filter = |condition: PhoneFilterConditionPB, content: &str| PhoneFilterPB {
+      condition,
+      content: content.to_owned(),
+    };
+
+    let cell_data = phone_cell_data("+15551234567");
+    for content in ["(555) 123", "555123", "555-123"] {
+      let contains = filter(PhoneFilterConditionPB::PhoneContains, content);
+      assert!(
+        type_option.apply_filter(&contains, &FieldType::Phone, &cell_data),
+        "{}",
+        content
+      );
+    }
+    let contains = filter(PhoneFilterConditionPB::PhoneContains, "999");
+    assert!(!type_option.apply_filter(&contains, &FieldType::Phone, &cell_data));
+    assert!(!type_option.apply_filter(&contains, &FieldType::Phone, &PhoneCellData(None)));
+
+    let starts_with = filter(PhoneFilterConditionPB::PhoneStartsWith, "+1 (555)");
+    assert!(type_option.apply_filter(&starts_with, &FieldType::Phone, &cell_data));
+    let starts_with = filter(PhoneFilterConditionPB::PhoneStartsWith, "555");
+    assert!(!type_option.apply_filter(&starts_with, &FieldType::Phone, &cell_data));
+
+    let is_empty = filter(PhoneFilterConditionPB::PhoneIsEmpty, "");
+    assert!(!type_option.apply_filter(&is_empty, &FieldType::Phone, &cell_data));
+    assert!(type_option.apply_filter(&is_empty, &FieldType::Phone, &PhoneCellData(None)));
+  }
+
+  #[test]
+  fn phone_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Phone).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Phone);
+    assert_eq!(handler.comparable_kind(), ComparableKind::String);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(&FieldType::Phone, &FieldType::Phone));
+
+    let filter = default_filter(&field_rev);
+    let filter = PhoneFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, PhoneFilterConditionPB::PhoneContains);
+    assert!(filter.content.is_empty());
+
+    // The phone number is exported in its canonical form.
+    let cell_rev = insert_text_cell("+1 (555) 123-4567".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("+15551234567".to_owned())
+    );
+    let cell_rev = insert_text_cell("+15551234567".to_owned(), &field_rev);
+    assert_eq!(
+      cell_value(&field_rev, &cell_rev).text.unwrap(),
+      "+15551234567"
+    );
+  }
+}
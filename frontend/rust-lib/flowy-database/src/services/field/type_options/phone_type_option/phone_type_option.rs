@@ -0,0 +1,258 @@
+use crate::entities::{CellValuePB, FieldType, PhoneFilterConditionPB, PhoneFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  plain_text, BoxTypeOptionBuilder, ComparableKind, EmptyFilterCondition, StrCellData, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct PhoneTypeOptionBuilder(PhoneTypeOptionPB);
+impl_into_box_type_option_builder!(PhoneTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(PhoneTypeOptionBuilder, PhoneTypeOptionPB);
+
+impl PhoneTypeOptionBuilder {
+  pub fn display_format(mut self, display_format: &str) -> Self {
+    self.0.display_format = display_format.to_owned();
+    self
+  }
+}
+
+impl TypeOptionBuilder for PhoneTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Phone
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The phone field stores the normalized phone number, the digits with an optional leading `+`,
+/// for example, `+15551234567` for `+1 (555) 123-4567`. So the differently-formatted inputs of
+/// the same number are equal when filtering and sorting.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct PhoneTypeOptionPB {
+  /// The format that the number is displayed with, each `#` is replaced by a digit of the number,
+  /// for example, `(###) ###-####`. The number is displayed as it's stored if the format is empty
+  /// or the number of `#` doesn't match the number of digits.
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub display_format: String,
+}
+impl_type_option!(PhoneTypeOptionPB, FieldType::Phone);
+
+impl PhoneTypeOptionPB {
+  /// Applies the `display_format` to the normalized number. The leading `+` is kept in front of
+  /// the formatted digits unless the format contains the `+` itself.
+  pub fn format_number(&self, number: &str) -> String {
+    let digits = number.trim_start_matches('+');
+    let placeholders = self.display_format.chars().filter(|c| *c == '#').count();
+    if placeholders == 0 || placeholders != digits.len() {
+      return number.to_owned();
+    }
+
+    let mut digits = digits.chars();
+    let formatted = self
+      .display_format
+      .chars()
+      .map(|c| match c {
+        '#' => digits.next().unwrap_or_default(),
+        c => c,
+      })
+      .collect::<String>();
+    if number.starts_with('+') && !self.display_format.contains('+') {
+      format!("+{}", formatted)
+    } else {
+      formatted
+    }
+  }
+}
+
+/// The normalized number of the phone cell. It's None if the cell is empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PhoneCellData(pub Option<String>);
+
+impl PhoneCellData {
+  /// Parses the phone number by stripping the spaces, dashes and parentheses. Returns None if the
+  /// string is empty, and error if the rest isn't the digits with an optional leading `+`.
+  pub fn parse(s: &str) -> FlowyResult<Self> {
+    let number = s
+      .chars()
+      .filter(|c| !is_phone_separator(*c))
+      .collect::<String>();
+    if number.is_empty() {
+      return Ok(Self(None));
+    }
+    let digits = number.strip_prefix('+').unwrap_or(&number);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+      let msg = format!("{} is not a valid phone number", s.trim());
+      return Err(FlowyError::new(ErrorCode::InvalidData, &msg));
+    }
+    Ok(Self(Some(number)))
+  }
+
+  /// Returns the digits of the number without the leading `+`.
+  pub fn digits(&self) -> Option<&str> {
+    self.0.as_ref().map(|number| number.trim_start_matches('+'))
+  }
+}
+
+/// The characters that are removed when normalizing the phone number.
+pub fn is_phone_separator(c: char) -> bool {
+  c.is_whitespace() || matches!(c, '-' | '(' | ')')
+}
+
+impl FromCellString for PhoneCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Ok(Self::parse(s).unwrap_or_default())
+  }
+}
+
+impl ToString for PhoneCellData {
+  fn to_string(&self) -> String {
+    self.0.clone().unwrap_or_default()
+  }
+}
+
+impl TypeOption for PhoneTypeOptionPB {
+  type CellData = PhoneCellData;
+  type CellChangeset = String;
+  type CellProtobufType = StrCellData;
+  type CellFilter = PhoneFilterPB;
+}
+
+impl TypeOptionTransform for PhoneTypeOptionPB {
+  fn transformable(&self) -> bool {
+    true
+  }
+
+  /// Keeps the text cells that are phone numbers, the other text cells become empty.
+  fn transform_type_option_cell_str(
+    &self,
+    cell_str: &str,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> Option<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_text() {
+      return None;
+    }
+    Some(PhoneCellData::parse(&plain_text(cell_str)).unwrap_or_default())
+  }
+}
+
+impl TypeOptionCellData for PhoneTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    StrCellData(self.decode_cell_data_to_str(cell_data))
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    PhoneCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.0.is_none()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      text: Some(cell_data.to_string()),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for PhoneTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_phone() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match &cell_data.0 {
+      Some(number) => self.format_number(number),
+      None => "".to_string(),
+    }
+  }
+}
+
+impl CellDataChangeset for PhoneTypeOptionPB {
+  /// The changeset is the phone number in any format, which is stored in its normalized form.
+  /// The empty changeset clears the cell.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = PhoneCellData::parse(&changeset)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+
+  fn validate_changeset(&self, changeset: &<Self as TypeOption>::CellChangeset) -> FlowyResult<()> {
+    PhoneCellData::parse(changeset).map(|_| ())
+  }
+}
+
+impl TypeOptionCellDataFilter for PhoneTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_phone() {
+      return true;
+    }
+    filter.is_visible(cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    PhoneFilterConditionPB::PhoneContains as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for PhoneTypeOptionPB {
+  /// Compares the normalized numbers, the empty cells come first.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data.0.cmp(&other_cell_data.0)
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::String
+  }
+}
@@ -0,0 +1,5 @@
+#![allow(clippy::module_inception)]
+mod rating_tests;
+mod rating_type_option;
+
+pub use rating_type_option::*;
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{insert_text_cell, CellDataChangeset, CellDataDecoder};
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, ComparableKind, FieldBuilder, RatingCellData, RatingTypeOptionBuilder,
+    RatingTypeOptionPB, SpreadsheetValue, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+  use std::cmp::Ordering;
+
+  #[test]
+  fn rating_changeset_clamp_test() {
+    let type_option = RatingTypeOptionPB::default();
+    assert_eq!(type_option.max, 5);
+    for (changeset, expected) in [
+      ("3", "3"),
+      (" 4 ", "4"),
+      ("0", "0"),
+      ("7", "5"),
+      ("-1", "0"),
+      ("2.6", "3"),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected, "{}", changeset);
+    }
+    assert!(type_option
+      .apply_changeset("five".to_owned(), None)
+      .is_err());
+
+    let type_option = RatingTypeOptionPB { max: 10 };
+    let (cell_str, _) = type_option.apply_changeset("7".to_owned(), None).unwrap();
+    assert_eq!(cell_str, "7");
+  }
+
+  #[test]
+  fn rating_stringify_test() {
+    let field_rev = FieldBuilder::new(RatingTypeOptionBuilder::default().max(10)).build();
+    let type_option = RatingTypeOptionPB { max: 10 };
+    let stringify = |cell_str: &str| {
+      let cell_data = type_option
+        .decode_cell_str(cell_str.to_owned(), &FieldType::Rating, &field_rev)
+        .unwrap();
+      type_option.decode_cell_data_to_str(cell_data)
+    };
+    assert_eq!(stringify("4"), "4");
+    assert_eq!(stringify(""), "");
+  }
+
+  #[test]
+  fn rating_at_least_filter_test() {
+    let type_option = RatingTypeOptionPB::default();
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThanOrEqualTo,
+      content: "3".to_owned(),
+    };
+    for (rating, visible) in [
+      (Some(5), true),
+      (Some(3), true),
+      (Some(2), false),
+      (None, false),
+    ] {
+      assert_eq!(
+        type_option.apply_filter(&filter, &FieldType::Rating, &RatingCellData(rating)),
+        visible,
+        "{:?}",
+        rating
+      );
+    }
+  }
+
+  #[test]
+  fn rating_cmp_test() {
+    let type_option = RatingTypeOptionPB::default();
+    let mut cells = vec![
+      RatingCellData(Some(4)),
+      RatingCellData(None),
+      RatingCellData(Some(1)),
+      RatingCellData(Some(5)),
+    ];
+    cells.sort_by(|left, right| type_option.apply_cmp(left, right));
+    assert_eq!(
+      cells,
+      vec![
+        RatingCellData(None),
+        RatingCellData(Some(1)),
+        RatingCellData(Some(4)),
+        RatingCellData(Some(5)),
+      ]
+    );
+    assert_eq!(
+      type_option.apply_cmp(&RatingCellData(Some(2)), &RatingCellData(Some(2))),
+      Ordering::Equal
+    );
+  }
+
+  #[test]
+  fn rating_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Rating).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Rating);
+    assert_eq!(handler.comparable_kind(), ComparableKind::Number);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::Rating,
+      &FieldType::Rating
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = NumberFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(
+      filter.condition,
+      NumberFilterConditionPB::GreaterThanOrEqualTo
+    );
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_text_cell("4".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Number(4.0)
+    );
+    // The rating is clamped to the max rating of the field.
+    let cell_rev = insert_text_cell("9".to_owned(), &field_rev);
+    assert_eq!(cell_value(&field_rev, &cell_rev).number.unwrap(), "5");
+  }
+}
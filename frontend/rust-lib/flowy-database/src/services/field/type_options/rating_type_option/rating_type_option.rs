@@ -0,0 +1,221 @@
+use crate::entities::{CellValuePB, FieldType, NumberFilterConditionPB, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  BoxTypeOptionBuilder, ComparableKind, EmptyFilterCondition, NumberCellData, SpreadsheetValue,
+  StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct RatingTypeOptionBuilder(RatingTypeOptionPB);
+impl_into_box_type_option_builder!(RatingTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(RatingTypeOptionBuilder, RatingTypeOptionPB);
+
+impl RatingTypeOptionBuilder {
+  pub fn max(mut self, max: i64) -> Self {
+    self.0.max = max;
+    self
+  }
+}
+
+impl TypeOptionBuilder for RatingTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Rating
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The rating field stores the number of stars, which is clamped to `0..=max`.
+#[derive(Debug, Clone, Serialize, Deserialize, ProtoBuf)]
+pub struct RatingTypeOptionPB {
+  #[pb(index = 1)]
+  #[serde(default = "DEFAULT_MAX_RATING")]
+  pub max: i64,
+}
+impl_type_option!(RatingTypeOptionPB, FieldType::Rating);
+
+const DEFAULT_MAX_RATING: fn() -> i64 = || 5;
+
+impl std::default::Default for RatingTypeOptionPB {
+  fn default() -> Self {
+    Self {
+      max: DEFAULT_MAX_RATING(),
+    }
+  }
+}
+
+impl RatingTypeOptionPB {
+  /// Parses the changeset into the number of stars. The fractional rating is rounded to the
+  /// nearest star, and the rating out of `0..=max` is clamped. Returns None if the changeset is
+  /// empty.
+  fn parse_rating(&self, changeset: &str) -> FlowyResult<Option<i64>> {
+    let changeset = changeset.trim();
+    if changeset.is_empty() {
+      return Ok(None);
+    }
+    let rating = match changeset.parse::<i64>() {
+      Ok(rating) => rating,
+      Err(_) => match changeset.parse::<f64>() {
+        Ok(rating) if rating.is_finite() => rating.round() as i64,
+        _ => {
+          let msg = format!("Invalid rating: {}", changeset);
+          return Err(FlowyError::new(ErrorCode::InvalidData, &msg));
+        },
+      },
+    };
+    Ok(Some(rating.clamp(0, self.max.max(0))))
+  }
+}
+
+/// The number of stars of the rating cell. It's None if the cell is empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RatingCellData(pub Option<i64>);
+
+impl FromCellString for RatingCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Ok(Self(s.trim().parse::<i64>().ok()))
+  }
+}
+
+impl ToString for RatingCellData {
+  fn to_string(&self) -> String {
+    self.0.map(|rating| rating.to_string()).unwrap_or_default()
+  }
+}
+
+impl TypeOption for RatingTypeOptionPB {
+  type CellData = RatingCellData;
+  type CellChangeset = String;
+  type CellProtobufType = StrCellData;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for RatingTypeOptionPB {}
+
+impl TypeOptionCellData for RatingTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    StrCellData(self.decode_cell_data_to_str(cell_data))
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    RatingCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.0.is_none()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      number: Some(cell_data.to_string()),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for RatingTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_rating() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+
+  fn decode_cell_data_to_spreadsheet_value(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> SpreadsheetValue {
+    match cell_data.0 {
+      None => SpreadsheetValue::Text("".to_owned()),
+      Some(rating) => SpreadsheetValue::Number(rating as f64),
+    }
+  }
+}
+
+impl CellDataChangeset for RatingTypeOptionPB {
+  /// The changeset is the number of stars. The empty changeset clears the cell.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = RatingCellData(self.parse_rating(&changeset)?);
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for RatingTypeOptionPB {
+  /// The content of the filter is the number of stars, for example, the `at least N stars`
+  /// filter is the `GreaterThanOrEqualTo` condition with `N` as its content.
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_rating() {
+      return true;
+    }
+
+    let number_cell_data = match cell_data.0 {
+      None => NumberCellData::new(),
+      Some(rating) => NumberCellData::from_decimal(Decimal::from(rating)),
+    };
+    filter.is_visible(&number_cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    NumberFilterConditionPB::GreaterThanOrEqualTo as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for RatingTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data.0.cmp(&other_cell_data.0)
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::Number
+  }
+}
@@ -0,0 +1,139 @@
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+pub const RELATION_ROW_IDS_SEPARATOR: &str = ",";
+
+/// The ids of the rows that the relation cell links to. The ids are stored as a comma-separated
+/// string.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RelationCellData(pub Vec<String>);
+
+impl RelationCellData {
+  /// Returns the linked row ids in order, the duplicated id is kept once.
+  pub fn row_ids(&self) -> Vec<String> {
+    let mut seen = HashSet::new();
+    self
+      .0
+      .iter()
+      .filter(|row_id| seen.insert(row_id.as_str()))
+      .cloned()
+      .collect()
+  }
+
+  /// Returns the number of the linked rows, the duplicated id is counted once.
+  pub fn linked_count(&self) -> usize {
+    self.row_ids().len()
+  }
+}
+
+impl FromCellString for RelationCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    let row_ids = s
+      .split(RELATION_ROW_IDS_SEPARATOR)
+      .map(|row_id| row_id.trim())
+      .filter(|row_id| !row_id.is_empty())
+      .map(|row_id| row_id.to_owned())
+      .collect::<Vec<String>>();
+    Ok(Self(row_ids))
+  }
+}
+
+impl ToString for RelationCellData {
+  fn to_string(&self) -> String {
+    self.row_ids().join(RELATION_ROW_IDS_SEPARATOR)
+  }
+}
+
+impl DecodedCellData for RelationCellData {
+  type Object = RelationCellData;
+
+  fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellDataPB {
+  #[pb(index = 1)]
+  pub row_ids: Vec<String>,
+}
+
+impl From<RelationCellData> for RelationCellDataPB {
+  fn from(data: RelationCellData) -> Self {
+    Self {
+      row_ids: data.row_ids(),
+    }
+  }
+}
+
+impl DecodedCellData for RelationCellDataPB {
+  type Object = RelationCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.row_ids.is_empty()
+  }
+}
+
+pub struct RelationCellDataParser();
+impl CellProtobufBlobParser for RelationCellDataParser {
+  type Object = RelationCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    RelationCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+/// The changeset of the relation cell. The row ids are removed before the new row ids are
+/// inserted. The plain text changeset is parsed as the comma-separated row ids to insert.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RelationCellChangeset {
+  #[serde(default)]
+  pub inserted_row_ids: Vec<String>,
+  #[serde(default)]
+  pub removed_row_ids: Vec<String>,
+}
+
+impl RelationCellChangeset {
+  pub fn from_insert_row_ids(inserted_row_ids: Vec<String>) -> Self {
+    Self {
+      inserted_row_ids,
+      removed_row_ids: vec![],
+    }
+  }
+
+  pub fn from_remove_row_ids(removed_row_ids: Vec<String>) -> Self {
+    Self {
+      inserted_row_ids: vec![],
+      removed_row_ids,
+    }
+  }
+}
+
+impl FromCellChangesetString for RelationCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    if changeset.trim_start().starts_with('{') {
+      return serde_json::from_str::<RelationCellChangeset>(&changeset).map_err(internal_error);
+    }
+    let row_ids = RelationCellData::from_cell_str(&changeset)?.0;
+    Ok(Self::from_insert_row_ids(row_ids))
+  }
+}
+
+impl ToCellChangesetString for RelationCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
@@ -0,0 +1,36 @@
+use crate::entities::{RelationFilterConditionPB, RelationFilterPB};
+use crate::services::field::{EmptyFilterCondition, RelationCellData};
+
+impl RelationFilterPB {
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      RelationFilterConditionPB::RelationIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      RelationFilterConditionPB::RelationIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
+  /// The `LinkCount` conditions compare the number of the linked rows with the content of the
+  /// filter. The cell is visible if the content isn't a number.
+  pub fn is_visible(&self, cell_data: &RelationCellData) -> bool {
+    let linked_count = cell_data.linked_count();
+    let expected = match self.condition {
+      RelationFilterConditionPB::RelationIsEmpty => return linked_count == 0,
+      RelationFilterConditionPB::RelationIsNotEmpty => return linked_count > 0,
+      _ => match self.content.trim().parse::<usize>() {
+        Ok(expected) => expected,
+        Err(_) => return true,
+      },
+    };
+    match self.condition {
+      RelationFilterConditionPB::LinkCountEqual => linked_count == expected,
+      RelationFilterConditionPB::LinkCountNotEqual => linked_count != expected,
+      RelationFilterConditionPB::LinkCountGreaterThan => linked_count > expected,
+      RelationFilterConditionPB::LinkCountLessThan => linked_count < expected,
+      RelationFilterConditionPB::LinkCountGreaterThanOrEqualTo => linked_count >= expected,
+      RelationFilterConditionPB::LinkCountLessThanOrEqualTo => linked_count <= expected,
+      RelationFilterConditionPB::RelationIsEmpty
+      | RelationFilterConditionPB::RelationIsNotEmpty => true,
+    }
+  }
+}
@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, RelationFilterConditionPB, RelationFilterPB};
+  use crate::services::cell::{insert_text_cell, CellDataChangeset, TypeCellData};
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, ingest_json_value, spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, ComparableKind, FieldBuilder, RelationCellChangeset, RelationCellData,
+    RelationLabelResolver, RelationTypeOptionBuilder, RelationTypeOptionPB, SpreadsheetValue,
+    TypeOptionCellDataFilter,
+  };
+  use serde_json::json;
+  use std::cell::RefCell;
+  use std::collections::HashMap;
+
+  #[test]
+  fn relation_linked_count_test() {
+    let field_rev = FieldBuilder::new(RelationTypeOptionBuilder::default()).build();
+    let type_option = RelationTypeOptionPB::from(&field_rev);
+
+    let cell_rev = insert_text_cell("".to_owned(), &field_rev);
+    assert_eq!(type_option.linked_count(&cell_rev, &field_rev), 0);
+
+    let cell_rev = insert_text_cell("row_1".to_owned(), &field_rev);
+    assert_eq!(type_option.linked_count(&cell_rev, &field_rev), 1);
+
+    // The duplicated row id is counted once.
+    let cell_rev = insert_text_cell("row_1,row_2,row_1,row_3".to_owned(), &field_rev);
+    assert_eq!(type_option.linked_count(&cell_rev, &field_rev), 3);
+  }
+
+  /// Records the row ids of every query.
+  #[derive(Default)]
+  struct MockRelationLabelResolver {
+    queries: RefCell<Vec<Vec<String>>>,
+  }
+
+  impl RelationLabelResolver for MockRelationLabelResolver {
+    fn resolve_row_labels(&self, row_ids: &[String]) -> HashMap<String, String> {
+      self.queries.borrow_mut().push(row_ids.to_vec());
+      row_ids
+        .iter()
+        .filter(|row_id| row_id.as_str() != "deleted_row")
+        .map(|row_id| (row_id.clone(), format!("Label of {}", row_id)))
+        .collect()
+    }
+  }
+
+  #[test]
+  fn relation_resolve_labels_in_bulk_test() {
+    let field_rev = FieldBuilder::new(RelationTypeOptionBuilder::default()).build();
+    let type_option = RelationTypeOptionPB::from(&field_rev);
+    let cell_revs = ["row_1,row_2", "", "row_2,deleted_row", "row_2"]
+      .map(|s| insert_text_cell(s.to_owned(), &field_rev));
+
+    let resolver = MockRelationLabelResolver::default();
+    let labels = type_option.resolve_relation_labels(&cell_revs, &field_rev, &resolver);
+    assert_eq!(
+      labels,
+      vec![
+        vec!["Label of row_1", "Label of row_2"],
+        vec![],
+        vec!["Label of row_2"],
+        vec!["Label of row_2"],
+      ]
+    );
+
+    // The shared row id is resolved once, in a single query.
+    assert_eq!(
+      *resolver.queries.borrow(),
+      vec![vec!["row_1", "row_2", "deleted_row"]]
+    );
+
+    // The resolver isn't queried if no cell links to any row.
+    let resolver = MockRelationLabelResolver::default();
+    let empty_cell_rev = insert_text_cell("".to_owned(), &field_rev);
+    assert_eq!(
+      type_option.resolve_relation_labels(&[empty_cell_rev], &field_rev, &resolver),
+      vec![Vec::<String>::new()]
+    );
+    assert!(resolver.queries.borrow().is_empty());
+  }
+
+  #[test]
+  fn relation_changeset_test() {
+    let type_option = RelationTypeOptionPB::default();
+    let changeset =
+      RelationCellChangeset::from_insert_row_ids(vec!["row_1".to_owned(), "row_2".to_owned()]);
+    let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_str, "row_1,row_2");
+    assert_eq!(cell_data.linked_count(), 2);
+
+    let type_cell_data = TypeCellData::new(cell_str, FieldType::Relation);
+    let changeset = RelationCellChangeset {
+      inserted_row_ids: vec!["row_2".to_owned(), "row_3".to_owned()],
+      removed_row_ids: vec!["row_1".to_owned()],
+    };
+    let (cell_str, _) = type_option
+      .apply_changeset(changeset, Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_str, "row_2,row_3");
+  }
+
+  #[test]
+  fn relation_link_count_filter_test() {
+    let type_option = RelationTypeOptionPB::default();
+    let zero = RelationCellData::default();
+    let one = RelationCellData(vec!["row_1".to_owned()]);
+    let several = RelationCellData(vec![
+      "row_1".to_owned(),
+      "row_2".to_owned(),
+      "row_3".to_owned(),
+    ]);
+
+    let more_than_two = RelationFilterPB {
+      condition: RelationFilterConditionPB::LinkCountGreaterThan,
+      content: "2".to_owned(),
+    };
+    assert!(!type_option.apply_filter(&more_than_two, &FieldType::Relation, &zero));
+    assert!(!type_option.apply_filter(&more_than_two, &FieldType::Relation, &one));
+    assert!(type_option.apply_filter(&more_than_two, &FieldType::Relation, &several));
+
+    let exactly_one = RelationFilterPB {
+      condition: RelationFilterConditionPB::LinkCountEqual,
+      content: "1".to_owned(),
+    };
+    assert!(!type_option.apply_filter(&exactly_one, &FieldType::Relation, &zero));
+    assert!(type_option.apply_filter(&exactly_one, &FieldType::Relation, &one));
+    assert!(!type_option.apply_filter(&exactly_one, &FieldType::Relation, &several));
+
+    let is_empty = RelationFilterPB {
+      condition: RelationFilterConditionPB::RelationIsEmpty,
+      content: "".to_owned(),
+    };
+    assert!(type_option.apply_filter(&is_empty, &FieldType::Relation, &zero));
+    assert!(!type_option.apply_filter(&is_empty, &FieldType::Relation, &one));
+  }
+
+  #[test]
+  fn relation_ingest_json_value_test() {
+    let field_rev = FieldBuilder::new(RelationTypeOptionBuilder::default()).build();
+    assert_eq!(
+      ingest_json_value(&field_rev, json!(["row_1", "row_2", "row_1"])).unwrap(),
+      "row_1,row_2"
+    );
+    assert_eq!(
+      ingest_json_value(&field_rev, json!("row_1, row_2")).unwrap(),
+      "row_1,row_2"
+    );
+    assert_eq!(ingest_json_value(&field_rev, json!(null)).unwrap(), "");
+
+    assert!(ingest_json_value(&field_rev, json!([1])).is_err());
+    assert!(ingest_json_value(&field_rev, json!(true)).is_err());
+  }
+
+  #[test]
+  fn relation_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Relation).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Relation);
+    assert_eq!(handler.comparable_kind(), ComparableKind::Number);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::Relation,
+      &FieldType::Relation
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = RelationFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(
+      filter.condition,
+      RelationFilterConditionPB::LinkCountGreaterThan
+    );
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_text_cell("".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("".to_owned())
+    );
+    let cell_rev = insert_text_cell("row_1,row_2".to_owned(), &field_rev);
+    assert_eq!(
+      cell_value(&field_rev, &cell_rev)
+        .relation
+        .unwrap()
+        .row_ids
+        .len(),
+      2
+    );
+  }
+}
@@ -0,0 +1,271 @@
+use crate::entities::{CellValuePB, FieldType, RelationFilterConditionPB, RelationFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{
+  invalid_json_value_error, CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData,
+};
+use crate::services::field::{
+  BoxTypeOptionBuilder, ComparableKind, EmptyFilterCondition, RelationCellChangeset,
+  RelationCellData, RelationCellDataPB, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{
+  CellRevision, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer,
+};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default)]
+pub struct RelationTypeOptionBuilder(RelationTypeOptionPB);
+impl_into_box_type_option_builder!(RelationTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(RelationTypeOptionBuilder, RelationTypeOptionPB);
+
+impl RelationTypeOptionBuilder {
+  pub fn database_id(mut self, database_id: &str) -> Self {
+    self.0.database_id = database_id.to_owned();
+    self
+  }
+}
+
+impl TypeOptionBuilder for RelationTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Relation
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The relation field links the row to the rows of the database with `database_id`. The cell
+/// stores the ids of the linked rows.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct RelationTypeOptionPB {
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub database_id: String,
+}
+impl_type_option!(RelationTypeOptionPB, FieldType::Relation);
+
+/// Resolves the labels, for example, the primary field's text, of the rows in the related
+/// database.
+pub trait RelationLabelResolver {
+  /// Returns the labels of the rows keyed by the row id. The row that doesn't exist anymore is
+  /// left out.
+  fn resolve_row_labels(&self, row_ids: &[String]) -> HashMap<String, String>;
+}
+
+impl RelationTypeOptionPB {
+  /// Returns the number of the rows that the cell links to, which is shown as the
+  /// "N linked records" badge. The duplicated row id is counted once.
+  pub fn linked_count(&self, cell_rev: &CellRevision, field_rev: &FieldRevision) -> usize {
+    self
+      .decode_cell_rev(cell_rev, field_rev)
+      .map(|cell_data| cell_data.linked_count())
+      .unwrap_or(0)
+  }
+
+  /// Returns the labels of the linked rows of each cell, in the order of the cells. The linked
+  /// row ids of all the cells are resolved in one batch, so the resolver is queried once however
+  /// many cells link to the same row. The linked row that can't be resolved is left out.
+  pub fn resolve_relation_labels(
+    &self,
+    cell_revs: &[CellRevision],
+    field_rev: &FieldRevision,
+    resolver: &dyn RelationLabelResolver,
+  ) -> Vec<Vec<String>> {
+    let row_ids_of_cells = cell_revs
+      .iter()
+      .map(|cell_rev| {
+        self
+          .decode_cell_rev(cell_rev, field_rev)
+          .map(|cell_data| cell_data.row_ids())
+          .unwrap_or_default()
+      })
+      .collect::<Vec<Vec<String>>>();
+
+    let mut seen = HashSet::new();
+    let unique_row_ids = row_ids_of_cells
+      .iter()
+      .flatten()
+      .filter(|row_id| seen.insert(row_id.as_str()))
+      .cloned()
+      .collect::<Vec<String>>();
+    let labels = if unique_row_ids.is_empty() {
+      HashMap::new()
+    } else {
+      resolver.resolve_row_labels(&unique_row_ids)
+    };
+
+    row_ids_of_cells
+      .iter()
+      .map(|row_ids| {
+        row_ids
+          .iter()
+          .filter_map(|row_id| labels.get(row_id).cloned())
+          .collect()
+      })
+      .collect()
+  }
+
+  fn decode_cell_rev(
+    &self,
+    cell_rev: &CellRevision,
+    field_rev: &FieldRevision,
+  ) -> Option<RelationCellData> {
+    let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+    self
+      .decode_cell_str(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        field_rev,
+      )
+      .ok()
+  }
+}
+
+impl TypeOption for RelationTypeOptionPB {
+  type CellData = RelationCellData;
+  type CellChangeset = RelationCellChangeset;
+  type CellProtobufType = RelationCellDataPB;
+  type CellFilter = RelationFilterPB;
+}
+
+impl TypeOptionTransform for RelationTypeOptionPB {}
+
+impl TypeOptionCellData for RelationTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    cell_data.into()
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    RelationCellData::from_cell_str(&cell_str)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.0.is_empty()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      relation: Some(self.convert_to_protobuf(cell_data)),
+      ..Default::default()
+    }
+  }
+}
+
+impl CellDataDecoder for RelationTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_relation() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+}
+
+impl CellDataChangeset for RelationTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let mut row_ids = match type_cell_data {
+      Some(type_cell_data) if type_cell_data.is_relation() => {
+        RelationCellData::from_cell_str(&type_cell_data.cell_str)?.row_ids()
+      },
+      _ => vec![],
+    };
+    row_ids.retain(|row_id| !changeset.removed_row_ids.contains(row_id));
+    for row_id in changeset.inserted_row_ids {
+      if !row_ids.contains(&row_id) {
+        row_ids.push(row_id);
+      }
+    }
+    let cell_data = RelationCellData(row_ids);
+    Ok((cell_data.to_string(), cell_data))
+  }
+
+  /// Accepts the comma-separated row ids or the array of the row ids.
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    let expected = "a row id or an array of row ids";
+    let row_ids = match value {
+      serde_json::Value::Null => vec![],
+      serde_json::Value::String(s) => RelationCellData::from_cell_str(s)?.0,
+      serde_json::Value::Array(values) => values
+        .iter()
+        .map(|value| match value {
+          serde_json::Value::String(s) if !s.trim().is_empty() => Ok(s.trim().to_owned()),
+          _ => Err(invalid_json_value_error(value, expected)),
+        })
+        .collect::<FlowyResult<Vec<String>>>()?,
+      _ => return Err(invalid_json_value_error(value, expected)),
+    };
+    Ok(RelationCellChangeset::from_insert_row_ids(row_ids))
+  }
+}
+
+impl TypeOptionCellDataFilter for RelationTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_relation() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    RelationFilterConditionPB::LinkCountGreaterThan as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl TypeOptionCellDataCompare for RelationTypeOptionPB {
+  /// Compares the number of the linked rows, then the row ids.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data
+      .linked_count()
+      .cmp(&other_cell_data.linked_count())
+      .then_with(|| cell_data.row_ids().cmp(&other_cell_data.row_ids()))
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::Number
+  }
+}
@@ -2,10 +2,13 @@ use crate::entities::{ChecklistFilterConditionPB, ChecklistFilterPB};
 use crate::services::field::{SelectOptionPB, SelectedSelectOptions};
 
 impl ChecklistFilterPB {
+  /// The `progress` is the completed ratio of the checklist between 0.0 and 1.0, see
+  /// [ChecklistTypeOptionPB::checklist_progress](crate::services::field::ChecklistTypeOptionPB::checklist_progress).
   pub fn is_visible(
     &self,
     all_options: &[SelectOptionPB],
     selected_options: &SelectedSelectOptions,
+    progress: f64,
   ) -> bool {
     let selected_option_ids = selected_options
       .options
@@ -35,6 +38,10 @@ impl ChecklistFilterPB {
         all_option_ids.retain(|option_id| !selected_option_ids.contains(option_id));
         !all_option_ids.is_empty()
       },
+      ChecklistFilterConditionPB::PercentAtLeast => match self.content.trim().parse::<f64>() {
+        Ok(percent) => progress >= percent / 100.0,
+        Err(_) => false,
+      },
     }
   }
 }
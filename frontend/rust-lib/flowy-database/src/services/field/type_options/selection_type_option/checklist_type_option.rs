@@ -1,10 +1,11 @@
-use crate::entities::{ChecklistFilterPB, FieldType};
+use crate::entities::{CellValuePB, ChecklistFilterConditionPB, ChecklistFilterPB, FieldType};
 use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, FromCellString, TypeCellData};
 use crate::services::field::{
-  BoxTypeOptionBuilder, SelectOptionCellChangeset, SelectOptionCellDataPB, SelectOptionIds,
-  SelectOptionPB, SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption,
+  BoxTypeOptionBuilder, ComparableKind, SelectOptionCellChangeset, SelectOptionCellDataPB,
+  SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption,
   TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  DEFAULT_SELECTION_JOIN_SEPARATOR,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
@@ -21,6 +22,12 @@ pub struct ChecklistTypeOptionPB {
 
   #[pb(index = 2)]
   pub disable_color: bool,
+
+  /// Joins the names of the selected options when the cell is stringified, for example, for
+  /// exporting. The [DEFAULT_SELECTION_JOIN_SEPARATOR] is used if it's empty.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub join_separator: String,
 }
 impl_type_option!(ChecklistTypeOptionPB, FieldType::Checklist);
 
@@ -39,11 +46,62 @@ impl TypeOptionCellData for ChecklistTypeOptionPB {
     self.get_selected_options(cell_data)
   }
 
+  /// The progress of the checklist is computed along with the ids, so it's cached with the
+  /// decoded cell data.
   fn decode_type_option_cell_str(
     &self,
     cell_str: String,
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
-    SelectOptionIds::from_cell_str(&cell_str)
+    let cell_data = SelectOptionIds::from_cell_str(&cell_str)?;
+    let progress = self.checklist_progress(&cell_data);
+    Ok(cell_data.with_progress(progress))
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.is_empty()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      checklist: Some(self.convert_to_protobuf(cell_data)),
+      ..Default::default()
+    }
+  }
+}
+
+impl ChecklistTypeOptionPB {
+  /// Returns the progress of the checklist between 0.0 and 1.0, which is the weight of the
+  /// completed items divided by the weight of all the items. Every item weighs 1 unless it has its
+  /// own weight, so the progress is the count of the completed items over the count of all the
+  /// items if none of the items is weighted.
+  pub fn checklist_progress(&self, cell_data: &SelectOptionIds) -> f64 {
+    let (completed, total) = self.checklist_weights(cell_data);
+    if total == 0 {
+      return 0.0;
+    }
+    completed as f64 / total as f64
+  }
+
+  /// Same as [Self::checklist_progress], but returns the progress that is computed when the cell
+  /// is decoded if there's one.
+  fn cached_checklist_progress(&self, cell_data: &SelectOptionIds) -> f64 {
+    cell_data
+      .progress()
+      .unwrap_or_else(|| self.checklist_progress(cell_data))
+  }
+
+  fn checklist_weights(&self, cell_data: &SelectOptionIds) -> (i64, i64) {
+    self
+      .options
+      .iter()
+      .fold((0, 0), |(completed, total), option| {
+        let weight = option.weight.unwrap_or(1).max(0) as i64;
+        if cell_data.contains(&option.id) {
+          (completed + weight, total + weight)
+        } else {
+          (completed, total + weight)
+        }
+      })
   }
 }
 
@@ -56,6 +114,14 @@ impl SelectTypeOptionSharedAction for ChecklistTypeOptionPB {
     &self.options
   }
 
+  fn join_separator(&self) -> &str {
+    if self.join_separator.is_empty() {
+      DEFAULT_SELECTION_JOIN_SEPARATOR
+    } else {
+      &self.join_separator
+    }
+  }
+
   fn mut_options(&mut self) -> &mut Vec<SelectOptionPB> {
     &mut self.options
   }
@@ -97,6 +163,13 @@ impl CellDataChangeset for ChecklistTypeOptionPB {
     };
     Ok((select_option_ids.to_string(), select_option_ids))
   }
+
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    self.select_changeset_from_json_value(value)
+  }
 }
 impl TypeOptionCellDataFilter for ChecklistTypeOptionPB {
   fn apply_filter(
@@ -110,7 +183,15 @@ impl TypeOptionCellDataFilter for ChecklistTypeOptionPB {
     }
     let selected_options =
       SelectedSelectOptions::from(self.get_selected_options(cell_data.clone()));
-    filter.is_visible(&self.options, &selected_options)
+    filter.is_visible(
+      &self.options,
+      &selected_options,
+      self.cached_checklist_progress(cell_data),
+    )
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    ChecklistFilterConditionPB::IsComplete as u8
   }
 }
 
@@ -120,7 +201,15 @@ impl TypeOptionCellDataCompare for ChecklistTypeOptionPB {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering {
-    cell_data.len().cmp(&other_cell_data.len())
+    self
+      .cached_checklist_progress(cell_data)
+      .partial_cmp(&self.cached_checklist_progress(other_cell_data))
+      .unwrap_or(Ordering::Equal)
+      .then_with(|| cell_data.len().cmp(&other_cell_data.len()))
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::CompletePercentage
   }
 }
 
@@ -133,6 +222,11 @@ impl ChecklistTypeOptionBuilder {
     self.0.options.push(opt);
     self
   }
+
+  pub fn set_join_separator(mut self, join_separator: &str) -> Self {
+    self.0.join_separator = join_separator.to_owned();
+    self
+  }
 }
 
 impl TypeOptionBuilder for ChecklistTypeOptionBuilder {
@@ -144,3 +238,256 @@ impl TypeOptionBuilder for ChecklistTypeOptionBuilder {
     &self.0
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::entities::{ChecklistFilterConditionPB, ChecklistFilterPB, FieldType};
+  use crate::services::cell::insert_select_option_cell;
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, is_cell_empty, row_single_cell_data,
+    spreadsheet_value,
+  };
+  use crate::services::field::{
+    is_transform_lossless, ChecklistTypeOptionBuilder, ChecklistTypeOptionPB, ComparableKind,
+    FieldBuilder, SelectOptionIds, SelectOptionPB, SpreadsheetValue, TypeOptionCellData,
+    TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+  use std::cmp::Ordering;
+
+  fn checklist_item(name: &str, weight: Option<i32>) -> SelectOptionPB {
+    let mut option = SelectOptionPB::new(name);
+    option.weight = weight;
+    option
+  }
+
+  #[test]
+  fn checklist_progress_default_weight_test() {
+    let type_option = ChecklistTypeOptionPB {
+      options: (0..5)
+        .map(|i| checklist_item(&format!("Item {}", i), None))
+        .collect(),
+      disable_color: false,
+      ..Default::default()
+    };
+    let completed = SelectOptionIds::from(
+      type_option.options[0..3]
+        .iter()
+        .map(|option| option.id.clone())
+        .collect::<Vec<String>>(),
+    );
+    assert_eq!(type_option.checklist_progress(&completed), 0.6);
+
+    let empty = SelectOptionIds::new();
+    assert_eq!(type_option.checklist_progress(&empty), 0.0);
+  }
+
+  #[test]
+  fn checklist_progress_weighted_test() {
+    let type_option = ChecklistTypeOptionPB {
+      options: vec![
+        checklist_item("Design", Some(5)),
+        checklist_item("Implement", Some(3)),
+        checklist_item("Review", None),
+        checklist_item("Release", Some(1)),
+      ],
+      disable_color: false,
+      ..Default::default()
+    };
+    // Completing the heaviest item makes more progress than completing the two lighter ones.
+    let completed = SelectOptionIds::from(vec![type_option.options[0].id.clone()]);
+    assert_eq!(type_option.checklist_progress(&completed), 0.5);
+
+    let completed = SelectOptionIds::from(vec![
+      type_option.options[2].id.clone(),
+      type_option.options[3].id.clone(),
+    ]);
+    assert_eq!(type_option.checklist_progress(&completed), 0.2);
+  }
+
+  #[test]
+  fn empty_checklist_progress_test() {
+    let type_option = ChecklistTypeOptionPB::default();
+    assert_eq!(type_option.checklist_progress(&SelectOptionIds::new()), 0.0);
+  }
+
+  fn five_items_checklist() -> ChecklistTypeOptionPB {
+    ChecklistTypeOptionPB {
+      options: (0..5)
+        .map(|i| checklist_item(&format!("Item {}", i), None))
+        .collect(),
+      ..Default::default()
+    }
+  }
+
+  fn completed_ids(type_option: &ChecklistTypeOptionPB, count: usize) -> SelectOptionIds {
+    SelectOptionIds::from(
+      type_option.options[0..count]
+        .iter()
+        .map(|option| option.id.clone())
+        .collect::<Vec<String>>(),
+    )
+  }
+
+  #[test]
+  fn checklist_complete_percentage_filter_test() {
+    let empty_type_option = ChecklistTypeOptionPB::default();
+    let type_option = five_items_checklist();
+    // 0/0, 2/5 and 5/5
+    let checklists = [
+      (&empty_type_option, SelectOptionIds::new()),
+      (&type_option, completed_ids(&type_option, 2)),
+      (&type_option, completed_ids(&type_option, 5)),
+    ];
+
+    for (condition, content, expected) in [
+      (
+        ChecklistFilterConditionPB::IsComplete,
+        "",
+        [false, false, true],
+      ),
+      (
+        ChecklistFilterConditionPB::IsIncomplete,
+        "",
+        [true, true, false],
+      ),
+      (
+        ChecklistFilterConditionPB::PercentAtLeast,
+        "40",
+        [false, true, true],
+      ),
+      (
+        ChecklistFilterConditionPB::PercentAtLeast,
+        "41",
+        [false, false, true],
+      ),
+      (
+        ChecklistFilterConditionPB::PercentAtLeast,
+        "0",
+        [true, true, true],
+      ),
+      (
+        ChecklistFilterConditionPB::PercentAtLeast,
+        "abc",
+        [false, false, false],
+      ),
+    ] {
+      let filter = ChecklistFilterPB {
+        condition: condition.clone(),
+        content: content.to_owned(),
+      };
+      for ((type_option, cell_data), expected) in checklists.iter().zip(expected) {
+        assert_eq!(
+          type_option.apply_filter(&filter, &FieldType::Checklist, cell_data),
+          expected,
+          "{:?} {} {:?}",
+          condition,
+          content,
+          cell_data
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn checklist_complete_percentage_compare_test() {
+    let empty_type_option = ChecklistTypeOptionPB::default();
+    assert_eq!(
+      empty_type_option.apply_cmp(&SelectOptionIds::new(), &SelectOptionIds::new()),
+      Ordering::Equal
+    );
+
+    let type_option = five_items_checklist();
+    let two_of_five = completed_ids(&type_option, 2);
+    let five_of_five = completed_ids(&type_option, 5);
+    assert_eq!(
+      type_option.apply_cmp(&two_of_five, &five_of_five),
+      Ordering::Less
+    );
+    assert_eq!(
+      type_option.apply_cmp(&five_of_five, &two_of_five),
+      Ordering::Greater
+    );
+    assert_eq!(
+      type_option.apply_cmp(&SelectOptionIds::new(), &two_of_five),
+      Ordering::Less
+    );
+  }
+
+  #[test]
+  fn checklist_progress_cached_in_decoded_cell_data_test() {
+    let type_option = five_items_checklist();
+    let two_of_five = type_option
+      .decode_type_option_cell_str(completed_ids(&type_option, 2).to_string())
+      .unwrap();
+    assert_eq!(two_of_five.progress(), Some(0.4));
+
+    // The cached progress is used instead of the options of the type option.
+    let stale = SelectOptionIds::from(vec!["unknown".to_owned()]).with_progress(1.0);
+    assert_eq!(type_option.apply_cmp(&two_of_five, &stale), Ordering::Less);
+    let filter = ChecklistFilterPB {
+      condition: ChecklistFilterConditionPB::PercentAtLeast,
+      content: "50".to_owned(),
+    };
+    assert!(type_option.apply_filter(&filter, &FieldType::Checklist, &stale));
+    assert!(!type_option.apply_filter(&filter, &FieldType::Checklist, &two_of_five));
+  }
+
+  #[test]
+  fn checklist_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Checklist).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::Checklist);
+    assert_eq!(
+      handler.comparable_kind(),
+      ComparableKind::CompletePercentage
+    );
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::Checklist,
+      &FieldType::Checklist
+    ));
+    assert!(!is_transform_lossless(
+      &FieldType::Checklist,
+      &FieldType::RichText
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = ChecklistFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, ChecklistFilterConditionPB::IsComplete);
+
+    let cell_rev = insert_select_option_cell(vec![], &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("".to_owned())
+    );
+    assert!(cell_value(&field_rev, &cell_rev).checklist.is_some());
+  }
+
+  #[test]
+  fn checklist_is_cell_empty_test() {
+    // The checklist filter has no empty condition, but the cell without options is empty.
+    let option = SelectOptionPB::new("A");
+    let field_rev =
+      FieldBuilder::new(ChecklistTypeOptionBuilder::default().add_option(option.clone())).build();
+    assert!(is_cell_empty(&field_rev, None));
+    assert!(!is_cell_empty(
+      &field_rev,
+      Some(&insert_select_option_cell(vec![option.id], &field_rev))
+    ));
+  }
+
+  #[test]
+  fn checklist_row_single_cell_data_test() {
+    let option = SelectOptionPB::new("Buy milk");
+    let field_rev =
+      FieldBuilder::new(ChecklistTypeOptionBuilder::default().add_option(option.clone())).build();
+    let cell_rev = insert_select_option_cell(vec![option.id.clone()], &field_rev);
+
+    let cell_data = row_single_cell_data(&field_rev, &cell_rev)
+      .into_check_list_field_cell_data()
+      .unwrap();
+    assert_eq!(cell_data.into_inner(), vec![option.id]);
+    assert!(row_single_cell_data(&field_rev, &cell_rev)
+      .into_checkbox_field_cell_data()
+      .is_none());
+  }
+}
@@ -1,17 +1,19 @@
-use crate::entities::{FieldType, SelectOptionFilterPB};
+use crate::entities::{CellValuePB, FieldType, SelectOptionConditionPB, SelectOptionFilterPB};
 use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, FromCellString, TypeCellData};
 use std::cmp::{min, Ordering};
 
 use crate::services::field::{
-  default_order, BoxTypeOptionBuilder, SelectOptionCellChangeset, SelectOptionCellDataPB,
-  SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption,
-  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  default_order, BoxTypeOptionBuilder, CellGlyph, ComparableKind, EmptyFilterCondition,
+  SelectOptionCellChangeset, SelectOptionCellDataPB, SelectOptionIds, SelectOptionPB,
+  SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption, TypeOptionBuilder,
+  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  DEFAULT_SELECTION_JOIN_SEPARATOR, NO_STATUS_GROUP_SORT_WEIGHT,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use flowy_derive::ProtoBuf;
-use flowy_error::FlowyResult;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use serde::{Deserialize, Serialize};
 
 // Multiple select
@@ -22,6 +24,35 @@ pub struct MultiSelectTypeOptionPB {
 
   #[pb(index = 2)]
   pub disable_color: bool,
+
+  /// Returns error when the changeset selects an option that doesn't exist if it's true.
+  /// Otherwise, the option id that doesn't exist will be ignored.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub strict_options: bool,
+
+  /// The maximum number of the options that can be selected in a cell. No limit if it's None.
+  #[pb(index = 4, one_of)]
+  #[serde(default)]
+  pub max_select: Option<i32>,
+
+  /// Returns error when the changeset selects more options than the `max_select` if it's true.
+  /// Otherwise, the exceeding options will be dropped.
+  #[pb(index = 5)]
+  #[serde(default)]
+  pub reject_exceeding_selections: bool,
+
+  /// Sorts the cells by the number of the selected options instead of the option names if it's
+  /// true. The cells that select the same number of options are ordered by the option order.
+  #[pb(index = 6)]
+  #[serde(default)]
+  pub sort_by_count: bool,
+
+  /// Joins the names of the selected options when the cell is stringified, for example, for
+  /// exporting. The [DEFAULT_SELECTION_JOIN_SEPARATOR] is used if it's empty.
+  #[pb(index = 7)]
+  #[serde(default)]
+  pub join_separator: String,
 }
 impl_type_option!(MultiSelectTypeOptionPB, FieldType::MultiSelect);
 
@@ -40,23 +71,60 @@ impl TypeOptionCellData for MultiSelectTypeOptionPB {
     self.get_selected_options(cell_data)
   }
 
+  fn convert_cells_to_protobuf(
+    &self,
+    cell_datas: Vec<<Self as TypeOption>::CellData>,
+  ) -> Vec<<Self as TypeOption>::CellProtobufType> {
+    self.get_selected_options_in_batch(cell_datas)
+  }
+
   fn decode_type_option_cell_str(
     &self,
     cell_str: String,
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     SelectOptionIds::from_cell_str(&cell_str)
   }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.is_empty()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      select_option: Some(self.convert_to_protobuf(cell_data)),
+      ..Default::default()
+    }
+  }
+
+  /// The glyph of the first selected option.
+  fn glyph(&self, cell_data: &<Self as TypeOption>::CellData) -> Option<CellGlyph> {
+    self
+      .get_selected_options(cell_data.clone())
+      .select_options
+      .first()
+      .map(SelectOptionPB::glyph)
+  }
 }
 
 impl SelectTypeOptionSharedAction for MultiSelectTypeOptionPB {
   fn number_of_max_options(&self) -> Option<usize> {
-    None
+    self
+      .max_select
+      .and_then(|max_select| usize::try_from(max_select).ok())
   }
 
   fn options(&self) -> &Vec<SelectOptionPB> {
     &self.options
   }
 
+  fn join_separator(&self) -> &str {
+    if self.join_separator.is_empty() {
+      DEFAULT_SELECTION_JOIN_SEPARATOR
+    } else {
+      &self.join_separator
+    }
+  }
+
   fn mut_options(&mut self) -> &mut Vec<SelectOptionPB> {
     &mut self.options
   }
@@ -68,39 +136,87 @@ impl CellDataChangeset for MultiSelectTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let insert_option_ids = changeset
-      .insert_option_ids
-      .into_iter()
-      .filter(|insert_option_id| {
-        self
-          .options
-          .iter()
-          .any(|option| &option.id == insert_option_id)
-      })
-      .collect::<Vec<String>>();
-
-    let select_option_ids = match type_cell_data {
-      None => SelectOptionIds::from(insert_option_ids),
-      Some(type_cell_data) => {
-        let mut select_ids: SelectOptionIds = type_cell_data.cell_str.into();
-        for insert_option_id in insert_option_ids {
-          if !select_ids.contains(&insert_option_id) {
-            select_ids.push(insert_option_id);
-          }
+    let mut select_option_ids = self.merge_changeset(changeset, type_cell_data.as_ref())?;
+    if let Some(max_select) = self.number_of_max_options() {
+      if select_option_ids.len() > max_select {
+        if self.reject_exceeding_selections {
+          return Err(exceeding_max_select_error(max_select));
         }
+        // The existing options come first, so the newly inserted options will be dropped.
+        select_option_ids.truncate(max_select);
+      }
+    }
+    Ok((select_option_ids.to_string(), select_option_ids))
+  }
 
-        for delete_option_id in changeset.delete_option_ids {
-          select_ids.retain(|id| id != &delete_option_id);
-        }
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    self.select_changeset_from_json_value(value)
+  }
+
+  fn validate_changeset(&self, changeset: &<Self as TypeOption>::CellChangeset) -> FlowyResult<()> {
+    // The selected options of the cell are unknown here, so only the inserted options are
+    // checked. The exceeding options are truncated by `apply_changeset` unless they are rejected.
+    if self.reject_exceeding_selections {
+      self.validate_changeset_with_cell(changeset, None)
+    } else {
+      self.merge_changeset(changeset.clone(), None).map(|_| ())
+    }
+  }
+}
 
-        tracing::trace!("Multi-select cell data: {}", select_ids.to_string());
-        select_ids
+impl MultiSelectTypeOptionPB {
+  /// Returns error if applying the changeset to the cell selects more options than the
+  /// `max_select`.
+  pub fn validate_changeset_with_cell(
+    &self,
+    changeset: &SelectOptionCellChangeset,
+    type_cell_data: Option<&TypeCellData>,
+  ) -> FlowyResult<()> {
+    let select_option_ids = self.merge_changeset(changeset.clone(), type_cell_data)?;
+    match self.number_of_max_options() {
+      Some(max_select) if select_option_ids.len() > max_select => {
+        Err(exceeding_max_select_error(max_select))
       },
+      _ => Ok(()),
+    }
+  }
+
+  fn merge_changeset(
+    &self,
+    changeset: SelectOptionCellChangeset,
+    type_cell_data: Option<&TypeCellData>,
+  ) -> FlowyResult<SelectOptionIds> {
+    let insert_option_ids =
+      self.filter_option_ids(changeset.insert_option_ids, self.strict_options)?;
+
+    // The option that is already selected, or inserted twice, is only selected once.
+    let mut select_ids = match type_cell_data {
+      None => SelectOptionIds::new(),
+      Some(type_cell_data) => type_cell_data.cell_str.clone().into(),
     };
-    Ok((select_option_ids.to_string(), select_option_ids))
+    for insert_option_id in insert_option_ids {
+      if !select_ids.contains(&insert_option_id) {
+        select_ids.push(insert_option_id);
+      }
+    }
+
+    for delete_option_id in changeset.delete_option_ids {
+      select_ids.retain(|id| id != &delete_option_id);
+    }
+
+    tracing::trace!("Multi-select cell data: {}", select_ids.to_string());
+    Ok(select_ids)
   }
 }
 
+fn exceeding_max_select_error(max_select: usize) -> FlowyError {
+  let msg = format!("Can't select more than {} options", max_select);
+  FlowyError::new(ErrorCode::InvalidData, &msg)
+}
+
 impl TypeOptionCellDataFilter for MultiSelectTypeOptionPB {
   fn apply_filter(
     &self,
@@ -113,7 +229,31 @@ impl TypeOptionCellDataFilter for MultiSelectTypeOptionPB {
     }
     let selected_options =
       SelectedSelectOptions::from(self.get_selected_options(cell_data.clone()));
-    filter.is_visible(&selected_options, FieldType::MultiSelect)
+    filter.is_visible_in_order(&selected_options, FieldType::MultiSelect, &self.options)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    SelectOptionConditionPB::OptionIs as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
+}
+
+impl MultiSelectTypeOptionPB {
+  /// Returns the positions of the selected options in the option list. The id of the option that
+  /// doesn't exist is skipped.
+  fn option_positions(&self, option_ids: &SelectOptionIds) -> Vec<usize> {
+    let mut positions = option_ids
+      .iter()
+      .flat_map(|id| self.options.iter().position(|option| &option.id == id))
+      .collect::<Vec<usize>>();
+    positions.sort_unstable();
+    positions
   }
 }
 
@@ -123,6 +263,15 @@ impl TypeOptionCellDataCompare for MultiSelectTypeOptionPB {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering {
+    if self.sort_by_count {
+      let positions = self.option_positions(cell_data);
+      let other_positions = self.option_positions(other_cell_data);
+      return positions
+        .len()
+        .cmp(&other_positions.len())
+        .then_with(|| positions.cmp(&other_positions));
+    }
+
     for i in 0..min(cell_data.len(), other_cell_data.len()) {
       let order = match (
         cell_data
@@ -144,6 +293,18 @@ impl TypeOptionCellDataCompare for MultiSelectTypeOptionPB {
     }
     default_order()
   }
+
+  fn group_sort_weight(&self, group_id: &str, _field_rev: &FieldRevision) -> i64 {
+    // The groups are sorted by the order of the options.
+    match self.options.iter().position(|option| option.id == group_id) {
+      None => NO_STATUS_GROUP_SORT_WEIGHT,
+      Some(index) => index as i64,
+    }
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::OptionSet
+  }
 }
 #[derive(Default)]
 pub struct MultiSelectTypeOptionBuilder(MultiSelectTypeOptionPB);
@@ -154,6 +315,27 @@ impl MultiSelectTypeOptionBuilder {
     self.0.options.push(opt);
     self
   }
+
+  pub fn set_strict_options(mut self, strict_options: bool) -> Self {
+    self.0.strict_options = strict_options;
+    self
+  }
+
+  pub fn set_max_select(mut self, max_select: i32, reject_exceeding_selections: bool) -> Self {
+    self.0.max_select = Some(max_select);
+    self.0.reject_exceeding_selections = reject_exceeding_selections;
+    self
+  }
+
+  pub fn set_sort_by_count(mut self, sort_by_count: bool) -> Self {
+    self.0.sort_by_count = sort_by_count;
+    self
+  }
+
+  pub fn set_join_separator(mut self, join_separator: &str) -> Self {
+    self.0.join_separator = join_separator.to_owned();
+    self
+  }
 }
 
 impl TypeOptionBuilder for MultiSelectTypeOptionBuilder {
@@ -168,13 +350,25 @@ impl TypeOptionBuilder for MultiSelectTypeOptionBuilder {
 
 #[cfg(test)]
 mod tests {
-  use crate::entities::FieldType;
-  use crate::services::cell::CellDataChangeset;
+  use crate::entities::{FieldType, SelectOptionConditionPB, SelectOptionFilterPB};
+  use crate::services::cell::{
+    insert_number_cell, insert_select_option_cell, insert_text_cell, CellDataChangeset,
+    FromCellChangesetString, ToCellChangesetString, TypeCellData,
+  };
   use crate::services::field::type_options::selection_type_option::*;
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, filter_is_empty, ingest_json_value,
+    spreadsheet_value,
+  };
   use crate::services::field::{
-    CheckboxTypeOptionBuilder, FieldBuilder, TypeOptionBuilder, TypeOptionTransform,
+    is_transform_lossless, public_cache_key, transform_type_option, CheckboxTypeOptionBuilder,
+    ComparableKind, FieldBuilder, SpreadsheetValue, TypeOptionBuilder, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter, TypeOptionCellExt, TypeOptionTransform,
   };
   use crate::services::field::{MultiSelectTypeOptionBuilder, MultiSelectTypeOptionPB};
+  use database_model::{CellRevision, FieldRevision, TypeOptionDataDeserializer};
+  use serde_json::json;
+  use std::cmp::Ordering;
 
   #[test]
   fn multi_select_transform_with_checkbox_type_option_test() {
@@ -309,4 +503,826 @@ mod tests {
     let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
     assert!(select_option_ids.is_empty());
   }
+
+  #[test]
+  fn multi_select_insert_deleted_option_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let multi_select = MultiSelectTypeOptionBuilder::default().add_option(google.clone());
+    let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+    let type_option = MultiSelectTypeOptionPB::from(&field_rev);
+
+    let option_ids = vec![google.id.clone(), facebook.id];
+    let changeset = SelectOptionCellChangeset::from_insert_options(option_ids);
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![google.id]);
+  }
+
+  #[test]
+  fn multi_select_strict_options_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .set_strict_options(true);
+    let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+    let type_option = MultiSelectTypeOptionPB::from(&field_rev);
+
+    let option_ids = vec![google.id, facebook.id];
+    let changeset = SelectOptionCellChangeset::from_insert_options(option_ids);
+    assert!(type_option.validate_changeset(&changeset).is_err());
+    assert!(type_option.apply_changeset(changeset, None).is_err());
+  }
+
+  #[test]
+  fn multi_select_truncate_exceeding_selections_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let twitter = SelectOptionPB::new("Twitter");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone())
+      .add_option(twitter.clone())
+      .set_max_select(2, false);
+    let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+    let type_option = MultiSelectTypeOptionPB::from(&field_rev);
+
+    let option_ids = vec![google.id.clone(), facebook.id.clone(), twitter.id];
+    let changeset = SelectOptionCellChangeset::from_insert_options(option_ids);
+    assert!(type_option
+      .validate_changeset_with_cell(&changeset, None)
+      .is_err());
+    assert!(type_option.validate_changeset(&changeset).is_ok());
+
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![google.id, facebook.id]);
+  }
+
+  #[test]
+  fn multi_select_reject_exceeding_selections_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone())
+      .set_max_select(1, true);
+    let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+    let type_option = MultiSelectTypeOptionPB::from(&field_rev);
+
+    let changeset =
+      SelectOptionCellChangeset::from_insert_options(vec![google.id.clone(), facebook.id.clone()]);
+    assert!(type_option.validate_changeset(&changeset).is_err());
+
+    let changeset = SelectOptionCellChangeset::from_insert_option_id(&google.id);
+    assert!(type_option.validate_changeset(&changeset).is_ok());
+    assert!(type_option
+      .validate_changeset_with_cell(&changeset, None)
+      .is_ok());
+    let cell_str = type_option.apply_changeset(changeset, None).unwrap().0;
+
+    // Selecting one more option exceeds the max_select
+    let type_cell_data = TypeCellData::new(cell_str, FieldType::MultiSelect);
+    let changeset = SelectOptionCellChangeset::from_insert_option_id(&facebook.id);
+    assert!(type_option
+      .validate_changeset_with_cell(&changeset, Some(&type_cell_data))
+      .is_err());
+    assert!(type_option
+      .apply_changeset(changeset, Some(type_cell_data))
+      .is_err());
+  }
+
+  #[test]
+  fn multi_select_sort_by_count_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let twitter = SelectOptionPB::new("Twitter");
+    let type_option = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone())
+      .add_option(twitter.clone())
+      .set_sort_by_count(true)
+      .0;
+
+    let one = SelectOptionIds::from(vec![twitter.id.clone()]);
+    let two = SelectOptionIds::from(vec![facebook.id.clone(), twitter.id.clone()]);
+    let three = SelectOptionIds::from(vec![
+      twitter.id.clone(),
+      google.id.clone(),
+      facebook.id.clone(),
+    ]);
+    assert_eq!(type_option.apply_cmp(&one, &two), Ordering::Less);
+    assert_eq!(type_option.apply_cmp(&three, &two), Ordering::Greater);
+    assert_eq!(type_option.apply_cmp(&three, &three), Ordering::Equal);
+
+    // The cells with the same number of options are ordered by the option order.
+    let other_two = SelectOptionIds::from(vec![google.id.clone(), twitter.id.clone()]);
+    assert_eq!(type_option.apply_cmp(&other_two, &two), Ordering::Less);
+  }
+
+  #[test]
+  fn select_option_name_escape_test() {
+    for name in ["Red, bright", r"C:\Users", r"a\,b", "Blue"] {
+      let escaped = escape_select_option_name(name);
+      assert_eq!(split_select_option_names(&escaped), vec![name.to_owned()]);
+    }
+
+    let names = ["Red, bright", r"back\slash", "Blue"]
+      .iter()
+      .map(|name| escape_select_option_name(name))
+      .collect::<Vec<String>>()
+      .join(SELECTION_IDS_SEPARATOR);
+    assert_eq!(names, r"Red\, bright,back\\slash,Blue");
+    assert_eq!(
+      split_select_option_names(&names),
+      vec!["Red, bright", r"back\slash", "Blue"]
+    );
+    assert_eq!(
+      split_select_option_names("Red, bright"),
+      vec!["Red", "bright"]
+    );
+    assert_eq!(split_select_option_names(r"Red\"), vec![r"Red\"]);
+    assert!(split_select_option_names(" , ").is_empty());
+  }
+
+  #[test]
+  fn multi_select_plain_text_changeset_test() {
+    let red = SelectOptionPB::new("Red, bright");
+    let blue = SelectOptionPB::new("Blue");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(red.clone())
+      .add_option(blue.clone());
+    let field_rev = FieldBuilder::new(multi_select).name("Color").build();
+    let type_option = MultiSelectTypeOptionPB::from(&field_rev);
+
+    let changeset =
+      SelectOptionCellChangeset::from_changeset(r"Red\, bright, Blue".to_owned()).unwrap();
+    assert_eq!(changeset.insert_option_ids, vec!["Red, bright", "Blue"]);
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![red.id.clone(), blue.id.clone()]);
+
+    // Without escaping, the name is split into two names that don't exist.
+    let changeset = SelectOptionCellChangeset::from_changeset("Red, bright".to_owned()).unwrap();
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert!(select_option_ids.is_empty());
+
+    // The JSON changeset still works.
+    let changeset = SelectOptionCellChangeset::from_insert_option_id(&blue.id);
+    let changeset =
+      SelectOptionCellChangeset::from_changeset(changeset.to_cell_changeset_str()).unwrap();
+    assert_eq!(changeset.insert_option_ids, vec![blue.id]);
+  }
+
+  #[test]
+  fn multi_select_paste_adjacent_duplicates_test() {
+    let a = SelectOptionPB::new("A");
+    let b = SelectOptionPB::new("B");
+    let type_option = MultiSelectTypeOptionBuilder::default()
+      .add_option(a.clone())
+      .add_option(b.clone())
+      .0;
+
+    let changeset = SelectOptionCellChangeset::from_changeset("A,A,B,A".to_owned()).unwrap();
+    assert_eq!(changeset.insert_option_ids, vec!["A", "B", "A"]);
+
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![a.id, b.id]);
+  }
+
+  #[test]
+  fn multi_select_limited_to_one_option_from_text_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let type_option = MultiSelectTypeOptionPB {
+      options: vec![google.clone(), facebook.clone()],
+      max_select: Some(1),
+      ..Default::default()
+    };
+    // The text is still split into the names of the options, unlike the single select.
+    let option_ids = type_option.option_ids_from_text("Google, Facebook");
+    assert_eq!(&*option_ids, &vec![google.id, facebook.id]);
+  }
+
+  #[test]
+  fn multi_select_limited_to_one_option_json_value_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let type_option = MultiSelectTypeOptionPB {
+      options: vec![google.clone(), facebook.clone()],
+      max_select: Some(1),
+      ..Default::default()
+    };
+    let changeset = type_option
+      .select_changeset_from_json_value(&json!("Google, Facebook"))
+      .unwrap();
+    assert_eq!(changeset.insert_option_ids, vec![google.id, facebook.id]);
+  }
+
+  #[test]
+  fn multi_select_ingest_json_value_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone());
+    let field_rev = FieldBuilder::new(multi_select).build();
+    let both = format!("{},{}", google.id, facebook.id);
+
+    assert_eq!(
+      ingest_json_value(&field_rev, json!(["Google", "Facebook"])).unwrap(),
+      both
+    );
+    assert_eq!(
+      ingest_json_value(&field_rev, json!([google.id, "Facebook"])).unwrap(),
+      both
+    );
+    assert_eq!(
+      ingest_json_value(&field_rev, json!("Google, Facebook")).unwrap(),
+      both
+    );
+    assert_eq!(
+      ingest_json_value(&field_rev, json!("Google")).unwrap(),
+      google.id
+    );
+    assert_eq!(ingest_json_value(&field_rev, json!([])).unwrap(), "");
+
+    assert!(ingest_json_value(&field_rev, json!(["Google", "Twitter"])).is_err());
+    assert!(ingest_json_value(&field_rev, json!([1, 2])).is_err());
+    assert!(ingest_json_value(&field_rev, json!({ "name": "Google" })).is_err());
+  }
+
+  #[test]
+  fn multi_select_set_filter_test() {
+    let rust = SelectOptionPB::new("Rust");
+    let dart = SelectOptionPB::new("Dart");
+    let go = SelectOptionPB::new("Go");
+    let type_option = MultiSelectTypeOptionBuilder::default()
+      .add_option(rust.clone())
+      .add_option(dart.clone())
+      .add_option(go.clone())
+      .0;
+    let is_visible = |condition: SelectOptionConditionPB,
+                      filter_options: &[&SelectOptionPB],
+                      cell_options: &[&SelectOptionPB]| {
+      let filter = SelectOptionFilterPB {
+        condition,
+        option_ids: filter_options
+          .iter()
+          .map(|option| option.id.clone())
+          .collect(),
+      };
+      let cell_data = SelectOptionIds::from(
+        cell_options
+          .iter()
+          .map(|option| option.id.clone())
+          .collect::<Vec<_>>(),
+      );
+      type_option.apply_filter(&filter, &FieldType::MultiSelect, &cell_data)
+    };
+
+    // Partial overlap
+    let filter_options = [&rust, &dart];
+    assert!(!is_visible(
+      SelectOptionConditionPB::OptionContainsAll,
+      &filter_options,
+      &[&rust, &go]
+    ));
+    assert!(is_visible(
+      SelectOptionConditionPB::OptionContainsAny,
+      &filter_options,
+      &[&rust, &go]
+    ));
+    assert!(!is_visible(
+      SelectOptionConditionPB::OptionIsExactly,
+      &filter_options,
+      &[&rust, &go]
+    ));
+    assert!(!is_visible(
+      SelectOptionConditionPB::OptionContainsAny,
+      &filter_options,
+      &[&go]
+    ));
+
+    // The superset contains all but is not exactly the options.
+    let cell_options = [&go, &dart, &rust];
+    assert!(is_visible(
+      SelectOptionConditionPB::OptionContainsAll,
+      &filter_options,
+      &cell_options
+    ));
+    assert!(!is_visible(
+      SelectOptionConditionPB::OptionIsExactly,
+      &filter_options,
+      &cell_options
+    ));
+
+    // Exact match with different ordering
+    assert!(is_visible(
+      SelectOptionConditionPB::OptionIsExactly,
+      &filter_options,
+      &[&dart, &rust]
+    ));
+    assert!(is_visible(
+      SelectOptionConditionPB::OptionContainsAll,
+      &filter_options,
+      &[&dart, &rust]
+    ));
+
+    // Empty cells
+    for condition in [
+      SelectOptionConditionPB::OptionContainsAll,
+      SelectOptionConditionPB::OptionContainsAny,
+      SelectOptionConditionPB::OptionIsExactly,
+    ] {
+      assert!(
+        !is_visible(condition.clone(), &filter_options, &[]),
+        "{:?}",
+        condition
+      );
+    }
+    assert!(is_visible(SelectOptionConditionPB::OptionIsEmpty, &[], &[]));
+    assert!(!is_visible(
+      SelectOptionConditionPB::OptionIsEmpty,
+      &[],
+      &[&rust]
+    ));
+  }
+
+  #[test]
+  fn multi_select_join_separator_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let builder = || {
+      MultiSelectTypeOptionBuilder::default()
+        .add_option(google.clone())
+        .add_option(facebook.clone())
+    };
+    let cell_str = format!("{},{}", google.id, facebook.id);
+    let stringify = |field_rev: &FieldRevision| {
+      cell_data_handler(field_rev, &FieldType::MultiSelect).stringify_cell_str(
+        cell_str.clone(),
+        &FieldType::MultiSelect,
+        field_rev,
+      )
+    };
+
+    let field_rev = FieldBuilder::new(builder()).build();
+    assert_eq!(stringify(&field_rev), "Google, Facebook");
+
+    let field_rev = FieldBuilder::new(builder().set_join_separator(" | ")).build();
+    assert_eq!(stringify(&field_rev), "Google | Facebook");
+  }
+
+  #[test]
+  fn multi_select_stringify_paste_round_trip_test() {
+    let red = SelectOptionPB::new("Red, bright");
+    let blue = SelectOptionPB::new(r"Blue\Green");
+    let builder = || {
+      MultiSelectTypeOptionBuilder::default()
+        .add_option(red.clone())
+        .add_option(blue.clone())
+    };
+    let cell_str = format!("{},{}", red.id, blue.id);
+    let stringify = |field_rev: &FieldRevision| {
+      cell_data_handler(field_rev, &FieldType::MultiSelect).stringify_cell_str(
+        cell_str.clone(),
+        &FieldType::MultiSelect,
+        field_rev,
+      )
+    };
+
+    // The names are escaped by the default separator, so the copied text is pasted back as the
+    // same options.
+    let field_rev = FieldBuilder::new(builder()).build();
+    let text = stringify(&field_rev);
+    assert_eq!(text, r"Red\, bright, Blue\\Green");
+    let changeset = SelectOptionCellChangeset::from_changeset(text).unwrap();
+    let select_option_ids = MultiSelectTypeOptionPB::from(&field_rev)
+      .apply_changeset(changeset, None)
+      .unwrap()
+      .1;
+    assert_eq!(&*select_option_ids, &vec![red.id.clone(), blue.id.clone()]);
+
+    // The names are kept as they are with the custom separator.
+    let field_rev = FieldBuilder::new(builder().set_join_separator(" | ")).build();
+    assert_eq!(stringify(&field_rev), r"Red, bright | Blue\Green");
+  }
+
+  #[test]
+  fn select_content_weight_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone());
+    let multi_select_field_rev = FieldBuilder::new(multi_select).build();
+    let multi_select_cell = insert_select_option_cell(
+      vec![google.id.clone(), facebook.id.clone()],
+      &multi_select_field_rev,
+    );
+    let multi_select_weight =
+      TypeOptionCellExt::new_with_cell_data_cache(&multi_select_field_rev, None)
+        .content_weight(&multi_select_cell);
+
+    let single_select = SingleSelectTypeOptionBuilder::default().add_option(google.clone());
+    let single_select_field_rev = FieldBuilder::new(single_select).build();
+    let single_select_cell = insert_select_option_cell(vec![google.id], &single_select_field_rev);
+    let single_select_weight =
+      TypeOptionCellExt::new_with_cell_data_cache(&single_select_field_rev, None)
+        .content_weight(&single_select_cell);
+
+    assert!(multi_select_weight > single_select_weight);
+    let empty_cell = insert_select_option_cell(vec![], &single_select_field_rev);
+    assert_eq!(
+      TypeOptionCellExt::new_with_cell_data_cache(&single_select_field_rev, None)
+        .content_weight(&empty_cell),
+      0
+    );
+  }
+
+  #[test]
+  fn select_mode_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone());
+    let field_rev = FieldBuilder::new(multi_select).build();
+    let cells = vec![
+      insert_select_option_cell(vec![google.id.clone(), facebook.id.clone()], &field_rev),
+      insert_select_option_cell(vec![facebook.id.clone()], &field_rev),
+      insert_select_option_cell(vec![google.id.clone()], &field_rev),
+    ];
+    // Both of the options are selected twice, the google option is seen first.
+    let mode = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None)
+      .mode(&cells)
+      .and_then(|cell_data| cell_data.unbox_or_none::<SelectOptionIds>())
+      .unwrap();
+    assert_eq!(mode.into_inner(), vec![google.id]);
+  }
+
+  #[test]
+  fn multi_select_count_distinct_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let twitter = SelectOptionPB::new("Twitter");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone())
+      .add_option(twitter.clone());
+    let field_rev = FieldBuilder::new(multi_select).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cells = vec![
+      insert_select_option_cell(vec![google.id.clone(), facebook.id.clone()], &field_rev),
+      // The same set of the options in a different order.
+      insert_select_option_cell(vec![facebook.id.clone(), google.id.clone()], &field_rev),
+      insert_select_option_cell(vec![google.id.clone()], &field_rev),
+      insert_select_option_cell(vec![], &field_rev),
+      insert_select_option_cell(vec![twitter.id.clone()], &field_rev),
+    ];
+    assert_eq!(ext.count_distinct(&cells, false), Some(3));
+    assert_eq!(ext.count_distinct(&cells, true), Some(4));
+  }
+
+  #[test]
+  fn select_option_handle_cells_str_test() {
+    let options = (0..100)
+      .map(|i| SelectOptionPB::new(&format!("Option {}", i)))
+      .collect::<Vec<_>>();
+    let mut multi_select = MultiSelectTypeOptionBuilder::default();
+    for option in options.iter() {
+      multi_select = multi_select.add_option(option.clone());
+    }
+    let field_rev = FieldBuilder::new(multi_select).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::MultiSelect);
+
+    let mut cells = (0..1000)
+      .map(|i| {
+        let option_ids = vec![
+          options[i % 100].id.clone(),
+          options[(i * 7) % 100].id.clone(),
+        ];
+        (
+          format!("row_{}", i),
+          insert_select_option_cell(option_ids, &field_rev),
+        )
+      })
+      .collect::<Vec<(String, CellRevision)>>();
+    cells.insert(
+      1,
+      (
+        "invalid_row".to_owned(),
+        CellRevision::new("invalid".to_owned()),
+      ),
+    );
+
+    let results = handler.handle_cells_str(&cells, &FieldType::MultiSelect, &field_rev);
+    assert_eq!(results.len(), cells.len());
+    for ((row_id, cell_rev), (result_row_id, result)) in cells.iter().zip(results) {
+      assert_eq!(row_id, &result_row_id);
+      if row_id == "invalid_row" {
+        assert!(result.is_err());
+        continue;
+      }
+      let type_cell_data = TypeCellData::try_from(cell_rev).unwrap();
+      let expected = handler
+        .handle_cell_str(type_cell_data.cell_str, &FieldType::MultiSelect, &field_rev)
+        .unwrap();
+      assert_eq!(result.unwrap().0, expected.0);
+    }
+
+    // The multi-select cells of the single select field are transformed the same way as a single
+    // cell, so only their first option is kept.
+    let mut single_select = SingleSelectTypeOptionBuilder::default();
+    for option in options.iter() {
+      single_select = single_select.add_option(option.clone());
+    }
+    let single_field_rev = FieldBuilder::new(single_select).build();
+    let single_handler = cell_data_handler(&single_field_rev, &FieldType::SingleSelect);
+    let cells = cells
+      .into_iter()
+      .filter(|(row_id, _)| row_id != "invalid_row")
+      .collect::<Vec<(String, CellRevision)>>();
+    let results =
+      single_handler.handle_cells_str(&cells, &FieldType::MultiSelect, &single_field_rev);
+    for ((_, cell_rev), (_, result)) in cells.iter().zip(results) {
+      let type_cell_data = TypeCellData::try_from(cell_rev).unwrap();
+      let expected = single_handler
+        .handle_cell_str(
+          type_cell_data.cell_str,
+          &FieldType::MultiSelect,
+          &single_field_rev,
+        )
+        .unwrap();
+      let result = result.unwrap();
+      assert_eq!(result.0, expected.0);
+      let cell_data = SelectOptionCellDataPB::try_from(result.0.as_ref()).unwrap();
+      assert_eq!(cell_data.select_options.len(), 1);
+    }
+  }
+
+  #[test]
+  fn select_option_numeric_value_test() {
+    let option = |name: &str, weight: Option<i32>| SelectOptionPB {
+      weight,
+      ..SelectOptionPB::new(name)
+    };
+    let low = option("Low", Some(1));
+    let high = option("High", Some(2));
+    let unscored = option("Unscored", None);
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(low.clone())
+      .add_option(high.clone())
+      .add_option(unscored.clone());
+    let field_rev = FieldBuilder::new(multi_select).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cell = |option_ids: Vec<String>| insert_select_option_cell(option_ids, &field_rev);
+
+    let all = cell(vec![low.id.clone(), high.id.clone(), unscored.id.clone()]);
+    assert_eq!(ext.numeric_value(&all), Some(3.0));
+    assert_eq!(ext.numeric_value(&cell(vec![high.id.clone()])), Some(2.0));
+    assert_eq!(ext.numeric_value(&cell(vec![unscored.id.clone()])), None);
+    assert_eq!(ext.numeric_value(&cell(vec![])), None);
+
+    let single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(low.clone())
+      .add_option(high.clone());
+    let field_rev = FieldBuilder::new(single_select).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cell = insert_select_option_cell(vec![high.id.clone()], &field_rev);
+    assert_eq!(ext.numeric_value(&cell), Some(2.0));
+
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    assert_eq!(ext.numeric_value(&insert_number_cell(1, &field_rev)), None);
+  }
+
+  #[test]
+  fn multi_select_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::MultiSelect).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::MultiSelect);
+    assert_eq!(handler.comparable_kind(), ComparableKind::OptionSet);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::MultiSelect,
+      &FieldType::MultiSelect
+    ));
+    assert!(!is_transform_lossless(
+      &FieldType::MultiSelect,
+      &FieldType::SingleSelect
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = SelectOptionFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, SelectOptionConditionPB::OptionIs);
+    assert!(filter.option_ids.is_empty());
+
+    let cell_rev = insert_select_option_cell(vec![], &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("".to_owned())
+    );
+    assert!(cell_value(&field_rev, &cell_rev).select_option.is_some());
+  }
+
+  #[test]
+  fn multi_select_is_empty_filter_test() {
+    let select_filter = |condition: SelectOptionConditionPB| SelectOptionFilterPB {
+      condition,
+      option_ids: vec![],
+    };
+    let option = SelectOptionPB::new("A");
+    let field_rev =
+      FieldBuilder::new(MultiSelectTypeOptionBuilder::default().add_option(option.clone())).build();
+    for (cell_rev, expected) in [
+      (None, true),
+      (Some(insert_select_option_cell(vec![], &field_rev)), true),
+      (
+        Some(insert_select_option_cell(vec![option.id], &field_rev)),
+        false,
+      ),
+    ] {
+      let is_empty = filter_is_empty(
+        &field_rev,
+        select_filter,
+        SelectOptionConditionPB::OptionIsEmpty,
+        SelectOptionConditionPB::OptionIsNotEmpty,
+        cell_rev.as_ref(),
+      );
+      assert_eq!(is_empty, expected);
+    }
+  }
+
+  #[test]
+  fn storage_bytes_test() {
+    let text_field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let text_cell = insert_text_cell("AppFlowy".to_owned(), &text_field_rev);
+    let text_bytes =
+      TypeOptionCellExt::new_with_cell_data_cache(&text_field_rev, None).storage_bytes(&text_cell);
+    assert_eq!(
+      text_bytes,
+      text_field_rev.id.len() + 3 + serde_json::to_string(&text_cell).unwrap().len()
+    );
+
+    let options = (0..20)
+      .map(|i| SelectOptionPB::new(&format!("Tag {}", i)))
+      .collect::<Vec<SelectOptionPB>>();
+    let multi_select = options.iter().fold(
+      MultiSelectTypeOptionBuilder::default(),
+      |builder, option| builder.add_option(option.clone()),
+    );
+    let multi_select_field_rev = FieldBuilder::new(multi_select).build();
+    let multi_select_cell = insert_select_option_cell(
+      options.iter().map(|option| option.id.clone()).collect(),
+      &multi_select_field_rev,
+    );
+    let multi_select_bytes =
+      TypeOptionCellExt::new_with_cell_data_cache(&multi_select_field_rev, None)
+        .storage_bytes(&multi_select_cell);
+    assert!(multi_select_bytes > text_bytes * 2);
+  }
+
+  #[test]
+  fn select_join_separator_cache_key_test() {
+    let field_rev = FieldBuilder::new(MultiSelectTypeOptionBuilder::default()).build();
+    let key = public_cache_key(&field_rev, &FieldType::MultiSelect, "a,b");
+
+    let field_rev =
+      FieldBuilder::new(MultiSelectTypeOptionBuilder::default().set_join_separator("|")).build();
+    let pipe_key = public_cache_key(&field_rev, &FieldType::MultiSelect, "a,b");
+    assert_ne!(key, pipe_key);
+  }
+
+  #[test]
+  fn audit_column_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone());
+    let old_field_rev = FieldBuilder::new(multi_select).build();
+    let cells = vec![
+      (
+        "row_1".to_owned(),
+        insert_select_option_cell(vec![google.id.clone()], &old_field_rev),
+      ),
+      ("row_2".to_owned(), CellRevision::new("{".to_owned())),
+      (
+        "row_3".to_owned(),
+        insert_select_option_cell(vec![google.id.clone(), facebook.id.clone()], &old_field_rev),
+      ),
+    ];
+
+    // The Facebook option was deleted after the cells were inserted.
+    let multi_select = MultiSelectTypeOptionBuilder::default().add_option(google.clone());
+    let field_rev = FieldBuilder::new(multi_select).build();
+    let report = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None).audit_column(&cells);
+    assert!(!report.is_healthy());
+    assert_eq!(report.undecodable_rows, vec!["row_2".to_owned()]);
+    assert_eq!(
+      report.dangling_option_rows,
+      vec![("row_3".to_owned(), vec![facebook.id])]
+    );
+    assert!(report.non_canonical_rows.is_empty());
+  }
+
+  #[test]
+  fn select_option_transform_round_trip_test() {
+    let red = SelectOptionPB::with_color("Red", SelectOptionColorPB::Pink);
+    let blue = SelectOptionPB::with_color("Blue", SelectOptionColorPB::Blue);
+    let single_select_data = SingleSelectTypeOptionBuilder::default()
+      .add_option(red.clone())
+      .add_option(blue.clone())
+      .serializer()
+      .json_str();
+    // The multi-select type option that was saved before has a stale option with the same name.
+    let stale_multi_select_data = MultiSelectTypeOptionBuilder::default()
+      .add_option(SelectOptionPB::new("Red"))
+      .serializer()
+      .json_str();
+    let single_field_rev = FieldBuilder::new(SingleSelectTypeOptionBuilder::from_json_str(
+      &single_select_data,
+    ))
+    .build();
+
+    // Single select to multi-select
+    let multi_select_data = transform_type_option(
+      &stale_multi_select_data,
+      &FieldType::MultiSelect,
+      Some(single_select_data.clone()),
+      FieldType::SingleSelect,
+    );
+    let options = MultiSelectTypeOptionPB::from_json_str(&multi_select_data).options;
+    assert!(options.contains(&red));
+    assert!(options.contains(&blue));
+    // The stale "Red" option is merged into the imported one, so the names stay unique.
+    let names = options
+      .iter()
+      .map(|option| option.name.as_str())
+      .collect::<std::collections::HashSet<_>>();
+    assert_eq!(names.len(), options.len());
+
+    let multi_field_rev = FieldBuilder::new(MultiSelectTypeOptionBuilder::from_json_str(
+      &multi_select_data,
+    ))
+    .build();
+    let handler = cell_data_handler(&multi_field_rev, &FieldType::MultiSelect);
+    let cell_rev = insert_select_option_cell(vec![red.id.clone()], &single_field_rev);
+    let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+    let option_ids = handler
+      .get_cell_data(
+        type_cell_data.cell_str.clone(),
+        &type_cell_data.field_type,
+        &multi_field_rev,
+      )
+      .unwrap()
+      .unbox_or_none::<SelectOptionIds>()
+      .unwrap();
+    assert_eq!(option_ids.to_vec(), vec![red.id.clone()]);
+    assert_eq!(
+      handler.stringify_cell_str(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &multi_field_rev
+      ),
+      "Red"
+    );
+
+    // Multi-select back to single select, the first selected option is kept.
+    let single_select_data = transform_type_option(
+      &single_select_data,
+      &FieldType::SingleSelect,
+      Some(multi_select_data),
+      FieldType::MultiSelect,
+    );
+    let options = SingleSelectTypeOptionPB::from_json_str(&single_select_data).options;
+    assert!(options.contains(&red));
+    assert!(options.contains(&blue));
+
+    let single_field_rev = FieldBuilder::new(SingleSelectTypeOptionBuilder::from_json_str(
+      &single_select_data,
+    ))
+    .build();
+    let handler = cell_data_handler(&single_field_rev, &FieldType::SingleSelect);
+    let cell_rev =
+      insert_select_option_cell(vec![blue.id.clone(), red.id.clone()], &multi_field_rev);
+    let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+    let option_ids = handler
+      .get_cell_data(
+        type_cell_data.cell_str.clone(),
+        &type_cell_data.field_type,
+        &single_field_rev,
+      )
+      .unwrap()
+      .unbox_or_none::<SelectOptionIds>()
+      .unwrap();
+    assert_eq!(option_ids.to_vec(), vec![blue.id.clone()]);
+    assert_eq!(
+      handler.stringify_cell_str(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &single_field_rev
+      ),
+      "Blue"
+    );
+  }
 }
@@ -1,7 +1,8 @@
 #![allow(clippy::needless_collect)]
 
 use crate::entities::{FieldType, SelectOptionConditionPB, SelectOptionFilterPB};
-use crate::services::field::SelectedSelectOptions;
+use crate::services::field::{EmptyFilterCondition, SelectOptionPB, SelectedSelectOptions};
+use std::collections::HashSet;
 
 impl SelectOptionFilterPB {
   pub fn is_visible(
@@ -79,8 +80,76 @@ impl SelectOptionFilterPB {
       },
       SelectOptionConditionPB::OptionIsEmpty => selected_option_ids.is_empty(),
       SelectOptionConditionPB::OptionIsNotEmpty => !selected_option_ids.is_empty(),
+      // The range is resolved by the order of the options, see [Self::is_visible_in_order].
+      SelectOptionConditionPB::OptionInRange => true,
+      SelectOptionConditionPB::OptionContainsAll
+      | SelectOptionConditionPB::OptionContainsAny
+      | SelectOptionConditionPB::OptionIsExactly => self.is_visible_in_set(selected_options),
     }
   }
+
+  /// Compares the set of the selected option ids with the set of the filter's option ids, so the
+  /// order and the duplicates of the ids don't matter. The filter without any option id matches
+  /// every cell.
+  fn is_visible_in_set(&self, selected_options: &SelectedSelectOptions) -> bool {
+    if self.option_ids.is_empty() {
+      return true;
+    }
+
+    let selected_option_ids = selected_options
+      .options
+      .iter()
+      .map(|option| option.id.as_str())
+      .collect::<HashSet<&str>>();
+    let option_ids = self
+      .option_ids
+      .iter()
+      .map(|id| id.as_str())
+      .collect::<HashSet<&str>>();
+    match self.condition {
+      SelectOptionConditionPB::OptionContainsAll => option_ids.is_subset(&selected_option_ids),
+      SelectOptionConditionPB::OptionContainsAny => !option_ids.is_disjoint(&selected_option_ids),
+      SelectOptionConditionPB::OptionIsExactly => option_ids == selected_option_ids,
+      _ => false,
+    }
+  }
+
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      SelectOptionConditionPB::OptionIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      SelectOptionConditionPB::OptionIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
+  /// Same as [Self::is_visible], but the [SelectOptionConditionPB::OptionInRange] is resolved by
+  /// the position of the option in the `options`. The cell is visible if any of its selected
+  /// options falls in the range. The filter that refers to an unknown option matches every cell.
+  pub fn is_visible_in_order(
+    &self,
+    selected_options: &SelectedSelectOptions,
+    field_type: FieldType,
+    options: &[SelectOptionPB],
+  ) -> bool {
+    if self.condition != SelectOptionConditionPB::OptionInRange {
+      return self.is_visible(selected_options, field_type);
+    }
+
+    let position = |id: &String| options.iter().position(|option| &option.id == id);
+    let (from, to) = match self.option_ids.as_slice() {
+      [from, to] => match (position(from), position(to)) {
+        (Some(from), Some(to)) => (from.min(to), from.max(to)),
+        _ => return true,
+      },
+      _ => return true,
+    };
+
+    selected_options
+      .options
+      .iter()
+      .flat_map(|option| position(&option.id))
+      .any(|index| from <= index && index <= to)
+  }
 }
 
 #[cfg(test)]
@@ -89,6 +158,68 @@ mod tests {
   use crate::entities::{FieldType, SelectOptionConditionPB, SelectOptionFilterPB};
   use crate::services::field::selection_type_option::{SelectOptionPB, SelectedSelectOptions};
 
+  #[test]
+  fn single_select_option_filter_in_range_test() {
+    let todo = SelectOptionPB::new("Todo");
+    let doing = SelectOptionPB::new("Doing");
+    let review = SelectOptionPB::new("Review");
+    let done = SelectOptionPB::new("Done");
+    let options = vec![todo.clone(), doing.clone(), review.clone(), done.clone()];
+
+    // Status is at least Doing.
+    let filter = SelectOptionFilterPB::in_range(&doing.id, &done.id);
+    for (options_of_cell, is_visible) in vec![
+      (vec![todo.clone()], false),
+      (vec![doing.clone()], true),
+      (vec![review.clone()], true),
+      (vec![done.clone()], true),
+      (vec![], false),
+    ] {
+      assert_eq!(
+        filter.is_visible_in_order(
+          &SelectedSelectOptions {
+            options: options_of_cell
+          },
+          FieldType::SingleSelect,
+          &options
+        ),
+        is_visible
+      );
+    }
+
+    // The bounds can be given in any order.
+    let filter = SelectOptionFilterPB::in_range(&review.id, &todo.id);
+    for (options_of_cell, is_visible) in vec![
+      (vec![todo.clone()], true),
+      (vec![review.clone()], true),
+      (vec![done.clone()], false),
+    ] {
+      assert_eq!(
+        filter.is_visible_in_order(
+          &SelectedSelectOptions {
+            options: options_of_cell
+          },
+          FieldType::SingleSelect,
+          &options
+        ),
+        is_visible
+      );
+    }
+
+    // The unknown bound matches every cell.
+    let filter = SelectOptionFilterPB::in_range(&doing.id, "unknown");
+    assert_eq!(
+      filter.is_visible_in_order(
+        &SelectedSelectOptions {
+          options: vec![todo.clone()]
+        },
+        FieldType::SingleSelect,
+        &options
+      ),
+      true
+    );
+  }
+
   #[test]
   fn select_option_filter_is_empty_test() {
     let option = SelectOptionPB::new("A");
@@ -1,24 +1,70 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{CellIdPB, CellIdParams, FieldType};
 use crate::services::cell::{
-  CellDataDecoder, CellProtobufBlobParser, DecodedCellData, FromCellChangesetString,
-  FromCellString, ToCellChangesetString,
+  invalid_json_value_error, CellDataDecoder, CellProtobufBlobParser, DecodedCellData,
+  FromCellChangesetString, FromCellString, ToCellChangesetString,
 };
 
 use crate::services::field::selection_type_option::type_option_transform::SelectOptionTypeOptionTransformHelper;
 use crate::services::field::{
-  CheckboxCellData, ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SingleSelectTypeOptionPB,
-  TypeOption, TypeOptionCellData, TypeOptionTransform,
+  plain_text, CellGlyph, CheckboxCellData, ChecklistTypeOptionPB, MultiSelectTypeOptionPB,
+  SingleSelectTypeOptionPB, TypeOption, TypeOptionCellData, TypeOptionTransform, OTHER_OPTION_NAME,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataSerializer};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use flowy_error::{internal_error, ErrorCode, FlowyResult};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 pub const SELECTION_IDS_SEPARATOR: &str = ",";
 
+/// Joins the names of the selected options when the cell is stringified, unless the type option
+/// configures its own `join_separator`.
+pub const DEFAULT_SELECTION_JOIN_SEPARATOR: &str = ", ";
+
+/// Escapes the [SELECTION_IDS_SEPARATOR] and itself in the option names of the plain text
+/// changeset, for example, `Red\, bright` is the name of one option.
+pub const SELECTION_NAME_ESCAPE: char = '\\';
+
+/// Escapes the separator and the escape character in the option name, so that it can be joined
+/// with the other names by the [SELECTION_IDS_SEPARATOR].
+pub fn escape_select_option_name(name: &str) -> String {
+  let mut escaped = String::with_capacity(name.len());
+  for c in name.chars() {
+    if c == SELECTION_NAME_ESCAPE || SELECTION_IDS_SEPARATOR.starts_with(c) {
+      escaped.push(SELECTION_NAME_ESCAPE);
+    }
+    escaped.push(c);
+  }
+  escaped
+}
+
+/// Splits the text by the unescaped [SELECTION_IDS_SEPARATOR] and unescapes each of the names.
+/// The surrounding whitespace of the names and the empty names are dropped.
+pub fn split_select_option_names(s: &str) -> Vec<String> {
+  let mut names = vec![];
+  let mut name = String::new();
+  let mut chars = s.chars();
+  while let Some(c) = chars.next() {
+    if c == SELECTION_NAME_ESCAPE {
+      // The trailing escape character is kept as it is.
+      name.push(chars.next().unwrap_or(SELECTION_NAME_ESCAPE));
+    } else if SELECTION_IDS_SEPARATOR.starts_with(c) {
+      names.push(std::mem::take(&mut name));
+    } else {
+      name.push(c);
+    }
+  }
+  names.push(name);
+  names
+    .into_iter()
+    .map(|name| name.trim().to_owned())
+    .filter(|name| !name.is_empty())
+    .collect()
+}
+
 /// [SelectOptionPB] represents an option for a single select, and multiple select.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
 pub struct SelectOptionPB {
@@ -30,8 +76,24 @@ pub struct SelectOptionPB {
 
   #[pb(index = 3)]
   pub color: SelectOptionColorPB,
+
+  /// The weight of the option. The checklist item without the weight counts as 1, see
+  /// [ChecklistTypeOptionPB::checklist_progress]. The weights of the selected options are also
+  /// the numeric value of the select option cell, see [SelectTypeOptionSharedAction::numeric_value].
+  #[pb(index = 4, one_of)]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub weight: Option<i32>,
+
+  /// The icon of the option, for example, an emoji. It's shown instead of the colored dot when the
+  /// option is rendered as a glyph.
+  #[pb(index = 5, one_of)]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub icon: Option<String>,
 }
 
+/// The glyph of the option without the icon.
+pub const DEFAULT_OPTION_GLYPH: char = '●';
+
 pub fn gen_option_id() -> String {
   nanoid!(4)
 }
@@ -42,6 +104,8 @@ impl SelectOptionPB {
       id: gen_option_id(),
       name: name.to_owned(),
       color: SelectOptionColorPB::default(),
+      weight: None,
+      icon: None,
     }
   }
 
@@ -50,6 +114,21 @@ impl SelectOptionPB {
       id: nanoid!(4),
       name: name.to_owned(),
       color,
+      weight: None,
+      icon: None,
+    }
+  }
+
+  /// Returns the first character of the icon, or the dot, colored by the option's color.
+  pub fn glyph(&self) -> CellGlyph {
+    let char = self
+      .icon
+      .as_ref()
+      .and_then(|icon| icon.trim().chars().next())
+      .unwrap_or(DEFAULT_OPTION_GLYPH);
+    CellGlyph {
+      char,
+      color: Some(self.color.clone()),
     }
   }
 }
@@ -88,11 +167,43 @@ pub fn make_selected_options(
     })
     .collect()
 }
+
+/// Indexes the options by their ids, so that the selected options of many cells can be found
+/// without searching the options for each id.
+pub struct SelectOptionLookup<'a> {
+  options_by_id: HashMap<&'a str, &'a SelectOptionPB>,
+}
+
+impl<'a> SelectOptionLookup<'a> {
+  pub fn new(options: &'a [SelectOptionPB]) -> Self {
+    let options_by_id = options
+      .iter()
+      .map(|option| (option.id.as_str(), option))
+      .collect();
+    Self { options_by_id }
+  }
+
+  /// Same as [make_selected_options], the ids that don't exist are ignored.
+  pub fn selected_options(&self, ids: &SelectOptionIds) -> Vec<SelectOptionPB> {
+    ids
+      .iter()
+      .flat_map(|option_id| self.options_by_id.get(option_id.as_str()))
+      .map(|option| (*option).clone())
+      .collect()
+  }
+}
+
 /// Defines the shared actions used by SingleSelect or Multi-Select.
 pub trait SelectTypeOptionSharedAction: TypeOptionDataSerializer + Send + Sync {
   /// Returns `None` means there is no limited
   fn number_of_max_options(&self) -> Option<usize>;
 
+  /// Returns true if it's the single select. The multi-select that is limited to one option
+  /// still takes the text as a list of options.
+  fn is_single_select(&self) -> bool {
+    false
+  }
+
   /// Insert the `SelectOptionPB` into corresponding type option.
   fn insert_option(&mut self, new_option: SelectOptionPB) {
     let options = self.mut_options();
@@ -137,8 +248,156 @@ pub trait SelectTypeOptionSharedAction: TypeOptionDataSerializer + Send + Sync {
     }
   }
 
+  /// Same as `get_selected_options` but for the cells of a whole column, the options are indexed
+  /// once instead of being searched for each cell.
+  fn get_selected_options_in_batch(
+    &self,
+    ids_list: Vec<SelectOptionIds>,
+  ) -> Vec<SelectOptionCellDataPB> {
+    let lookup = SelectOptionLookup::new(self.options());
+    ids_list
+      .into_iter()
+      .map(|ids| {
+        let mut select_options = lookup.selected_options(&ids);
+        if let Some(number_of_max_options) = self.number_of_max_options() {
+          select_options.truncate(number_of_max_options);
+        }
+        SelectOptionCellDataPB {
+          options: self.options().clone(),
+          select_options,
+        }
+      })
+      .collect()
+  }
+
+  /// Returns the sum of the weights of the selected options, so the single select cell has the
+  /// weight of its option. The options without the weight are ignored. Returns None if none of the
+  /// selected options has a weight.
+  fn numeric_value(&self, ids: SelectOptionIds) -> Option<f64> {
+    self
+      .get_selected_options(ids)
+      .select_options
+      .iter()
+      .flat_map(|option| option.weight)
+      .fold(None, |sum, weight| Some(sum.unwrap_or(0.0) + weight as f64))
+  }
+
+  /// Returns the option ids that belong to the options of the type option. The option can also be
+  /// referred by its name, which comes from the plain text changeset. If `strict_options` is true,
+  /// returns error when any of the ids doesn't exist. Otherwise, the ids that don't exist, for
+  /// example, the id of a deleted option, are dropped.
+  fn filter_option_ids(
+    &self,
+    option_ids: Vec<String>,
+    strict_options: bool,
+  ) -> FlowyResult<Vec<String>> {
+    let mut filtered_option_ids = vec![];
+    for option_id in option_ids {
+      if self.options().iter().any(|option| option.id == option_id) {
+        filtered_option_ids.push(option_id);
+      } else if let Some(option) = self
+        .options()
+        .iter()
+        .find(|option| option.name == option_id)
+      {
+        filtered_option_ids.push(option.id.clone());
+      } else if strict_options {
+        let msg = format!("The option with id: {} doesn't exist", option_id);
+        return Err(FlowyError::new(ErrorCode::RecordNotFound, &msg));
+      }
+    }
+    Ok(filtered_option_ids)
+  }
+
+  /// Returns the ids of the options that the text refers to. The text is either a list of option
+  /// ids, for example, the pasted ids string, or the names of the options. The single select takes
+  /// the whole text as one name and falls back to the [OTHER_OPTION_NAME] option if there is no
+  /// option with that name.
+  fn option_ids_from_text(&self, text: &str) -> SelectOptionIds {
+    let options = self.options();
+    let option_ids = SelectOptionIds::from(text.to_owned())
+      .iter()
+      .filter(|id| options.iter().any(|option| &option.id == *id))
+      .cloned()
+      .collect::<Vec<String>>();
+    if !option_ids.is_empty() {
+      return SelectOptionIds::from(option_ids);
+    }
+
+    let find_option_id = |name: &str| {
+      options
+        .iter()
+        .find(|option| option.name == name)
+        .map(|option| option.id.clone())
+    };
+    let option_ids = if self.is_single_select() {
+      let name = text.trim();
+      if name.is_empty() {
+        vec![]
+      } else {
+        // The name is escaped if the text was stringified from the select cell.
+        find_option_id(name)
+          .or_else(|| match split_select_option_names(name).as_slice() {
+            [unescaped] => find_option_id(unescaped),
+            _ => None,
+          })
+          .or_else(|| find_option_id(OTHER_OPTION_NAME))
+          .into_iter()
+          .collect()
+      }
+    } else {
+      split_select_option_names(text)
+        .into_iter()
+        .filter_map(|name| find_option_id(&name))
+        .collect()
+    };
+    SelectOptionIds::from(option_ids)
+  }
+
+  /// Returns the changeset that selects the options of the JSON value, which is either a string or
+  /// an array of strings. Each string is the id or the name of the option, and the string of the
+  /// multi-select can contain several names separated by the [SELECTION_IDS_SEPARATOR]. Returns
+  /// error if any of the options doesn't exist or the single select receives more than one
+  /// option.
+  fn select_changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<SelectOptionCellChangeset> {
+    let expected = "an option name or an array of option names";
+    let names = match value {
+      serde_json::Value::Null => vec![],
+      serde_json::Value::String(s) if self.is_single_select() => {
+        vec![s.trim().to_owned()]
+      },
+      serde_json::Value::String(s) => split_select_option_names(s),
+      serde_json::Value::Array(values) => values
+        .iter()
+        .map(|value| match value {
+          serde_json::Value::String(s) => Ok(s.trim().to_owned()),
+          _ => Err(invalid_json_value_error(value, expected)),
+        })
+        .collect::<FlowyResult<Vec<String>>>()?,
+      _ => return Err(invalid_json_value_error(value, expected)),
+    };
+    let names = names
+      .into_iter()
+      .filter(|name| !name.is_empty())
+      .collect::<Vec<String>>();
+    if self.is_single_select() && names.len() > 1 {
+      return Err(invalid_json_value_error(value, "a single option name"));
+    }
+    let option_ids = self.filter_option_ids(names, true)?;
+    Ok(SelectOptionCellChangeset::from_insert_options(option_ids))
+  }
+
   fn options(&self) -> &Vec<SelectOptionPB>;
 
+  /// Returns the separator that joins the names of the selected options in
+  /// [CellDataDecoder::decode_cell_data_to_str].
+  fn join_separator(&self) -> &str {
+    DEFAULT_SELECTION_JOIN_SEPARATOR
+  }
+
   fn mut_options(&mut self) -> &mut Vec<SelectOptionPB>;
 }
 
@@ -172,7 +431,24 @@ where
     _field_rev: &FieldRevision,
   ) -> Option<<Self as TypeOption>::CellData> {
     match decoded_field_type {
-      FieldType::SingleSelect | FieldType::MultiSelect | FieldType::Checklist => None,
+      // The single select keeps the first selected option of the multi-select cell. The other
+      // cells are decoded as they are, which goes through the cell data cache.
+      FieldType::SingleSelect | FieldType::MultiSelect => {
+        let option_ids = SelectOptionIds::from_cell_str(cell_str).ok()?;
+        match self.number_of_max_options() {
+          Some(number_of_max_options) if option_ids.len() > number_of_max_options => {
+            Some(SelectOptionIds::from(
+              option_ids
+                .iter()
+                .take(number_of_max_options)
+                .cloned()
+                .collect::<Vec<String>>(),
+            ))
+          },
+          _ => None,
+        }
+      },
+      FieldType::Checklist => None,
       FieldType::Checkbox => match CheckboxCellData::from_cell_str(cell_str) {
         Ok(checkbox_cell_data) => {
           let cell_content = checkbox_cell_data.to_string();
@@ -185,7 +461,7 @@ where
         },
         Err(_) => None,
       },
-      FieldType::RichText => SelectOptionIds::from_cell_str(cell_str).ok(),
+      FieldType::RichText => Some(self.option_ids_from_text(&plain_text(cell_str))),
       _ => Some(SelectOptionIds::from(vec![])),
     }
   }
@@ -204,14 +480,54 @@ where
     self.decode_type_option_cell_str(cell_str)
   }
 
+  /// The names are escaped if they're joined by the [SELECTION_IDS_SEPARATOR], so the text can be
+  /// pasted back, see [split_select_option_names].
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    let join_separator = self.join_separator();
+    let is_escaped = join_separator.trim() == SELECTION_IDS_SEPARATOR;
     self
       .get_selected_options(cell_data)
       .select_options
       .into_iter()
-      .map(|option| option.name)
+      .map(|option| {
+        if is_escaped {
+          escape_select_option_name(&option.name)
+        } else {
+          option.name
+        }
+      })
       .collect::<Vec<String>>()
-      .join(SELECTION_IDS_SEPARATOR)
+      .join(join_separator)
+  }
+
+  /// Prefixes the names of the selected options with the field name, for example, `Status: Done`.
+  fn accessibility_label(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+    field_rev: &FieldRevision,
+  ) -> String {
+    let names = self
+      .get_selected_options(cell_data)
+      .select_options
+      .into_iter()
+      .map(|option| option.name)
+      .collect::<Vec<String>>();
+    if names.is_empty() {
+      return "".to_owned();
+    }
+    format!("{}: {}", field_rev.name, names.join(", "))
+  }
+
+  fn decode_cell_data_to_pivot_keys(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> Vec<String> {
+    self
+      .get_selected_options(cell_data)
+      .select_options
+      .into_iter()
+      .map(|option| option.id)
+      .collect()
   }
 }
 
@@ -272,14 +588,29 @@ pub fn new_select_option_color(options: &Vec<SelectOptionPB>) -> SelectOptionCol
 /// placing a commas separator between each
 ///
 #[derive(Default, Clone, Debug)]
-pub struct SelectOptionIds(Vec<String>);
+pub struct SelectOptionIds {
+  ids: Vec<String>,
+  /// The progress of the checklist, which is computed once when the checklist cell is decoded, so
+  /// the filter and the sort don't compute it again. It's None for the other cells and for the ids
+  /// that aren't decoded by the checklist type option.
+  progress: Option<f64>,
+}
 
 impl SelectOptionIds {
   pub fn new() -> Self {
     Self::default()
   }
   pub fn into_inner(self) -> Vec<String> {
-    self.0
+    self.ids
+  }
+
+  pub fn with_progress(mut self, progress: f64) -> Self {
+    self.progress = Some(progress);
+    self
+  }
+
+  pub fn progress(&self) -> Option<f64> {
+    self.progress
   }
 }
 
@@ -295,14 +626,17 @@ impl FromCellString for SelectOptionIds {
 impl std::convert::From<String> for SelectOptionIds {
   fn from(s: String) -> Self {
     if s.is_empty() {
-      return Self(vec![]);
+      return Self::default();
     }
 
     let ids = s
       .split(SELECTION_IDS_SEPARATOR)
       .map(|id| id.to_string())
       .collect::<Vec<String>>();
-    Self(ids)
+    Self {
+      ids,
+      progress: None,
+    }
   }
 }
 
@@ -312,7 +646,10 @@ impl std::convert::From<Vec<String>> for SelectOptionIds {
       .into_iter()
       .filter(|id| !id.is_empty())
       .collect::<Vec<String>>();
-    Self(ids)
+    Self {
+      ids,
+      progress: None,
+    }
   }
 }
 
@@ -320,14 +657,14 @@ impl ToString for SelectOptionIds {
   /// Returns a string that consists list of ids, placing a commas
   /// separator between each
   fn to_string(&self) -> String {
-    self.0.join(SELECTION_IDS_SEPARATOR)
+    self.ids.join(SELECTION_IDS_SEPARATOR)
   }
 }
 
 impl std::convert::From<Option<String>> for SelectOptionIds {
   fn from(s: Option<String>) -> Self {
     match s {
-      None => Self(vec![]),
+      None => Self::default(),
       Some(s) => Self::from(s),
     }
   }
@@ -337,13 +674,13 @@ impl std::ops::Deref for SelectOptionIds {
   type Target = Vec<String>;
 
   fn deref(&self) -> &Self::Target {
-    &self.0
+    &self.ids
   }
 }
 
 impl std::ops::DerefMut for SelectOptionIds {
   fn deref_mut(&mut self) -> &mut Self::Target {
-    &mut self.0
+    &mut self.ids
   }
 }
 
@@ -351,7 +688,7 @@ impl DecodedCellData for SelectOptionIds {
   type Object = SelectOptionIds;
 
   fn is_empty(&self) -> bool {
-    self.0.is_empty()
+    self.ids.is_empty()
   }
 }
 
@@ -449,6 +786,14 @@ impl FromCellChangesetString for SelectOptionCellChangeset {
   where
     Self: Sized,
   {
+    if !changeset.trim_start().starts_with('{') {
+      // The plain text changeset, for example, the pasted text, is a list of the option ids or
+      // the option names separated by the SELECTION_IDS_SEPARATOR.
+      return Ok(
+        SelectOptionCellChangeset::from_insert_options(split_select_option_names(&changeset))
+          .collapse_adjacent_duplicates(),
+      );
+    }
     serde_json::from_str::<SelectOptionCellChangeset>(&changeset).map_err(internal_error)
   }
 }
@@ -487,6 +832,13 @@ impl SelectOptionCellChangeset {
       delete_option_ids: option_ids,
     }
   }
+
+  /// Collapses the consecutive identical ids of the inserted options into the first one, for
+  /// example, the pasted `A,A,B` inserts `A,B`.
+  pub fn collapse_adjacent_duplicates(mut self) -> Self {
+    self.insert_option_ids.dedup();
+    self
+  }
 }
 
 /// [SelectOptionCellDataPB] contains a list of user's selected options and a list of all the options
@@ -502,6 +854,33 @@ pub struct SelectOptionCellDataPB {
   pub select_options: Vec<SelectOptionPB>,
 }
 
+/// [SelectDistributionPB] describes how many cells select each option of the field. It's used
+/// to draw the chart of a select option field.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct SelectDistributionPB {
+  #[pb(index = 1)]
+  pub items: Vec<SelectDistributionItemPB>,
+
+  /// The number of cells that don't select any option.
+  #[pb(index = 2)]
+  pub no_option_count: i64,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct SelectDistributionItemPB {
+  #[pb(index = 1)]
+  pub option_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub color: SelectOptionColorPB,
+
+  #[pb(index = 4)]
+  pub count: i64,
+}
+
 /// [SelectOptionChangesetPB] describes the changes of a FieldTypeOptionData. For the moment,
 /// it is used by [MultiSelectTypeOptionPB] and [SingleSelectTypeOptionPB].
 #[derive(Clone, Debug, Default, ProtoBuf)]
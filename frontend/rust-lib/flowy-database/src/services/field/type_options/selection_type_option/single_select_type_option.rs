@@ -1,11 +1,12 @@
-use crate::entities::{FieldType, SelectOptionFilterPB};
+use crate::entities::{CellValuePB, FieldType, SelectOptionConditionPB, SelectOptionFilterPB};
 use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, FromCellString, TypeCellData};
 use std::cmp::Ordering;
 
 use crate::services::field::{
-  default_order, BoxTypeOptionBuilder, SelectOptionCellDataPB, SelectedSelectOptions, TypeOption,
-  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  default_order, plain_text, BoxTypeOptionBuilder, CellGlyph, ComparableKind, EmptyFilterCondition,
+  SelectOptionCellDataPB, SelectedSelectOptions, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, NO_STATUS_GROUP_SORT_WEIGHT,
 };
 use crate::services::field::{
   SelectOptionCellChangeset, SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction,
@@ -17,16 +18,91 @@ use flowy_error::FlowyResult;
 use serde::{Deserialize, Serialize};
 
 // Single select
-#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+#[derive(Clone, Debug, Serialize, Deserialize, ProtoBuf)]
 pub struct SingleSelectTypeOptionPB {
   #[pb(index = 1)]
   pub options: Vec<SelectOptionPB>,
 
   #[pb(index = 2)]
   pub disable_color: bool,
+
+  /// Returns error when the changeset selects an option that doesn't exist if it's true.
+  /// Otherwise, the option id that doesn't exist will be ignored.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub strict_options: bool,
+
+  /// The max number of options that are created from the distinct texts when switching a text
+  /// field to single select. The rest of the texts are put into the [OTHER_OPTION_NAME] option.
+  #[pb(index = 4)]
+  #[serde(default = "DEFAULT_MAX_OPTIONS_FROM_TEXT")]
+  pub max_options_from_text: i32,
 }
 impl_type_option!(SingleSelectTypeOptionPB, FieldType::SingleSelect);
 
+const DEFAULT_MAX_OPTIONS_FROM_TEXT: fn() -> i32 = || 50;
+
+/// The name of the catch-all option that is created when switching a text field to single select.
+pub const OTHER_OPTION_NAME: &str = "Other";
+
+impl std::default::Default for SingleSelectTypeOptionPB {
+  fn default() -> Self {
+    Self {
+      options: vec![],
+      disable_color: false,
+      strict_options: false,
+      max_options_from_text: DEFAULT_MAX_OPTIONS_FROM_TEXT(),
+    }
+  }
+}
+
+impl SingleSelectTypeOptionPB {
+  /// Creates an option for each distinct text of the text cells when switching a text field to
+  /// single select. The text that has the same name as an existing option reuses it. The existing
+  /// options, except the [OTHER_OPTION_NAME] option, count towards the
+  /// [SingleSelectTypeOptionPB::max_options_from_text], and the texts beyond it are put into the
+  /// [OTHER_OPTION_NAME] option. The text cells are mapped to the options
+  /// by name, see [SelectTypeOptionSharedAction::option_ids_from_text]. The inline marks of the
+  /// text cells are dropped, see [plain_text].
+  pub fn transform_text_cells<'a, I>(&mut self, cell_strs: I)
+  where
+    I: IntoIterator<Item = &'a str>,
+  {
+    let max_options = self.max_options_from_text.max(0) as usize;
+    let mut number_of_options = self
+      .options
+      .iter()
+      .filter(|option| option.name != OTHER_OPTION_NAME)
+      .count();
+    let mut needs_other_option = false;
+    for cell_str in cell_strs {
+      let text = plain_text(cell_str);
+      let name = text.trim();
+      if name.is_empty() || self.options.iter().any(|option| option.name == name) {
+        continue;
+      }
+
+      if number_of_options < max_options {
+        let option = self.create_option(name);
+        self.options.push(option);
+        number_of_options += 1;
+      } else {
+        needs_other_option = true;
+      }
+    }
+
+    if needs_other_option
+      && !self
+        .options
+        .iter()
+        .any(|option| option.name == OTHER_OPTION_NAME)
+    {
+      let option = self.create_option(OTHER_OPTION_NAME);
+      self.options.push(option);
+    }
+  }
+}
+
 impl TypeOption for SingleSelectTypeOptionPB {
   type CellData = SelectOptionIds;
   type CellChangeset = SelectOptionCellChangeset;
@@ -42,12 +118,39 @@ impl TypeOptionCellData for SingleSelectTypeOptionPB {
     self.get_selected_options(cell_data)
   }
 
+  fn convert_cells_to_protobuf(
+    &self,
+    cell_datas: Vec<<Self as TypeOption>::CellData>,
+  ) -> Vec<<Self as TypeOption>::CellProtobufType> {
+    self.get_selected_options_in_batch(cell_datas)
+  }
+
   fn decode_type_option_cell_str(
     &self,
     cell_str: String,
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     SelectOptionIds::from_cell_str(&cell_str)
   }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.is_empty()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      select_option: Some(self.convert_to_protobuf(cell_data)),
+      ..Default::default()
+    }
+  }
+
+  /// The glyph of the selected option.
+  fn glyph(&self, cell_data: &<Self as TypeOption>::CellData) -> Option<CellGlyph> {
+    self
+      .get_selected_options(cell_data.clone())
+      .select_options
+      .first()
+      .map(SelectOptionPB::glyph)
+  }
 }
 
 impl SelectTypeOptionSharedAction for SingleSelectTypeOptionPB {
@@ -55,6 +158,10 @@ impl SelectTypeOptionSharedAction for SingleSelectTypeOptionPB {
     Some(1)
   }
 
+  fn is_single_select(&self) -> bool {
+    true
+  }
+
   fn options(&self) -> &Vec<SelectOptionPB> {
     &self.options
   }
@@ -70,16 +177,8 @@ impl CellDataChangeset for SingleSelectTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     _type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let mut insert_option_ids = changeset
-      .insert_option_ids
-      .into_iter()
-      .filter(|insert_option_id| {
-        self
-          .options
-          .iter()
-          .any(|option| &option.id == insert_option_id)
-      })
-      .collect::<Vec<String>>();
+    let mut insert_option_ids =
+      self.filter_option_ids(changeset.insert_option_ids, self.strict_options)?;
 
     // In single select, the insert_option_ids should only contain one select option id.
     // Sometimes, the insert_option_ids may contain list of option ids. For example,
@@ -93,6 +192,13 @@ impl CellDataChangeset for SingleSelectTypeOptionPB {
     };
     Ok((select_option_ids.to_string(), select_option_ids))
   }
+
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    self.select_changeset_from_json_value(value)
+  }
 }
 
 impl TypeOptionCellDataFilter for SingleSelectTypeOptionPB {
@@ -107,7 +213,18 @@ impl TypeOptionCellDataFilter for SingleSelectTypeOptionPB {
     }
     let selected_options =
       SelectedSelectOptions::from(self.get_selected_options(cell_data.clone()));
-    filter.is_visible(&selected_options, FieldType::SingleSelect)
+    filter.is_visible_in_order(&selected_options, FieldType::SingleSelect, &self.options)
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    SelectOptionConditionPB::OptionIs as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
   }
 }
 
@@ -131,6 +248,18 @@ impl TypeOptionCellDataCompare for SingleSelectTypeOptionPB {
       (None, None) => default_order(),
     }
   }
+
+  fn group_sort_weight(&self, group_id: &str, _field_rev: &FieldRevision) -> i64 {
+    // The groups are sorted by the order of the options.
+    match self.options.iter().position(|option| option.id == group_id) {
+      None => NO_STATUS_GROUP_SORT_WEIGHT,
+      Some(index) => index as i64,
+    }
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::OptionSet
+  }
 }
 #[derive(Default)]
 pub struct SingleSelectTypeOptionBuilder(SingleSelectTypeOptionPB);
@@ -142,6 +271,16 @@ impl SingleSelectTypeOptionBuilder {
     self.0.options.push(opt);
     self
   }
+
+  pub fn set_strict_options(mut self, strict_options: bool) -> Self {
+    self.0.strict_options = strict_options;
+    self
+  }
+
+  pub fn max_options_from_text(mut self, max_options_from_text: i32) -> Self {
+    self.0.max_options_from_text = max_options_from_text;
+    self
+  }
 }
 
 impl TypeOptionBuilder for SingleSelectTypeOptionBuilder {
@@ -156,10 +295,59 @@ impl TypeOptionBuilder for SingleSelectTypeOptionBuilder {
 
 #[cfg(test)]
 mod tests {
-  use crate::entities::FieldType;
-  use crate::services::cell::CellDataChangeset;
+  use crate::entities::{FieldType, SelectOptionConditionPB, SelectOptionFilterPB};
+  use crate::services::cell::{
+    insert_number_cell, insert_select_option_cell, insert_text_cell, CellDataChangeset,
+    TypeCellData,
+  };
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, filter_is_empty, ingest_json_value,
+    spreadsheet_value,
+  };
   use crate::services::field::type_options::*;
-  use crate::services::field::{FieldBuilder, TypeOptionBuilder};
+  use crate::services::field::{FieldBuilder, TypeOptionBuilder, TypeOptionCellExt};
+  use crate::services::group::make_no_status_group;
+  use serde_json::json;
+  use std::collections::HashMap;
+
+  #[test]
+  fn single_select_glyph_test() {
+    let hot = SelectOptionPB {
+      icon: Some("🔥".to_owned()),
+      ..SelectOptionPB::with_color("Hot", SelectOptionColorPB::Orange)
+    };
+    let cold = SelectOptionPB::with_color("Cold", SelectOptionColorPB::Blue);
+    let field_rev = FieldBuilder::new(
+      SingleSelectTypeOptionBuilder::default()
+        .add_option(hot.clone())
+        .add_option(cold.clone()),
+    )
+    .build();
+    let handler = cell_data_handler(&field_rev, &FieldType::SingleSelect);
+    let glyph = |option_ids: Vec<String>| {
+      handler.glyph(
+        &insert_select_option_cell(option_ids, &field_rev),
+        &field_rev,
+      )
+    };
+
+    assert_eq!(
+      glyph(vec![hot.id.clone()]),
+      Some(CellGlyph {
+        char: '🔥',
+        color: Some(SelectOptionColorPB::Orange),
+      })
+    );
+    // The option without the icon is a dot colored by the option's color.
+    assert_eq!(
+      glyph(vec![cold.id.clone()]),
+      Some(CellGlyph {
+        char: DEFAULT_OPTION_GLYPH,
+        color: Some(SelectOptionColorPB::Blue),
+      })
+    );
+    assert_eq!(glyph(vec![]), None);
+  }
 
   #[test]
   fn single_select_transform_with_checkbox_type_option_test() {
@@ -260,4 +448,392 @@ mod tests {
     let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
     assert!(select_option_ids.is_empty());
   }
+
+  #[test]
+  fn single_select_insert_deleted_option_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let single_select = SingleSelectTypeOptionBuilder::default().add_option(google.clone());
+    let field_rev = FieldBuilder::new(single_select).name("Platform").build();
+    let type_option = SingleSelectTypeOptionPB::from(&field_rev);
+
+    let option_ids = vec![facebook.id, google.id.clone()];
+    let changeset = SelectOptionCellChangeset::from_insert_options(option_ids);
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![google.id]);
+  }
+
+  #[test]
+  fn single_select_strict_options_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .set_strict_options(true);
+    let field_rev = FieldBuilder::new(single_select).name("Platform").build();
+    let type_option = SingleSelectTypeOptionPB::from(&field_rev);
+
+    let changeset = SelectOptionCellChangeset::from_insert_options(vec![facebook.id]);
+    assert!(type_option.apply_changeset(changeset, None).is_err());
+
+    let changeset = SelectOptionCellChangeset::from_insert_options(vec![google.id.clone()]);
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![google.id]);
+  }
+
+  #[test]
+  fn single_select_transform_text_cells_test() {
+    let google = SelectOptionPB::new("Google");
+    let mut single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .0;
+    single_select.transform_text_cells(vec!["Google", " Facebook ", "", "Facebook", "Twitter"]);
+    let names = single_select
+      .options
+      .iter()
+      .map(|option| option.name.as_str())
+      .collect::<Vec<&str>>();
+    assert_eq!(names, vec!["Google", "Facebook", "Twitter"]);
+
+    let option_ids = single_select.option_ids_from_text("Facebook");
+    assert_eq!(&*option_ids, &vec![single_select.options[1].id.clone()]);
+    assert!(single_select.option_ids_from_text("").is_empty());
+    assert!(single_select.option_ids_from_text("Notion").is_empty());
+
+    // The pasted option id still selects the option.
+    let option_ids = single_select.option_ids_from_text(&google.id);
+    assert_eq!(&*option_ids, &vec![google.id]);
+
+    // The name that is escaped when the cell is stringified is found too.
+    let red = SelectOptionPB::new("Red, bright");
+    single_select.options.push(red.clone());
+    let option_ids = single_select.option_ids_from_text("Red, bright");
+    assert_eq!(&*option_ids, &vec![red.id.clone()]);
+    let option_ids = single_select.option_ids_from_text(r"Red\, bright");
+    assert_eq!(&*option_ids, &vec![red.id]);
+  }
+
+  #[test]
+  fn single_select_transform_formatted_text_cells_test() {
+    let mut single_select = SingleSelectTypeOptionBuilder::default().0;
+    let bold_google = r#"[{"insert":"Google","attributes":{"bold":true}}]"#;
+    single_select.transform_text_cells(vec![bold_google, "Google"]);
+    let names = single_select
+      .options
+      .iter()
+      .map(|option| option.name.as_str())
+      .collect::<Vec<&str>>();
+    assert_eq!(names, vec!["Google"]);
+
+    let field_rev = FieldBuilder::new(SingleSelectTypeOptionBuilder::default()).build();
+    let option_ids = single_select
+      .transform_type_option_cell_str(bold_google, &FieldType::RichText, &field_rev)
+      .unwrap();
+    assert_eq!(&*option_ids, &vec![single_select.options[0].id.clone()]);
+  }
+
+  #[test]
+  fn single_select_transform_high_cardinality_text_cells_test() {
+    let mut single_select = SingleSelectTypeOptionBuilder::default()
+      .max_options_from_text(10)
+      .0;
+    let texts = (0..1000)
+      .map(|i| format!("Company {}", i % 100))
+      .collect::<Vec<String>>();
+    single_select.transform_text_cells(texts.iter().map(|s| s.as_str()));
+    assert_eq!(single_select.options.len(), 11);
+    assert_eq!(single_select.options[0].name, "Company 0");
+    assert_eq!(single_select.options[9].name, "Company 9");
+
+    let other = single_select.options.last().unwrap().clone();
+    assert_eq!(other.name, OTHER_OPTION_NAME);
+
+    // Transforming again doesn't create the options twice.
+    single_select.transform_text_cells(texts.iter().map(|s| s.as_str()));
+    assert_eq!(single_select.options.len(), 11);
+
+    let field_rev = FieldBuilder::new(SingleSelectTypeOptionBuilder::default()).build();
+    let option_ids = single_select
+      .transform_type_option_cell_str("Company 3", &FieldType::RichText, &field_rev)
+      .unwrap();
+    assert_eq!(&*option_ids, &vec![single_select.options[3].id.clone()]);
+
+    let option_ids = single_select
+      .transform_type_option_cell_str("Company 42", &FieldType::RichText, &field_rev)
+      .unwrap();
+    assert_eq!(&*option_ids, &vec![other.id]);
+  }
+
+  #[test]
+  fn single_select_transform_text_cells_with_existing_options_test() {
+    let mut single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(SelectOptionPB::new("Google"))
+      .add_option(SelectOptionPB::new(OTHER_OPTION_NAME))
+      .max_options_from_text(2)
+      .0;
+    single_select.transform_text_cells(vec!["Google", "Facebook", "Twitter"]);
+    let names = single_select
+      .options
+      .iter()
+      .map(|option| option.name.as_str())
+      .collect::<Vec<&str>>();
+    assert_eq!(names, vec!["Google", OTHER_OPTION_NAME, "Facebook"]);
+  }
+
+  #[test]
+  fn single_select_ingest_json_value_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(google.clone())
+      .add_option(facebook.clone());
+    let field_rev = FieldBuilder::new(single_select).build();
+
+    assert_eq!(
+      ingest_json_value(&field_rev, json!("Google")).unwrap(),
+      google.id
+    );
+    assert_eq!(
+      ingest_json_value(&field_rev, json!(facebook.id)).unwrap(),
+      facebook.id
+    );
+    assert_eq!(
+      ingest_json_value(&field_rev, json!(["Google"])).unwrap(),
+      google.id
+    );
+    assert_eq!(ingest_json_value(&field_rev, json!(null)).unwrap(), "");
+
+    assert!(ingest_json_value(&field_rev, json!("Twitter")).is_err());
+    assert!(ingest_json_value(&field_rev, json!(["Google", "Facebook"])).is_err());
+    assert!(ingest_json_value(&field_rev, json!(42)).is_err());
+  }
+
+  #[test]
+  fn select_distribution_test() {
+    let todo = SelectOptionPB::new("Todo");
+    let done = SelectOptionPB::new("Done");
+    let single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(todo.clone())
+      .add_option(done.clone());
+    let field_rev = FieldBuilder::new(single_select).name("Status").build();
+    let cells = vec![
+      insert_select_option_cell(vec![todo.id.clone()], &field_rev),
+      insert_select_option_cell(vec![done.id.clone()], &field_rev),
+      insert_select_option_cell(vec![todo.id.clone()], &field_rev),
+      insert_select_option_cell(vec![], &field_rev),
+    ];
+
+    let distribution =
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None).select_distribution(&cells);
+    assert_eq!(distribution.items.len(), 2);
+    assert_eq!(distribution.items[0].option_id, todo.id);
+    assert_eq!(distribution.items[0].name, "Todo");
+    assert_eq!(distribution.items[0].count, 2);
+    assert_eq!(distribution.items[1].option_id, done.id);
+    assert_eq!(distribution.items[1].count, 1);
+    assert_eq!(distribution.no_option_count, 1);
+  }
+
+  #[test]
+  fn select_group_sort_weight_test() {
+    let todo = SelectOptionPB::new("Todo");
+    let done = SelectOptionPB::new("Done");
+    let single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(todo.clone())
+      .add_option(done.clone());
+    let field_rev = FieldBuilder::new(single_select).name("Status").build();
+    let no_status_group = make_no_status_group(&field_rev);
+
+    let mut group_ids = vec![no_status_group.id.clone(), done.id.clone(), todo.id.clone()];
+    let handler = cell_data_handler(&field_rev, &FieldType::SingleSelect);
+    group_ids.sort_by_key(|group_id| handler.group_sort_weight(group_id, &field_rev));
+    assert_eq!(group_ids, vec![todo.id, done.id, no_status_group.id]);
+  }
+
+  #[test]
+  fn select_describe_changeset_test() {
+    let todo = SelectOptionPB::new("Todo");
+    let done = SelectOptionPB::new("Done");
+    let single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(todo.clone())
+      .add_option(done.clone());
+    let field_rev = FieldBuilder::new(single_select).name("Status").build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let old_cell = insert_select_option_cell(vec![todo.id.clone()], &field_rev);
+    let new_cell = insert_select_option_cell(vec![done.id.clone()], &field_rev);
+    assert_eq!(
+      ext.describe_changeset(Some(&old_cell), &new_cell),
+      "changed Status from Todo to Done"
+    );
+    assert_eq!(ext.describe_changeset(Some(&new_cell), &new_cell), "");
+
+    let multi_select = MultiSelectTypeOptionBuilder::default()
+      .add_option(todo.clone())
+      .add_option(done.clone());
+    let field_rev = FieldBuilder::new(multi_select).name("Tags").build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let old_cell = insert_select_option_cell(vec![todo.id.clone()], &field_rev);
+    let new_cell = insert_select_option_cell(vec![done.id.clone()], &field_rev);
+    assert_eq!(
+      ext.describe_changeset(Some(&old_cell), &new_cell),
+      "added Done to Tags and removed Todo from Tags"
+    );
+    assert_eq!(
+      ext.describe_changeset(None, &new_cell),
+      "added Done to Tags"
+    );
+  }
+
+  #[test]
+  fn select_accessibility_label_test() {
+    let done = SelectOptionPB::new("Done");
+    let single_select = SingleSelectTypeOptionBuilder::default().add_option(done.clone());
+    let field_rev = FieldBuilder::new(single_select).name("Status").build();
+    let cell_rev = insert_select_option_cell(vec![done.id], &field_rev);
+    let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+    let label = cell_data_handler(&field_rev, &FieldType::SingleSelect).accessibility_label(
+      type_cell_data.cell_str,
+      &type_cell_data.field_type,
+      &field_rev,
+    );
+    assert_eq!(label, "Status: Done");
+  }
+
+  #[test]
+  fn single_select_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::SingleSelect).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::SingleSelect);
+    assert_eq!(handler.comparable_kind(), ComparableKind::OptionSet);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::SingleSelect,
+      &FieldType::SingleSelect
+    ));
+    assert!(is_transform_lossless(
+      &FieldType::SingleSelect,
+      &FieldType::MultiSelect
+    ));
+    assert!(!is_transform_lossless(
+      &FieldType::SingleSelect,
+      &FieldType::Checkbox
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = SelectOptionFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, SelectOptionConditionPB::OptionIs);
+    assert!(filter.option_ids.is_empty());
+
+    let cell_rev = insert_select_option_cell(vec![], &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("".to_owned())
+    );
+    assert!(cell_value(&field_rev, &cell_rev).select_option.is_some());
+  }
+
+  #[test]
+  fn single_select_is_empty_filter_test() {
+    let select_filter = |condition: SelectOptionConditionPB| SelectOptionFilterPB {
+      condition,
+      option_ids: vec![],
+    };
+    let option = SelectOptionPB::new("A");
+    let field_rev =
+      FieldBuilder::new(SingleSelectTypeOptionBuilder::default().add_option(option.clone()))
+        .build();
+    for (cell_rev, expected) in [
+      (None, true),
+      (Some(insert_select_option_cell(vec![], &field_rev)), true),
+      (
+        Some(insert_select_option_cell(vec![option.id], &field_rev)),
+        false,
+      ),
+    ] {
+      let is_empty = filter_is_empty(
+        &field_rev,
+        select_filter,
+        SelectOptionConditionPB::OptionIsEmpty,
+        SelectOptionConditionPB::OptionIsNotEmpty,
+        cell_rev.as_ref(),
+      );
+      assert_eq!(is_empty, expected);
+    }
+  }
+
+  #[test]
+  fn single_select_required_field_satisfied_test() {
+    let todo = SelectOptionPB::new("Todo");
+    let single_select = SingleSelectTypeOptionBuilder::default().add_option(todo.clone());
+    let field_rev = FieldBuilder::new(single_select).required(true).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::SingleSelect);
+    let select_cell = insert_select_option_cell(vec![todo.id], &field_rev);
+    let empty_select_cell = insert_select_option_cell(vec![], &field_rev);
+    assert!(handler.satisfies_required(Some(&select_cell), &field_rev));
+    assert!(!handler.satisfies_required(Some(&empty_select_cell), &field_rev));
+    assert!(!handler.satisfies_required(None, &field_rev));
+  }
+
+  #[test]
+  fn pivot_number_by_single_select_test() {
+    let google = SelectOptionPB::new("Google");
+    let facebook = SelectOptionPB::new("Facebook");
+    let select_field_rev = FieldBuilder::new(
+      SingleSelectTypeOptionBuilder::default()
+        .add_option(google.clone())
+        .add_option(facebook.clone()),
+    )
+    .build();
+    let number_field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let select_handler = cell_data_handler(&select_field_rev, &FieldType::SingleSelect);
+    let number_handler = cell_data_handler(&number_field_rev, &FieldType::Number);
+
+    let rows = vec![
+      (Some(&google), Some(10)),
+      (Some(&facebook), Some(5)),
+      (Some(&google), Some(7)),
+      (Some(&google), None),
+      (None, Some(3)),
+    ];
+    let mut measure_by_key: HashMap<String, f64> = HashMap::new();
+    for (option, number) in rows {
+      let option_ids = option.map(|option| option.id.clone()).into_iter().collect();
+      let select_cell = insert_select_option_cell(option_ids, &select_field_rev);
+      let number_cell = match number {
+        None => insert_text_cell("".to_owned(), &number_field_rev),
+        Some(number) => insert_number_cell(number, &number_field_rev),
+      };
+      let select_cell = TypeCellData::try_from(&select_cell).unwrap();
+      let number_cell = TypeCellData::try_from(&number_cell).unwrap();
+      let dimension = select_handler.pivot_cell(
+        select_cell.cell_str,
+        &select_cell.field_type,
+        &select_field_rev,
+      );
+      let measure = number_handler.pivot_cell(
+        number_cell.cell_str,
+        &number_cell.field_type,
+        &number_field_rev,
+      );
+      assert_eq!(dimension.measure, None);
+      assert_eq!(measure.keys.len(), number.map_or(0, |_| 1));
+      for key in dimension.keys {
+        *measure_by_key.entry(key).or_default() += measure.measure.unwrap_or_default();
+      }
+    }
+
+    assert_eq!(measure_by_key.len(), 2);
+    assert_eq!(measure_by_key.get(&google.id), Some(&17.0));
+    assert_eq!(measure_by_key.get(&facebook.id), Some(&5.0));
+
+    let empty_cell = insert_select_option_cell(vec![], &select_field_rev);
+    let empty_cell = TypeCellData::try_from(&empty_cell).unwrap();
+    assert_eq!(
+      select_handler.pivot_cell(
+        empty_cell.cell_str,
+        &empty_cell.field_type,
+        &select_field_rev
+      ),
+      PivotValue::default()
+    );
+  }
 }
@@ -38,32 +38,44 @@ impl SelectOptionTypeOptionTransformHelper {
       },
       FieldType::MultiSelect => {
         let options = MultiSelectTypeOptionPB::from_json_str(&old_type_option_data).options;
-        options.iter().for_each(|new_option| {
-          if !shared
-            .options()
-            .iter()
-            .any(|option| option.name == new_option.name)
-          {
-            shared.mut_options().push(new_option.clone());
-          }
-        })
+        Self::import_options(shared, options);
       },
       FieldType::SingleSelect => {
         let options = SingleSelectTypeOptionPB::from_json_str(&old_type_option_data).options;
-        options.iter().for_each(|new_option| {
-          if !shared
-            .options()
-            .iter()
-            .any(|option| option.name == new_option.name)
-          {
-            shared.mut_options().push(new_option.clone());
-          }
-        })
+        Self::import_options(shared, options);
       },
       _ => {},
     }
   }
 
+  /// Imports the options of the old type option with their ids, so that the existing cells still
+  /// resolve to the options. The option that has the same id, or else the same name, is replaced
+  /// by the imported one, which is the latest definition of the option. So the names of the
+  /// options stay unique.
+  fn import_options<T>(shared: &mut T, options: Vec<SelectOptionPB>)
+  where
+    T: SelectTypeOptionSharedAction,
+  {
+    for new_option in options {
+      let options = shared.mut_options();
+      let position = options
+        .iter()
+        .position(|option| option.id == new_option.id)
+        .or_else(|| {
+          options
+            .iter()
+            .position(|option| option.name == new_option.name)
+        });
+      match position {
+        Some(position) => options[position] = new_option.clone(),
+        None => options.push(new_option.clone()),
+      }
+      // The stale option that has the same name but a different id is merged into the imported
+      // one.
+      options.retain(|option| option.id == new_option.id || option.name != new_option.name);
+    }
+  }
+
   // pub fn transform_e_option_cell_data<T>(
   //     //     shared: &T,
   //     //     cell_data: String,
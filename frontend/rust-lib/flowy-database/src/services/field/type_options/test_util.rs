@@ -0,0 +1,152 @@
+use crate::entities::{CellValuePB, FieldType, FilterPB};
+use crate::services::cell::{AnyTypeCache, TypeCellData};
+use crate::services::field::{
+  RowSingleCellData, SpreadsheetValue, TypeOptionCellDataHandler, TypeOptionCellExt,
+};
+use crate::services::filter::FilterType;
+use database_model::{CellRevision, FieldRevision};
+use flowy_error::FlowyResult;
+
+/// Returns the handler of the field's type option, which doesn't cache the cell data.
+pub(crate) fn cell_data_handler(
+  field_rev: &FieldRevision,
+  field_type: &FieldType,
+) -> Box<dyn TypeOptionCellDataHandler> {
+  TypeOptionCellExt::new_with_cell_data_cache(field_rev, None)
+    .get_type_option_cell_data_handler(field_type)
+    .unwrap()
+}
+
+/// Ingests the JSON value into a cell of the field, and returns the stored cell string.
+pub(crate) fn ingest_json_value(
+  field_rev: &FieldRevision,
+  value: serde_json::Value,
+) -> FlowyResult<String> {
+  let field_type: FieldType = field_rev.ty.into();
+  let cell_rev = cell_data_handler(field_rev, &field_type).ingest_json_value(&value, field_rev)?;
+  Ok(TypeCellData::try_from(&cell_rev).unwrap().cell_str)
+}
+
+/// Returns the default filter of the field, and checks that it's created for this field.
+pub(crate) fn default_filter(field_rev: &FieldRevision) -> FilterPB {
+  let field_type: FieldType = field_rev.ty.into();
+  let filter = cell_data_handler(field_rev, &field_type).default_filter(field_rev);
+  assert!(filter.id.is_empty());
+  assert_eq!(filter.field_id, field_rev.id);
+  assert_eq!(filter.field_type, field_type);
+  filter
+}
+
+/// Returns the [CellValuePB] of the cell, and checks that only one of its arms is set.
+pub(crate) fn cell_value(field_rev: &FieldRevision, cell_rev: &CellRevision) -> CellValuePB {
+  let field_type: FieldType = field_rev.ty.into();
+  let type_cell_data = TypeCellData::try_from(cell_rev).unwrap();
+  let cell_value = cell_data_handler(field_rev, &field_type)
+    .get_cell_oneof(
+      type_cell_data.cell_str,
+      &type_cell_data.field_type,
+      field_rev,
+    )
+    .unwrap();
+  let arms = [
+    cell_value.text.is_some(),
+    cell_value.number.is_some(),
+    cell_value.date.is_some(),
+    cell_value.select_option.is_some(),
+    cell_value.checkbox.is_some(),
+    cell_value.url.is_some(),
+    cell_value.checklist.is_some(),
+    cell_value.relation.is_some(),
+  ];
+  assert_eq!(arms.iter().filter(|is_some| **is_some).count(), 1);
+  cell_value
+}
+
+pub(crate) fn spreadsheet_value(
+  field_rev: &FieldRevision,
+  cell_rev: &CellRevision,
+) -> SpreadsheetValue {
+  let field_type: FieldType = field_rev.ty.into();
+  let type_cell_data = TypeCellData::try_from(cell_rev).unwrap();
+  cell_data_handler(field_rev, &field_type).to_spreadsheet_value(
+    type_cell_data.cell_str,
+    &type_cell_data.field_type,
+    field_rev,
+  )
+}
+
+pub(crate) fn row_single_cell_data(
+  field_rev: &FieldRevision,
+  cell_rev: &CellRevision,
+) -> RowSingleCellData {
+  let field_type: FieldType = field_rev.ty.into();
+  let type_cell_data = TypeCellData::try_from(cell_rev).unwrap();
+  let cell_data = cell_data_handler(field_rev, &field_type)
+    .get_cell_data(type_cell_data.cell_str, &field_type, field_rev)
+    .unwrap();
+  RowSingleCellData {
+    row_id: "row_1".to_owned(),
+    field_id: field_rev.id.clone(),
+    field_type,
+    cell_data,
+  }
+}
+
+/// Filters the cell the same way as the filter controller, which caches the filter by the
+/// [FilterType] of the field.
+pub(crate) fn filter_cell<T: Send + Sync + 'static>(
+  field_rev: &FieldRevision,
+  filter: T,
+  cell_rev: Option<&CellRevision>,
+) -> bool {
+  let field_type: FieldType = field_rev.ty.into();
+  let filter_type = FilterType {
+    field_id: field_rev.id.clone(),
+    field_type: field_type.clone(),
+  };
+  let cell_filter_cache = AnyTypeCache::<FilterType>::new();
+  cell_filter_cache.write().insert(&filter_type, filter);
+  let type_cell_data = match cell_rev {
+    None => TypeCellData::from_field_type(&field_type),
+    Some(cell_rev) => TypeCellData::try_from(cell_rev).unwrap(),
+  };
+  TypeOptionCellExt::new(field_rev, None, Some(cell_filter_cache))
+    .get_type_option_cell_data_handler(&field_type)
+    .unwrap()
+    .handle_cell_filter(&filter_type, field_rev, type_cell_data)
+}
+
+pub(crate) fn is_cell_empty(field_rev: &FieldRevision, cell_rev: Option<&CellRevision>) -> bool {
+  let field_type: FieldType = field_rev.ty.into();
+  let type_cell_data = match cell_rev {
+    None => TypeCellData::from_field_type(&field_type),
+    Some(cell_rev) => TypeCellData::try_from(cell_rev).unwrap(),
+  };
+  cell_data_handler(field_rev, &field_type).is_cell_empty(
+    type_cell_data.cell_str,
+    &type_cell_data.field_type,
+    field_rev,
+  )
+}
+
+/// Returns whether the cell is visible with the `is empty` filter, and checks that the
+/// `is not empty` filter and the handler's `is_cell_empty` agree with it.
+pub(crate) fn filter_is_empty<C, T, F>(
+  field_rev: &FieldRevision,
+  filter: F,
+  is_empty_condition: C,
+  is_not_empty_condition: C,
+  cell_rev: Option<&CellRevision>,
+) -> bool
+where
+  T: Send + Sync + 'static,
+  F: Fn(C) -> T,
+{
+  let is_empty = filter_cell(field_rev, filter(is_empty_condition), cell_rev);
+  assert_ne!(
+    is_empty,
+    filter_cell(field_rev, filter(is_not_empty_condition), cell_rev)
+  );
+  assert_eq!(is_empty, is_cell_empty(field_rev, cell_rev));
+  is_empty
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+/// A run of the [FormattedText]. The `attributes` are the inline marks of the run, for example,
+/// `{"bold":true}` or `{"href":"https://appflowy.io"}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FormattedTextRun {
+  pub insert: String,
+
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub attributes: Option<Map<String, Value>>,
+}
+
+/// The text cell that carries the inline marks is stored as the Delta JSON, for example,
+/// `[{"insert":"Hello","attributes":{"bold":true}},{"insert":" world"}]`. The other text cells
+/// are stored as the plain text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormattedText(pub Vec<FormattedTextRun>);
+
+impl FormattedText {
+  /// Returns None if the string is not the Delta JSON with at least one insert, so the plain text
+  /// that happens to be a JSON array, for example, `[1, 2]`, stays plain.
+  pub fn parse(s: &str) -> Option<Self> {
+    if !s.trim_start().starts_with('[') {
+      return None;
+    }
+    let runs = serde_json::from_str::<Vec<FormattedTextRun>>(s).ok()?;
+    (!runs.is_empty()).then(|| Self(runs))
+  }
+
+  /// Returns the text without the marks.
+  pub fn plain_text(&self) -> String {
+    self.0.iter().map(|run| run.insert.as_str()).collect()
+  }
+
+  /// Returns true if any of the runs has the marks.
+  pub fn has_marks(&self) -> bool {
+    self.0.iter().any(|run| {
+      run
+        .attributes
+        .as_ref()
+        .map_or(false, |marks| !marks.is_empty())
+    })
+  }
+
+  /// Replaces the text of each run with the output of `f`, the marks are kept.
+  pub fn map_text(mut self, f: impl Fn(&str) -> String) -> Self {
+    for run in self.0.iter_mut() {
+      run.insert = f(&run.insert);
+    }
+    self
+  }
+
+  /// Removes the leading and trailing whitespace of the text, and the runs that become empty.
+  pub fn trim(&mut self) {
+    for run in self.0.iter_mut() {
+      run.insert = run.insert.trim_start().to_owned();
+      if !run.insert.is_empty() {
+        break;
+      }
+    }
+    for run in self.0.iter_mut().rev() {
+      run.insert = run.insert.trim_end().to_owned();
+      if !run.insert.is_empty() {
+        break;
+      }
+    }
+    self.0.retain(|run| !run.insert.is_empty());
+  }
+}
+
+impl ToString for FormattedText {
+  fn to_string(&self) -> String {
+    serde_json::to_string(&self.0).unwrap_or_default()
+  }
+}
+
+/// Returns the plain text of the text cell, which is used to filter and sort the cells. The cell
+/// without the marks is returned as it is.
+pub fn plain_text(cell_str: &str) -> Cow<str> {
+  match FormattedText::parse(cell_str) {
+    None => Cow::Borrowed(cell_str),
+    Some(formatted_text) => Cow::Owned(formatted_text.plain_text()),
+  }
+}
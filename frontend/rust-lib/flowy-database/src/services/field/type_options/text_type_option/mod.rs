@@ -1,6 +1,13 @@
 #![allow(clippy::module_inception)]
+mod formatted_text;
 mod text_filter;
+mod text_inference;
+mod text_script;
 mod text_tests;
 mod text_type_option;
 
+pub use formatted_text::*;
+pub use text_filter::*;
+pub use text_inference::*;
+pub use text_script::*;
 pub use text_type_option::*;
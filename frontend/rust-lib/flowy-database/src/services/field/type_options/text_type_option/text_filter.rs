@@ -1,26 +1,117 @@
 use crate::entities::{TextFilterConditionPB, TextFilterPB};
+use crate::services::field::EmptyFilterCondition;
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use regex::Regex;
 
+/// Compiles the filter on every call, so it's only for the tests. The filtering keeps the
+/// [CompiledTextFilter] instead.
+#[cfg(test)]
 impl TextFilterPB {
   pub fn is_visible<T: AsRef<str>>(&self, cell_data: T) -> bool {
-    let cell_data = cell_data.as_ref().to_lowercase();
-    let content = &self.content.to_lowercase();
+    CompiledTextFilter::from(self).is_visible(cell_data)
+  }
+}
+
+/// The prepared form of the [TextFilterPB]. The content of the filter is lowercased, or compiled
+/// into the regex for the `Matches` and `DoesNotMatch` conditions, once when the filter is cached
+/// instead of every time a cell is filtered.
+#[derive(Debug, Clone)]
+pub struct CompiledTextFilter {
+  condition: TextFilterConditionPB,
+  content: String,
+  /// None if the condition is not a regex condition or the pattern is invalid.
+  regex: Option<Regex>,
+}
+
+impl CompiledTextFilter {
+  pub fn is_visible<T: AsRef<str>>(&self, cell_data: T) -> bool {
+    let cell_data = cell_data.as_ref();
+    let lowercase = || cell_data.to_lowercase();
+    let content = self.content.as_str();
     match self.condition {
-      TextFilterConditionPB::Is => &cell_data == content,
-      TextFilterConditionPB::IsNot => &cell_data != content,
-      TextFilterConditionPB::Contains => cell_data.contains(content),
-      TextFilterConditionPB::DoesNotContain => !cell_data.contains(content),
-      TextFilterConditionPB::StartsWith => cell_data.starts_with(content),
-      TextFilterConditionPB::EndsWith => cell_data.ends_with(content),
+      TextFilterConditionPB::Is => lowercase() == content,
+      TextFilterConditionPB::IsNot => lowercase() != content,
+      TextFilterConditionPB::Contains => lowercase().contains(content),
+      TextFilterConditionPB::DoesNotContain => !lowercase().contains(content),
+      TextFilterConditionPB::StartsWith => lowercase().starts_with(content),
+      TextFilterConditionPB::EndsWith => lowercase().ends_with(content),
       TextFilterConditionPB::TextIsEmpty => cell_data.is_empty(),
       TextFilterConditionPB::TextIsNotEmpty => !cell_data.is_empty(),
+      // The pattern is matched against the text as it is, and the invalid pattern matches nothing.
+      TextFilterConditionPB::Matches => self
+        .regex
+        .as_ref()
+        .map_or(false, |regex| regex.is_match(cell_data)),
+      TextFilterConditionPB::DoesNotMatch => self
+        .regex
+        .as_ref()
+        .map_or(false, |regex| !regex.is_match(cell_data)),
+    }
+  }
+
+  pub fn empty_filter_condition(&self) -> Option<EmptyFilterCondition> {
+    match self.condition {
+      TextFilterConditionPB::TextIsEmpty => Some(EmptyFilterCondition::IsEmpty),
+      TextFilterConditionPB::TextIsNotEmpty => Some(EmptyFilterCondition::IsNotEmpty),
+      _ => None,
+    }
+  }
+
+  /// Checks the filter against a list of values, for example, the links of the URL cell. The
+  /// negative conditions, `IsNot`, `DoesNotContain` and `DoesNotMatch`, require every value to
+  /// match, and the others require any value to match. The empty list is treated as an empty cell.
+  pub fn is_visible_in_list<T: AsRef<str>>(&self, values: &[T]) -> bool {
+    match self.condition {
+      TextFilterConditionPB::TextIsEmpty => values.iter().all(|value| value.as_ref().is_empty()),
+      TextFilterConditionPB::IsNot
+      | TextFilterConditionPB::DoesNotContain
+      | TextFilterConditionPB::DoesNotMatch => values.iter().all(|value| self.is_visible(value)),
+      _ => values.iter().any(|value| self.is_visible(value)),
+    }
+  }
+}
+
+impl std::convert::From<&TextFilterPB> for CompiledTextFilter {
+  fn from(filter: &TextFilterPB) -> Self {
+    let regex = match filter.condition {
+      TextFilterConditionPB::Matches | TextFilterConditionPB::DoesNotMatch => {
+        match Regex::new(&filter.content) {
+          Ok(regex) => Some(regex),
+          Err(err) => {
+            tracing::warn!(
+              "Invalid regex in the text filter: {}, {}",
+              filter.content,
+              err
+            );
+            None
+          },
+        }
+      },
+      _ => None,
+    };
+    Self {
+      condition: filter.condition.clone(),
+      content: filter.content.to_lowercase(),
+      regex,
     }
   }
 }
 
+impl FromFilterString for CompiledTextFilter {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    CompiledTextFilter::from(&TextFilterPB::from_filter_rev(filter_rev))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::all)]
   use crate::entities::{TextFilterConditionPB, TextFilterPB};
+  use crate::services::field::CompiledTextFilter;
 
   #[test]
   fn text_filter_equal_test() {
@@ -80,4 +171,52 @@ mod tests {
     assert_eq!(text_filter.is_visible(""), false);
     assert_eq!(text_filter.is_visible("github"), false);
   }
+
+  #[test]
+  fn text_filter_regex_test() {
+    let filter = |condition: TextFilterConditionPB, content: &str| {
+      CompiledTextFilter::from(&TextFilterPB {
+        condition,
+        content: content.to_owned(),
+      })
+    };
+
+    // Anchors
+    let matches = filter(TextFilterConditionPB::Matches, "^App.*y$");
+    assert!(matches.is_visible("AppFlowy"));
+    assert!(!matches.is_visible("The AppFlowy"));
+    assert!(!matches.is_visible("appflowy"));
+
+    // Character classes
+    let matches = filter(TextFilterConditionPB::Matches, r"^[A-Z]{2}-\d+$");
+    assert!(matches.is_visible("AF-2023"));
+    assert!(!matches.is_visible("af-2023"));
+    assert!(!matches.is_visible("AF-"));
+
+    let does_not_match = filter(TextFilterConditionPB::DoesNotMatch, r"\s");
+    assert!(does_not_match.is_visible("AppFlowy"));
+    assert!(!does_not_match.is_visible("App Flowy"));
+
+    // The invalid pattern matches nothing, whatever the condition is.
+    for condition in [
+      TextFilterConditionPB::Matches,
+      TextFilterConditionPB::DoesNotMatch,
+    ] {
+      let invalid = filter(condition, "(App");
+      assert!(!invalid.is_visible("(App"));
+      assert!(!invalid.is_visible(""));
+    }
+  }
+
+  #[test]
+  fn compiled_text_filter_test() {
+    let text_filter = TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "AppFlowy".to_owned(),
+    };
+    let compiled = CompiledTextFilter::from(&text_filter);
+    for s in ["https://github.com/AppFlowy", "appflowy", "App", ""] {
+      assert_eq!(compiled.is_visible(s), text_filter.is_visible(s));
+    }
+  }
 }
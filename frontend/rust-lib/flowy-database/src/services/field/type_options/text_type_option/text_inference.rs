@@ -0,0 +1,75 @@
+use crate::entities::FieldType;
+use crate::services::field::{strip_currency_symbol, DateTypeOptionPB};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// The minimum number of the non-empty values that [infer_field_type] needs to be confident.
+const MIN_INFERENCE_VALUES: usize = 3;
+
+/// Returns the field type that all the non-empty values fit, for example, [FieldType::Number] for
+/// `12`, `$4.50` and `30%`. The empty values are ignored. Returns None if there are fewer than
+/// three non-empty values, or the values don't agree on a type other than the text.
+pub fn infer_field_type<'a>(values: impl IntoIterator<Item = &'a str>) -> Option<FieldType> {
+  let mut inferred: Option<FieldType> = None;
+  let mut count = 0;
+  for value in values
+    .into_iter()
+    .map(str::trim)
+    .filter(|value| !value.is_empty())
+  {
+    let field_type = infer_value_field_type(value)?;
+    match &inferred {
+      Some(inferred) if *inferred != field_type => return None,
+      _ => inferred = Some(field_type),
+    }
+    count += 1;
+  }
+  if count < MIN_INFERENCE_VALUES {
+    return None;
+  }
+  inferred
+}
+
+/// The digits are numbers rather than checkboxes, and the numbers are never dates, so `2022` is a
+/// number.
+fn infer_value_field_type(value: &str) -> Option<FieldType> {
+  if is_checkbox_str(value) {
+    Some(FieldType::Checkbox)
+  } else if is_number_str(value) {
+    Some(FieldType::Number)
+  } else if is_url_str(value) {
+    Some(FieldType::URL)
+  } else if DateTypeOptionPB::default()
+    .date_cell_data_from_text(value)
+    .is_some()
+  {
+    Some(FieldType::DateTime)
+  } else {
+    None
+  }
+}
+
+fn is_checkbox_str(value: &str) -> bool {
+  matches!(
+    value.to_lowercase().as_str(),
+    "yes" | "no" | "true" | "false"
+  )
+}
+
+/// Accepts the currency symbol before the number, the `%` after it and the `,` group separators.
+fn is_number_str(value: &str) -> bool {
+  let value = strip_currency_symbol(value.strip_prefix('-').unwrap_or(value));
+  let value = value
+    .trim()
+    .trim_end_matches('%')
+    .trim_end()
+    .replace(',', "");
+  value.starts_with(|c: char| c.is_ascii_digit()) && Decimal::from_str(&value).is_ok()
+}
+
+fn is_url_str(value: &str) -> bool {
+  ["http://", "https://", "www."]
+    .iter()
+    .any(|scheme| value.starts_with(scheme))
+    && !value.contains(char::is_whitespace)
+}
@@ -0,0 +1,71 @@
+/// The writing system of the text, see [detect_script].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+  Latin,
+  Greek,
+  Cyrillic,
+  Armenian,
+  Hebrew,
+  Arabic,
+  Devanagari,
+  Thai,
+  Hangul,
+  /// The Chinese characters and the Japanese kana.
+  Cjk,
+  /// The letters that don't belong to any of the other scripts.
+  Other,
+}
+
+impl Script {
+  /// Returns the script of the letter by its Unicode block. Returns None if the char is not a
+  /// letter, for example, the digits, the punctuation and the emoji.
+  pub fn from_char(c: char) -> Option<Self> {
+    if !c.is_alphabetic() {
+      return None;
+    }
+
+    let script = match c as u32 {
+      0x0041..=0x024F | 0x1E00..=0x1EFF | 0x2C60..=0x2C7F | 0xA720..=0xA7FF => Script::Latin,
+      // The fullwidth Latin letters.
+      0xFF21..=0xFF3A | 0xFF41..=0xFF5A => Script::Latin,
+      0x0370..=0x03FF | 0x1F00..=0x1FFF => Script::Greek,
+      0x0400..=0x052F | 0x1C80..=0x1C8F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => Script::Cyrillic,
+      0x0530..=0x058F => Script::Armenian,
+      0x0590..=0x05FF | 0xFB1D..=0xFB4F => Script::Hebrew,
+      0x0600..=0x06FF | 0x0750..=0x077F | 0x08A0..=0x08FF => Script::Arabic,
+      0xFB50..=0xFDFF | 0xFE70..=0xFEFF => Script::Arabic,
+      0x0900..=0x097F | 0xA8E0..=0xA8FF => Script::Devanagari,
+      0x0E00..=0x0E7F => Script::Thai,
+      0x1100..=0x11FF | 0x3130..=0x318F | 0xA960..=0xA97F | 0xAC00..=0xD7FF => Script::Hangul,
+      // The hiragana, the katakana and the CJK unified ideographs with their extensions.
+      0x3040..=0x30FF | 0x31F0..=0x31FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF => Script::Cjk,
+      0xF900..=0xFAFF | 0xFF66..=0xFF9F | 0x20000..=0x323AF => Script::Cjk,
+      _ => Script::Other,
+    };
+    Some(script)
+  }
+}
+
+/// Returns the script that most of the letters of the text are written in, for example,
+/// [Script::Latin] for `Hello 世界`. The ties go to the script that appears first. Returns None
+/// if the text has no letters.
+pub fn detect_script(s: &str) -> Option<Script> {
+  // Keeps the order of appearance, there are only a few scripts in a text.
+  let mut counts: Vec<(Script, usize)> = vec![];
+  for script in s.chars().flat_map(Script::from_char) {
+    match counts.iter_mut().find(|(other, _)| *other == script) {
+      Some((_, count)) => *count += 1,
+      None => counts.push((script, 1)),
+    }
+  }
+  counts
+    .into_iter()
+    .fold(
+      None,
+      |dominant: Option<(Script, usize)>, (script, count)| match dominant {
+        Some((_, dominant_count)) if dominant_count >= count => dominant,
+        _ => Some((script, count)),
+      },
+    )
+    .map(|(script, _)| script)
+}
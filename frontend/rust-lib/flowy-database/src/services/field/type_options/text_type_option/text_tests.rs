@@ -1,10 +1,20 @@
 #[cfg(test)]
 mod tests {
-  use crate::entities::FieldType;
-  use crate::services::cell::stringify_cell_data;
+  use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+  use crate::services::cell::{
+    insert_number_cell, insert_text_cell, stringify_cell_data, AnyTypeCache, CacheStats,
+    CellDataChangeset, TypeCellData,
+  };
 
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, filter_is_empty, ingest_json_value,
+    spreadsheet_value,
+  };
   use crate::services::field::FieldBuilder;
   use crate::services::field::*;
+  use database_model::CellRevision;
+  use serde_json::json;
+  use std::cmp::Ordering;
 
   // Test parser the cell data which field's type is FieldType::Date to cell data
   // which field's type is FieldType::Text
@@ -26,6 +36,8 @@ mod tests {
     let data = DateCellData {
       timestamp: Some(1647251762),
       include_time: true,
+      precision: None,
+      end_timestamp: None,
     };
 
     assert_eq!(
@@ -81,7 +93,717 @@ mod tests {
         &field_type,
         &field_rev
       ),
-      format!("{},{}", france.name, argentina.name)
+      format!("{}, {}", france.name, argentina.name)
+    );
+  }
+
+  #[test]
+  fn text_trim_whitespace_test() {
+    let type_option = RichTextTypeOptionPB::default();
+    let (cell_str, _) = type_option
+      .apply_changeset("  hello  world  ".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "hello  world");
+
+    let mut type_option = RichTextTypeOptionPB::default();
+    type_option.trim = false;
+    let (cell_str, _) = type_option
+      .apply_changeset("  hello  world  ".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "  hello  world  ");
+  }
+
+  #[test]
+  fn text_collapse_internal_whitespace_test() {
+    let mut type_option = RichTextTypeOptionPB::default();
+    type_option.collapse_internal_whitespace = true;
+    let (cell_str, _) = type_option
+      .apply_changeset("  hello   \t world  ".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "hello world");
+
+    type_option.trim = false;
+    let (cell_str, _) = type_option
+      .apply_changeset("  hello   world  ".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, " hello world ");
+  }
+
+  #[test]
+  fn text_treat_whitespace_as_empty_test() {
+    let mut type_option = RichTextTypeOptionPB::default();
+    let is_empty_filter = CompiledTextFilter::from(&TextFilterPB {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_owned(),
+    });
+    let is_not_empty_filter = CompiledTextFilter::from(&TextFilterPB {
+      condition: TextFilterConditionPB::TextIsNotEmpty,
+      content: "".to_owned(),
+    });
+    let is_empty = |type_option: &RichTextTypeOptionPB, s: &str| {
+      let cell_data = StrCellData::from(s);
+      let filter_is_empty =
+        type_option.apply_filter(&is_empty_filter, &FieldType::RichText, &cell_data);
+      let filter_is_not_empty =
+        type_option.apply_filter(&is_not_empty_filter, &FieldType::RichText, &cell_data);
+      assert_eq!(type_option.is_cell_empty(&cell_data), filter_is_empty);
+      assert_ne!(filter_is_empty, filter_is_not_empty);
+      filter_is_empty
+    };
+
+    assert!(is_empty(&type_option, ""));
+    assert!(!is_empty(&type_option, " "));
+    assert!(!is_empty(&type_option, "\t\n"));
+    assert!(!is_empty(&type_option, "a"));
+
+    type_option.treat_whitespace_as_empty = true;
+    assert!(is_empty(&type_option, ""));
+    assert!(is_empty(&type_option, " "));
+    assert!(is_empty(&type_option, "\t\n"));
+    assert!(!is_empty(&type_option, "a"));
+  }
+
+  #[test]
+  fn text_ingest_json_value_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    assert_eq!(
+      ingest_json_value(&field_rev, json!("AppFlowy")).unwrap(),
+      "AppFlowy"
+    );
+    assert_eq!(ingest_json_value(&field_rev, json!(42)).unwrap(), "42");
+    assert_eq!(ingest_json_value(&field_rev, json!(true)).unwrap(), "true");
+    assert_eq!(ingest_json_value(&field_rev, json!(null)).unwrap(), "");
+
+    assert!(ingest_json_value(&field_rev, json!(["AppFlowy"])).is_err());
+    assert!(ingest_json_value(&field_rev, json!({ "text": "AppFlowy" })).is_err());
+  }
+
+  #[test]
+  fn text_transform_test() {
+    for (text_transform, expected) in [
+      (TextTransform::None, "hello wORLD  foo"),
+      (TextTransform::Upper, "HELLO WORLD  FOO"),
+      (TextTransform::Lower, "hello world  foo"),
+      (TextTransform::Title, "Hello World  Foo"),
+    ] {
+      let mut type_option = RichTextTypeOptionPB::default();
+      type_option.text_transform = text_transform;
+      let mut field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+      field_rev.insert_type_option(&type_option);
+
+      let (cell_str, _) = type_option
+        .apply_changeset("hello wORLD  foo".to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, "hello wORLD  foo", "{:?}", text_transform);
+      let display_str = cell_data_handler(&field_rev, &FieldType::RichText).stringify_cell_str(
+        cell_str,
+        &FieldType::RichText,
+        &field_rev,
+      );
+      assert_eq!(display_str, expected, "{:?}", text_transform);
+    }
+  }
+
+  #[test]
+  fn text_natural_sort_test() {
+    let mut type_option = RichTextTypeOptionPB::default();
+    let sort = |type_option: &RichTextTypeOptionPB| {
+      let mut cells = ["a2", "a10", "a1"].map(StrCellData::from).to_vec();
+      cells.sort_by(|left, right| type_option.apply_cmp(left, right));
+      cells
+        .into_iter()
+        .map(|cell_data| cell_data.0)
+        .collect::<Vec<String>>()
+    };
+    assert_eq!(sort(&type_option), vec!["a1", "a10", "a2"]);
+
+    type_option.natural_sort = true;
+    assert_eq!(sort(&type_option), vec!["a1", "a2", "a10"]);
+
+    assert_eq!(natural_cmp("item2", "item10"), Ordering::Less);
+    assert_eq!(natural_cmp("a01", "a1"), Ordering::Less);
+    assert_eq!(natural_cmp("a1b", "a1"), Ordering::Greater);
+    assert_eq!(
+      natural_cmp("v99999999999999999999999", "v100000000000000000000000"),
+      Ordering::Less
+    );
+  }
+
+  #[test]
+  fn text_case_insensitive_sort_test() {
+    let cell_data_cache = AnyTypeCache::<u64>::new();
+    let sort = |type_option: &RichTextTypeOptionPB, cells: [&str; 3]| {
+      let mut field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+      field_rev.insert_type_option(type_option);
+      let handler =
+        TypeOptionCellExt::new_with_cell_data_cache(&field_rev, Some(cell_data_cache.clone()))
+          .get_type_option_cell_data_handler(&FieldType::RichText)
+          .unwrap();
+      let mut cells = cells.to_vec();
+      cells.sort_by(|left, right| handler.handle_cell_compare(left, right, &field_rev, None));
+      cells
+    };
+
+    let mut type_option = RichTextTypeOptionPB::default();
+    assert!(type_option.case_sensitive);
+    assert_eq!(
+      sort(&type_option, ["banana", "apple", "Cherry"]),
+      vec!["Cherry", "apple", "banana"]
+    );
+
+    type_option.case_sensitive = false;
+    assert_eq!(
+      sort(&type_option, ["banana", "Apple", "cherry"]),
+      vec!["Apple", "banana", "cherry"]
+    );
+    assert_eq!(
+      sort(&type_option, ["banana", "apple", "Cherry"]),
+      vec!["apple", "banana", "Cherry"]
+    );
+
+    // The folded texts are cached, so sorting the same cells again doesn't fold them again.
+    let misses = cell_data_cache.read().stats().misses;
+    sort(&type_option, ["banana", "Apple", "cherry"]);
+    assert_eq!(cell_data_cache.read().stats().misses, misses);
+  }
+
+  #[test]
+  fn text_transform_compare_test() {
+    let mut type_option = RichTextTypeOptionPB::default();
+    type_option.text_transform = TextTransform::Upper;
+    let apple = StrCellData::from("apple");
+    let banana = StrCellData::from("Banana");
+    assert_eq!(type_option.apply_cmp(&apple, &banana), Ordering::Greater);
+
+    type_option.compare_transformed = true;
+    assert_eq!(type_option.apply_cmp(&apple, &banana), Ordering::Less);
+  }
+
+  #[test]
+  fn text_formatted_text_keeps_marks_test() {
+    let mut type_option = RichTextTypeOptionPB::default();
+    type_option.collapse_internal_whitespace = true;
+    let changeset = json!([
+      {"insert": "  Hello", "attributes": {"bold": true}},
+      {"insert": "   "},
+      {"insert": "AppFlowy  ", "attributes": {"href": "https://appflowy.io"}},
+    ])
+    .to_string();
+    let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    let expected = r#"[{"insert":"Hello","attributes":{"bold":true}},{"insert":" "},{"insert":"AppFlowy","attributes":{"href":"https://appflowy.io"}}]"#;
+    assert_eq!(cell_str, expected);
+    assert_eq!(cell_data.plain_text(), "Hello AppFlowy");
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), expected);
+
+    type_option.text_transform = TextTransform::Upper;
+    let cell_data = StrCellData::from(expected);
+    assert_eq!(
+      type_option.decode_cell_data_to_str(cell_data),
+      expected
+        .replace("\"Hello\"", "\"HELLO\"")
+        .replace("\"AppFlowy\"", "\"APPFLOWY\"")
+    );
+  }
+
+  #[test]
+  fn text_formatted_text_without_marks_test() {
+    let type_option = RichTextTypeOptionPB::default();
+    let changeset = json!([{"insert": "Hello"}, {"insert": " world"}]).to_string();
+    let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_str, "Hello world");
+
+    let (cell_str, cell_data) = type_option
+      .apply_changeset("[1, 2]".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "[1, 2]");
+    assert_eq!(cell_data.plain_text(), "[1, 2]");
+  }
+
+  #[test]
+  fn text_formatted_text_sort_and_filter_test() {
+    let type_option = RichTextTypeOptionPB::default();
+    let bold_b = StrCellData(
+      json!([{"insert": "b", "attributes": {"bold": true}}, {"insert": "ar"}]).to_string(),
+    );
+    let plain_a = StrCellData::from("az");
+    assert_eq!(type_option.apply_cmp(&bold_b, &plain_a), Ordering::Greater);
+    assert_eq!(type_option.apply_cmp(&plain_a, &bold_b), Ordering::Less);
+
+    let contains_filter = CompiledTextFilter::from(&TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "bar".to_owned(),
+    });
+    assert!(type_option.apply_filter(&contains_filter, &FieldType::RichText, &bold_b));
+
+    let attributes_filter = CompiledTextFilter::from(&TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "bold".to_owned(),
+    });
+    assert!(!type_option.apply_filter(&attributes_filter, &FieldType::RichText, &bold_b));
+  }
+
+  #[test]
+  fn text_get_cells_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let cells = vec![
+      (
+        "row_1".to_owned(),
+        insert_text_cell("b".to_owned(), &field_rev),
+      ),
+      ("row_2".to_owned(), CellRevision::new("invalid".to_owned())),
+      (
+        "row_3".to_owned(),
+        insert_text_cell("a".to_owned(), &field_rev),
+      ),
+      (
+        "row_4".to_owned(),
+        insert_text_cell("".to_owned(), &field_rev),
+      ),
+    ];
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let texts = ext
+      .get_cells::<StrCellData>(&cells)
+      .into_iter()
+      .map(|cell_data| cell_data.0)
+      .collect::<Vec<String>>();
+    assert_eq!(texts, vec!["b", "a", ""]);
+
+    assert!(ext.get_cells::<DateCellData>(&cells).is_empty());
+  }
+
+  #[test]
+  fn text_content_weight_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let short_text = insert_text_cell("Hi".to_owned(), &field_rev);
+    let long_text = insert_text_cell("Hello, AppFlowy".to_owned(), &field_rev);
+    assert_eq!(ext.content_weight(&short_text), 2);
+    assert!(ext.content_weight(&long_text) > ext.content_weight(&short_text));
+  }
+
+  #[test]
+  fn formatted_text_content_weight_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let bold_text = insert_text_cell(
+      r#"[{"insert":"Hi","attributes":{"bold":true}}]"#.to_owned(),
+      &field_rev,
+    );
+    assert_eq!(ext.content_weight(&bold_text), 2);
+  }
+
+  #[test]
+  fn text_mode_tie_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let cells = vec![
+      insert_text_cell("b".to_owned(), &field_rev),
+      insert_text_cell("a".to_owned(), &field_rev),
+      insert_text_cell("a".to_owned(), &field_rev),
+      insert_text_cell("b".to_owned(), &field_rev),
+    ];
+    let mode = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None)
+      .mode(&cells)
+      .and_then(|cell_data| cell_data.unbox_or_none::<StrCellData>())
+      .unwrap();
+    assert_eq!(mode.as_str(), "b");
+
+    let empty_cells = vec![insert_text_cell("".to_owned(), &field_rev)];
+    assert!(
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None)
+        .mode(&empty_cells)
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn text_common_prefix_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let cells = vec![
+      insert_text_cell("TASK-001".to_owned(), &field_rev),
+      insert_text_cell("".to_owned(), &field_rev),
+      insert_text_cell("TASK-012".to_owned(), &field_rev),
+      insert_text_cell("TASK-002".to_owned(), &field_rev),
+    ];
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    assert_eq!(ext.common_prefix(&cells), "TASK-0");
+    assert_eq!(ext.common_prefix(&cells[..1]), "TASK-001");
+    assert_eq!(ext.common_prefix(&[]), "");
+
+    let cells = vec![
+      insert_text_cell("TASK-001".to_owned(), &field_rev),
+      insert_text_cell("BUG-001".to_owned(), &field_rev),
+    ];
+    assert_eq!(ext.common_prefix(&cells), "");
+  }
+
+  #[test]
+  fn text_count_distinct_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cells = ["Apple", "apple", "Apple", "", "Banana", ""]
+      .iter()
+      .map(|s| insert_text_cell(s.to_string(), &field_rev))
+      .collect::<Vec<CellRevision>>();
+    // The values that only differ in the case are different values.
+    assert_eq!(ext.count_distinct(&cells, false), Some(3));
+    assert_eq!(ext.count_distinct(&cells, true), Some(4));
+    assert_eq!(
+      ext.aggregate(&cells, AggregateKind::CountDistinct),
+      Some(3.0)
+    );
+    assert_eq!(ext.count_distinct(&[], true), Some(0));
+
+    let number_field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    assert_eq!(
+      TypeOptionCellExt::new_with_cell_data_cache(&number_field_rev, None)
+        .count_distinct(&[insert_number_cell(1, &number_field_rev)], false),
+      None
+    );
+  }
+
+  #[test]
+  fn formatted_text_count_distinct_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let cells = [
+      "Apple",
+      r#"[{"insert":"Apple","attributes":{"bold":true}}]"#,
+      r#"[{"insert":"App","attributes":{"italic":true}},{"insert":"le"}]"#,
+    ]
+    .iter()
+    .map(|s| insert_text_cell(s.to_string(), &field_rev))
+    .collect::<Vec<CellRevision>>();
+    // The cells that only differ in the marks are the same value.
+    assert_eq!(ext.count_distinct(&cells, false), Some(1));
+  }
+
+  #[test]
+  fn text_auto_color_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::RichText);
+    let auto_color = |s: &str| {
+      let cell_rev = insert_text_cell(s.to_owned(), &field_rev);
+      let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+      handler.auto_color(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &field_rev,
+      )
+    };
+
+    assert!(auto_color("").is_none());
+    assert!(auto_color("AppFlowy").is_some());
+    assert_eq!(auto_color("AppFlowy"), auto_color("AppFlowy"));
+
+    let colors = ["Rust", "Dart", "Flutter", "Tauri", "AppFlowy", "Notion"]
+      .iter()
+      .map(|s| auto_color(s).unwrap() as u8)
+      .collect::<std::collections::HashSet<_>>();
+    assert!(colors.len() > 1);
+
+    // The marks don't change the color of the text.
+    assert_eq!(
+      auto_color(r#"[{"insert":"AppFlowy","attributes":{"bold":true}}]"#),
+      auto_color("AppFlowy")
+    );
+  }
+
+  #[test]
+  fn detect_script_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::RichText);
+    let detect_script = |s: &str| {
+      let cell_rev = insert_text_cell(s.to_owned(), &field_rev);
+      let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+      handler.detect_script(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &field_rev,
+      )
+    };
+
+    assert_eq!(detect_script(""), None);
+    assert_eq!(detect_script("42 - 7!"), None);
+    assert_eq!(detect_script("Crème brûlée"), Some(Script::Latin));
+    assert_eq!(detect_script("今日は良い天気です"), Some(Script::Cjk));
+    assert_eq!(detect_script("你好，世界"), Some(Script::Cjk));
+    assert_eq!(detect_script("Привет, мир"), Some(Script::Cyrillic));
+    assert_eq!(detect_script("مرحبا بالعالم"), Some(Script::Arabic));
+    assert_eq!(detect_script("안녕하세요"), Some(Script::Hangul));
+
+    // The marks are not the letters of the text.
+    assert_eq!(
+      detect_script(r#"[{"insert":"Привет","attributes":{"href":"https://appflowy.io"}}]"#),
+      Some(Script::Cyrillic)
+    );
+
+    // The mixed strings get the script of the most letters, the ties go to the first script.
+    assert_eq!(detect_script("Hello 世界"), Some(Script::Latin));
+    assert_eq!(
+      detect_script("AppFlowy 是一个开源的协作工具"),
+      Some(Script::Cjk)
+    );
+    assert_eq!(detect_script("ab 世界"), Some(Script::Latin));
+    assert_eq!(detect_script("世界 ab"), Some(Script::Cjk));
+  }
+
+  #[test]
+  fn text_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::RichText);
+    assert_eq!(handler.comparable_kind(), ComparableKind::String);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(
+      &FieldType::RichText,
+      &FieldType::RichText
+    ));
+    assert!(!is_transform_lossless(
+      &FieldType::RichText,
+      &FieldType::Number
+    ));
+
+    let filter = default_filter(&field_rev);
+    let filter = TextFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, TextFilterConditionPB::Contains);
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_text_cell("AppFlowy".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("AppFlowy".to_owned())
+    );
+    assert_eq!(cell_value(&field_rev, &cell_rev).text.unwrap(), "AppFlowy");
+    // The text cells don't have a glyph.
+    assert_eq!(handler.glyph(&cell_rev, &field_rev), None);
+  }
+
+  #[test]
+  fn text_is_empty_filter_test() {
+    let text_filter = |condition: TextFilterConditionPB| {
+      CompiledTextFilter::from(&TextFilterPB {
+        condition,
+        content: "".to_owned(),
+      })
+    };
+    let mut field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let type_option = RichTextTypeOptionPB {
+      treat_whitespace_as_empty: true,
+      ..Default::default()
+    };
+    field_rev.insert_type_option(&type_option);
+    for (cell_rev, expected) in [
+      (None, true),
+      (Some(insert_text_cell("   ".to_owned(), &field_rev)), true),
+      (Some(insert_text_cell("a".to_owned(), &field_rev)), false),
+    ] {
+      let is_empty = filter_is_empty(
+        &field_rev,
+        text_filter,
+        TextFilterConditionPB::TextIsEmpty,
+        TextFilterConditionPB::TextIsNotEmpty,
+        cell_rev.as_ref(),
+      );
+      assert_eq!(is_empty, expected);
+    }
+  }
+
+  #[test]
+  fn text_validate_cell_changeset_test() {
+    // The text field accepts any changeset.
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    assert!(cell_data_handler(&field_rev, &FieldType::RichText)
+      .validate_cell_changeset("anything", &field_rev)
+      .is_ok());
+  }
+
+  #[test]
+  fn text_required_field_satisfied_test() {
+    let field_rev = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+      .required(true)
+      .build();
+    let handler = cell_data_handler(&field_rev, &FieldType::RichText);
+    let text_cell = insert_text_cell("hello".to_owned(), &field_rev);
+    let empty_text_cell = insert_text_cell("".to_owned(), &field_rev);
+    assert!(handler.satisfies_required(Some(&text_cell), &field_rev));
+    assert!(!handler.satisfies_required(Some(&empty_text_cell), &field_rev));
+    assert!(!handler.satisfies_required(None, &field_rev));
+  }
+
+  #[test]
+  fn text_next_in_series_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::RichText);
+    for (texts, expected) in [
+      (vec!["A1", "A2"], Some("A3")),
+      (vec!["item009"], Some("item010")),
+      (vec!["5", "10"], Some("15")),
+      (vec!["A1", "B2"], None),
+      (vec!["A", "B"], None),
+      (vec!["A1", ""], None),
+    ] {
+      let cell_revs = texts
+        .iter()
+        .map(|text| insert_text_cell(text.to_string(), &field_rev))
+        .collect::<Vec<CellRevision>>();
+      let next = handler
+        .next_in_series(&cell_revs, &field_rev)
+        .map(|cell_rev| TypeCellData::try_from(&cell_rev).unwrap().cell_str);
+      assert_eq!(next.as_deref(), expected, "{:?}", texts);
+    }
+  }
+
+  #[test]
+  fn text_transform_cache_key_test() {
+    let mut field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let key = public_cache_key(&field_rev, &FieldType::RichText, "hello");
+
+    let mut type_option = RichTextTypeOptionPB::default();
+    type_option.text_transform = TextTransform::Upper;
+    field_rev.insert_type_option(&type_option);
+    let upper_key = public_cache_key(&field_rev, &FieldType::RichText, "hello");
+    assert_ne!(key, upper_key);
+  }
+
+  #[test]
+  fn cell_data_cache_stats_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let cell_rev = insert_text_cell("hello".to_owned(), &field_rev);
+    let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+    let cell_data_cache = AnyTypeCache::<u64>::new();
+    let handler =
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, Some(cell_data_cache.clone()))
+        .get_type_option_cell_data_handler(&FieldType::RichText)
+        .unwrap();
+
+    let decode = || {
+      handler
+        .get_cell_data(
+          type_cell_data.cell_str.clone(),
+          &FieldType::RichText,
+          &field_rev,
+        )
+        .unwrap();
+      cell_data_cache.read().stats()
+    };
+    assert_eq!(
+      decode(),
+      CacheStats {
+        hits: 0,
+        misses: 1,
+        len: 1
+      }
+    );
+    assert_eq!(
+      decode(),
+      CacheStats {
+        hits: 1,
+        misses: 1,
+        len: 1
+      }
+    );
+  }
+
+  #[test]
+  fn cell_data_cache_invalidate_field_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let cell_data_cache = AnyTypeCache::<u64>::new();
+    let handler =
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, Some(cell_data_cache.clone()))
+        .get_type_option_cell_data_handler(&FieldType::RichText)
+        .unwrap();
+    let decode = || {
+      handler
+        .get_cell_data("hello".to_owned(), &FieldType::RichText, &field_rev)
+        .unwrap();
+      cell_data_cache.read().stats()
+    };
+    assert_eq!(decode().misses, 1);
+    assert_eq!(decode().hits, 1);
+
+    // The other fields are kept.
+    cell_data_cache.write().invalidate_field("other field");
+    assert_eq!(decode().hits, 2);
+
+    cell_data_cache.write().invalidate_field(&field_rev.id);
+    assert_eq!(cell_data_cache.read().len(), 0);
+    assert_eq!(
+      decode(),
+      CacheStats {
+        hits: 2,
+        misses: 2,
+        len: 1
+      }
+    );
+  }
+
+  #[test]
+  fn public_cache_key_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let cell_data_cache = AnyTypeCache::<u64>::new();
+    let handler =
+      TypeOptionCellExt::new_with_cell_data_cache(&field_rev, Some(cell_data_cache.clone()))
+        .get_type_option_cell_data_handler(&FieldType::RichText)
+        .unwrap();
+    handler
+      .get_cell_data("hello".to_owned(), &FieldType::RichText, &field_rev)
+      .unwrap();
+
+    let key = public_cache_key(&field_rev, &FieldType::RichText, "hello");
+    assert_eq!(
+      cell_data_cache
+        .read()
+        .get::<StrCellData>(&key)
+        .unwrap()
+        .as_str(),
+      "hello"
+    );
+    let other_key = public_cache_key(&field_rev, &FieldType::RichText, "world");
+    assert!(!cell_data_cache.read().contains(&other_key));
+  }
+
+  #[test]
+  fn suggest_field_type_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let ext = TypeOptionCellExt::new_with_cell_data_cache(&field_rev, None);
+    let text_cells = |texts: &[&str]| {
+      texts
+        .iter()
+        .map(|s| insert_text_cell(s.to_string(), &field_rev))
+        .collect::<Vec<CellRevision>>()
+    };
+
+    // The empty cells are ignored.
+    let numbers = text_cells(&["12", "$4.50", "", "1,299", "-3", "30%"]);
+    assert_eq!(ext.suggest_field_type(&numbers), Some(FieldType::Number));
+    let checkboxes = text_cells(&["Yes", "no", "TRUE"]);
+    assert_eq!(
+      ext.suggest_field_type(&checkboxes),
+      Some(FieldType::Checkbox)
+    );
+    let dates = text_cells(&["2022-03-14", "03/15/2022", "16.03.2022"]);
+    assert_eq!(ext.suggest_field_type(&dates), Some(FieldType::DateTime));
+    let urls = text_cells(&["https://appflowy.io", "www.rust-lang.org", "http://a.com"]);
+    assert_eq!(ext.suggest_field_type(&urls), Some(FieldType::URL));
+
+    // The mixed or plain text content.
+    let mixed = text_cells(&["12", "4.5", "twelve", "7"]);
+    assert_eq!(ext.suggest_field_type(&mixed), None);
+    let numbers_and_dates = text_cells(&["12", "2022-03-14", "7"]);
+    assert_eq!(ext.suggest_field_type(&numbers_and_dates), None);
+    let words = text_cells(&["Apple", "Banana", "Cherry"]);
+    assert_eq!(ext.suggest_field_type(&words), None);
+    // Too few values to be confident.
+    assert_eq!(ext.suggest_field_type(&text_cells(&["1", "2", ""])), None);
+
+    // The other field types are already typed.
+    let number_field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let number_cells = (1..=3)
+      .map(|i| insert_number_cell(i, &number_field_rev))
+      .collect::<Vec<CellRevision>>();
+    assert_eq!(
+      TypeOptionCellExt::new_with_cell_data_cache(&number_field_rev, None)
+        .suggest_field_type(&number_cells),
+      None
     );
   }
 }
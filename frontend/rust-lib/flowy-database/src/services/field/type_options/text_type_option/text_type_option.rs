@@ -1,19 +1,21 @@
-use crate::entities::{FieldType, TextFilterPB};
+use crate::entities::{CellValuePB, FieldType, TextFilterConditionPB};
 use crate::impl_type_option;
 use crate::services::cell::{
-  stringify_cell_data, CellDataChangeset, CellDataDecoder, CellProtobufBlobParser, DecodedCellData,
-  FromCellString, TypeCellData,
+  series_step, stringify_cell_data, CellDataChangeset, CellDataDecoder, CellProtobufBlobParser,
+  DecodedCellData, FromCellString, TypeCellData,
 };
 use crate::services::field::{
-  BoxTypeOptionBuilder, TypeOption, TypeOptionBuilder, TypeOptionCellData,
-  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+  plain_text, BoxTypeOptionBuilder, ComparableKind, CompiledTextFilter, EmptyFilterCondition,
+  FormattedText, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
-use flowy_derive::ProtoBuf;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::{FlowyError, FlowyResult};
 use protobuf::ProtobufError;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::cmp::Ordering;
 
 #[derive(Default)]
@@ -31,21 +33,114 @@ impl TypeOptionBuilder for RichTextTypeOptionBuilder {
   }
 }
 
-/// For the moment, the `RichTextTypeOptionPB` is empty. The `data` property is not
-/// used yet.
-#[derive(Debug, Clone, Default, Serialize, Deserialize, ProtoBuf)]
+/// The `data` property is not used yet.
+#[derive(Debug, Clone, Serialize, Deserialize, ProtoBuf)]
 pub struct RichTextTypeOptionPB {
   #[pb(index = 1)]
   #[serde(default)]
   data: String,
+
+  /// Removes the leading and trailing whitespace of the text before storing it.
+  #[pb(index = 2)]
+  #[serde(default = "DEFAULT_TRIM_VALUE")]
+  pub trim: bool,
+
+  /// Collapses the runs of spaces inside the text into a single space before storing it.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub collapse_internal_whitespace: bool,
+
+  /// Treats the text that only contains whitespace as empty, which affects the `is empty` and
+  /// `is not empty` filters.
+  #[pb(index = 4)]
+  #[serde(default)]
+  pub treat_whitespace_as_empty: bool,
+
+  /// Changes the case of the text when displaying it. The stored text is not changed.
+  #[pb(index = 5)]
+  #[serde(default)]
+  pub text_transform: TextTransform,
+
+  /// Compares the transformed text instead of the stored text when sorting the cells.
+  #[pb(index = 6)]
+  #[serde(default)]
+  pub compare_transformed: bool,
+
+  /// Compares the runs of digits numerically when sorting the cells, so `item2` comes before
+  /// `item10`. Otherwise, the text is compared lexicographically.
+  #[pb(index = 7)]
+  #[serde(default)]
+  pub natural_sort: bool,
+
+  /// Compares the case-folded text when sorting the cells if it's false, so `apple` comes before
+  /// `Banana`.
+  #[pb(index = 8)]
+  #[serde(default = "DEFAULT_CASE_SENSITIVE_VALUE")]
+  pub case_sensitive: bool,
 }
 impl_type_option!(RichTextTypeOptionPB, FieldType::RichText);
 
+const DEFAULT_TRIM_VALUE: fn() -> bool = || true;
+const DEFAULT_CASE_SENSITIVE_VALUE: fn() -> bool = || true;
+
+impl std::default::Default for RichTextTypeOptionPB {
+  fn default() -> Self {
+    Self {
+      data: "".to_string(),
+      trim: DEFAULT_TRIM_VALUE(),
+      collapse_internal_whitespace: false,
+      treat_whitespace_as_empty: false,
+      text_transform: TextTransform::default(),
+      compare_transformed: false,
+      natural_sort: false,
+      case_sensitive: DEFAULT_CASE_SENSITIVE_VALUE(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum TextTransform {
+  None = 0,
+  Upper = 1,
+  Lower = 2,
+  /// Capitalizes the first letter of each word and lowercases the rest.
+  Title = 3,
+}
+
+impl std::default::Default for TextTransform {
+  fn default() -> Self {
+    TextTransform::None
+  }
+}
+
+impl TextTransform {
+  pub fn apply(&self, text: &str) -> String {
+    match self {
+      TextTransform::None => text.to_owned(),
+      TextTransform::Upper => text.to_uppercase(),
+      TextTransform::Lower => text.to_lowercase(),
+      TextTransform::Title => {
+        let mut title = String::with_capacity(text.len());
+        let mut is_word_start = true;
+        for c in text.chars() {
+          if is_word_start {
+            title.extend(c.to_uppercase());
+          } else {
+            title.extend(c.to_lowercase());
+          }
+          is_word_start = c.is_whitespace();
+        }
+        title
+      },
+    }
+  }
+}
+
 impl TypeOption for RichTextTypeOptionPB {
   type CellData = StrCellData;
   type CellChangeset = String;
   type CellProtobufType = StrCellData;
-  type CellFilter = TextFilterPB;
+  type CellFilter = CompiledTextFilter;
 }
 
 impl TypeOptionTransform for RichTextTypeOptionPB {
@@ -101,6 +196,22 @@ impl TypeOptionCellData for RichTextTypeOptionPB {
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     StrCellData::from_cell_str(&cell_str)
   }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    let text = cell_data.plain_text();
+    if self.treat_whitespace_as_empty {
+      text.trim().is_empty()
+    } else {
+      text.is_empty()
+    }
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      text: Some(cell_data.0),
+      ..Default::default()
+    }
+  }
 }
 
 impl CellDataDecoder for RichTextTypeOptionPB {
@@ -113,12 +224,20 @@ impl CellDataDecoder for RichTextTypeOptionPB {
     StrCellData::from_cell_str(&cell_str)
   }
 
+  /// The formatted text keeps its marks, only the text of the runs is transformed.
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
-    cell_data.to_string()
+    match FormattedText::parse(&cell_data) {
+      None => self.text_transform.apply(&cell_data),
+      Some(formatted_text) => formatted_text
+        .map_text(|text| self.text_transform.apply(text))
+        .to_string(),
+    }
   }
 }
 
 impl CellDataChangeset for RichTextTypeOptionPB {
+  /// The changeset is the plain text or the [FormattedText]. The formatted text is stored with
+  /// its marks, or as the plain text if none of its runs has the marks.
   fn apply_changeset(
     &self,
     changeset: <Self as TypeOption>::CellChangeset,
@@ -127,10 +246,103 @@ impl CellDataChangeset for RichTextTypeOptionPB {
     if changeset.len() > 10000 {
       Err(FlowyError::text_too_long().context("The len of the text should not be more than 10000"))
     } else {
-      let text_cell_data = StrCellData(changeset);
+      let text = match FormattedText::parse(&changeset) {
+        None => self.normalize_whitespace(changeset),
+        Some(formatted_text) => {
+          let formatted_text = self.normalize_formatted_whitespace(formatted_text);
+          if formatted_text.has_marks() {
+            formatted_text.to_string()
+          } else {
+            formatted_text.plain_text()
+          }
+        },
+      };
+      let text_cell_data = StrCellData(text);
       Ok((text_cell_data.to_string(), text_cell_data))
     }
   }
+
+  /// Increments the trailing number of the texts that share the same prefix, for example, `A3`
+  /// after `A1, A2`. A single text is incremented by one. The number keeps its leading zeros, so
+  /// `item009` is followed by `item010`.
+  fn next_in_series(
+    &self,
+    cells: &[<Self as TypeOption>::CellData],
+  ) -> Option<<Self as TypeOption>::CellChangeset> {
+    let (prefix, digits) = split_trailing_number(cells.last()?)?;
+    let numbers = cells
+      .iter()
+      .map(|cell_data| match split_trailing_number(cell_data) {
+        Some((other_prefix, digits)) if other_prefix == prefix => digits.parse::<i64>().ok(),
+        _ => None,
+      })
+      .collect::<Option<Vec<i64>>>()?;
+    let step = if numbers.len() == 1 {
+      1
+    } else {
+      series_step(&numbers)?
+    };
+    let next = numbers
+      .last()?
+      .checked_add(step)
+      .filter(|next| *next >= 0)?;
+    Some(format!("{}{:0width$}", prefix, next, width = digits.len()))
+  }
+}
+
+/// Collapses the runs of spaces and tabs into a single space.
+fn collapse_whitespace(text: &str) -> String {
+  let mut collapsed = String::with_capacity(text.len());
+  let mut last_is_space = false;
+  for c in text.chars() {
+    let is_space = c == ' ' || c == '\t';
+    if !(is_space && last_is_space) {
+      collapsed.push(if is_space { ' ' } else { c });
+    }
+    last_is_space = is_space;
+  }
+  collapsed
+}
+
+/// Splits the text into the prefix and the trailing ASCII digits. Returns None if the text doesn't
+/// end with a digit.
+fn split_trailing_number(text: &str) -> Option<(&str, &str)> {
+  let prefix = text.trim_end_matches(|c: char| c.is_ascii_digit());
+  (prefix.len() < text.len()).then(|| text.split_at(prefix.len()))
+}
+
+impl RichTextTypeOptionPB {
+  fn normalize_whitespace(&self, mut text: String) -> String {
+    if self.trim {
+      text = text.trim().to_owned();
+    }
+
+    if self.collapse_internal_whitespace {
+      text = collapse_whitespace(&text);
+    }
+    text
+  }
+
+  /// Same as `normalize_whitespace` but keeps the marks. The runs of spaces are collapsed within
+  /// each run of the text.
+  fn normalize_formatted_whitespace(&self, mut formatted_text: FormattedText) -> FormattedText {
+    if self.trim {
+      formatted_text.trim();
+    }
+
+    if self.collapse_internal_whitespace {
+      formatted_text = formatted_text.map_text(collapse_whitespace);
+    }
+    formatted_text
+  }
+
+  fn cmp_text(&self, left: &str, right: &str) -> Ordering {
+    if self.natural_sort {
+      natural_cmp(left, right)
+    } else {
+      left.cmp(right)
+    }
+  }
 }
 
 impl TypeOptionCellDataFilter for RichTextTypeOptionPB {
@@ -144,7 +356,21 @@ impl TypeOptionCellDataFilter for RichTextTypeOptionPB {
       return false;
     }
 
-    filter.is_visible(cell_data)
+    if self.is_cell_empty(cell_data) {
+      return filter.is_visible("");
+    }
+    filter.is_visible(&cell_data.plain_text())
+  }
+
+  fn default_filter_condition(&self) -> u8 {
+    TextFilterConditionPB::Contains as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
   }
 }
 
@@ -154,10 +380,75 @@ impl TypeOptionCellDataCompare for RichTextTypeOptionPB {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering {
-    cell_data.0.cmp(&other_cell_data.0)
+    let left = cell_data.plain_text();
+    let right = other_cell_data.plain_text();
+    // The transform only changes the case of the text, which is ignored by the case-insensitive
+    // comparison.
+    if self.compare_transformed && self.case_sensitive {
+      let left = self.text_transform.apply(&left);
+      let right = self.text_transform.apply(&right);
+      return self.cmp_text(&left, &right);
+    }
+    self.cmp_text(&left, &right)
+  }
+
+  fn sort_key(&self, cell_data: &<Self as TypeOption>::CellData) -> Option<StrCellData> {
+    if self.case_sensitive {
+      None
+    } else {
+      Some(StrCellData(cell_data.plain_text().to_lowercase()))
+    }
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::String
   }
 }
 
+/// Compares the texts by splitting them into the runs of digits and the runs of other characters.
+/// The runs of digits are compared by their numeric value, and the other characters are compared
+/// one by one. The texts that are equal in this way, for example, `a01` and `a1`, fall back to the
+/// lexicographic order.
+pub fn natural_cmp(left: &str, right: &str) -> Ordering {
+  let mut left_chars = left.chars().peekable();
+  let mut right_chars = right.chars().peekable();
+  loop {
+    let order = match (left_chars.peek(), right_chars.peek()) {
+      (None, None) => return left.cmp(right),
+      (None, Some(_)) => return Ordering::Less,
+      (Some(_), None) => return Ordering::Greater,
+      (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+        let left_digits = take_digits(&mut left_chars);
+        let right_digits = take_digits(&mut right_chars);
+        // Comparing the lengths first handles the numbers that don't fit into an integer.
+        let left_digits = left_digits.trim_start_matches('0');
+        let right_digits = right_digits.trim_start_matches('0');
+        left_digits
+          .len()
+          .cmp(&right_digits.len())
+          .then_with(|| left_digits.cmp(right_digits))
+      },
+      (Some(l), Some(r)) => {
+        let order = l.cmp(r);
+        left_chars.next();
+        right_chars.next();
+        order
+      },
+    };
+    if order.is_ne() {
+      return order;
+    }
+  }
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+  let mut digits = String::new();
+  while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+    digits.push(c);
+  }
+  digits
+}
+
 #[derive(Clone)]
 pub struct TextCellData(pub String);
 impl AsRef<str> for TextCellData {
@@ -224,6 +515,13 @@ impl std::ops::DerefMut for StrCellData {
   }
 }
 
+impl StrCellData {
+  /// Returns the text of the text cell without the marks, see [FormattedText].
+  pub fn plain_text(&self) -> Cow<str> {
+    plain_text(&self.0)
+  }
+}
+
 impl FromCellString for StrCellData {
   fn from_cell_str(s: &str) -> FlowyResult<Self> {
     Ok(Self(s.to_owned()))
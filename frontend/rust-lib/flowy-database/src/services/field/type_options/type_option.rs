@@ -1,8 +1,8 @@
-use crate::entities::FieldType;
+use crate::entities::{CellValuePB, FieldType};
 use crate::services::cell::{
   CellDataDecoder, FromCellChangesetString, FromCellString, ToCellChangesetString,
 };
-
+use crate::services::field::SelectOptionColorPB;
 use crate::services::filter::FromFilterString;
 use bytes::Bytes;
 use database_model::FieldRevision;
@@ -53,6 +53,19 @@ pub trait TypeOptionCellData: TypeOption {
     cell_data: <Self as TypeOption>::CellData,
   ) -> <Self as TypeOption>::CellProtobufType;
 
+  /// Same as `convert_to_protobuf` but for the cells of a whole column. Override it if the type
+  /// option can share some work between the cells, the returned protobufs must keep the order of
+  /// the `cell_datas`.
+  fn convert_cells_to_protobuf(
+    &self,
+    cell_datas: Vec<<Self as TypeOption>::CellData>,
+  ) -> Vec<<Self as TypeOption>::CellProtobufType> {
+    cell_datas
+      .into_iter()
+      .map(|cell_data| self.convert_to_protobuf(cell_data))
+      .collect()
+  }
+
   /// Decodes the opaque cell string to corresponding data struct.
   // For example, the cell data is timestamp if its field type is `FieldType::Date`. This cell
   // data can not directly show to user. So it needs to be encode as the date string with custom
@@ -61,6 +74,33 @@ pub trait TypeOptionCellData: TypeOption {
     &self,
     cell_str: String,
   ) -> FlowyResult<<Self as TypeOption>::CellData>;
+
+  /// Returns true if the decoded cell data is considered empty. For example, the date cell
+  /// without timestamp or the select option cell without any selected option.
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool;
+
+  /// Convert the decoded cell data into the [CellValuePB], which can represent the value of any
+  /// field type.
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB;
+
+  /// Returns the canonical cell string of the decoded cell data. It should be the same as the
+  /// cell string that is generated by applying the changeset.
+  fn normalize_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> FlowyResult<String> {
+    Ok(cell_data.to_string())
+  }
+
+  /// Returns the key of the decoded cell data that doesn't depend on the format of the field, so
+  /// the cells of the differently-formatted fields that hold the same value have the same key.
+  /// Defaults to the cell string of the cell data.
+  fn value_key(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+
+  /// Returns the single-character form of the cell data, which is shown on the dense board cards.
+  /// Returns None if the field type has no glyph, for example, the text, or the cell is empty.
+  fn glyph(&self, _cell_data: &<Self as TypeOption>::CellData) -> Option<CellGlyph> {
+    None
+  }
 }
 
 pub trait TypeOptionTransform: TypeOption {
@@ -113,6 +153,26 @@ pub trait TypeOptionCellDataFilter: TypeOption + CellDataDecoder {
     field_type: &FieldType,
     cell_data: &<Self as TypeOption>::CellData,
   ) -> bool;
+
+  /// Returns the condition that is pre-filled when the user adds a filter to the field. The
+  /// content of the default filter is empty, for example, the empty query of the text filter.
+  fn default_filter_condition(&self) -> u8;
+
+  /// Returns the [EmptyFilterCondition] if the filter only checks whether the cell is empty. Such
+  /// filters are evaluated by [TypeOptionCellData::is_cell_empty] instead of the `apply_filter`,
+  /// so all the field types agree on what an empty cell is.
+  fn empty_filter_condition(
+    &self,
+    _filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    None
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyFilterCondition {
+  IsEmpty,
+  IsNotEmpty,
 }
 
 #[inline(always)]
@@ -120,10 +180,82 @@ pub fn default_order() -> Ordering {
   Ordering::Equal
 }
 
+/// The sort weight of the `No status` group. It makes the `No status` group always be the last
+/// group when sorting the groups by their weights.
+pub const NO_STATUS_GROUP_SORT_WEIGHT: i64 = i64::MAX;
+
+/// Describes how the cells of a field are compared when sorting, so the sort UI can be built
+/// without knowing the concrete type option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparableKind {
+  String,
+  Number,
+  DateTime,
+  Bool,
+  OptionSet,
+  /// The cells are compared by the percentage of the completed items, for example, the checklist.
+  CompletePercentage,
+  /// The field can't be sorted.
+  None,
+}
+
+/// The native value of a cell in the spreadsheet, which is used when exporting to XLSX.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpreadsheetValue {
+  Number(f64),
+  /// The Excel serial date, the number of days since 1899-12-30.
+  Date(f64),
+  Bool(bool),
+  Text(String),
+}
+
+/// The contribution of a cell to the pivot table, see [TypeOptionCellDataHandler::pivot_cell].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PivotValue {
+  /// The keys of the row or column headers that the cell is grouped into. The select option cell
+  /// is grouped into each of its selected options, the key is the id of the option.
+  pub keys: Vec<String>,
+  /// The numeric value that is aggregated by the pivot table. Only the number cell has the measure.
+  pub measure: Option<f64>,
+}
+
+/// The single-character status of a cell, see [TypeOptionCellData::glyph].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellGlyph {
+  pub char: char,
+  /// None means the glyph uses the default text color.
+  pub color: Option<SelectOptionColorPB>,
+}
+
 pub trait TypeOptionCellDataCompare: TypeOption {
   fn apply_cmp(
     &self,
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering;
+
+  /// Returns the form of the cell data that is passed to [Self::apply_cmp] when sorting the cells,
+  /// for example, the case-folded text. It's cached along with the decoded cell data, so it's
+  /// computed once per cell instead of on every comparison. Returns None if the cell data is
+  /// compared as it is.
+  fn sort_key(
+    &self,
+    _cell_data: &<Self as TypeOption>::CellData,
+  ) -> Option<<Self as TypeOption>::CellData> {
+    None
+  }
+
+  /// Returns the kind of the value that is used to compare the cells.
+  fn comparable_kind(&self) -> ComparableKind;
+
+  /// Returns the weight of the group when grouping by the field. The group with a smaller weight
+  /// comes first. The `No status` group, whose id is the same as the field id, gets the
+  /// [NO_STATUS_GROUP_SORT_WEIGHT]. The other groups keep their original order by default.
+  fn group_sort_weight(&self, group_id: &str, field_rev: &FieldRevision) -> i64 {
+    if group_id == field_rev.id {
+      NO_STATUS_GROUP_SORT_WEIGHT
+    } else {
+      0
+    }
+  }
 }
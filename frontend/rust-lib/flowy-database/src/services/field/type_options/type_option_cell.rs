@@ -1,21 +1,35 @@
-use crate::entities::FieldType;
+use crate::entities::{CellValuePB, FieldType, FilterPB};
 use crate::services::cell::{
   AtomicCellDataCache, AtomicCellFilterCache, CellDataChangeset, CellDataDecoder, CellProtobufBlob,
   FromCellChangesetString, FromCellString, TypeCellData,
 };
 use crate::services::field::{
-  CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB, MultiSelectTypeOptionPB,
-  NumberTypeOptionPB, RichTextTypeOptionPB, SingleSelectTypeOptionPB, TypeOption,
-  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
-  URLTypeOptionPB,
+  detect_script, infer_field_type, plain_text, select_type_option_from_field_rev, CellGlyph,
+  CheckboxCellData, CheckboxTypeOptionPB, ChecklistTypeOptionPB, ComparableKind,
+  ConcatTypeOptionPB, DateCellData, DateTypeOptionPB, DurationTypeOptionPB, EmailTypeOptionPB,
+  EmptyFilterCondition, IpTypeOptionPB, JsonTypeOptionPB, MultiSelectTypeOptionPB,
+  NumberTypeOptionPB, OrderTypeOptionPB, PhoneTypeOptionPB, PivotValue, RatingTypeOptionPB,
+  RelationTypeOptionPB, RichTextTypeOptionPB, Script, SelectDistributionItemPB,
+  SelectDistributionPB, SelectOptionColorPB, SelectOptionIds, SingleSelectTypeOptionPB,
+  SpreadsheetValue, StrCellData, TypeOption, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform, URLTypeOptionPB, UrlCategory,
+  NO_STATUS_GROUP_SORT_WEIGHT,
 };
 use crate::services::filter::FilterType;
-use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
-use flowy_error::FlowyResult;
+use database_model::{
+  CellRevision, FieldId, FieldRevision, FilterRevision, NullsOrder, RowId,
+  TypeOptionDataDeserializer, TypeOptionDataSerializer,
+};
+use flowy_error::{FlowyError, FlowyResult};
+use indexmap::IndexMap;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use std::any::Any;
 use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 
 /// A helper trait that used to erase the `Self` of `TypeOption` trait to make it become a Object-safe trait
 /// Only object-safe traits can be made into trait objects.
@@ -31,6 +45,26 @@ pub trait TypeOptionCellDataHandler {
     field_rev: &FieldRevision,
   ) -> FlowyResult<CellProtobufBlob>;
 
+  /// Same as `handle_cell_str` but handles the cells of a whole column in one call, for example,
+  /// the visible rows of a grid page. The results keep the order of the `cells`, and a cell that
+  /// fails to be handled doesn't affect the others.
+  fn handle_cells_str(
+    &self,
+    cells: &[(RowId, CellRevision)],
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> Vec<(RowId, FlowyResult<CellProtobufBlob>)> {
+    cells
+      .iter()
+      .map(|(row_id, cell_rev)| {
+        let result = TypeCellData::try_from(cell_rev).and_then(|type_cell_data| {
+          self.handle_cell_str(type_cell_data.cell_str, decoded_field_type, field_rev)
+        });
+        (row_id.clone(), result)
+      })
+      .collect()
+  }
+
   fn handle_cell_changeset(
     &self,
     cell_changeset: String,
@@ -38,11 +72,23 @@ pub trait TypeOptionCellDataHandler {
     field_rev: &FieldRevision,
   ) -> FlowyResult<String>;
 
+  /// Returns the error of the changeset without writing anything, so the UI can show it inline
+  /// before the changeset is committed. See [CellDataChangeset::validate_changeset].
+  fn validate_cell_changeset(
+    &self,
+    cell_changeset: &str,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<()>;
+
+  /// Compares the cells in ascending order. The empty cells are grouped before or after the other
+  /// cells if the `nulls_order` is not None, otherwise they're compared as the default value of the
+  /// field type.
   fn handle_cell_compare(
     &self,
     left_cell_data: &str,
     right_cell_data: &str,
     field_rev: &FieldRevision,
+    nulls_order: Option<NullsOrder>,
   ) -> Ordering;
 
   fn handle_cell_filter(
@@ -61,12 +107,258 @@ pub trait TypeOptionCellDataHandler {
     field_rev: &FieldRevision,
   ) -> String;
 
+  /// Same as `stringify_cell_str` but honors the `precision` of the cell, which overrides the
+  /// decimal places of the number field.
+  fn stringify_type_cell_data(
+    &self,
+    type_cell_data: TypeCellData,
+    field_rev: &FieldRevision,
+  ) -> String;
+
+  /// Same as `stringify_cell_str` but drops the inline marks of the text cell, see [plain_text].
+  /// It's used where the content of the cell is processed instead of displayed.
+  fn stringify_cell_plain_text(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> String {
+    let s = self.stringify_cell_str(cell_str, decoded_field_type, field_rev);
+    let field_type: FieldType = field_rev.ty.into();
+    if field_type.is_text() {
+      plain_text(&s).into_owned()
+    } else {
+      s
+    }
+  }
+
   fn get_cell_data(
     &self,
     cell_str: String,
     decoded_field_type: &FieldType,
     field_rev: &FieldRevision,
   ) -> FlowyResult<BoxCellData>;
+
+  /// Returns true if the cell_str is decoded into an empty cell data. The cell that can't be
+  /// decoded is treated as empty.
+  fn is_cell_empty(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> bool;
+
+  /// Returns false if the field is marked as required and the cell is empty, which is used to
+  /// gate the form submission. The missing cell is treated as empty. The field that is not
+  /// required is always satisfied.
+  fn satisfies_required(&self, cell_rev: Option<&CellRevision>, field_rev: &FieldRevision) -> bool;
+
+  /// Decode the cell_str to corresponding cell data, and then return the value of the cell data
+  /// as the [CellValuePB]. Unlike the `handle_cell_str`, the returned value has the same type
+  /// for all field types.
+  fn get_cell_oneof(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<CellValuePB>;
+
+  /// Decode the cell_str and then encode it again, returns the canonical cell string.
+  fn handle_cell_normalize(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<String>;
+
+  /// Returns the weight that is used to sort the group headers when grouping by the field. By
+  /// default, the `No status` group comes last and the other groups keep their original order.
+  fn group_sort_weight(&self, group_id: &str, field_rev: &FieldRevision) -> i64 {
+    if group_id == field_rev.id {
+      NO_STATUS_GROUP_SORT_WEIGHT
+    } else {
+      0
+    }
+  }
+
+  /// Returns the kind of the value that is used to sort the cells.
+  fn comparable_kind(&self) -> ComparableKind;
+
+  /// Returns a color that is derived from the cell's display string. The same string always gets
+  /// the same color, which is used to render the text as a chip. Returns None if the cell is empty.
+  fn auto_color(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> Option<SelectOptionColorPB> {
+    if self.is_cell_empty(cell_str.clone(), decoded_field_type, field_rev) {
+      return None;
+    }
+    let s = self.stringify_cell_plain_text(cell_str, decoded_field_type, field_rev);
+    if s.is_empty() {
+      return None;
+    }
+    // FNV-1a, the color of the string must not change across the releases.
+    let hash = s.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+      (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    let index = (hash % AUTO_COLOR_PALETTE.len() as u64) as usize;
+    Some(AUTO_COLOR_PALETTE[index].clone())
+  }
+
+  /// Returns the text of the cell that is read by the screen readers. Defaults to the display
+  /// string of the cell.
+  fn accessibility_label(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> String {
+    self.stringify_cell_str(cell_str, decoded_field_type, field_rev)
+  }
+
+  /// Returns the native value of the cell that is used when exporting to the spreadsheet. Defaults
+  /// to the display string of the cell as the text value.
+  fn to_spreadsheet_value(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> SpreadsheetValue {
+    SpreadsheetValue::Text(self.stringify_cell_str(cell_str, decoded_field_type, field_rev))
+  }
+
+  /// Returns the category of the link in the cell, which is used to style the link. Returns None
+  /// if the cell is empty or the field isn't a [FieldType::URL] field.
+  fn url_category(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> Option<UrlCategory> {
+    let field_type: FieldType = field_rev.ty.into();
+    if !field_type.is_url() || self.is_cell_empty(cell_str.clone(), decoded_field_type, field_rev) {
+      return None;
+    }
+    let s = self.stringify_cell_str(cell_str, decoded_field_type, field_rev);
+    if s.trim().is_empty() {
+      return None;
+    }
+    Some(UrlCategory::from_url_str(&s))
+  }
+
+  /// Returns the dominant script of the cell's display string, which is used by the localization
+  /// tools to pick the cells to translate. Returns None if the cell is empty or has no letters.
+  fn detect_script(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> Option<Script> {
+    if self.is_cell_empty(cell_str.clone(), decoded_field_type, field_rev) {
+      return None;
+    }
+    detect_script(&self.stringify_cell_plain_text(cell_str, decoded_field_type, field_rev))
+  }
+
+  /// Returns the grouping keys and the numeric measure that the cell contributes to the pivot
+  /// table. Returns the default [PivotValue] if the cell is empty. By default, the cell is grouped
+  /// by its display string and has no measure.
+  fn pivot_cell(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> PivotValue {
+    if self.is_cell_empty(cell_str.clone(), decoded_field_type, field_rev) {
+      return PivotValue::default();
+    }
+    PivotValue {
+      keys: vec![self.stringify_cell_plain_text(cell_str, decoded_field_type, field_rev)],
+      measure: None,
+    }
+  }
+
+  /// Returns the markdown of the cell, for example, the links of the URL cell. Returns an empty
+  /// string if the cell is empty. Defaults to the plain text of the cell.
+  fn stringify_cell_markdown(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> String {
+    self.stringify_cell_plain_text(cell_str, decoded_field_type, field_rev)
+  }
+
+  /// Converts the JSON value into the cell of the field, which is used to ingest the values
+  /// posted by the webhooks. The value is converted tolerantly, for example, the number cell
+  /// accepts the JSON number or the numeric string. Returns error if the value has the wrong
+  /// shape, for example, the object for the number cell.
+  fn ingest_json_value(
+    &self,
+    value: &serde_json::Value,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<CellRevision>;
+
+  /// Returns the hash of the cell's value, which is used as the key to join the cells of two
+  /// fields. Unlike the `CellDataCacheKey`, the hash doesn't depend on the format of the field, so
+  /// `1,000` and `1000` have the same hash. All the empty cells have the same hash. Defaults to
+  /// the hash of the plain text of the cell.
+  fn value_hash(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> u64 {
+    let value_key = if self.is_cell_empty(cell_str.clone(), decoded_field_type, field_rev) {
+      "".to_owned()
+    } else {
+      self.stringify_cell_plain_text(cell_str, decoded_field_type, field_rev)
+    };
+    let mut hasher = DefaultHasher::new();
+    value_key.hash(&mut hasher);
+    hasher.finish()
+  }
+
+  /// Returns the filter with the default condition of the field type, which is pre-filled when
+  /// the user adds a filter to the field. The returned filter is not saved yet, so its id is empty.
+  /// Defaults to the first condition, whose value is 0.
+  fn default_filter(&self, field_rev: &FieldRevision) -> FilterPB {
+    let filter_rev = FilterRevision {
+      id: "".to_owned(),
+      field_id: field_rev.id.clone(),
+      field_type: field_rev.ty,
+      condition: 0,
+      content: "".to_owned(),
+    };
+    FilterPB::from(&filter_rev)
+  }
+
+  /// Returns false if the cells of the field are derived, for example, the concat cells, so the
+  /// UI disables editing them instead of sending the changeset that would be ignored. The cells are
+  /// editable by default.
+  fn is_editable(&self) -> bool {
+    true
+  }
+
+  /// Returns the cell that follows the `cell_revs` when filling a series down the column, for
+  /// example, `4` after `1, 2, 3` or `A3` after `A1, A2`. Returns None if any of the cells is empty
+  /// or there is no pattern. Returns None by default.
+  fn next_in_series(
+    &self,
+    _cell_revs: &[CellRevision],
+    _field_rev: &FieldRevision,
+  ) -> Option<CellRevision> {
+    None
+  }
+
+  /// Returns the single-character status of the cell that is shown on the dense board cards, for
+  /// example, `✓` for the checked checkbox. Returns None if the field type has no glyph, which is
+  /// the default.
+  fn glyph(&self, _cell_rev: &CellRevision, _field_rev: &FieldRevision) -> Option<CellGlyph> {
+    None
+  }
 }
 
 struct CellDataCacheKey(u64);
@@ -81,6 +373,18 @@ impl CellDataCacheKey {
     cell_str.hash(&mut hasher);
     Self(hasher.finish())
   }
+
+  /// The key of the cell's sort key, which is cached next to the decoded cell data.
+  pub fn sort_key(
+    field_rev: &FieldRevision,
+    decoded_field_type: FieldType,
+    cell_str: &str,
+  ) -> Self {
+    let mut hasher = DefaultHasher::new();
+    hasher.write_u64(Self::new(field_rev, decoded_field_type, cell_str).0);
+    hasher.write(b"sort_key");
+    Self(hasher.finish())
+  }
 }
 
 impl AsRef<u64> for CellDataCacheKey {
@@ -89,6 +393,17 @@ impl AsRef<u64> for CellDataCacheKey {
   }
 }
 
+/// Returns the key that the decoded cell data is cached with in the [AtomicCellDataCache], so the
+/// caches outside of the type options can share the same keying scheme. The key changes if the
+/// type option of the `decoded_field_type` changes.
+pub fn public_cache_key(
+  field_rev: &FieldRevision,
+  decoded_field_type: &FieldType,
+  cell_str: &str,
+) -> u64 {
+  *CellDataCacheKey::new(field_rev, decoded_field_type.clone(), cell_str).as_ref()
+}
+
 struct TypeOptionCellDataHandlerImpl<T> {
   inner: T,
   cell_data_cache: Option<AtomicCellDataCache>,
@@ -133,6 +448,7 @@ where
     if let Some(cell_data_cache) = self.cell_data_cache.as_ref() {
       let read_guard = cell_data_cache.read();
       if let Some(cell_data) = read_guard.get(key.as_ref()).cloned() {
+        read_guard.record_hit();
         tracing::trace!(
           "Cell cache hit: field_type:{}, cell_str: {}, cell_data: {:?}",
           decoded_field_type,
@@ -141,6 +457,7 @@ where
         );
         return Ok(cell_data);
       }
+      read_guard.record_miss();
     }
 
     let cell_data = self.decode_cell_str(cell_str.clone(), decoded_field_type, field_rev)?;
@@ -153,11 +470,45 @@ where
       );
       cell_data_cache
         .write()
-        .insert(key.as_ref(), cell_data.clone());
+        .insert_for_field(&field_rev.id, key.as_ref(), cell_data.clone());
     }
     Ok(cell_data)
   }
 
+  /// Returns the [TypeOptionCellDataCompare::sort_key] of the cell, or the decoded cell data if
+  /// the type option doesn't have the sort key.
+  fn get_sort_key_cell_data(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData>
+  where
+    T: TypeOptionCellDataCompare,
+  {
+    // The hits and misses are recorded by the lookup of the decoded cell data, so the type options
+    // without the sort key don't count a miss on every comparison.
+    let key = CellDataCacheKey::sort_key(field_rev, decoded_field_type.clone(), &cell_str);
+    if let Some(cell_data_cache) = self.cell_data_cache.as_ref() {
+      if let Some(sort_key) = cell_data_cache.read().get(key.as_ref()).cloned() {
+        return Ok(sort_key);
+      }
+    }
+
+    let cell_data = self.get_decoded_cell_data(cell_str, decoded_field_type, field_rev)?;
+    match self.sort_key(&cell_data) {
+      None => Ok(cell_data),
+      Some(sort_key) => {
+        if let Some(cell_data_cache) = self.cell_data_cache.as_ref() {
+          cell_data_cache
+            .write()
+            .insert_for_field(&field_rev.id, key.as_ref(), sort_key.clone());
+        }
+        Ok(sort_key)
+      },
+    }
+  }
+
   fn set_decoded_cell_data(
     &self,
     cell_str: &str,
@@ -173,7 +524,9 @@ where
         cell_str,
         cell_data
       );
-      cell_data_cache.write().insert(key.as_ref(), cell_data);
+      cell_data_cache
+        .write()
+        .insert_for_field(&field_rev.id, key.as_ref(), cell_data);
     }
   }
 }
@@ -219,6 +572,41 @@ where
     CellProtobufBlob::from(self.convert_to_protobuf(cell_data))
   }
 
+  fn handle_cells_str(
+    &self,
+    cells: &[(RowId, CellRevision)],
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> Vec<(RowId, FlowyResult<CellProtobufBlob>)> {
+    let mut cell_datas = Vec::with_capacity(cells.len());
+    let decoded = cells
+      .iter()
+      .map(|(row_id, cell_rev)| {
+        let result = TypeCellData::try_from(cell_rev)
+          .and_then(|type_cell_data| {
+            self.get_cell_data(type_cell_data.cell_str, decoded_field_type, field_rev)
+          })
+          .map(|cell_data| {
+            cell_datas.push(cell_data.unbox_or_default::<<Self as TypeOption>::CellData>())
+          });
+        (row_id.clone(), result)
+      })
+      .collect::<Vec<_>>();
+
+    // The protobufs are in the same order as the cells that are decoded successfully.
+    let mut protobufs = self.convert_cells_to_protobuf(cell_datas).into_iter();
+    decoded
+      .into_iter()
+      .map(|(row_id, result)| {
+        let result = result.and_then(|_| match protobufs.next() {
+          None => Err(FlowyError::internal().context("Missing the protobuf of the decoded cell")),
+          Some(protobuf) => CellProtobufBlob::from(protobuf),
+        });
+        (row_id, result)
+      })
+      .collect()
+  }
+
   fn handle_cell_changeset(
     &self,
     cell_changeset: String,
@@ -231,18 +619,43 @@ where
     Ok(cell_str)
   }
 
+  fn validate_cell_changeset(
+    &self,
+    cell_changeset: &str,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<()> {
+    let changeset = <Self as TypeOption>::CellChangeset::from_changeset(cell_changeset.to_owned())?;
+    self.validate_changeset(&changeset)
+  }
+
   fn handle_cell_compare(
     &self,
     left_cell_data: &str,
     right_cell_data: &str,
     field_rev: &FieldRevision,
+    nulls_order: Option<NullsOrder>,
   ) -> Ordering {
     let field_type: FieldType = field_rev.ty.into();
+    if let Some(nulls_order) = nulls_order {
+      let is_empty = |cell_str: &str| {
+        self
+          .get_decoded_cell_data(cell_str.to_owned(), &field_type, field_rev)
+          .map(|cell_data| self.inner.is_cell_empty(&cell_data))
+          .unwrap_or(true)
+      };
+      match (is_empty(left_cell_data), is_empty(right_cell_data)) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return nulls_order.empty_cell_order(),
+        (false, true) => return nulls_order.empty_cell_order().reverse(),
+        (false, false) => {},
+      }
+    }
+
     let left = self
-      .get_decoded_cell_data(left_cell_data.to_owned(), &field_type, field_rev)
+      .get_sort_key_cell_data(left_cell_data.to_owned(), &field_type, field_rev)
       .unwrap_or_default();
     let right = self
-      .get_decoded_cell_data(right_cell_data.to_owned(), &field_type, field_rev)
+      .get_sort_key_cell_data(right_cell_data.to_owned(), &field_type, field_rev)
       .unwrap_or_default();
     self.apply_cmp(&left, &right)
   }
@@ -256,10 +669,16 @@ where
     let perform_filter = || {
       let filter_cache = self.cell_filter_cache.as_ref()?.read();
       let cell_filter = filter_cache.get::<<Self as TypeOption>::CellFilter>(filter_type)?;
-      let cell_data = self
-        .get_decoded_cell_data(type_cell_data.cell_str, &filter_type.field_type, field_rev)
-        .ok()?;
-      Some(self.apply_filter(cell_filter, &filter_type.field_type, &cell_data))
+      let cell_data =
+        self.get_decoded_cell_data(type_cell_data.cell_str, &filter_type.field_type, field_rev);
+      if let Some(condition) = self.empty_filter_condition(cell_filter) {
+        // The cell that can't be decoded is treated as empty.
+        let is_empty = cell_data
+          .map(|cell_data| self.inner.is_cell_empty(&cell_data))
+          .unwrap_or(true);
+        return Some(is_empty == (condition == EmptyFilterCondition::IsEmpty));
+      }
+      Some(self.apply_filter(cell_filter, &filter_type.field_type, &cell_data.ok()?))
     };
 
     perform_filter().unwrap_or(true)
@@ -283,6 +702,27 @@ where
     }
   }
 
+  fn stringify_type_cell_data(
+    &self,
+    type_cell_data: TypeCellData,
+    field_rev: &FieldRevision,
+  ) -> String {
+    let field_type: FieldType = field_rev.ty.into();
+    match type_cell_data.precision {
+      Some(precision) if type_cell_data.field_type == field_type => {
+        match <Self as TypeOption>::CellData::from_cell_str(&type_cell_data.cell_str) {
+          Ok(cell_data) => self.decode_cell_data_to_str_with_precision(cell_data, precision),
+          Err(_) => "".to_string(),
+        }
+      },
+      _ => self.stringify_cell_str(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        field_rev,
+      ),
+    }
+  }
+
   fn get_cell_data(
     &self,
     cell_str: String,
@@ -300,74 +740,1172 @@ where
     };
     Ok(BoxCellData::new(cell_data))
   }
-}
 
-pub struct TypeOptionCellExt<'a> {
-  field_rev: &'a FieldRevision,
-  cell_data_cache: Option<AtomicCellDataCache>,
-  cell_filter_cache: Option<AtomicCellFilterCache>,
-}
+  fn is_cell_empty(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> bool {
+    match self.get_cell_data(cell_str, decoded_field_type, field_rev) {
+      Ok(cell_data) => self
+        .inner
+        .is_cell_empty(&cell_data.unbox_or_default::<<Self as TypeOption>::CellData>()),
+      Err(_) => true,
+    }
+  }
 
-impl<'a> TypeOptionCellExt<'a> {
-  pub fn new_with_cell_data_cache(
-    field_rev: &'a FieldRevision,
-    cell_data_cache: Option<AtomicCellDataCache>,
-  ) -> Self {
-    Self {
-      field_rev,
-      cell_data_cache,
-      cell_filter_cache: None,
+  fn satisfies_required(&self, cell_rev: Option<&CellRevision>, field_rev: &FieldRevision) -> bool {
+    if !field_rev.is_required {
+      return true;
+    }
+    match cell_rev.map(TypeCellData::try_from) {
+      Some(Ok(type_cell_data)) => !self.is_cell_empty(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        field_rev,
+      ),
+      _ => false,
     }
   }
 
-  pub fn new(
-    field_rev: &'a FieldRevision,
-    cell_data_cache: Option<AtomicCellDataCache>,
-    cell_filter_cache: Option<AtomicCellFilterCache>,
-  ) -> Self {
-    let mut this = Self::new_with_cell_data_cache(field_rev, cell_data_cache);
-    this.cell_filter_cache = cell_filter_cache;
-    this
+  fn get_cell_oneof(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<CellValuePB> {
+    let cell_data = self
+      .get_cell_data(cell_str, decoded_field_type, field_rev)?
+      .unbox_or_default::<<Self as TypeOption>::CellData>();
+    Ok(self.inner.convert_to_cell_value(cell_data))
   }
 
-  pub fn get_cells<T>(&self) -> Vec<T> {
-    let field_type: FieldType = self.field_rev.ty.into();
-    match self.get_type_option_cell_data_handler(&field_type) {
-      None => vec![],
-      Some(_handler) => {
-        todo!()
-      },
+  fn handle_cell_normalize(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<String> {
+    let cell_data = self
+      .get_cell_data(cell_str, decoded_field_type, field_rev)?
+      .unbox_or_default::<<Self as TypeOption>::CellData>();
+    self.inner.normalize_cell_data(cell_data)
+  }
+
+  fn group_sort_weight(&self, group_id: &str, field_rev: &FieldRevision) -> i64 {
+    self.inner.group_sort_weight(group_id, field_rev)
+  }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    self.inner.comparable_kind()
+  }
+
+  fn accessibility_label(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> String {
+    match self.get_cell_data(cell_str.clone(), decoded_field_type, field_rev) {
+      Ok(cell_data) => self.inner.accessibility_label(
+        cell_data.unbox_or_default::<<Self as TypeOption>::CellData>(),
+        field_rev,
+      ),
+      Err(_) => self.stringify_cell_str(cell_str, decoded_field_type, field_rev),
     }
   }
 
-  pub fn get_type_option_cell_data_handler(
+  fn to_spreadsheet_value(
     &self,
-    field_type: &FieldType,
-  ) -> Option<Box<dyn TypeOptionCellDataHandler>> {
-    match field_type {
-      FieldType::RichText => self
-        .field_rev
-        .get_type_option::<RichTextTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::Number => self
-        .field_rev
-        .get_type_option::<NumberTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::DateTime => self
-        .field_rev
-        .get_type_option::<DateTypeOptionPB>(field_type.into())
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> SpreadsheetValue {
+    match self.get_cell_data(cell_str, decoded_field_type, field_rev) {
+      Ok(cell_data) => self.inner.decode_cell_data_to_spreadsheet_value(
+        cell_data.unbox_or_default::<<Self as TypeOption>::CellData>(),
+      ),
+      Err(_) => SpreadsheetValue::Text("".to_owned()),
+    }
+  }
+
+  fn pivot_cell(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> PivotValue {
+    if self.is_cell_empty(cell_str.clone(), decoded_field_type, field_rev) {
+      return PivotValue::default();
+    }
+    let cell_data = match self.get_cell_data(cell_str, decoded_field_type, field_rev) {
+      Ok(cell_data) => cell_data.unbox_or_default::<<Self as TypeOption>::CellData>(),
+      Err(_) => return PivotValue::default(),
+    };
+    let measure = match self
+      .inner
+      .decode_cell_data_to_spreadsheet_value(cell_data.clone())
+    {
+      SpreadsheetValue::Number(n) => Some(n),
+      _ => None,
+    };
+    PivotValue {
+      keys: self.inner.decode_cell_data_to_pivot_keys(cell_data),
+      measure,
+    }
+  }
+
+  fn stringify_cell_markdown(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> String {
+    if self.is_cell_empty(cell_str.clone(), decoded_field_type, field_rev) {
+      return "".to_owned();
+    }
+    match self.get_cell_data(cell_str, decoded_field_type, field_rev) {
+      Ok(cell_data) => self.inner.decode_cell_data_to_markdown(
+        cell_data.unbox_or_default::<<Self as TypeOption>::CellData>(),
+      ),
+      Err(_) => "".to_owned(),
+    }
+  }
+
+  fn ingest_json_value(
+    &self,
+    value: &serde_json::Value,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<CellRevision> {
+    let changeset = self.changeset_from_json_value(value)?;
+    let (cell_str, cell_data) = self.apply_changeset(changeset, None)?;
+    self.set_decoded_cell_data(&cell_str, cell_data, field_rev);
+    let field_type: FieldType = field_rev.ty.into();
+    Ok(CellRevision::new(
+      TypeCellData::new(cell_str, field_type).to_json(),
+    ))
+  }
+
+  fn value_hash(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> u64 {
+    let value_key = match self.get_cell_data(cell_str, decoded_field_type, field_rev) {
+      Ok(cell_data) => {
+        let cell_data = cell_data.unbox_or_default::<<Self as TypeOption>::CellData>();
+        if self.inner.is_cell_empty(&cell_data) {
+          "".to_owned()
+        } else {
+          self.inner.value_key(cell_data)
+        }
+      },
+      Err(_) => "".to_owned(),
+    };
+    let mut hasher = DefaultHasher::new();
+    value_key.hash(&mut hasher);
+    hasher.finish()
+  }
+
+  fn is_editable(&self) -> bool {
+    self.inner.is_editable()
+  }
+
+  fn next_in_series(
+    &self,
+    cell_revs: &[CellRevision],
+    field_rev: &FieldRevision,
+  ) -> Option<CellRevision> {
+    let cells = cell_revs
+      .iter()
+      .map(|cell_rev| {
+        let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+        let cell_data = self
+          .get_cell_data(
+            type_cell_data.cell_str,
+            &type_cell_data.field_type,
+            field_rev,
+          )
+          .ok()?
+          .unbox_or_none::<<Self as TypeOption>::CellData>()?;
+        (!self.inner.is_cell_empty(&cell_data)).then_some(cell_data)
+      })
+      .collect::<Option<Vec<_>>>()?;
+    if cells.is_empty() {
+      return None;
+    }
+
+    let changeset = self.inner.next_in_series(&cells)?;
+    let (cell_str, cell_data) = self.apply_changeset(changeset, None).ok()?;
+    self.set_decoded_cell_data(&cell_str, cell_data, field_rev);
+    let field_type: FieldType = field_rev.ty.into();
+    Some(CellRevision::new(
+      TypeCellData::new(cell_str, field_type).to_json(),
+    ))
+  }
+
+  fn glyph(&self, cell_rev: &CellRevision, field_rev: &FieldRevision) -> Option<CellGlyph> {
+    let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+    let cell_data = self
+      .get_cell_data(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        field_rev,
+      )
+      .ok()?
+      .unbox_or_none::<<Self as TypeOption>::CellData>()?;
+    self.inner.glyph(&cell_data)
+  }
+
+  fn default_filter(&self, field_rev: &FieldRevision) -> FilterPB {
+    let filter_rev = FilterRevision {
+      id: "".to_owned(),
+      field_id: field_rev.id.clone(),
+      field_type: field_rev.ty,
+      condition: self.inner.default_filter_condition(),
+      content: "".to_owned(),
+    };
+    FilterPB::from(&filter_rev)
+  }
+}
+
+const AUTO_COLOR_PALETTE: [SelectOptionColorPB; 9] = [
+  SelectOptionColorPB::Purple,
+  SelectOptionColorPB::Pink,
+  SelectOptionColorPB::LightPink,
+  SelectOptionColorPB::Orange,
+  SelectOptionColorPB::Yellow,
+  SelectOptionColorPB::Lime,
+  SelectOptionColorPB::Green,
+  SelectOptionColorPB::Aqua,
+  SelectOptionColorPB::Blue,
+];
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// The extra weight of each option tag, which is the padding around the option name.
+const SELECT_OPTION_TAG_WEIGHT: usize = 2;
+
+/// The kinds of the aggregation that summarizes the cells of a column into a single value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateKind {
+  /// The sum of the numbers.
+  Sum,
+  /// The average of the numbers.
+  Average,
+  /// The smallest number.
+  Min,
+  /// The largest number.
+  Max,
+  /// The number of the cells that are numbers.
+  CountNonEmpty,
+  /// The number of the cells that are empty or not numbers.
+  CountEmpty,
+  /// The number of the distinct values, see [TypeOptionCellExt::count_distinct].
+  CountDistinct,
+  /// The timestamp of the earliest date.
+  EarliestDate,
+  /// The timestamp of the latest date.
+  LatestDate,
+  /// The number of days between the earliest and the latest date.
+  DateRangeDays,
+  /// The percentage of the checked cells.
+  PercentChecked,
+}
+
+/// The health check result of a column, see [TypeOptionCellExt::audit_column].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ColumnAuditReport {
+  /// The rows whose cell can't be decoded.
+  pub undecodable_rows: Vec<RowId>,
+  /// The rows whose cell references the options that don't exist, with the ids of the options.
+  pub dangling_option_rows: Vec<(RowId, Vec<String>)>,
+  /// The rows whose cell isn't stored in the canonical format.
+  pub non_canonical_rows: Vec<RowId>,
+}
+
+impl ColumnAuditReport {
+  pub fn is_healthy(&self) -> bool {
+    self.undecodable_rows.is_empty()
+      && self.dangling_option_rows.is_empty()
+      && self.non_canonical_rows.is_empty()
+  }
+}
+
+/// The statistics of the number cells of a column, see [TypeOptionCellExt::number_stats]. The
+/// `min`, `max`, `mean` and `stddev` are None if all the cells are empty.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NumberStats {
+  /// The number of the non-empty cells.
+  pub count: usize,
+  /// The number of the cells that are empty or not a number.
+  pub empty: usize,
+  pub sum: f64,
+  pub min: Option<f64>,
+  pub max: Option<f64>,
+  pub mean: Option<f64>,
+  /// The population standard deviation.
+  pub stddev: Option<f64>,
+}
+
+pub struct TypeOptionCellExt<'a> {
+  field_rev: &'a FieldRevision,
+  cell_data_cache: Option<AtomicCellDataCache>,
+  cell_filter_cache: Option<AtomicCellFilterCache>,
+}
+
+impl<'a> TypeOptionCellExt<'a> {
+  pub fn new_with_cell_data_cache(
+    field_rev: &'a FieldRevision,
+    cell_data_cache: Option<AtomicCellDataCache>,
+  ) -> Self {
+    Self {
+      field_rev,
+      cell_data_cache,
+      cell_filter_cache: None,
+    }
+  }
+
+  pub fn new(
+    field_rev: &'a FieldRevision,
+    cell_data_cache: Option<AtomicCellDataCache>,
+    cell_filter_cache: Option<AtomicCellFilterCache>,
+  ) -> Self {
+    let mut this = Self::new_with_cell_data_cache(field_rev, cell_data_cache);
+    this.cell_filter_cache = cell_filter_cache;
+    this
+  }
+
+  /// Returns the decoded cell data of the cells, keeping the order of the passed-in cells. The
+  /// cell that can't be decoded into `T` is skipped.
+  pub fn get_cells<T: Default + 'static>(&self, cells: &[(RowId, CellRevision)]) -> Vec<T> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    match self.get_type_option_cell_data_handler(&field_type) {
+      None => vec![],
+      Some(handler) => cells
+        .iter()
+        .flat_map(|(_, cell_rev)| {
+          let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+          handler
+            .get_cell_data(
+              type_cell_data.cell_str,
+              &type_cell_data.field_type,
+              self.field_rev,
+            )
+            .ok()?
+            .unbox_or_none::<T>()
+        })
+        .collect(),
+    }
+  }
+
+  /// Returns the percentile rank of each number cell within the passed-in cells. The rank is
+  /// defined as `rank / (n - 1)`, where `rank` is the number of cells whose value is less than
+  /// the current cell and `n` is the number of non-empty cells. So the cells with the same value
+  /// share the same rank. The rank of the empty cell is None. If there is only one non-empty cell,
+  /// its rank is `Some(1.0)` instead of dividing by zero.
+  pub fn percentile_ranks(&self, cells: &[CellRevision]) -> Vec<Option<f64>> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    if !field_type.is_number() {
+      return vec![None; cells.len()];
+    }
+
+    let decimals = self.decode_number_cells(cells);
+    let mut sorted_decimals = decimals.iter().flatten().collect::<Vec<&Decimal>>();
+    sorted_decimals.sort();
+    let len = sorted_decimals.len();
+    decimals
+      .iter()
+      .map(|decimal| {
+        let decimal = decimal.as_ref()?;
+        if len == 1 {
+          return Some(1.0);
+        }
+        let rank = sorted_decimals.partition_point(|other| *other < decimal);
+        Some(rank as f64 / (len - 1) as f64)
+      })
+      .collect()
+  }
+
+  /// Returns the number of cells that select each option, keyed by the option id. The id that
+  /// doesn't belong to any option of the field is ignored. Returns an empty map if the field is
+  /// not a select option field.
+  pub fn option_usage(&self, cells: &[CellRevision]) -> HashMap<String, usize> {
+    let mut usage = HashMap::new();
+    let type_option = match select_type_option_from_field_rev(self.field_rev) {
+      Ok(type_option) => type_option,
+      Err(_) => return usage,
+    };
+
+    for option_ids in self
+      .decode_cells::<SelectOptionIds>(cells)
+      .into_iter()
+      .flatten()
+    {
+      for option in type_option.get_selected_options(option_ids).select_options {
+        *usage.entry(option.id).or_insert(0) += 1;
+      }
+    }
+    usage
+  }
+
+  /// Returns the distribution of the options of the select option field. The items are in the
+  /// same order as the options of the field. The cells that don't select any option are counted
+  /// in the `no_option_count`.
+  pub fn select_distribution(&self, cells: &[CellRevision]) -> SelectDistributionPB {
+    let type_option = match select_type_option_from_field_rev(self.field_rev) {
+      Ok(type_option) => type_option,
+      Err(_) => return SelectDistributionPB::default(),
+    };
+
+    let usage = self.option_usage(cells);
+    let items = type_option
+      .options()
+      .iter()
+      .map(|option| SelectDistributionItemPB {
+        option_id: option.id.clone(),
+        name: option.name.clone(),
+        color: option.color.clone(),
+        count: usage.get(&option.id).cloned().unwrap_or(0) as i64,
+      })
+      .collect::<Vec<SelectDistributionItemPB>>();
+
+    let no_option_count = self
+      .decode_cells::<SelectOptionIds>(cells)
+      .into_iter()
+      .filter(|option_ids| match option_ids {
+        None => true,
+        Some(option_ids) => type_option
+          .get_selected_options(option_ids.clone())
+          .select_options
+          .is_empty(),
+      })
+      .count();
+
+    SelectDistributionPB {
+      items,
+      no_option_count: no_option_count as i64,
+    }
+  }
+
+  /// Returns the most frequent value of the cells. The empty cells are excluded, and the tie is
+  /// broken by picking the value that is seen first. For the select option field, each selected
+  /// option is counted separately, so the mode is the most common single option.
+  pub fn mode(&self, cells: &[CellRevision]) -> Option<BoxCellData> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    let handler = self.get_type_option_cell_data_handler(&field_type)?;
+    let is_select_option = select_type_option_from_field_rev(self.field_rev).is_ok();
+
+    // The values are counted by their canonical cell strings, for example, the option id.
+    let mut values: Vec<(String, usize)> = vec![];
+    for cell_rev in cells {
+      let type_cell_data = match TypeCellData::try_from(cell_rev) {
+        Ok(type_cell_data) => type_cell_data,
+        Err(_) => continue,
+      };
+      if handler.is_cell_empty(
+        type_cell_data.cell_str.clone(),
+        &type_cell_data.field_type,
+        self.field_rev,
+      ) {
+        continue;
+      }
+
+      let cell_str = match handler.handle_cell_normalize(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        self.field_rev,
+      ) {
+        Ok(cell_str) => cell_str,
+        Err(_) => continue,
+      };
+      let keys = if is_select_option {
+        SelectOptionIds::from(cell_str).into_inner()
+      } else {
+        vec![cell_str]
+      };
+      for key in keys {
+        match values.iter_mut().find(|(value, _)| value == &key) {
+          None => values.push((key, 1)),
+          Some((_, count)) => *count += 1,
+        }
+      }
+    }
+
+    // `max_by_key` returns the last max element, so iterate in reverse to get the first seen.
+    let (cell_str, _) = values.into_iter().rev().max_by_key(|(_, count)| *count)?;
+    handler
+      .get_cell_data(cell_str, &field_type, self.field_rev)
+      .ok()
+  }
+
+  /// Returns the longest leading substring shared by all the non-empty text cells, for example,
+  /// `TASK-` for `TASK-001` and `TASK-002`, which can be suggested as the prefix of the
+  /// auto-number field. Returns an empty string if there is no common prefix or the field is not
+  /// a text field.
+  pub fn common_prefix(&self, cells: &[CellRevision]) -> String {
+    let field_type: FieldType = self.field_rev.ty.into();
+    if !field_type.is_text() {
+      return "".to_owned();
+    }
+
+    let mut prefix: Option<String> = None;
+    for cell_str in cells
+      .iter()
+      .map(|cell_rev| self.stringify_cell(cell_rev))
+      .filter(|cell_str| !cell_str.is_empty())
+    {
+      prefix = Some(match prefix {
+        None => cell_str,
+        Some(prefix) => prefix
+          .chars()
+          .zip(cell_str.chars())
+          .take_while(|(left, right)| left == right)
+          .map(|(c, _)| c)
+          .collect(),
+      });
+    }
+    prefix.unwrap_or_default()
+  }
+
+  /// Returns the numeric value of the select option cell, which is the sum of the weights of the
+  /// selected options, so that the select option fields can be aggregated like the number field.
+  /// Returns None if the field is not a select option field or none of the selected options has
+  /// a weight.
+  pub fn numeric_value(&self, cell_rev: &CellRevision) -> Option<f64> {
+    let type_option = select_type_option_from_field_rev(self.field_rev).ok()?;
+    let option_ids = self
+      .decode_cells::<SelectOptionIds>(std::slice::from_ref(cell_rev))
+      .pop()
+      .flatten()?;
+    type_option.numeric_value(option_ids)
+  }
+
+  /// Returns an estimate of the rendered content length of the cell, which is used to auto-size
+  /// the grid columns. The weight is the number of characters of the cell's display string.
+  /// Each selected option of the select option cell is rendered as a tag, so its weight also
+  /// grows with the number of the selected options.
+  pub fn content_weight(&self, cell_rev: &CellRevision) -> usize {
+    if let Ok(type_option) = select_type_option_from_field_rev(self.field_rev) {
+      let option_ids = self
+        .decode_cells::<SelectOptionIds>(std::slice::from_ref(cell_rev))
+        .pop()
+        .flatten()
+        .unwrap_or_default();
+      return type_option
+        .get_selected_options(option_ids)
+        .select_options
+        .iter()
+        .map(|option| option.name.chars().count() + SELECT_OPTION_TAG_WEIGHT)
+        .sum();
+    }
+
+    let field_type: FieldType = self.field_rev.ty.into();
+    let type_cell_data = match TypeCellData::try_from(cell_rev) {
+      Ok(type_cell_data) => type_cell_data,
+      Err(_) => return 0,
+    };
+    match self.get_type_option_cell_data_handler(&field_type) {
+      None => 0,
+      Some(handler) => handler
+        .stringify_cell_plain_text(
+          type_cell_data.cell_str,
+          &type_cell_data.field_type,
+          self.field_rev,
+        )
+        .chars()
+        .count(),
+    }
+  }
+
+  /// Returns the estimated number of bytes that the cell takes in the serialized row, which helps
+  /// to find the rows that bloat the sync payloads. The cell is stored as a JSON entry keyed by
+  /// the field id, so the key is counted too.
+  pub fn storage_bytes(&self, cell_rev: &CellRevision) -> usize {
+    let cell_bytes = serde_json::to_string(cell_rev)
+      .map(|s| s.len())
+      .unwrap_or_else(|_| cell_rev.type_cell_data.len());
+    // The quoted field id and the colon.
+    self.field_rev.id.len() + 3 + cell_bytes
+  }
+
+  /// Re-encodes each cell into the canonical cell string of the field. Returns the new cell only
+  /// if the normalization changed the cell, otherwise returns None. The cell that is not decoded
+  /// by the current field type is skipped.
+  pub fn normalize_column(&self, cells: &[CellRevision]) -> Vec<Option<CellRevision>> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    let handler = match self.get_type_option_cell_data_handler(&field_type) {
+      None => return vec![None; cells.len()],
+      Some(handler) => handler,
+    };
+
+    cells
+      .iter()
+      .map(|cell_rev| {
+        let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+        if type_cell_data.field_type != field_type {
+          return None;
+        }
+        let cell_str = handler
+          .handle_cell_normalize(
+            type_cell_data.cell_str,
+            &type_cell_data.field_type,
+            self.field_rev,
+          )
+          .ok()?;
+        let type_cell_data = TypeCellData::new(cell_str, field_type.clone()).to_json();
+        if type_cell_data == cell_rev.type_cell_data {
+          None
+        } else {
+          Some(CellRevision::new(type_cell_data))
+        }
+      })
+      .collect()
+  }
+
+  /// Checks the cells of the column against the field. The report lists the cells that fail to
+  /// decode, reference the deleted options, or are not stored in the canonical format.
+  pub fn audit_column(&self, cells: &[(RowId, CellRevision)]) -> ColumnAuditReport {
+    let mut report = ColumnAuditReport::default();
+    let field_type: FieldType = self.field_rev.ty.into();
+    let handler = match self.get_type_option_cell_data_handler(&field_type) {
+      None => return report,
+      Some(handler) => handler,
+    };
+    let select_type_option = select_type_option_from_field_rev(self.field_rev).ok();
+    let cell_revs = cells
+      .iter()
+      .map(|(_, cell_rev)| cell_rev.clone())
+      .collect::<Vec<CellRevision>>();
+    let normalized_cells = self.normalize_column(&cell_revs);
+
+    for ((row_id, cell_rev), normalized_cell) in cells.iter().zip(normalized_cells) {
+      let cell_data = TypeCellData::try_from(cell_rev).and_then(|type_cell_data| {
+        handler.get_cell_data(
+          type_cell_data.cell_str,
+          &type_cell_data.field_type,
+          self.field_rev,
+        )
+      });
+      let cell_data = match cell_data {
+        Ok(cell_data) => cell_data,
+        Err(_) => {
+          report.undecodable_rows.push(row_id.clone());
+          continue;
+        },
+      };
+
+      if let Some(type_option) = select_type_option.as_ref() {
+        let dangling_ids = cell_data
+          .unbox_or_default::<SelectOptionIds>()
+          .iter()
+          .filter(|id| !type_option.options().iter().any(|option| &option.id == *id))
+          .cloned()
+          .collect::<Vec<String>>();
+        if !dangling_ids.is_empty() {
+          report
+            .dangling_option_rows
+            .push((row_id.clone(), dangling_ids));
+        }
+      }
+
+      if normalized_cell.is_some() {
+        report.non_canonical_rows.push(row_id.clone());
+      }
+    }
+    report
+  }
+
+  /// Returns true if the derived cell needs to be recomputed, that is, the cell was computed more
+  /// than `ttl` seconds before `now`, or it was computed for another field type. The cell that
+  /// doesn't carry the `last_computed` timestamp is not a derived cell, so it's never stale.
+  pub fn is_stale(&self, cell_rev: &CellRevision, now: i64, ttl: i64) -> bool {
+    let type_cell_data = match TypeCellData::try_from(cell_rev) {
+      Ok(type_cell_data) => type_cell_data,
+      Err(_) => return false,
+    };
+    match type_cell_data.last_computed {
+      None => false,
+      Some(last_computed) => {
+        let field_type: FieldType = self.field_rev.ty.into();
+        type_cell_data.field_type != field_type || now.saturating_sub(last_computed) > ttl
+      },
+    }
+  }
+
+  /// Returns true if the two cells hold the same value after decoding, so the cells that only
+  /// differ in the format are equal, for example, `1,000` and `1000.00` of the number cell. The
+  /// empty cells are equal regardless of their cell strings.
+  pub fn cells_equal_by_value(&self, left: &CellRevision, right: &CellRevision) -> bool {
+    if left.type_cell_data == right.type_cell_data {
+      return true;
+    }
+    let field_type: FieldType = self.field_rev.ty.into();
+    let handler = match self.get_type_option_cell_data_handler(&field_type) {
+      None => return false,
+      Some(handler) => handler,
+    };
+    let (left, right) = match (TypeCellData::try_from(left), TypeCellData::try_from(right)) {
+      (Ok(left), Ok(right)) => (left, right),
+      _ => return false,
+    };
+
+    let is_empty = |type_cell_data: &TypeCellData| {
+      handler.is_cell_empty(
+        type_cell_data.cell_str.clone(),
+        &type_cell_data.field_type,
+        self.field_rev,
+      )
+    };
+    match (is_empty(&left), is_empty(&right)) {
+      (true, true) => return true,
+      (false, false) => {},
+      _ => return false,
+    }
+
+    let normalize = |type_cell_data: TypeCellData| {
+      handler
+        .handle_cell_normalize(
+          type_cell_data.cell_str,
+          &type_cell_data.field_type,
+          self.field_rev,
+        )
+        .ok()
+    };
+    match (normalize(left), normalize(right)) {
+      (Some(left), Some(right)) => {
+        left == right
+          || handler.handle_cell_compare(&left, &right, self.field_rev, None) == Ordering::Equal
+      },
+      _ => false,
+    }
+  }
+
+  /// Returns true if the value of the cell differs from the `baseline`, which is the snapshot of
+  /// the cell taken at the last sync. Reformatting the cell doesn't count as a change.
+  pub fn diff_against_baseline(&self, cell_rev: &CellRevision, baseline: &CellRevision) -> bool {
+    !self.cells_equal_by_value(cell_rev, baseline)
+  }
+
+  /// Returns the rows whose cells changed since the `baseline` snapshot was taken. The row that
+  /// is missing from the baseline is considered as changed unless its cell is empty.
+  pub fn rows_changed_since_baseline(
+    &self,
+    cells: &[(RowId, CellRevision)],
+    baseline: &HashMap<RowId, CellRevision>,
+  ) -> Vec<RowId> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    let empty_cell = CellRevision::new(TypeCellData::new("".to_owned(), field_type).to_json());
+    cells
+      .iter()
+      .filter(|(row_id, cell_rev)| {
+        let baseline = baseline.get(row_id).unwrap_or(&empty_cell);
+        self.diff_against_baseline(cell_rev, baseline)
+      })
+      .map(|(row_id, _)| row_id.clone())
+      .collect()
+  }
+
+  /// Aggregates the cells into a single value according to the `kind`. The empty cells are
+  /// excluded. Returns None if the kind doesn't apply to the field type or all the cells are empty.
+  pub fn aggregate(&self, cells: &[CellRevision], kind: AggregateKind) -> Option<f64> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    match kind {
+      AggregateKind::Sum
+      | AggregateKind::Average
+      | AggregateKind::Min
+      | AggregateKind::Max
+      | AggregateKind::CountNonEmpty
+      | AggregateKind::CountEmpty => self.aggregate_number(cells, kind, false),
+      AggregateKind::PercentChecked => self.percent_checked(cells, false),
+      AggregateKind::CountDistinct => self.count_distinct(cells, false).map(|count| count as f64),
+      AggregateKind::EarliestDate | AggregateKind::LatestDate | AggregateKind::DateRangeDays => {
+        if !field_type.is_date() {
+          return None;
+        }
+        let timestamps = self
+          .decode_cells::<DateCellData>(cells)
+          .into_iter()
+          .flat_map(|cell_data| cell_data?.timestamp)
+          .collect::<Vec<i64>>();
+        let earliest = *timestamps.iter().min()?;
+        let latest = *timestamps.iter().max()?;
+        match kind {
+          AggregateKind::EarliestDate => Some(earliest as f64),
+          AggregateKind::LatestDate => Some(latest as f64),
+          _ => Some((latest - earliest) as f64 / SECONDS_PER_DAY),
+        }
+      },
+    }
+  }
+
+  /// Same as [TypeOptionCellExt::aggregate] but for the number field. The cell that is empty or
+  /// not a number is counted as zero if `empty_as_zero` is true, which makes it count towards
+  /// the denominator of the average. Otherwise, it is excluded. The count kinds always count such
+  /// cells as empty.
+  pub fn aggregate_number(
+    &self,
+    cells: &[CellRevision],
+    kind: AggregateKind,
+    empty_as_zero: bool,
+  ) -> Option<f64> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    if !field_type.is_number() {
+      return None;
+    }
+
+    let decoded = self.decode_number_cells(cells);
+    let non_empty = decoded.iter().filter(|decimal| decimal.is_some()).count();
+    let decimals = decoded
+      .into_iter()
+      .flat_map(|decimal| match decimal {
+        None if empty_as_zero => Some(Decimal::ZERO),
+        decimal => decimal,
+      })
+      .collect::<Vec<Decimal>>();
+    let sum = decimals.iter().sum::<Decimal>();
+    match kind {
+      AggregateKind::Sum => sum.to_f64(),
+      AggregateKind::Average => {
+        if decimals.is_empty() {
+          None
+        } else {
+          (sum / Decimal::from(decimals.len())).to_f64()
+        }
+      },
+      AggregateKind::Min => decimals.iter().min()?.to_f64(),
+      AggregateKind::Max => decimals.iter().max()?.to_f64(),
+      AggregateKind::CountNonEmpty => Some(non_empty as f64),
+      AggregateKind::CountEmpty => Some((cells.len() - non_empty) as f64),
+      _ => None,
+    }
+  }
+
+  /// Returns the statistics of the number cells, which are computed in a single pass over the
+  /// decoded cells, so it's cheaper than calling [TypeOptionCellExt::aggregate] for each value
+  /// of a footer. All the cells are counted as empty if the field is not a number field.
+  pub fn number_stats(&self, cells: &[CellRevision]) -> NumberStats {
+    let field_type: FieldType = self.field_rev.ty.into();
+    if !field_type.is_number() {
+      return NumberStats {
+        empty: cells.len(),
+        ..Default::default()
+      };
+    }
+
+    let mut stats = NumberStats::default();
+    let mut sum = Decimal::ZERO;
+    // Welford's algorithm, which updates the mean and the sum of the squared deviations from the
+    // mean with each value.
+    let mut mean = 0.0;
+    let mut squared_deviations = 0.0;
+    for decimal in self.decode_number_cells(cells) {
+      let value = match decimal.and_then(|decimal| Some((decimal, decimal.to_f64()?))) {
+        None => {
+          stats.empty += 1;
+          continue;
+        },
+        Some((decimal, value)) => {
+          sum += decimal;
+          value
+        },
+      };
+      stats.count += 1;
+      stats.min = Some(stats.min.map_or(value, |min| min.min(value)));
+      stats.max = Some(stats.max.map_or(value, |max| max.max(value)));
+      let delta = value - mean;
+      mean += delta / stats.count as f64;
+      squared_deviations += delta * (value - mean);
+    }
+
+    stats.sum = sum.to_f64().unwrap_or_default();
+    if stats.count > 0 {
+      stats.mean = Some(mean);
+      stats.stddev = Some((squared_deviations / stats.count as f64).sqrt());
+    }
+    stats
+  }
+
+  /// Returns the relative change from the `from` cell to the `to` cell of the number field, that
+  /// is, `(to - from) / from`, for example, `0.5` if the number goes from 2 to 3. Returns None if
+  /// either cell is empty or not a number, or if the `from` cell is zero.
+  pub fn percent_change(&self, from: &CellRevision, to: &CellRevision) -> Option<f64> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    if !field_type.is_number() {
+      return None;
+    }
+
+    let decode = |cell_rev: &CellRevision| {
+      self
+        .decode_number_cells(std::slice::from_ref(cell_rev))
+        .pop()
+        .flatten()
+    };
+    let from = decode(from)?;
+    let to = decode(to)?;
+    if from.is_zero() {
+      return None;
+    }
+    ((to - from) / from).to_f64()
+  }
+
+  /// Returns the percentage of the checked cells of the checkbox field. The empty cell, that is,
+  /// the missing cell or the unset cell, counts towards the denominator only if `include_empty` is
+  /// true. The unchecked cell always counts. So the result is either `checked / total` or
+  /// `checked / non-empty`. Returns None if the denominator is zero.
+  pub fn percent_checked(&self, cells: &[CellRevision], include_empty: bool) -> Option<f64> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    if !field_type.is_checkbox() {
+      return None;
+    }
+
+    let mut checked = 0;
+    let mut total = 0;
+    for cell_data in self.decode_cells::<CheckboxCellData>(cells) {
+      let is_empty = cell_data
+        .as_ref()
+        .map(|cell_data| cell_data.is_unset())
+        .unwrap_or(true);
+      if is_empty && !include_empty {
+        continue;
+      }
+      total += 1;
+      if cell_data
+        .map(|cell_data| cell_data.is_check())
+        .unwrap_or(false)
+      {
+        checked += 1;
+      }
+    }
+
+    if total == 0 {
+      None
+    } else {
+      Some(checked as f64 * 100.0 / total as f64)
+    }
+  }
+
+  /// Returns the number of the distinct values of the text, URL or select option cells. The text
+  /// and URL cells are compared by their display strings, so the cells that only differ in the
+  /// format are the same value. The select option cells are compared by the set of the selected
+  /// options regardless of their order. The empty cells count as one more distinct value if
+  /// `count_empty_as_distinct` is true, otherwise they're excluded. Returns None for the other
+  /// field types.
+  pub fn count_distinct(
+    &self,
+    cells: &[CellRevision],
+    count_empty_as_distinct: bool,
+  ) -> Option<usize> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    let handler = self.get_type_option_cell_data_handler(&field_type)?;
+    let values: Vec<Vec<String>> = if field_type.is_text() || field_type.is_url() {
+      cells
+        .iter()
+        .map(|cell_rev| {
+          let value = TypeCellData::try_from(cell_rev)
+            .map(|type_cell_data| {
+              handler.stringify_cell_plain_text(
+                type_cell_data.cell_str,
+                &type_cell_data.field_type,
+                self.field_rev,
+              )
+            })
+            .unwrap_or_default();
+          if value.is_empty() {
+            vec![]
+          } else {
+            vec![value]
+          }
+        })
+        .collect()
+    } else if field_type.is_select_option() {
+      self
+        .decode_cells::<SelectOptionIds>(cells)
+        .into_iter()
+        .map(|option_ids| {
+          let mut option_ids = option_ids.map(|ids| ids.into_inner()).unwrap_or_default();
+          option_ids.sort();
+          option_ids.dedup();
+          option_ids
+        })
+        .collect()
+    } else {
+      return None;
+    };
+
+    let has_empty = values.iter().any(|value| value.is_empty());
+    let distinct = values
+      .into_iter()
+      .filter(|value| !value.is_empty())
+      .collect::<HashSet<Vec<String>>>()
+      .len();
+    Some(distinct + usize::from(count_empty_as_distinct && has_empty))
+  }
+
+  /// Suggests the field type that fits the content of the text cells better, for example,
+  /// [FieldType::Number] if all the cells are numbers. The cells are compared by their plain text,
+  /// see [infer_field_type]. Returns None if the content is ambiguous or the field is not a text
+  /// field, the content of the other field types is already typed.
+  pub fn suggest_field_type(&self, cells: &[CellRevision]) -> Option<FieldType> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    if !field_type.is_text() {
+      return None;
+    }
+
+    let texts = self
+      .decode_cells::<StrCellData>(cells)
+      .into_iter()
+      .flatten()
+      .map(|cell_data| cell_data.plain_text().into_owned())
+      .collect::<Vec<String>>();
+    infer_field_type(texts.iter().map(String::as_str)).filter(|suggested| *suggested != field_type)
+  }
+
+  /// Decodes the cells of the number field into the numbers. Returns None for the cell that is
+  /// empty or not a number.
+  fn decode_number_cells(&self, cells: &[CellRevision]) -> Vec<Option<Decimal>> {
+    let type_option = NumberTypeOptionPB::from(self.field_rev);
+    self
+      .decode_cells::<StrCellData>(cells)
+      .into_iter()
+      .map(|cell_data| {
+        let number_cell_data = type_option.format_cell_data(&cell_data?).ok()?;
+        *number_cell_data.decimal()
+      })
+      .collect()
+  }
+
+  /// Returns a readable sentence that describes the change of the cell, which is used in the
+  /// activity feeds. For example, `changed Status from Todo to Done`. The select option cells
+  /// list the added and removed options. Returns an empty string if nothing changed.
+  pub fn describe_changeset(
+    &self,
+    old_cell_rev: Option<&CellRevision>,
+    new_cell_rev: &CellRevision,
+  ) -> String {
+    let field_name = &self.field_rev.name;
+    let field_type: FieldType = self.field_rev.ty.into();
+    if field_type.is_multi_select() || field_type.is_check_list() {
+      if let Ok(type_option) = select_type_option_from_field_rev(self.field_rev) {
+        let old_ids = old_cell_rev
+          .and_then(|cell_rev| {
+            self
+              .decode_cells::<SelectOptionIds>(std::slice::from_ref(cell_rev))
+              .pop()
+              .flatten()
+          })
+          .unwrap_or_default();
+        let new_ids = self
+          .decode_cells::<SelectOptionIds>(std::slice::from_ref(new_cell_rev))
+          .pop()
+          .flatten()
+          .unwrap_or_default();
+        let option_names = |ids: &SelectOptionIds, other_ids: &SelectOptionIds| {
+          type_option
+            .options()
+            .iter()
+            .filter(|option| ids.contains(&option.id) && !other_ids.contains(&option.id))
+            .map(|option| option.name.clone())
+            .collect::<Vec<String>>()
+        };
+
+        let mut descs = vec![];
+        let added = option_names(&new_ids, &old_ids);
+        if !added.is_empty() {
+          descs.push(format!("added {} to {}", added.join(", "), field_name));
+        }
+        let removed = option_names(&old_ids, &new_ids);
+        if !removed.is_empty() {
+          descs.push(format!(
+            "removed {} from {}",
+            removed.join(", "),
+            field_name
+          ));
+        }
+        return descs.join(" and ");
+      }
+    }
+
+    let old_str = old_cell_rev
+      .map(|cell_rev| self.stringify_cell(cell_rev))
+      .unwrap_or_default();
+    let new_str = self.stringify_cell(new_cell_rev);
+    if old_str == new_str {
+      "".to_owned()
+    } else if old_str.is_empty() {
+      format!("set {} to {}", field_name, new_str)
+    } else if new_str.is_empty() {
+      format!("cleared {}", field_name)
+    } else {
+      format!("changed {} from {} to {}", field_name, old_str, new_str)
+    }
+  }
+
+  fn stringify_cell(&self, cell_rev: &CellRevision) -> String {
+    let field_type: FieldType = self.field_rev.ty.into();
+    let type_cell_data = match TypeCellData::try_from(cell_rev) {
+      Ok(type_cell_data) => type_cell_data,
+      Err(_) => return "".to_owned(),
+    };
+    match self.get_type_option_cell_data_handler(&field_type) {
+      None => "".to_owned(),
+      Some(handler) => handler.stringify_type_cell_data(type_cell_data, self.field_rev),
+    }
+  }
+
+  /// Decodes the cells using the handler of the current field type. Returns None for the cell
+  /// that can't be decoded into `T`.
+  fn decode_cells<T: Default + 'static>(&self, cells: &[CellRevision]) -> Vec<Option<T>> {
+    let field_type: FieldType = self.field_rev.ty.into();
+    match self.get_type_option_cell_data_handler(&field_type) {
+      None => cells.iter().map(|_| None).collect(),
+      Some(handler) => cells
+        .iter()
+        .map(|cell_rev| {
+          let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+          handler
+            .get_cell_data(
+              type_cell_data.cell_str,
+              &type_cell_data.field_type,
+              self.field_rev,
+            )
+            .ok()?
+            .unbox_or_none::<T>()
+        })
+        .collect(),
+    }
+  }
+
+  pub fn get_type_option_cell_data_handler(
+    &self,
+    field_type: &FieldType,
+  ) -> Option<Box<dyn TypeOptionCellDataHandler>> {
+    match field_type {
+      FieldType::RichText => self
+        .field_rev
+        .get_type_option::<RichTextTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Number => self
+        .field_rev
+        .get_type_option::<NumberTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::DateTime => self
+        .field_rev
+        .get_type_option::<DateTypeOptionPB>(field_type.into())
         .map(|type_option| {
           TypeOptionCellDataHandlerImpl::new_with_boxed(
             type_option,
@@ -425,10 +1963,202 @@ impl<'a> TypeOptionCellExt<'a> {
             self.cell_data_cache.clone(),
           )
         }),
+      FieldType::Concat => self
+        .field_rev
+        .get_type_option::<ConcatTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Order => self
+        .field_rev
+        .get_type_option::<OrderTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Relation => self
+        .field_rev
+        .get_type_option::<RelationTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Duration => self
+        .field_rev
+        .get_type_option::<DurationTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Rating => self
+        .field_rev
+        .get_type_option::<RatingTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::IpAddress => self
+        .field_rev
+        .get_type_option::<IpTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Json => self
+        .field_rev
+        .get_type_option::<JsonTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Email => self
+        .field_rev
+        .get_type_option::<EmailTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Phone => self
+        .field_rev
+        .get_type_option::<PhoneTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
     }
   }
 }
 
+/// Resolves the reference to the sibling cells of a row. It's used by the fields whose cell
+/// data depends on other cells in the same row, for example, `this row's field X`.
+pub struct FieldRefResolver<'a> {
+  field_revs: &'a [Arc<FieldRevision>],
+  cell_data_cache: Option<AtomicCellDataCache>,
+}
+
+impl<'a> FieldRefResolver<'a> {
+  pub fn new(
+    field_revs: &'a [Arc<FieldRevision>],
+    cell_data_cache: Option<AtomicCellDataCache>,
+  ) -> Self {
+    Self {
+      field_revs,
+      cell_data_cache,
+    }
+  }
+
+  /// Returns the decoded cell data of the field referenced by `ref_name`. The `ref_name` can be
+  /// either the id or the name of the field, and the id takes precedence over the name.
+  /// Returns None if the field or its cell doesn't exist in the row.
+  pub fn resolve_field_ref(
+    &self,
+    ref_name: &str,
+    row_cells: &IndexMap<FieldId, CellRevision>,
+  ) -> Option<BoxCellData> {
+    let (field_rev, type_cell_data) = self.find_field_ref(ref_name, row_cells)?;
+    let field_type: FieldType = field_rev.ty.into();
+    TypeOptionCellExt::new_with_cell_data_cache(field_rev, self.cell_data_cache.clone())
+      .get_type_option_cell_data_handler(&field_type)?
+      .get_cell_data(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        field_rev,
+      )
+      .ok()
+  }
+
+  /// Same as [FieldRefResolver::resolve_field_ref] but returns the display string of the cell
+  /// without the inline marks of the text cell.
+  pub fn stringify_field_ref(
+    &self,
+    ref_name: &str,
+    row_cells: &IndexMap<FieldId, CellRevision>,
+  ) -> Option<String> {
+    let (field_rev, type_cell_data) = self.find_field_ref(ref_name, row_cells)?;
+    let field_type: FieldType = field_rev.ty.into();
+    let s = TypeOptionCellExt::new_with_cell_data_cache(field_rev, self.cell_data_cache.clone())
+      .get_type_option_cell_data_handler(&field_type)?
+      .stringify_cell_plain_text(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        field_rev,
+      );
+    Some(s)
+  }
+
+  fn find_field_ref(
+    &self,
+    ref_name: &str,
+    row_cells: &IndexMap<FieldId, CellRevision>,
+  ) -> Option<(&'a FieldRevision, TypeCellData)> {
+    let field_revs: &'a [Arc<FieldRevision>] = self.field_revs;
+    let field_rev = field_revs
+      .iter()
+      .find(|field_rev| field_rev.id == ref_name)
+      .or_else(|| {
+        field_revs
+          .iter()
+          .find(|field_rev| field_rev.name == ref_name)
+      })?;
+    let type_cell_data = TypeCellData::try_from(row_cells.get(&field_rev.id)?).ok()?;
+    Some((&**field_rev, type_cell_data))
+  }
+}
+
+/// Returns true if the cell data survives when switching the field type from `from` to `to`.
+/// For example, switching from `Number` to `RichText` keeps the cell data, but switching back
+/// from `RichText` to `Number` drops the cells that are not numbers.
+pub fn is_transform_lossless(from: &FieldType, to: &FieldType) -> bool {
+  if from == to {
+    return true;
+  }
+
+  match (from, to) {
+    // The text type option stringifies the cell data of these field types.
+    (
+      FieldType::Number
+      | FieldType::DateTime
+      | FieldType::SingleSelect
+      | FieldType::MultiSelect
+      | FieldType::Checkbox
+      | FieldType::URL,
+      FieldType::RichText,
+    ) => true,
+    (FieldType::SingleSelect, FieldType::MultiSelect) => true,
+    // The select type option inserts the `Yes` and `No` options for the checkbox cells.
+    (FieldType::Checkbox, FieldType::SingleSelect | FieldType::MultiSelect) => true,
+    _ => false,
+  }
+}
+
 pub fn transform_type_option(
   type_option_data: &str,
   new_field_type: &FieldType,
@@ -484,6 +2214,24 @@ fn get_type_option_transform_handler(
       as Box<dyn TypeOptionTransformHandler>,
     FieldType::Checklist => Box::new(ChecklistTypeOptionPB::from_json_str(type_option_data))
       as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Concat => Box::new(ConcatTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Order => Box::new(OrderTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Relation => Box::new(RelationTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Duration => Box::new(DurationTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Rating => Box::new(RatingTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::IpAddress => Box::new(IpTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Json => Box::new(JsonTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Email => Box::new(EmailTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Phone => Box::new(PhoneTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
   }
 }
 
@@ -566,6 +2314,10 @@ impl RowSingleCellData {
   );
   into_cell_data!(
     into_check_list_field_cell_data,
+    <ChecklistTypeOptionPB as TypeOption>::CellData
+  );
+  into_cell_data!(
+    into_checkbox_field_cell_data,
     <CheckboxTypeOptionPB as TypeOption>::CellData
   );
 }
@@ -1,11 +1,24 @@
 #[cfg(test)]
 mod tests {
-  use crate::entities::FieldType;
-  use crate::services::cell::CellDataChangeset;
+  use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+  use crate::services::cell::{
+    insert_text_cell, insert_url_cell, CellDataChangeset, CellDataDecoder, FromCellString,
+    ToCellChangesetString, TypeCellData,
+  };
 
+  use crate::services::field::type_options::test_util::{
+    cell_data_handler, cell_value, default_filter, filter_is_empty, ingest_json_value,
+    spreadsheet_value,
+  };
   use crate::services::field::FieldBuilder;
-  use crate::services::field::URLTypeOptionPB;
+  use crate::services::field::{
+    is_transform_lossless, ComparableKind, CompiledTextFilter, SpreadsheetValue,
+    TypeOptionCellDataCompare, TypeOptionCellDataFilter, URLCellData, URLLinkPB,
+    URLListCellChangeset, URLTypeOptionBuilder, URLTypeOptionPB, UrlCategory,
+  };
   use database_model::FieldRevision;
+  use serde_json::json;
+  use std::cmp::Ordering;
 
   /// The expected_str will equal to the input string, but the expected_url will be empty if there's no
   /// http url in the input string.
@@ -150,6 +163,160 @@ mod tests {
     );
   }
 
+  #[test]
+  fn url_list_parse_test() {
+    let type_option = URLTypeOptionPB {
+      is_list: true,
+      ..Default::default()
+    };
+    let (cell_str, cell_data) = type_option
+      .apply_changeset(
+        "https://www.appflowy.io appflowy.com not-a-link".to_owned(),
+        None,
+      )
+      .unwrap();
+    assert_eq!(
+      cell_data.links,
+      vec![
+        URLLinkPB::new("https://www.appflowy.io/"),
+        URLLinkPB::new("https://appflowy.com"),
+      ]
+    );
+    assert_eq!(cell_data.url, "https://www.appflowy.io/");
+
+    // Add a link with the title and remove a link by its uri.
+    let changeset = URLListCellChangeset {
+      insert_links: vec![URLLinkPB {
+        uri: "https://github.com".to_owned(),
+        title: "GitHub".to_owned(),
+      }],
+      delete_uris: vec!["https://appflowy.com".to_owned()],
+    };
+    let (_, cell_data) = type_option
+      .apply_changeset(
+        changeset.to_cell_changeset_str(),
+        Some(TypeCellData::new(cell_str, FieldType::URL)),
+      )
+      .unwrap();
+    assert_eq!(cell_data.links.len(), 2);
+    assert_eq!(
+      type_option.decode_cell_data_to_str(cell_data),
+      "https://www.appflowy.io/, GitHub"
+    );
+  }
+
+  #[test]
+  fn url_list_filter_and_cmp_test() {
+    let type_option = URLTypeOptionPB {
+      is_list: true,
+      ..Default::default()
+    };
+    let (_, one_link) = type_option
+      .apply_changeset("https://github.com".to_owned(), None)
+      .unwrap();
+    let (_, two_links) = type_option
+      .apply_changeset("https://appflowy.io https://github.com".to_owned(), None)
+      .unwrap();
+
+    let filter = |condition: TextFilterConditionPB, content: &str| {
+      CompiledTextFilter::from(&TextFilterPB {
+        condition,
+        content: content.to_owned(),
+      })
+    };
+    let contains = filter(TextFilterConditionPB::Contains, "appflowy");
+    assert!(type_option.apply_filter(&contains, &FieldType::URL, &two_links));
+    assert!(!type_option.apply_filter(&contains, &FieldType::URL, &one_link));
+
+    let does_not_contain = filter(TextFilterConditionPB::DoesNotContain, "github");
+    assert!(!type_option.apply_filter(&does_not_contain, &FieldType::URL, &two_links));
+
+    assert_eq!(type_option.apply_cmp(&one_link, &two_links), Ordering::Less);
+    assert_eq!(type_option.apply_cmp(&one_link, &one_link), Ordering::Equal);
+  }
+
+  #[test]
+  fn url_regex_filter_test() {
+    let filter = |condition: TextFilterConditionPB| {
+      CompiledTextFilter::from(&TextFilterPB {
+        condition,
+        content: r"^https?://[^/]+\.edu(/|$)".to_owned(),
+      })
+    };
+    let matches = filter(TextFilterConditionPB::Matches);
+    let does_not_match = filter(TextFilterConditionPB::DoesNotMatch);
+
+    let type_option = URLTypeOptionPB::default();
+    for (url, is_edu) in [
+      ("https://mit.edu", true),
+      ("https://cs.stanford.edu/people", true),
+      ("https://edu.example.com", false),
+      ("https://example.com/page.edu", false),
+    ] {
+      let (_, cell_data) = type_option.apply_changeset(url.to_owned(), None).unwrap();
+      assert_eq!(
+        type_option.apply_filter(&matches, &FieldType::URL, &cell_data),
+        is_edu,
+        "{}",
+        url
+      );
+      assert_eq!(
+        type_option.apply_filter(&does_not_match, &FieldType::URL, &cell_data),
+        !is_edu,
+        "{}",
+        url
+      );
+    }
+
+    // Every link of the list must not match the pattern.
+    let type_option = URLTypeOptionPB {
+      is_list: true,
+      ..Default::default()
+    };
+    let (_, links) = type_option
+      .apply_changeset("https://mit.edu https://appflowy.io".to_owned(), None)
+      .unwrap();
+    assert!(type_option.apply_filter(&matches, &FieldType::URL, &links));
+    assert!(!type_option.apply_filter(&does_not_match, &FieldType::URL, &links));
+  }
+
+  #[test]
+  fn url_markdown_test() {
+    let type_option = URLTypeOptionPB::default();
+    let (_, cell_data) = type_option
+      .apply_changeset("https://appflowy.io".to_owned(), None)
+      .unwrap();
+    assert_eq!(
+      type_option.decode_cell_data_to_markdown(cell_data),
+      "[https://appflowy.io](https://appflowy.io/)"
+    );
+
+    let (_, cell_data) = type_option.apply_changeset("".to_owned(), None).unwrap();
+    assert_eq!(type_option.decode_cell_data_to_markdown(cell_data), "");
+  }
+
+  #[test]
+  fn url_list_markdown_test() {
+    let type_option = URLTypeOptionPB {
+      is_list: true,
+      ..Default::default()
+    };
+    let changeset = URLListCellChangeset::from_insert_links(vec![
+      URLLinkPB {
+        uri: "https://github.com".to_owned(),
+        title: "GitHub [mirror]".to_owned(),
+      },
+      URLLinkPB::new("https://appflowy.io"),
+    ]);
+    let (_, cell_data) = type_option
+      .apply_changeset(changeset.to_cell_changeset_str(), None)
+      .unwrap();
+    assert_eq!(
+      type_option.decode_cell_data_to_markdown(cell_data),
+      "- [GitHub \\[mirror\\]](https://github.com)\n- [https://appflowy.io](https://appflowy.io)"
+    );
+  }
+
   fn assert_url(
     type_option: &URLTypeOptionPB,
     input_str: &str,
@@ -164,4 +331,146 @@ mod tests {
     assert_eq!(expected_str.to_owned(), decode_cell_data.content);
     assert_eq!(expected_url.to_owned(), decode_cell_data.url);
   }
+
+  #[test]
+  fn url_ingest_json_value_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::URL).build();
+    let cell_str = ingest_json_value(&field_rev, json!("https://appflowy.io")).unwrap();
+    let cell_data = URLCellData::from_cell_str(&cell_str).unwrap();
+    assert_eq!(cell_data.content, "https://appflowy.io");
+    assert!(ingest_json_value(&field_rev, json!(["https://appflowy.io"])).is_err());
+    assert!(ingest_json_value(&field_rev, json!(42)).is_err());
+
+    let field_rev = FieldBuilder::new(URLTypeOptionBuilder::default().is_list(true)).build();
+    let value = json!(["appflowy.io", { "uri": "https://github.com", "title": "GitHub" }]);
+    let cell_str = ingest_json_value(&field_rev, value).unwrap();
+    let cell_data = URLCellData::from_cell_str(&cell_str).unwrap();
+    assert_eq!(cell_data.links.len(), 2);
+    assert_eq!(cell_data.links[0].uri, "https://appflowy.io");
+    assert_eq!(cell_data.links[1].title, "GitHub");
+
+    let cell_str = ingest_json_value(&field_rev, json!("appflowy.io github.com")).unwrap();
+    let cell_data = URLCellData::from_cell_str(&cell_str).unwrap();
+    assert_eq!(cell_data.links.len(), 2);
+    assert!(ingest_json_value(&field_rev, json!([42])).is_err());
+    assert!(ingest_json_value(&field_rev, json!([{ "title": "GitHub" }])).is_err());
+  }
+
+  #[test]
+  fn url_category_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::URL).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::URL);
+    let url_category = |s: &str| {
+      let cell_rev = insert_url_cell(s.to_owned(), &field_rev);
+      let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+      handler.url_category(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &field_rev,
+      )
+    };
+
+    assert_eq!(url_category(""), None);
+    assert_eq!(
+      url_category("https://www.appflowy.io"),
+      Some(UrlCategory::Web)
+    );
+    assert_eq!(
+      url_category("mailto:annie@appflowy.io"),
+      Some(UrlCategory::Email)
+    );
+    assert_eq!(url_category("tel:+1-555-0100"), Some(UrlCategory::Phone));
+    assert_eq!(url_category("appflowy.io"), Some(UrlCategory::Web));
+    assert_eq!(url_category("localhost:8080/docs"), Some(UrlCategory::Web));
+    assert_eq!(
+      url_category("file:///home/annie/notes.md"),
+      Some(UrlCategory::File)
+    );
+    assert_eq!(url_category("ftp://appflowy.io"), Some(UrlCategory::Other));
+
+    // The links in the other fields aren't styled.
+    let text_field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let text_handler = cell_data_handler(&text_field_rev, &FieldType::RichText);
+    let cell_rev = insert_text_cell("https://www.appflowy.io".to_owned(), &text_field_rev);
+    let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+    assert_eq!(
+      text_handler.url_category(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &text_field_rev,
+      ),
+      None
+    );
+  }
+
+  #[test]
+  fn url_cell_data_handler_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::URL).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::URL);
+    assert_eq!(handler.comparable_kind(), ComparableKind::String);
+    assert!(handler.is_editable());
+    assert!(is_transform_lossless(&FieldType::URL, &FieldType::URL));
+
+    let filter = default_filter(&field_rev);
+    let filter = TextFilterPB::try_from(filter.data.as_slice()).unwrap();
+    assert_eq!(filter.condition, TextFilterConditionPB::Contains);
+    assert!(filter.content.is_empty());
+
+    let cell_rev = insert_url_cell("https://appflowy.io".to_owned(), &field_rev);
+    assert_eq!(
+      spreadsheet_value(&field_rev, &cell_rev),
+      SpreadsheetValue::Text("https://appflowy.io".to_owned())
+    );
+    assert_eq!(
+      cell_value(&field_rev, &cell_rev).url.unwrap().content,
+      "https://appflowy.io"
+    );
+  }
+
+  #[test]
+  fn url_is_empty_filter_test() {
+    let text_filter = |condition: TextFilterConditionPB| {
+      CompiledTextFilter::from(&TextFilterPB {
+        condition,
+        content: "".to_owned(),
+      })
+    };
+    let field_rev = FieldBuilder::from_field_type(&FieldType::URL).build();
+    for (cell_rev, expected) in [
+      (None, true),
+      (Some(insert_url_cell("".to_owned(), &field_rev)), true),
+      (
+        Some(insert_url_cell(
+          "https://appflowy.io".to_owned(),
+          &field_rev,
+        )),
+        false,
+      ),
+    ] {
+      let is_empty = filter_is_empty(
+        &field_rev,
+        text_filter,
+        TextFilterConditionPB::TextIsEmpty,
+        TextFilterConditionPB::TextIsNotEmpty,
+        cell_rev.as_ref(),
+      );
+      assert_eq!(is_empty, expected);
+    }
+  }
+
+  #[test]
+  fn url_validate_cell_changeset_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::URL).build();
+    let handler = cell_data_handler(&field_rev, &FieldType::URL);
+    assert!(handler
+      .validate_cell_changeset("https://appflowy.io", &field_rev)
+      .is_ok());
+    assert!(handler.validate_cell_changeset("", &field_rev).is_ok());
+    assert!(handler
+      .validate_cell_changeset("not a url", &field_rev)
+      .is_err());
+    assert!(handler
+      .validate_cell_changeset("htps//appflowy", &field_rev)
+      .is_err());
+  }
 }
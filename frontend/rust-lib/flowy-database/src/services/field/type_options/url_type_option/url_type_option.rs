@@ -1,16 +1,19 @@
-use crate::entities::{FieldType, TextFilterPB};
+use crate::entities::{CellValuePB, FieldType, TextFilterConditionPB};
 use crate::impl_type_option;
-use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::cell::{
+  invalid_json_value_error, CellDataChangeset, CellDataDecoder, FromCellChangesetString,
+  FromCellString, ToCellChangesetString, TypeCellData,
+};
 use crate::services::field::{
-  BoxTypeOptionBuilder, TypeOption, TypeOptionBuilder, TypeOptionCellData,
-  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, URLCellData,
-  URLCellDataPB,
+  BoxTypeOptionBuilder, ComparableKind, CompiledTextFilter, EmptyFilterCondition, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform, URLCellData, URLCellDataPB, URLLinkPB, URLListCellChangeset,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use fancy_regex::Regex;
 use flowy_derive::ProtoBuf;
-use flowy_error::FlowyResult;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -20,6 +23,13 @@ pub struct URLTypeOptionBuilder(URLTypeOptionPB);
 impl_into_box_type_option_builder!(URLTypeOptionBuilder);
 impl_builder_from_json_str_and_from_bytes!(URLTypeOptionBuilder, URLTypeOptionPB);
 
+impl URLTypeOptionBuilder {
+  pub fn is_list(mut self, is_list: bool) -> Self {
+    self.0.is_list = is_list;
+    self
+  }
+}
+
 impl TypeOptionBuilder for URLTypeOptionBuilder {
   fn field_type(&self) -> FieldType {
     FieldType::URL
@@ -37,14 +47,69 @@ pub struct URLTypeOptionPB {
 
   #[pb(index = 2)]
   pub content: String,
+
+  /// If true, the cell holds a list of links instead of a single url. See [URLListCellChangeset]
+  /// for adding or removing the links.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub is_list: bool,
 }
 impl_type_option!(URLTypeOptionPB, FieldType::URL);
 
+impl URLTypeOptionPB {
+  /// Returns the links of the cell. The cell that was saved before the field became a list is
+  /// read as a single link.
+  fn cell_links(&self, cell_data: &URLCellData) -> Vec<URLLinkPB> {
+    if !cell_data.links.is_empty() || cell_data.url.is_empty() {
+      return cell_data.links.clone();
+    }
+    vec![URLLinkPB::new(&cell_data.url)]
+  }
+
+  fn cell_data_from_links(&self, links: Vec<URLLinkPB>) -> URLCellData {
+    URLCellData {
+      url: links
+        .first()
+        .map(|link| link.uri.clone())
+        .unwrap_or_default(),
+      content: join_links(&links),
+      links,
+    }
+  }
+
+  /// Parses the links of the plain text changeset, each url of the text becomes a link.
+  fn links_from_text(&self, s: &str) -> Vec<URLLinkPB> {
+    s.split_whitespace()
+      .filter_map(|word| match URL_REGEX.find(word) {
+        Ok(Some(m)) => Some(URLLinkPB::new(&auto_append_scheme(m.as_str()))),
+        _ => None,
+      })
+      .collect()
+  }
+}
+
+fn markdown_link(title: &str, uri: &str) -> String {
+  let title = title.replace('[', "\\[").replace(']', "\\]");
+  format!(
+    "[{}]({})",
+    title,
+    uri.replace(' ', "%20").replace(')', "%29")
+  )
+}
+
+fn join_links(links: &[URLLinkPB]) -> String {
+  links
+    .iter()
+    .map(|link| link.display_str())
+    .collect::<Vec<&str>>()
+    .join(", ")
+}
+
 impl TypeOption for URLTypeOptionPB {
   type CellData = URLCellData;
   type CellChangeset = URLCellChangeset;
   type CellProtobufType = URLCellDataPB;
-  type CellFilter = TextFilterPB;
+  type CellFilter = CompiledTextFilter;
 }
 
 impl TypeOptionTransform for URLTypeOptionPB {}
@@ -63,6 +128,20 @@ impl TypeOptionCellData for URLTypeOptionPB {
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     URLCellData::from_cell_str(&cell_str)
   }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    if self.is_list {
+      return self.cell_links(cell_data).is_empty();
+    }
+    cell_data.content.is_empty()
+  }
+
+  fn convert_to_cell_value(&self, cell_data: <Self as TypeOption>::CellData) -> CellValuePB {
+    CellValuePB {
+      url: Some(self.convert_to_protobuf(cell_data)),
+      ..Default::default()
+    }
+  }
 }
 
 impl CellDataDecoder for URLTypeOptionPB {
@@ -80,8 +159,28 @@ impl CellDataDecoder for URLTypeOptionPB {
   }
 
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    if self.is_list {
+      return join_links(&self.cell_links(&cell_data));
+    }
     cell_data.content
   }
+
+  /// The list of links is rendered as a bullet list, and the single url is rendered as one link
+  /// whose title is the content of the cell.
+  fn decode_cell_data_to_markdown(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    if self.is_list {
+      return self
+        .cell_links(&cell_data)
+        .iter()
+        .map(|link| format!("- {}", markdown_link(link.display_str(), &link.uri)))
+        .collect::<Vec<String>>()
+        .join("\n");
+    }
+    if cell_data.url.is_empty() {
+      return cell_data.content;
+    }
+    markdown_link(&cell_data.content, &cell_data.url)
+  }
 }
 
 pub type URLCellChangeset = String;
@@ -90,8 +189,30 @@ impl CellDataChangeset for URLTypeOptionPB {
   fn apply_changeset(
     &self,
     changeset: <Self as TypeOption>::CellChangeset,
-    _type_cell_data: Option<TypeCellData>,
+    type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    if self.is_list {
+      let changeset = match URLListCellChangeset::from_changeset(changeset.clone()) {
+        Ok(changeset) => changeset,
+        Err(_) => URLListCellChangeset::from_insert_links(self.links_from_text(&changeset)),
+      };
+      let mut links = match type_cell_data {
+        Some(type_cell_data) if type_cell_data.is_url() => {
+          let cell_data = URLCellData::from_cell_str(&type_cell_data.cell_str).unwrap_or_default();
+          self.cell_links(&cell_data)
+        },
+        _ => vec![],
+      };
+      links.retain(|link| !changeset.delete_uris.contains(&link.uri));
+      for link in changeset.insert_links {
+        if !links.iter().any(|other| other.uri == link.uri) {
+          links.push(link);
+        }
+      }
+      let url_cell_data = self.cell_data_from_links(links);
+      return Ok((url_cell_data.to_string(), url_cell_data));
+    }
+
     let mut url = "".to_string();
     if let Ok(Some(m)) = URL_REGEX.find(&changeset) {
       url = auto_append_scheme(m.as_str());
@@ -99,9 +220,74 @@ impl CellDataChangeset for URLTypeOptionPB {
     let url_cell_data = URLCellData {
       url,
       content: changeset,
+      links: vec![],
     };
     Ok((url_cell_data.to_string(), url_cell_data))
   }
+
+  /// Rejects the text that doesn't contain a URL. The links of the list field are accepted too.
+  fn validate_changeset(&self, changeset: &<Self as TypeOption>::CellChangeset) -> FlowyResult<()> {
+    let changeset = changeset.trim();
+    let is_valid = if changeset.is_empty() {
+      true
+    } else if self.is_list {
+      URLListCellChangeset::from_changeset(changeset.to_owned()).is_ok()
+        || !self.links_from_text(changeset).is_empty()
+    } else {
+      matches!(URL_REGEX.find(changeset), Ok(Some(_)))
+    };
+    if is_valid {
+      Ok(())
+    } else {
+      let msg = format!("{} is not a valid URL", changeset);
+      Err(FlowyError::new(ErrorCode::InvalidData, &msg))
+    }
+  }
+
+  /// Accepts the url string. If the field holds a list of links, the array of the urls or the
+  /// links, for example, `{"uri": "https://appflowy.io", "title": "AppFlowy"}`, is accepted too.
+  fn changeset_from_json_value(
+    &self,
+    value: &serde_json::Value,
+  ) -> FlowyResult<<Self as TypeOption>::CellChangeset> {
+    match value {
+      serde_json::Value::Null => Ok("".to_owned()),
+      serde_json::Value::String(s) => Ok(s.clone()),
+      serde_json::Value::Array(values) if self.is_list => {
+        let links = values
+          .iter()
+          .map(|value| {
+            link_from_json_value(value)
+              .ok_or_else(|| invalid_json_value_error(value, "a url string or a link object"))
+          })
+          .collect::<FlowyResult<Vec<URLLinkPB>>>()?;
+        Ok(URLListCellChangeset::from_insert_links(links).to_cell_changeset_str())
+      },
+      _ => Err(invalid_json_value_error(value, "a url string")),
+    }
+  }
+}
+
+fn link_from_json_value(value: &serde_json::Value) -> Option<URLLinkPB> {
+  let (uri, title) = match value {
+    serde_json::Value::String(uri) => (uri.as_str(), ""),
+    serde_json::Value::Object(object) => (
+      object.get("uri")?.as_str()?,
+      object
+        .get("title")
+        .and_then(|title| title.as_str())
+        .unwrap_or_default(),
+    ),
+    _ => return None,
+  };
+  let uri = uri.trim();
+  if uri.is_empty() {
+    return None;
+  }
+  Some(URLLinkPB {
+    uri: auto_append_scheme(uri),
+    title: title.to_owned(),
+  })
 }
 
 impl TypeOptionCellDataFilter for URLTypeOptionPB {
@@ -115,8 +301,28 @@ impl TypeOptionCellDataFilter for URLTypeOptionPB {
       return true;
     }
 
+    if self.is_list {
+      let links = self.cell_links(cell_data);
+      let values = links
+        .iter()
+        .flat_map(|link| [link.uri.as_str(), link.title.as_str()])
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<&str>>();
+      return filter.is_visible_in_list(&values);
+    }
     filter.is_visible(cell_data)
   }
+
+  fn default_filter_condition(&self) -> u8 {
+    TextFilterConditionPB::Contains as u8
+  }
+
+  fn empty_filter_condition(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+  ) -> Option<EmptyFilterCondition> {
+    filter.empty_filter_condition()
+  }
 }
 
 impl TypeOptionCellDataCompare for URLTypeOptionPB {
@@ -125,8 +331,20 @@ impl TypeOptionCellDataCompare for URLTypeOptionPB {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering {
+    if self.is_list {
+      let links = self.cell_links(cell_data);
+      let other_links = self.cell_links(other_cell_data);
+      return links.len().cmp(&other_links.len()).then_with(|| {
+        let uri = links.first().map(|link| &link.uri);
+        uri.cmp(&other_links.first().map(|link| &link.uri))
+      });
+    }
     cell_data.content.cmp(&other_cell_data.content)
   }
+
+  fn comparable_kind(&self) -> ComparableKind {
+    ComparableKind::String
+  }
 }
 fn auto_append_scheme(s: &str) -> String {
   // Only support https scheme by now
@@ -1,4 +1,7 @@
-use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString,
+};
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
 use flowy_error::{internal_error, FlowyResult};
@@ -11,6 +14,9 @@ pub struct URLCellDataPB {
 
   #[pb(index = 2)]
   pub content: String,
+
+  #[pb(index = 3)]
+  pub links: Vec<URLLinkPB>,
 }
 
 impl From<URLCellData> for URLCellDataPB {
@@ -18,6 +24,35 @@ impl From<URLCellData> for URLCellDataPB {
     Self {
       url: data.url,
       content: data.content,
+      links: data.links,
+    }
+  }
+}
+
+/// A link of the URL cell that holds a list of links.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct URLLinkPB {
+  #[pb(index = 1)]
+  pub uri: String,
+
+  #[pb(index = 2)]
+  pub title: String,
+}
+
+impl URLLinkPB {
+  pub fn new(uri: &str) -> Self {
+    Self {
+      uri: uri.to_owned(),
+      title: "".to_owned(),
+    }
+  }
+
+  /// Returns the title of the link, or the uri if the link doesn't have a title.
+  pub fn display_str(&self) -> &str {
+    if self.title.is_empty() {
+      &self.uri
+    } else {
+      &self.title
     }
   }
 }
@@ -34,6 +69,11 @@ impl DecodedCellData for URLCellDataPB {
 pub struct URLCellData {
   pub url: String,
   pub content: String,
+
+  /// The links of the cell if the field holds a list of links. The `url` and the `content` are
+  /// kept in sync with the first link and the joined links respectively.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub links: Vec<URLLinkPB>,
 }
 
 impl URLCellData {
@@ -41,6 +81,7 @@ impl URLCellData {
     Self {
       url: "".to_string(),
       content: s.to_string(),
+      links: vec![],
     }
   }
 
@@ -54,6 +95,7 @@ impl From<URLCellDataPB> for URLCellData {
     Self {
       url: data.url,
       content: data.content,
+      links: data.links,
     }
   }
 }
@@ -68,7 +110,48 @@ impl DecodedCellData for URLCellData {
   type Object = URLCellData;
 
   fn is_empty(&self) -> bool {
-    self.content.is_empty()
+    self.content.is_empty() && self.links.is_empty()
+  }
+}
+
+/// The changeset of the URL cell that holds a list of links. The links are deleted by their uri
+/// before the new links are inserted.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct URLListCellChangeset {
+  #[serde(default)]
+  pub insert_links: Vec<URLLinkPB>,
+  #[serde(default)]
+  pub delete_uris: Vec<String>,
+}
+
+impl URLListCellChangeset {
+  pub fn from_insert_links(insert_links: Vec<URLLinkPB>) -> Self {
+    Self {
+      insert_links,
+      delete_uris: vec![],
+    }
+  }
+
+  pub fn from_delete_uris(delete_uris: Vec<String>) -> Self {
+    Self {
+      insert_links: vec![],
+      delete_uris,
+    }
+  }
+}
+
+impl FromCellChangesetString for URLListCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<URLListCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangesetString for URLListCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
   }
 }
 
@@ -81,6 +164,57 @@ impl CellProtobufBlobParser for URLCellDataParser {
   }
 }
 
+/// The category of the link that is derived from the scheme of the url.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlCategory {
+  /// http, https or a bare host without the scheme, e.g. appflowy.io
+  Web,
+  /// mailto:
+  Email,
+  /// tel:
+  Phone,
+  /// file:
+  File,
+  Other,
+}
+
+impl UrlCategory {
+  pub fn from_url_str(s: &str) -> Self {
+    let s = s.trim();
+    let scheme = match s.split_once(':') {
+      Some((scheme, rest)) if is_scheme(scheme) && !is_port(rest) => scheme.to_lowercase(),
+      _ => return UrlCategory::Web,
+    };
+    match scheme.as_str() {
+      "http" | "https" => UrlCategory::Web,
+      "mailto" => UrlCategory::Email,
+      "tel" => UrlCategory::Phone,
+      "file" => UrlCategory::File,
+      _ => UrlCategory::Other,
+    }
+  }
+}
+
+/// https://www.rfc-editor.org/rfc/rfc3986#section-3.1
+fn is_scheme(s: &str) -> bool {
+  let mut chars = s.chars();
+  match chars.next() {
+    Some(c) if c.is_ascii_alphabetic() => {
+      chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    },
+    _ => false,
+  }
+}
+
+/// The host with a port, e.g. localhost:8080, is not a scheme.
+fn is_port(s: &str) -> bool {
+  let port = s
+    .split(|c| c == '/' || c == '?' || c == '#')
+    .next()
+    .unwrap_or("");
+  !port.is_empty() && port.chars().all(|c| c.is_ascii_digit())
+}
+
 impl FromCellString for URLCellData {
   fn from_cell_str(s: &str) -> FlowyResult<Self> {
     serde_json::from_str::<URLCellData>(s).map_err(internal_error)
@@ -6,7 +6,8 @@ use crate::services::cell::{
 use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
 use crate::services::field::*;
 use crate::services::filter::{
-  FilterChangeset, FilterResult, FilterResultNotification, FilterType,
+  FilterChangeset, FilterResult, FilterResultNotification, FilterType, ValueInSet,
+  VALUE_IN_SET_FILTER_CONDITION,
 };
 use crate::services::row::DatabaseBlockRowRevision;
 use dashmap::DashMap;
@@ -21,6 +22,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 type RowId = String;
+type HandlerByFieldId = HashMap<FieldId, Box<dyn TypeOptionCellDataHandler>>;
 pub trait FilterDelegate: Send + Sync + 'static {
   fn get_filter_rev(&self, filter_type: FilterType) -> Fut<Option<Arc<FilterRevision>>>;
   fn get_field_rev(&self, field_id: &str) -> Fut<Option<Arc<FieldRevision>>>;
@@ -104,12 +106,14 @@ impl FilterController {
       return;
     }
     let field_rev_by_field_id = self.get_filter_revs_map().await;
+    self.refresh_date_filters(&field_rev_by_field_id);
+    let handler_by_field_id = self.get_handler_by_field_id(&field_rev_by_field_id);
     row_revs.iter().for_each(|row_rev| {
       let _ = filter_row(
         row_rev,
         &self.result_by_row_id,
         &field_rev_by_field_id,
-        &self.cell_data_cache,
+        &handler_by_field_id,
         &self.cell_filter_cache,
       );
     });
@@ -123,6 +127,50 @@ impl FilterController {
     });
   }
 
+  /// Refreshes the current date and the holidays of the cached date filters once per filter pass,
+  /// so the relative conditions, for example, `DateIsToday`, follow the date and the timezone of
+  /// the field, and the business day conditions skip its holidays.
+  fn refresh_date_filters(&self, field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>) {
+    let mut cell_filter_cache = self.cell_filter_cache.write();
+    for field_rev in field_rev_by_field_id.values() {
+      let filter_type = FilterType::from(field_rev);
+      if !filter_type.field_type.is_date() {
+        continue;
+      }
+      if let Some(filter) = cell_filter_cache.get_mut::<CompiledDateFilter>(&filter_type) {
+        let type_option = field_rev
+          .get_type_option::<DateTypeOptionPB>(field_rev.ty)
+          .unwrap_or_default();
+        filter.refresh_context(&type_option);
+      }
+    }
+  }
+
+  /// Builds the cell data handler of each filtered field once per filter pass instead of once per
+  /// cell.
+  fn get_handler_by_field_id(
+    &self,
+    field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>,
+  ) -> HandlerByFieldId {
+    let cell_filter_cache = self.cell_filter_cache.read();
+    field_rev_by_field_id
+      .iter()
+      .filter_map(|(field_id, field_rev)| {
+        let filter_type = FilterType::from(field_rev);
+        if !cell_filter_cache.contains(&filter_type) {
+          return None;
+        }
+        let handler = TypeOptionCellExt::new(
+          field_rev.as_ref(),
+          Some(self.cell_data_cache.clone()),
+          Some(self.cell_filter_cache.clone()),
+        )
+        .get_type_option_cell_data_handler(&filter_type.field_type)?;
+        Some((field_id.clone(), handler))
+      })
+      .collect()
+  }
+
   async fn get_filter_revs_map(&self) -> HashMap<String, Arc<FieldRevision>> {
     self
       .delegate
@@ -152,15 +200,20 @@ impl FilterController {
   async fn filter_row(&self, row_id: String) -> FlowyResult<()> {
     if let Some((_, row_rev)) = self.delegate.get_row_rev(&row_id).await {
       let field_rev_by_field_id = self.get_filter_revs_map().await;
+      self.refresh_date_filters(&field_rev_by_field_id);
       let mut notification =
         FilterResultNotification::new(self.view_id.clone(), row_rev.block_id.clone());
-      if let Some((row_id, is_visible)) = filter_row(
-        &row_rev,
-        &self.result_by_row_id,
-        &field_rev_by_field_id,
-        &self.cell_data_cache,
-        &self.cell_filter_cache,
-      ) {
+      let result = {
+        let handler_by_field_id = self.get_handler_by_field_id(&field_rev_by_field_id);
+        filter_row(
+          &row_rev,
+          &self.result_by_row_id,
+          &field_rev_by_field_id,
+          &handler_by_field_id,
+          &self.cell_filter_cache,
+        )
+      };
+      if let Some((row_id, is_visible)) = result {
         if is_visible {
           if let Some((index, row_rev)) = self.delegate.get_row_rev(&row_id).await {
             let row_pb = RowPB::from(row_rev.as_ref());
@@ -182,7 +235,10 @@ impl FilterController {
 
   async fn filter_all_rows(&self) -> FlowyResult<()> {
     let field_rev_by_field_id = self.get_filter_revs_map().await;
-    for block in self.delegate.get_blocks().await.into_iter() {
+    self.refresh_date_filters(&field_rev_by_field_id);
+    let blocks = self.delegate.get_blocks().await;
+    let handler_by_field_id = self.get_handler_by_field_id(&field_rev_by_field_id);
+    for block in blocks.into_iter() {
       // The row_ids contains the row that its visibility was changed.
       let mut visible_rows = vec![];
       let mut invisible_rows = vec![];
@@ -192,7 +248,7 @@ impl FilterController {
           row_rev,
           &self.result_by_row_id,
           &field_rev_by_field_id,
-          &self.cell_data_cache,
+          &handler_by_field_id,
           &self.cell_filter_cache,
         ) {
           if is_visible {
@@ -256,7 +312,11 @@ impl FilterController {
           filter_id = new_filter.as_ref().map(|filter| filter.id.clone());
         }
 
-        // Update the corresponding filter in the cache
+        // Update the corresponding filter in the cache. The compiled filter of the old filter type
+        // is stale if the filter type was changed.
+        if old_filter_type != updated_filter_type.new {
+          self.cell_filter_cache.write().remove(&old_filter_type);
+        }
         if let Some(filter_rev) = self
           .delegate
           .get_filter_rev(updated_filter_type.new.clone())
@@ -308,14 +368,21 @@ impl FilterController {
       if let Some(field_rev) = self.delegate.get_field_rev(&filter_rev.field_id).await {
         let filter_type = FilterType::from(&field_rev);
         tracing::trace!("Create filter with type: {:?}", filter_type);
+        if filter_rev.condition == VALUE_IN_SET_FILTER_CONDITION {
+          self.cell_filter_cache.write().insert(
+            &filter_type,
+            ValueInSet::from_filter_rev(filter_rev.as_ref()),
+          );
+          continue;
+        }
         match &filter_type.field_type {
           FieldType::RichText => {
             self.cell_filter_cache.write().insert(
               &filter_type,
-              TextFilterPB::from_filter_rev(filter_rev.as_ref()),
+              CompiledTextFilter::from_filter_rev(filter_rev.as_ref()),
             );
           },
-          FieldType::Number => {
+          FieldType::Number | FieldType::Duration | FieldType::Rating => {
             self.cell_filter_cache.write().insert(
               &filter_type,
               NumberFilterPB::from_filter_rev(filter_rev.as_ref()),
@@ -324,7 +391,7 @@ impl FilterController {
           FieldType::DateTime => {
             self.cell_filter_cache.write().insert(
               &filter_type,
-              DateFilterPB::from_filter_rev(filter_rev.as_ref()),
+              CompiledDateFilter::from_filter_rev(filter_rev.as_ref()),
             );
           },
           FieldType::SingleSelect | FieldType::MultiSelect => {
@@ -339,10 +406,40 @@ impl FilterController {
               CheckboxFilterPB::from_filter_rev(filter_rev.as_ref()),
             );
           },
-          FieldType::URL => {
+          FieldType::Relation => {
             self.cell_filter_cache.write().insert(
               &filter_type,
-              TextFilterPB::from_filter_rev(filter_rev.as_ref()),
+              RelationFilterPB::from_filter_rev(filter_rev.as_ref()),
+            );
+          },
+          FieldType::IpAddress => {
+            self.cell_filter_cache.write().insert(
+              &filter_type,
+              IpFilterPB::from_filter_rev(filter_rev.as_ref()),
+            );
+          },
+          FieldType::Json => {
+            self.cell_filter_cache.write().insert(
+              &filter_type,
+              JsonFilterPB::from_filter_rev(filter_rev.as_ref()),
+            );
+          },
+          FieldType::Email => {
+            self.cell_filter_cache.write().insert(
+              &filter_type,
+              EmailFilterPB::from_filter_rev(filter_rev.as_ref()),
+            );
+          },
+          FieldType::Phone => {
+            self.cell_filter_cache.write().insert(
+              &filter_type,
+              PhoneFilterPB::from_filter_rev(filter_rev.as_ref()),
+            );
+          },
+          FieldType::URL | FieldType::Concat | FieldType::Order => {
+            self.cell_filter_cache.write().insert(
+              &filter_type,
+              CompiledTextFilter::from_filter_rev(filter_rev.as_ref()),
             );
           },
           FieldType::Checklist => {
@@ -363,7 +460,7 @@ fn filter_row(
   row_rev: &Arc<RowRevision>,
   result_by_row_id: &DashMap<RowId, FilterResult>,
   field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>,
-  cell_data_cache: &AtomicCellDataCache,
+  handler_by_field_id: &HandlerByFieldId,
   cell_filter_cache: &AtomicCellFilterCache,
 ) -> Option<(String, bool)> {
   // Create a filter result cache if it's not exist
@@ -380,6 +477,10 @@ fn filter_row(
       continue;
     }
 
+    let handler = match handler_by_field_id.get(field_id) {
+      None => continue,
+      Some(handler) => handler.as_ref(),
+    };
     let cell_rev = row_rev.cells.get(field_id);
     // if the visibility of the cell_rew is changed, which means the visibility of the
     // row is changed too.
@@ -387,7 +488,7 @@ fn filter_row(
       &filter_type,
       field_rev,
       cell_rev,
-      cell_data_cache,
+      handler,
       cell_filter_cache,
     ) {
       filter_result
@@ -412,7 +513,7 @@ fn filter_cell(
   filter_type: &FilterType,
   field_rev: &Arc<FieldRevision>,
   cell_rev: Option<&CellRevision>,
-  cell_data_cache: &AtomicCellDataCache,
+  handler: &dyn TypeOptionCellDataHandler,
   cell_filter_cache: &AtomicCellFilterCache,
 ) -> Option<bool> {
   let type_cell_data = match cell_rev {
@@ -426,13 +527,12 @@ fn filter_cell(
     },
   };
 
-  let handler = TypeOptionCellExt::new(
-    field_rev.as_ref(),
-    Some(cell_data_cache.clone()),
-    Some(cell_filter_cache.clone()),
-  )
-  .get_type_option_cell_data_handler(&filter_type.field_type)?;
+  if let Some(value_in_set) = cell_filter_cache.read().get::<ValueInSet>(filter_type) {
+    return Some(value_in_set.is_visible(handler, type_cell_data, field_rev.as_ref()));
+  }
 
+  // The read guard is released here because handling the filter of the field type reads the
+  // cache again.
   let is_visible = handler.handle_cell_filter(filter_type, field_rev.as_ref(), type_cell_data);
   Some(is_visible)
 }
@@ -1,7 +1,11 @@
 use crate::entities::{
   AlterFilterParams, DatabaseSettingChangesetParams, DeleteFilterParams, FieldType, InsertedRowPB,
 };
-use database_model::{FieldRevision, FieldTypeRevision};
+use crate::services::cell::TypeCellData;
+use crate::services::field::TypeOptionCellDataHandler;
+use crate::services::filter::FromFilterString;
+use database_model::{FieldRevision, FieldTypeRevision, FilterRevision};
+use std::collections::HashSet;
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -128,3 +132,161 @@ impl FilterResultNotification {
     }
   }
 }
+
+/// The condition of the [FilterRevision] that holds a [ValueInSet] instead of the filter of the
+/// field type. It's out of the range of the conditions of all the field types.
+pub const VALUE_IN_SET_FILTER_CONDITION: u8 = u8::MAX;
+
+/// Keeps the rows whose cell is one of the values, for example, the ids of the rows that were
+/// imported in the same batch. It works on any field type by comparing the display string of the
+/// cell. It's saved as the [FilterRevision] whose condition is [VALUE_IN_SET_FILTER_CONDITION] and
+/// whose content is the JSON array of the values.
+#[derive(Debug, Clone, Default)]
+pub struct ValueInSet(pub HashSet<String>);
+
+impl ValueInSet {
+  /// Returns true if the display string of the cell is in the set. The `handler` is the handler of
+  /// the field, which is built once per field instead of per cell.
+  pub fn is_visible(
+    &self,
+    handler: &dyn TypeOptionCellDataHandler,
+    type_cell_data: TypeCellData,
+    field_rev: &FieldRevision,
+  ) -> bool {
+    self
+      .0
+      .contains(&handler.stringify_type_cell_data(type_cell_data, field_rev))
+  }
+
+  /// Returns the params that save the set as the filter of the field.
+  pub fn to_filter_params(
+    &self,
+    view_id: &str,
+    field_rev: &FieldRevision,
+    filter_id: Option<String>,
+  ) -> AlterFilterParams {
+    let mut values = self.0.iter().collect::<Vec<&String>>();
+    values.sort();
+    AlterFilterParams {
+      view_id: view_id.to_owned(),
+      field_id: field_rev.id.clone(),
+      filter_id,
+      field_type: field_rev.ty,
+      condition: VALUE_IN_SET_FILTER_CONDITION,
+      content: serde_json::to_string(&values).unwrap_or_default(),
+    }
+  }
+}
+
+impl FromFilterString for ValueInSet {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    ValueInSet(serde_json::from_str(&filter_rev.content).unwrap_or_default())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, FilterPB, TextFilterPB, ValueInSetFilterPB};
+  use crate::services::cell::{insert_number_cell, insert_text_cell, TypeCellData};
+  use crate::services::field::{FieldBuilder, TypeOptionCellExt};
+  use crate::services::filter::ValueInSet;
+  use bytes::Bytes;
+  use std::convert::TryFrom;
+
+  #[test]
+  fn value_in_set_test() {
+    let filter = ValueInSet(
+      ["batch-1", "batch-3", "42"]
+        .iter()
+        .map(|value| value.to_string())
+        .collect(),
+    );
+
+    let text_field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let handler = TypeOptionCellExt::new_with_cell_data_cache(&text_field_rev, None)
+      .get_type_option_cell_data_handler(&FieldType::RichText)
+      .unwrap();
+    for (text, expected) in [
+      ("batch-1", true),
+      ("batch-3", true),
+      ("batch-2", false),
+      ("", false),
+    ] {
+      let cell_rev = insert_text_cell(text.to_owned(), &text_field_rev);
+      assert_eq!(
+        filter.is_visible(
+          handler.as_ref(),
+          TypeCellData::try_from(&cell_rev).unwrap(),
+          &text_field_rev
+        ),
+        expected,
+        "{}",
+        text
+      );
+    }
+    assert!(!filter.is_visible(
+      handler.as_ref(),
+      TypeCellData::from_field_type(&FieldType::RichText),
+      &text_field_rev
+    ));
+
+    // The number cell is compared by its display string.
+    let number_field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+    let handler = TypeOptionCellExt::new_with_cell_data_cache(&number_field_rev, None)
+      .get_type_option_cell_data_handler(&FieldType::Number)
+      .unwrap();
+    for (number, expected) in [(42, true), (7, false)] {
+      let cell_rev = insert_number_cell(number, &number_field_rev);
+      assert_eq!(
+        filter.is_visible(
+          handler.as_ref(),
+          TypeCellData::try_from(&cell_rev).unwrap(),
+          &number_field_rev
+        ),
+        expected
+      );
+    }
+  }
+
+  #[test]
+  fn value_in_set_filter_pb_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let filter = ValueInSet(
+      ["batch-2", "batch-1"]
+        .iter()
+        .map(|value| value.to_string())
+        .collect(),
+    );
+    let params = filter.to_filter_params("view", &field_rev, Some("filter".to_owned()));
+    let filter_rev = database_model::FilterRevision {
+      id: "filter".to_owned(),
+      field_id: params.field_id,
+      field_type: params.field_type,
+      condition: params.condition,
+      content: params.content,
+    };
+
+    let filter_pb = FilterPB::from(&filter_rev);
+    assert!(filter_pb.is_value_in_set);
+    let value_in_set_pb = ValueInSetFilterPB::try_from(Bytes::from(filter_pb.data)).unwrap();
+    assert_eq!(value_in_set_pb.values, vec!["batch-1", "batch-2"]);
+
+    // The filter of the field type is not marked as the value in set.
+    let text_filter_rev = database_model::FilterRevision {
+      condition: 0,
+      content: "batch".to_owned(),
+      ..filter_rev
+    };
+    let filter_pb = FilterPB::from(&text_filter_rev);
+    assert!(!filter_pb.is_value_in_set);
+    assert_eq!(
+      TextFilterPB::try_from(Bytes::from(filter_pb.data))
+        .unwrap()
+        .content,
+      "batch"
+    );
+  }
+}
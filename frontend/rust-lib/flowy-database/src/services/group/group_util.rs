@@ -148,13 +148,20 @@ pub fn default_group_configuration(field_rev: &FieldRevision) -> GroupConfigurat
   let field_type_rev = field_rev.ty;
   let field_type: FieldType = field_rev.ty.into();
   match field_type {
-    FieldType::RichText => GroupConfigurationRevision::new(
+    FieldType::RichText
+    | FieldType::Concat
+    | FieldType::Order
+    | FieldType::Relation
+    | FieldType::IpAddress
+    | FieldType::Json
+    | FieldType::Email
+    | FieldType::Phone => GroupConfigurationRevision::new(
       field_id,
       field_type_rev,
       TextGroupConfigurationRevision::default(),
     )
     .unwrap(),
-    FieldType::Number => GroupConfigurationRevision::new(
+    FieldType::Number | FieldType::Duration | FieldType::Rating => GroupConfigurationRevision::new(
       field_id,
       field_type_rev,
       NumberGroupConfigurationRevision::default(),
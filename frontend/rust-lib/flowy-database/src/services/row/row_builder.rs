@@ -78,6 +78,16 @@ impl RowRevisionBuilder {
               builder.insert_select_option_cell(&field_id, ids.into_inner());
             }
           },
+          // The concat cell is computed from the other cells of the row.
+          FieldType::Concat => {},
+          FieldType::Order
+          | FieldType::Relation
+          | FieldType::Duration
+          | FieldType::Rating
+          | FieldType::IpAddress
+          | FieldType::Json
+          | FieldType::Email
+          | FieldType::Phone => builder.insert_text_cell(&field_id, cell_data),
         }
       }
     }
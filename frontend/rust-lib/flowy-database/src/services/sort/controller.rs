@@ -6,7 +6,9 @@ use crate::services::field::{default_order, TypeOptionCellExt};
 use crate::services::sort::{
   ReorderAllRowsResult, ReorderSingleRowResult, SortChangeset, SortType,
 };
-use database_model::{CellRevision, FieldRevision, RowRevision, SortCondition, SortRevision};
+use database_model::{
+  CellRevision, FieldRevision, NullsOrder, RowRevision, SortCondition, SortRevision,
+};
 use flowy_error::FlowyResult;
 use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
 use lib_infra::future::Fut;
@@ -224,11 +226,22 @@ fn cmp_row(
   field_revs: &[Arc<FieldRevision>],
   cell_data_cache: &AtomicCellDataCache,
 ) -> Ordering {
+  // The order is reversed below if the condition is descending, so the nulls order is reversed
+  // here to keep the empty cells where they are asked to be.
+  let nulls_order = sort.nulls_order.map(|nulls_order| match sort.condition {
+    SortCondition::Ascending => nulls_order,
+    SortCondition::Descending => nulls_order.reverse(),
+  });
   let order = match (
     left.cells.get(&sort.field_id),
     right.cells.get(&sort.field_id),
+    nulls_order,
   ) {
-    (Some(left_cell), Some(right_cell)) => {
+    (None, None, _) => default_order(),
+    (Some(_), None, None) => Ordering::Greater,
+    (None, Some(_), None) => Ordering::Less,
+    // The missing cell is compared as the empty cell if the nulls order is set.
+    (left_cell, right_cell, nulls_order) => {
       let field_type: FieldType = sort.field_type.into();
       match field_revs
         .iter()
@@ -240,13 +253,11 @@ fn cmp_row(
           right_cell,
           field_rev,
           field_type,
+          nulls_order,
           cell_data_cache,
         ),
       }
     },
-    (Some(_), None) => Ordering::Greater,
-    (None, Some(_)) => Ordering::Less,
-    _ => default_order(),
   };
 
   // The order is calculated by Ascending. So reverse the order if the SortCondition is descending.
@@ -257,10 +268,11 @@ fn cmp_row(
 }
 
 fn cmp_cell(
-  left_cell: &CellRevision,
-  right_cell: &CellRevision,
+  left_cell: Option<&CellRevision>,
+  right_cell: Option<&CellRevision>,
   field_rev: &Arc<FieldRevision>,
   field_type: FieldType,
+  nulls_order: Option<NullsOrder>,
   cell_data_cache: &AtomicCellDataCache,
 ) -> Ordering {
   match TypeOptionCellExt::new_with_cell_data_cache(
@@ -271,11 +283,19 @@ fn cmp_cell(
   {
     None => default_order(),
     Some(handler) => {
+      let cell_str = |cell: Option<&CellRevision>| match cell {
+        None => Some("".to_owned()),
+        Some(cell) => Some(TypeCellData::try_from(cell).ok()?.into_inner()),
+      };
       let cal_order = || {
-        let left_cell_str = TypeCellData::try_from(left_cell).ok()?.into_inner();
-        let right_cell_str = TypeCellData::try_from(right_cell).ok()?.into_inner();
-        let order =
-          handler.handle_cell_compare(&left_cell_str, &right_cell_str, field_rev.as_ref());
+        let left_cell_str = cell_str(left_cell)?;
+        let right_cell_str = cell_str(right_cell)?;
+        let order = handler.handle_cell_compare(
+          &left_cell_str,
+          &right_cell_str,
+          field_rev.as_ref(),
+          nulls_order,
+        );
         Option::<Ordering>::Some(order)
       };
 
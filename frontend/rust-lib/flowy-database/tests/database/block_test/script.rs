@@ -176,7 +176,15 @@ impl DatabaseRowTest {
     expected: String,
   ) {
     match field_type {
-      FieldType::RichText => {
+      FieldType::RichText
+      | FieldType::Concat
+      | FieldType::Order
+      | FieldType::Duration
+      | FieldType::Rating
+      | FieldType::IpAddress
+      | FieldType::Json
+      | FieldType::Email
+      | FieldType::Phone => {
         let cell_data = self
           .editor
           .get_cell_protobuf(&cell_id)
@@ -282,6 +290,17 @@ impl DatabaseRowTest {
         assert_eq!(cell_data.content, expected);
         // assert_eq!(cell_data.url, expected);
       },
+      FieldType::Relation => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<RelationCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.row_ids.join(RELATION_ROW_IDS_SEPARATOR), expected);
+      },
     }
   }
 }
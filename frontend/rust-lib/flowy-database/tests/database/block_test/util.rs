@@ -44,6 +44,8 @@ impl DatabaseRowTestBuilder {
       time: None,
       is_utc: true,
       include_time: Some(false),
+      precision: None,
+      end_timestamp: None,
     })
     .unwrap();
     let date_field = self.field_rev_with_type(&FieldType::DateTime);
@@ -1,7 +1,7 @@
 use crate::database::cell_test::script::CellScript::*;
 use crate::database::cell_test::script::DatabaseCellTest;
 use crate::database::field_test::util::make_date_cell_string;
-use flowy_database::entities::{CellChangesetPB, FieldType};
+use flowy_database::entities::{CellChangesetPB, CellIdParams, FieldType};
 use flowy_database::services::cell::ToCellChangesetString;
 use flowy_database::services::field::selection_type_option::SelectOptionCellChangeset;
 use flowy_database::services::field::{
@@ -43,6 +43,15 @@ async fn grid_cell_update() {
         },
         FieldType::Checkbox => "1".to_string(),
         FieldType::URL => "1".to_string(),
+        FieldType::Concat => "".to_string(),
+        FieldType::Order => "".to_string(),
+        FieldType::Relation => "".to_string(),
+        FieldType::Duration => "90m".to_string(),
+        FieldType::Rating => "4".to_string(),
+        FieldType::IpAddress => "10.0.0.1".to_string(),
+        FieldType::Json => r#"{"a":1}"#.to_string(),
+        FieldType::Email => "hello@appflowy.io".to_string(),
+        FieldType::Phone => "+15551234567".to_string(),
       };
 
       scripts.push(UpdateCell {
@@ -101,3 +110,44 @@ async fn url_cell_date_test() {
     }
   }
 }
+
+#[tokio::test]
+async fn concat_cell_follows_source_cells_test() {
+  let mut test = DatabaseCellTest::new().await;
+  let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+  let concat_field = test.get_first_field_rev(FieldType::Concat).clone();
+  let row_id = test.row_revs[0].id.clone();
+  let concat_cell = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: concat_field.id.clone(),
+    row_id: row_id.clone(),
+  };
+
+  test
+    .run_script(UpdateCell {
+      changeset: CellChangesetPB {
+        view_id: test.view_id.clone(),
+        row_id: row_id.clone(),
+        field_id: text_field.id.clone(),
+        type_cell_data: "AppFlowy".to_owned(),
+      },
+      is_err: false,
+    })
+    .await;
+  let s = test.editor.get_cell_display_str(&concat_cell).await;
+  assert!(s.starts_with("AppFlowy"));
+
+  test
+    .run_script(UpdateCell {
+      changeset: CellChangesetPB {
+        view_id: test.view_id.clone(),
+        row_id,
+        field_id: text_field.id.clone(),
+        type_cell_data: "".to_owned(),
+      },
+      is_err: false,
+    })
+    .await;
+  let s = test.editor.get_cell_display_str(&concat_cell).await;
+  assert!(!s.contains("AppFlowy"));
+}
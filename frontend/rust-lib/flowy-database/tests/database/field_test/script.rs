@@ -26,6 +26,10 @@ pub enum FieldScript {
     field_index: usize,
     frozen: bool,
   },
+  AssertFieldRequired {
+    field_index: usize,
+    is_required: bool,
+  },
   AssertFieldTypeOptionEqual {
     field_index: usize,
     expected_type_option_data: String,
@@ -122,6 +126,14 @@ impl DatabaseFieldTest {
         let field_rev = field_revs[field_index].as_ref();
         assert_eq!(field_rev.frozen, frozen);
       },
+      FieldScript::AssertFieldRequired {
+        field_index,
+        is_required,
+      } => {
+        let field_revs = self.editor.get_field_revs(None).await.unwrap();
+        let field_rev = field_revs[field_index].as_ref();
+        assert_eq!(field_rev.is_required, is_required);
+      },
       FieldScript::AssertFieldTypeOptionEqual {
         field_index,
         expected_type_option_data,
@@ -118,6 +118,45 @@ async fn grid_update_field() {
   test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_update_field_required() {
+  let mut test = DatabaseFieldTest::new().await;
+  let (params, _) = create_text_field(&test.view_id());
+  let scripts = vec![CreateField { params }];
+  let create_field_index = test.field_count();
+  test.run_scripts(scripts).await;
+
+  let text_field_rev = (*test.field_revs.clone().pop().unwrap()).clone();
+  let view_id = test.view_id();
+  let changeset = |is_required: bool| FieldChangesetParams {
+    field_id: text_field_rev.id.clone(),
+    view_id: view_id.clone(),
+    is_required: Some(is_required),
+    ..Default::default()
+  };
+  let scripts = vec![
+    AssertFieldRequired {
+      field_index: create_field_index,
+      is_required: false,
+    },
+    UpdateField {
+      changeset: changeset(true),
+    },
+    AssertFieldRequired {
+      field_index: create_field_index,
+      is_required: true,
+    },
+    UpdateField {
+      changeset: changeset(false),
+    },
+    AssertFieldRequired {
+      field_index: create_field_index,
+      is_required: false,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn grid_delete_field() {
   let mut test = DatabaseFieldTest::new().await;
@@ -149,12 +188,16 @@ async fn grid_switch_from_select_option_to_checkbox_test() {
     id: gen_option_id(),
     name: CHECK.to_string(),
     color: Default::default(),
+    weight: None,
+    icon: None,
   });
   // Add a new option with name UNCHECK
   single_select_type_option.options.push(SelectOptionPB {
     id: gen_option_id(),
     name: UNCHECK.to_string(),
     color: Default::default(),
+    weight: None,
+    icon: None,
   });
 
   let bytes: Bytes = single_select_type_option.try_into().unwrap();
@@ -233,7 +276,7 @@ async fn grid_switch_from_multi_select_to_text_test() {
     row_index: 0,
     from_field_type: FieldType::MultiSelect,
     expected_content: format!(
-      "{},{}",
+      "{}, {}",
       multi_select_type_option.get(0).unwrap().name,
       multi_select_type_option.get(1).unwrap().name
     ),
@@ -65,6 +65,8 @@ pub fn make_date_cell_string(s: &str) -> String {
     time: None,
     is_utc: true,
     include_time: Some(false),
+    precision: None,
+    end_timestamp: None,
   })
   .unwrap()
 }
@@ -106,3 +106,49 @@ async fn grid_filter_date_within_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+// 2022-11-21, a Monday. The row of 2022-11-13 is 6 business days older than it, and the row of
+// 2022-11-17 is 2 business days older.
+const BUSINESS_DAYS_REFERENCE: i64 = 1668988800;
+
+#[tokio::test]
+async fn grid_filter_date_older_than_business_days_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let expected = 4;
+  let scripts = vec![
+    CreateBusinessDaysDateFilter {
+      timestamp: BUSINESS_DAYS_REFERENCE,
+      business_days: 5,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_date_older_than_business_days_with_holidays_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  // The holidays make the row of 2022-11-13 only 4 business days older.
+  let expected = 3;
+  let scripts = vec![
+    UpdateDateHolidays {
+      holidays: vec!["2022-11-15".to_owned(), "2022-11-16".to_owned()],
+    },
+    CreateBusinessDaysDateFilter {
+      timestamp: BUSINESS_DAYS_REFERENCE,
+      business_days: 5,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -8,12 +8,12 @@ use bytes::Bytes;
 use futures::TryFutureExt;
 use tokio::sync::broadcast::Receiver;
 use flowy_database::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, LayoutTypePB, DatabaseSettingChangesetParams, DatabaseViewSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB};
-use flowy_database::services::field::{SelectOptionCellChangeset, SelectOptionIds};
+use flowy_database::services::field::{DateTypeOptionPB, SelectOptionCellChangeset, SelectOptionIds};
 use flowy_database::services::setting::GridSettingChangesetBuilder;
 use database_model::{FieldRevision, FieldTypeRevision};
 use flowy_sqlite::schema::view_table::dsl::view_table;
 use flowy_database::services::cell::insert_select_option_cell;
-use flowy_database::services::filter::FilterType;
+use flowy_database::services::filter::{FilterType, ValueInSet};
 use flowy_database::services::database_view::DatabaseViewChanged;
 use crate::database::database_editor::DatabaseEditorTest;
 
@@ -41,6 +41,10 @@ pub enum FilterScript {
         content: String,
         changed: Option<FilterRowChanged>,
     },
+    CreateValueInSetFilter {
+        values: Vec<String>,
+        changed: Option<FilterRowChanged>,
+    },
     UpdateTextFilter {
         filter: FilterPB,
         condition: TextFilterConditionPB,
@@ -63,6 +67,14 @@ pub enum FilterScript {
         timestamp: Option<i64>,
         changed: Option<FilterRowChanged>,
     },
+    CreateBusinessDaysDateFilter {
+        timestamp: i64,
+        business_days: i64,
+        changed: Option<FilterRowChanged>,
+    },
+    UpdateDateHolidays {
+        holidays: Vec<String>,
+    },
     CreateMultiSelectFilter {
         condition: SelectOptionConditionPB,
         option_ids: Vec<String>,
@@ -157,6 +169,14 @@ impl DatabaseFilterTest {
                         field_rev, text_filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateValueInSetFilter { values, changed } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::RichText);
+                let value_in_set = ValueInSet(values.into_iter().collect());
+                let params = value_in_set.to_filter_params(&self.view_id(), field_rev, None);
+                self.editor.create_or_update_filter(params).await.unwrap();
+            }
             FilterScript::UpdateTextFilter { filter, condition, content, changed} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.assert_future_changed(changed).await;
@@ -203,13 +223,45 @@ impl DatabaseFilterTest {
                     condition,
                     start,
                     end,
-                    timestamp
+                    timestamp,
+                    business_days: None,
+                    days: None,
                 };
 
                 let payload =
                     AlterFilterPayloadPB::new( &self.view_id(), field_rev, date_filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateBusinessDaysDateFilter { timestamp, business_days, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::DateTime);
+                let date_filter = DateFilterPB {
+                    condition: DateFilterConditionPB::DateOlderThanBusinessDays,
+                    start: None,
+                    end: None,
+                    timestamp: Some(timestamp),
+                    business_days: Some(business_days),
+                    days: None,
+                };
+
+                let payload =
+                    AlterFilterPayloadPB::new( &self.view_id(), field_rev, date_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::UpdateDateHolidays { holidays } => {
+                let field_rev = self.get_first_field_rev(FieldType::DateTime).clone();
+                let mut type_option = field_rev
+                    .get_type_option::<DateTypeOptionPB>(field_rev.ty)
+                    .unwrap_or_default();
+                type_option.holidays = holidays;
+                let bytes: Bytes = type_option.try_into().unwrap();
+                self.editor
+                    .update_field_type_option(&self.view_id(), &field_rev.id, bytes.to_vec(), None)
+                    .await
+                    .unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
             FilterScript::CreateMultiSelectFilter { condition, option_ids} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 let field_rev = self.get_first_field_rev(FieldType::MultiSelect);
@@ -232,7 +284,7 @@ impl DatabaseFilterTest {
                 self.assert_future_changed(changed).await;
                 let field_rev = self.get_first_field_rev(FieldType::Checklist);
                 // let type_option = self.get_checklist_type_option(&field_rev.id);
-                let filter = ChecklistFilterPB { condition };
+                let filter = ChecklistFilterPB { condition, content: "".to_string() };
                 let payload =
                     AlterFilterPayloadPB::new(& self.view_id(),field_rev, filter);
                 self.insert_filter(payload).await;
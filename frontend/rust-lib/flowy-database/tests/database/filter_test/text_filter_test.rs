@@ -154,6 +154,23 @@ async fn grid_filter_ends_with_text_test() {
   test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_filter_text_in_value_set_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateValueInSetFilter {
+      values: vec!["A".to_string(), "C".to_string(), "Z".to_string()],
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: 4,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 2 },
+    AssertFilterCount { count: 1 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn grid_update_text_filter_test() {
   let mut test = DatabaseFilterTest::new().await;
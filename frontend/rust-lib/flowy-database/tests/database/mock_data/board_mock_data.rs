@@ -102,6 +102,82 @@ pub fn make_test_board() -> BuildDatabaseContext {
           .build();
         database_builder.add_field(checklist_field);
       },
+      FieldType::Concat => {
+        let field_revs = database_builder.field_revs();
+        let concat = ConcatTypeOptionBuilder::default()
+          .add_source_field(&field_revs[0].id)
+          .add_source_field(&field_revs[1].id)
+          .separator(" - ");
+        let concat_field = FieldBuilder::new(concat)
+          .name("Summary")
+          .visibility(true)
+          .build();
+        database_builder.add_field(concat_field);
+      },
+      FieldType::Order => {
+        let order = OrderTypeOptionBuilder::default();
+        let order_field = FieldBuilder::new(order)
+          .name("Position")
+          .visibility(true)
+          .build();
+        database_builder.add_field(order_field);
+      },
+      FieldType::Relation => {
+        let relation = RelationTypeOptionBuilder::default();
+        let relation_field = FieldBuilder::new(relation)
+          .name("Links")
+          .visibility(true)
+          .build();
+        database_builder.add_field(relation_field);
+      },
+      FieldType::Duration => {
+        let duration = DurationTypeOptionBuilder::default();
+        let duration_field = FieldBuilder::new(duration)
+          .name("Time spent")
+          .visibility(true)
+          .build();
+        database_builder.add_field(duration_field);
+      },
+      FieldType::Rating => {
+        let rating = RatingTypeOptionBuilder::default();
+        let rating_field = FieldBuilder::new(rating)
+          .name("Rating")
+          .visibility(true)
+          .build();
+        database_builder.add_field(rating_field);
+      },
+      FieldType::IpAddress => {
+        let ip = IpTypeOptionBuilder::default();
+        let ip_field = FieldBuilder::new(ip)
+          .name("IP address")
+          .visibility(true)
+          .build();
+        database_builder.add_field(ip_field);
+      },
+      FieldType::Json => {
+        let json = JsonTypeOptionBuilder::default();
+        let json_field = FieldBuilder::new(json)
+          .name("Metadata")
+          .visibility(true)
+          .build();
+        database_builder.add_field(json_field);
+      },
+      FieldType::Email => {
+        let email = EmailTypeOptionBuilder::default();
+        let email_field = FieldBuilder::new(email)
+          .name("Email")
+          .visibility(true)
+          .build();
+        database_builder.add_field(email_field);
+      },
+      FieldType::Phone => {
+        let phone = PhoneTypeOptionBuilder::default();
+        let phone_field = FieldBuilder::new(phone)
+          .name("Phone")
+          .visibility(true)
+          .build();
+        database_builder.add_field(phone_field);
+      },
     }
   }
 
@@ -1,6 +1,6 @@
 use crate::database::database_editor::DatabaseEditorTest;
 use async_stream::stream;
-use database_model::{FieldRevision, SortCondition, SortRevision};
+use database_model::{FieldRevision, NullsOrder, SortCondition, SortRevision};
 use flowy_database::entities::{AlterSortParams, CellIdParams, DeleteSortParams};
 use flowy_database::services::database_view::DatabaseViewChanged;
 use flowy_database::services::sort::SortType;
@@ -15,6 +15,11 @@ pub enum SortScript {
     field_rev: Arc<FieldRevision>,
     condition: SortCondition,
   },
+  InsertSortWithNullsOrder {
+    field_rev: Arc<FieldRevision>,
+    condition: SortCondition,
+    nulls_order: NullsOrder,
+  },
   DeleteSort {
     field_rev: Arc<FieldRevision>,
     sort_id: String,
@@ -57,28 +62,47 @@ impl DatabaseSortTest {
     }
   }
 
+  async fn insert_sort(
+    &mut self,
+    field_rev: Arc<FieldRevision>,
+    condition: SortCondition,
+    nulls_order: Option<NullsOrder>,
+  ) {
+    self.recv = Some(
+      self
+        .editor
+        .subscribe_view_changed(&self.view_id)
+        .await
+        .unwrap(),
+    );
+    let params = AlterSortParams {
+      view_id: self.view_id.clone(),
+      field_id: field_rev.id.clone(),
+      sort_id: None,
+      field_type: field_rev.ty,
+      condition: condition.into(),
+      nulls_order: nulls_order.map(|nulls_order| nulls_order as u8),
+    };
+    let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
+    self.current_sort_rev = Some(sort_rev);
+  }
+
   pub async fn run_script(&mut self, script: SortScript) {
     match script {
       SortScript::InsertSort {
         condition,
         field_rev,
       } => {
-        self.recv = Some(
-          self
-            .editor
-            .subscribe_view_changed(&self.view_id)
-            .await
-            .unwrap(),
-        );
-        let params = AlterSortParams {
-          view_id: self.view_id.clone(),
-          field_id: field_rev.id.clone(),
-          sort_id: None,
-          field_type: field_rev.ty,
-          condition: condition.into(),
-        };
-        let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
-        self.current_sort_rev = Some(sort_rev);
+        self.insert_sort(field_rev, condition, None).await;
+      },
+      SortScript::InsertSortWithNullsOrder {
+        condition,
+        field_rev,
+        nulls_order,
+      } => {
+        self
+          .insert_sort(field_rev, condition, Some(nulls_order))
+          .await;
       },
       SortScript::DeleteSort { field_rev, sort_id } => {
         self.recv = Some(
@@ -1,5 +1,5 @@
 use crate::database::sort_test::script::{DatabaseSortTest, SortScript::*};
-use database_model::SortCondition;
+use database_model::{NullsOrder, SortCondition};
 use flowy_database::entities::FieldType;
 
 #[tokio::test]
@@ -254,7 +254,14 @@ async fn sort_multi_select_by_ascending_test() {
   let scripts = vec![
     AssertCellContentOrder {
       field_id: multi_select.id.clone(),
-      orders: vec!["Google,Facebook", "Google,Twitter", "Facebook", "", "", ""],
+      orders: vec![
+        "Google, Facebook",
+        "Google, Twitter",
+        "Facebook",
+        "",
+        "",
+        "",
+      ],
     },
     InsertSort {
       field_rev: multi_select.clone(),
@@ -262,7 +269,50 @@ async fn sort_multi_select_by_ascending_test() {
     },
     AssertCellContentOrder {
       field_id: multi_select.id.clone(),
-      orders: vec!["", "", "", "Facebook", "Google,Facebook", "Google,Twitter"],
+      orders: vec![
+        "",
+        "",
+        "",
+        "Facebook",
+        "Google, Facebook",
+        "Google, Twitter",
+      ],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_number_by_descending_with_nulls_first_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let number_field = test.get_first_field_rev(FieldType::Number);
+  let scripts = vec![
+    InsertSortWithNullsOrder {
+      field_rev: number_field.clone(),
+      condition: SortCondition::Descending,
+      nulls_order: NullsOrder::First,
+    },
+    AssertCellContentOrder {
+      field_id: number_field.id.clone(),
+      orders: vec!["", "$5", "$4", "$3", "$2", "$1"],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_number_by_ascending_with_nulls_last_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let number_field = test.get_first_field_rev(FieldType::Number);
+  let scripts = vec![
+    InsertSortWithNullsOrder {
+      field_rev: number_field.clone(),
+      condition: SortCondition::Ascending,
+      nulls_order: NullsOrder::Last,
+    },
+    AssertCellContentOrder {
+      field_id: number_field.id.clone(),
+      orders: vec!["$1", "$2", "$3", "$4", "$5", ""],
     },
   ];
   test.run_scripts(scripts).await;
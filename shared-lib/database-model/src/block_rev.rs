@@ -17,6 +17,7 @@ pub struct DatabaseBlockRevision {
 }
 
 pub type FieldId = String;
+pub type RowId = String;
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RowRevision {
   pub id: String,
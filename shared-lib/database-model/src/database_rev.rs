@@ -118,6 +118,10 @@ pub struct FieldRevision {
 
   #[serde(default = "DEFAULT_IS_PRIMARY_VALUE")]
   pub is_primary: bool,
+
+  /// The cell of the required field must not be empty when submitting the form.
+  #[serde(default)]
+  pub is_required: bool,
 }
 
 impl AsRef<FieldRevision> for FieldRevision {
@@ -146,6 +150,7 @@ impl FieldRevision {
       width,
       type_options: Default::default(),
       is_primary,
+      is_required: false,
     }
   }
 
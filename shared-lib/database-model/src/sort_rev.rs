@@ -8,6 +8,11 @@ pub struct SortRevision {
   pub field_id: String,
   pub field_type: FieldTypeRevision,
   pub condition: SortCondition,
+
+  /// Where the empty cells go, whatever the condition is. None means the empty cells are compared
+  /// as the default value of the field type.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub nulls_order: Option<NullsOrder>,
 }
 
 #[derive(Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, Clone, Debug)]
@@ -38,3 +43,36 @@ impl std::convert::From<SortCondition> for u8 {
     condition as u8
   }
 }
+
+#[derive(Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(u8)]
+pub enum NullsOrder {
+  First = 0,
+  Last = 1,
+}
+
+impl NullsOrder {
+  /// Returns the order of the empty cell compared to the non-empty cell.
+  pub fn empty_cell_order(&self) -> std::cmp::Ordering {
+    match self {
+      NullsOrder::First => std::cmp::Ordering::Less,
+      NullsOrder::Last => std::cmp::Ordering::Greater,
+    }
+  }
+
+  pub fn reverse(&self) -> Self {
+    match self {
+      NullsOrder::First => NullsOrder::Last,
+      NullsOrder::Last => NullsOrder::First,
+    }
+  }
+}
+
+impl std::convert::From<u8> for NullsOrder {
+  fn from(num: u8) -> Self {
+    match num {
+      0 => NullsOrder::First,
+      _ => NullsOrder::Last,
+    }
+  }
+}